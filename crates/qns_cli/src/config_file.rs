@@ -0,0 +1,326 @@
+//! `qns.toml` configuration file.
+//!
+//! Settings are resolved in three layers: built-in defaults, then the config
+//! file, then explicit command-line flags. Every file entry is optional; an
+//! omitted entry keeps the built-in default.
+//!
+//! ```toml
+//! topology = "grid"
+//! shots = 2000
+//!
+//! [gate_times]
+//! single_qubit = 35.0
+//! two_qubit = 300.0
+//! measure = 1000.0
+//!
+//! [rewire]
+//! max_variants = 20
+//! beam_width = 5
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use qns_core::{QnsError, Result};
+
+use crate::pipeline::PipelineConfig;
+
+/// File name looked up in the working directory when no path is given.
+pub const DEFAULT_CONFIG_FILE: &str = "qns.toml";
+
+/// Built-in topology when neither the file nor a flag sets one.
+pub const DEFAULT_TOPOLOGY: &str = "linear";
+
+/// Contents of a `qns.toml` file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QnsFileConfig {
+    /// Hardware topology (linear, grid, all-to-all)
+    pub topology: Option<String>,
+    /// Number of simulation shots
+    pub shots: Option<usize>,
+    /// Gate durations used for scoring
+    pub gate_times: GateTimesSection,
+    /// Live rewirer settings
+    pub rewire: RewireSection,
+}
+
+/// `[gate_times]` section, in nanoseconds.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GateTimesSection {
+    /// Single-qubit gate time
+    pub single_qubit: Option<f64>,
+    /// Two-qubit gate time
+    pub two_qubit: Option<f64>,
+    /// Measurement time
+    pub measure: Option<f64>,
+}
+
+/// `[rewire]` section.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RewireSection {
+    /// Maximum number of variants to evaluate
+    pub max_variants: Option<usize>,
+    /// Maximum depth for variant generation
+    pub max_depth: Option<usize>,
+    /// Beam width for beam search
+    pub beam_width: Option<usize>,
+    /// Variant count above which beam search replaces BFS
+    pub beam_search_threshold: Option<usize>,
+    /// Minimum fidelity threshold to consider a circuit valid
+    pub min_fidelity_threshold: Option<f64>,
+    /// Enable hardware-aware optimization
+    pub hardware_aware: Option<bool>,
+    /// Enable parallel evaluation
+    pub parallel: Option<bool>,
+    /// Weight for crosstalk penalty in routing and scoring
+    pub crosstalk_weight: Option<f64>,
+    /// Use SABRE router instead of the basic noise-aware router
+    pub use_sabre: Option<bool>,
+}
+
+/// Settings given explicitly on the command line; these win over the file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CliOverrides {
+    /// `--topology`
+    pub topology: Option<String>,
+    /// `--shots`
+    pub shots: Option<usize>,
+    /// `--max-variants`
+    pub max_variants: Option<usize>,
+    /// `--beam-width`
+    pub beam_width: Option<usize>,
+}
+
+/// Fully resolved run settings.
+#[derive(Debug, Clone)]
+pub struct RunSettings {
+    /// Hardware topology name
+    pub topology: String,
+    /// Number of simulation shots
+    pub shots: usize,
+    /// Pipeline configuration
+    pub pipeline: PipelineConfig,
+}
+
+impl QnsFileConfig {
+    /// Parses config file contents.
+    pub fn parse(text: &str) -> Result<Self> {
+        let config: Self = toml::from_str(text).map_err(|e| QnsError::config(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Reads and parses the config file at `path`.
+    ///
+    /// Errors name the file, so a malformed config is easy to locate.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            QnsError::config(format!("cannot read config file {}: {}", path.display(), e))
+        })?;
+        Self::parse(&text).map_err(|e| match e {
+            QnsError::Config(msg) => {
+                QnsError::config(format!("invalid config file {}: {}", path.display(), msg))
+            },
+            other => other,
+        })
+    }
+
+    /// Loads `explicit` if given, otherwise `qns.toml` in the working
+    /// directory if it exists.
+    ///
+    /// Returns `Ok(None)` only when no path was given and no default file
+    /// exists; an explicit path that cannot be read is an error.
+    pub fn discover(explicit: Option<&Path>) -> Result<Option<(PathBuf, Self)>> {
+        let path = match explicit {
+            Some(path) => path.to_path_buf(),
+            None => {
+                let path = PathBuf::from(DEFAULT_CONFIG_FILE);
+                if !path.is_file() {
+                    return Ok(None);
+                }
+                path
+            },
+        };
+        let config = Self::load(&path)?;
+        Ok(Some((path, config)))
+    }
+
+    /// Rejects values no pipeline could run with.
+    fn validate(&self) -> Result<()> {
+        let times = [
+            ("gate_times.single_qubit", self.gate_times.single_qubit),
+            ("gate_times.two_qubit", self.gate_times.two_qubit),
+            ("gate_times.measure", self.gate_times.measure),
+        ];
+        for (key, value) in times {
+            if let Some(t) = value {
+                if !(t.is_finite() && t > 0.0) {
+                    return Err(QnsError::config(format!(
+                        "{} must be a positive number, got {}",
+                        key, t
+                    )));
+                }
+            }
+        }
+        let counts = [
+            ("shots", self.shots),
+            ("rewire.max_variants", self.rewire.max_variants),
+            ("rewire.beam_width", self.rewire.beam_width),
+        ];
+        for (key, value) in counts {
+            if value == Some(0) {
+                return Err(QnsError::config(format!("{} must be at least 1", key)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the file's settings on top of `config`.
+    pub fn apply_to(&self, config: &mut PipelineConfig) {
+        if let Some(shots) = self.shots {
+            config.simulation_shots = shots;
+        }
+
+        let score = &mut config.rewirer.score_config;
+        let times = &self.gate_times;
+        if let Some(t) = times.single_qubit {
+            score.gate_time_1q = t;
+        }
+        if let Some(t) = times.two_qubit {
+            score.gate_time_2q = t;
+        }
+        if let Some(t) = times.measure {
+            score.measure_time = t;
+        }
+
+        let rewirer = &mut config.rewirer;
+        let rewire = &self.rewire;
+        if let Some(v) = rewire.max_variants {
+            rewirer.max_variants = v;
+        }
+        if let Some(v) = rewire.max_depth {
+            rewirer.max_depth = v;
+        }
+        if let Some(v) = rewire.beam_width {
+            rewirer.beam_width = v;
+        }
+        if let Some(v) = rewire.beam_search_threshold {
+            rewirer.beam_search_threshold = v;
+        }
+        if let Some(v) = rewire.min_fidelity_threshold {
+            rewirer.min_fidelity_threshold = v;
+        }
+        if let Some(v) = rewire.hardware_aware {
+            rewirer.hardware_aware = v;
+        }
+        if let Some(v) = rewire.parallel {
+            rewirer.parallel = v;
+        }
+        if let Some(v) = rewire.crosstalk_weight {
+            rewirer.crosstalk_weight = v;
+        }
+        if let Some(v) = rewire.use_sabre {
+            rewirer.use_sabre = v;
+        }
+    }
+
+    /// Resolves run settings: built-in defaults, then this file, then
+    /// `overrides`.
+    pub fn resolve(&self, overrides: &CliOverrides) -> RunSettings {
+        let mut pipeline = PipelineConfig::default();
+        self.apply_to(&mut pipeline);
+
+        if let Some(shots) = overrides.shots {
+            pipeline.simulation_shots = shots;
+        }
+        if let Some(v) = overrides.max_variants {
+            pipeline.rewirer.max_variants = v;
+        }
+        if let Some(v) = overrides.beam_width {
+            pipeline.rewirer.beam_width = v;
+        }
+
+        let topology = overrides
+            .topology
+            .clone()
+            .or_else(|| self.topology.clone())
+            .unwrap_or_else(|| DEFAULT_TOPOLOGY.to_string());
+
+        RunSettings {
+            topology,
+            shots: pipeline.simulation_shots,
+            pipeline,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::QnsSystem;
+
+    #[test]
+    fn test_file_settings_respected_unless_overridden() {
+        let file = QnsFileConfig::parse(
+            r#"
+            topology = "grid"
+
+            [gate_times]
+            two_qubit = 450.0
+
+            [rewire]
+            max_variants = 5
+            "#,
+        )
+        .unwrap();
+
+        let settings = file.resolve(&CliOverrides::default());
+        let system = QnsSystem::with_config(settings.pipeline);
+        assert_eq!(system.config().rewirer.max_variants, 5);
+        assert_eq!(system.config().rewirer.score_config.gate_time_2q, 450.0);
+        assert_eq!(settings.topology, "grid");
+        // Untouched entries keep the built-in defaults
+        let defaults = PipelineConfig::default();
+        assert_eq!(settings.shots, defaults.simulation_shots);
+        assert_eq!(
+            system.config().rewirer.beam_width,
+            defaults.rewirer.beam_width
+        );
+
+        let overrides = CliOverrides {
+            topology: Some("linear".to_string()),
+            max_variants: Some(12),
+            ..Default::default()
+        };
+        let settings = file.resolve(&overrides);
+        let system = QnsSystem::with_config(settings.pipeline);
+        assert_eq!(system.config().rewirer.max_variants, 12);
+        assert_eq!(settings.topology, "linear");
+    }
+
+    #[test]
+    fn test_malformed_config_is_reported() {
+        let path = std::env::temp_dir().join(format!("qns_config_{}.toml", std::process::id()));
+        std::fs::write(&path, "[rewire]\nmax_variants = \"five\"\n").unwrap();
+        let err = QnsFileConfig::load(&path).unwrap_err().to_string();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.contains(&path.display().to_string()), "{}", err);
+        assert!(err.contains("max_variants"), "{}", err);
+
+        // Unknown keys are typos, not silently ignored
+        let err = QnsFileConfig::parse("[rewire]\nmax_variant = 5\n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("max_variant"), "{}", err);
+
+        assert!(QnsFileConfig::parse("[gate_times]\ntwo_qubit = -1.0\n").is_err());
+
+        // An explicit path must exist
+        let missing = std::env::temp_dir().join("qns_config_does_not_exist.toml");
+        assert!(QnsFileConfig::discover(Some(&missing)).is_err());
+    }
+}
@@ -5,6 +5,8 @@
 //! This crate provides:
 //! - QnsSystem: Unified pipeline integrating all QNS components
 //! - CLI commands for profiling, optimization, and benchmarking
+//! - OptimizationObserver: Structured telemetry hooks for embedding
+//! - QnsFileConfig: `qns.toml` defaults for the CLI
 //!
 //! ## Library Usage
 //!
@@ -22,6 +24,12 @@
 //! let result = system.optimize(circuit).unwrap();
 //! ```
 
+pub mod config_file;
+pub mod observer;
 pub mod pipeline;
 
-pub use pipeline::{BenchmarkResult, PipelineConfig, PipelineResult, PipelineTiming, QnsSystem};
+pub use config_file::{CliOverrides, QnsFileConfig, RunSettings};
+pub use observer::{NoopObserver, OptimizationObserver};
+pub use pipeline::{
+    BenchmarkResult, IterationTiming, PipelineConfig, PipelineResult, PipelineTiming, QnsSystem,
+};
@@ -5,6 +5,7 @@
 //! - Benchmarking performance
 //! - Profiling noise characteristics
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::time::Instant;
 
@@ -13,9 +14,11 @@ use clap::{Parser, Subcommand};
 use tracing::{info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use qns_cli::pipeline::QnsSystem;
+use qns_cli::pipeline::{PipelineConfig, QnsSystem};
+use qns_cli::{CliOverrides, QnsFileConfig, RunSettings};
 use qns_core::prelude::*;
 use qns_qasm::{parse_qasm, resolve_includes};
+use qns_rewire::{calculate_total_idle_time, ScoreConfig};
 use qns_zne::{ExtrapolationMethod, FidelityEstimator, ZneConfig, ZneExecutor};
 
 /// QNS - Quantum Noise Symbiote
@@ -33,6 +36,10 @@ struct Cli {
     #[arg(short, long, global = true, default_value = "text")]
     format: OutputFormat,
 
+    /// Config file (default: ./qns.toml if present)
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -51,13 +58,21 @@ enum Commands {
         #[arg(value_name = "FILE")]
         input: PathBuf,
 
-        /// Hardware topology (linear, grid, all-to-all)
-        #[arg(short, long, default_value = "linear")]
-        topology: String,
+        /// Hardware topology (linear, grid, all-to-all) [default: linear]
+        #[arg(short, long)]
+        topology: Option<String>,
+
+        /// Number of simulation shots [default: 1000]
+        #[arg(short, long)]
+        shots: Option<usize>,
+
+        /// Maximum number of rewiring variants to evaluate
+        #[arg(long)]
+        max_variants: Option<usize>,
 
-        /// Number of simulation shots
-        #[arg(short, long, default_value = "1000")]
-        shots: usize,
+        /// Beam width for the rewiring search
+        #[arg(long)]
+        beam_width: Option<usize>,
 
         /// Backend type (simulator, aer-ideal, aer-noisy, aer-ibm)
         #[arg(short, long, default_value = "simulator")]
@@ -78,6 +93,10 @@ enum Commands {
         /// Zero-Noise Extrapolation method (off, linear, richardson)
         #[arg(long, default_value = "off")]
         zne: String,
+
+        /// Write the measurement histogram as CSV (bitstring,count,probability)
+        #[arg(long, value_name = "FILE")]
+        counts_out: Option<PathBuf>,
     },
 
     /// Benchmark the QNS pipeline
@@ -93,6 +112,13 @@ enum Commands {
         /// Number of iterations
         #[arg(short, long, default_value = "10")]
         iterations: usize,
+
+        /// Emit one JSON line per iteration to stdout before the summary.
+        ///
+        /// With `--format json` the summary is one more JSON line; the text
+        /// summary goes to stderr so stdout stays JSON Lines.
+        #[arg(long)]
+        per_iteration: bool,
     },
 
     /// Profile noise characteristics
@@ -102,14 +128,18 @@ enum Commands {
         qubits: usize,
     },
 
-    /// Show system information
-    Info,
+    /// Show system information, or statistics for a circuit file
+    Info {
+        /// QASM file to profile without running it
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+    },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    // Setup logging
+    // Setup logging (stderr, so stdout carries only command output)
     let level = if cli.verbose {
         Level::DEBUG
     } else {
@@ -119,6 +149,7 @@ fn main() -> Result<()> {
         .with_max_level(level)
         .with_target(false)
         .without_time()
+        .with_writer(std::io::stderr)
         .try_init();
 
     match cli.command {
@@ -126,62 +157,116 @@ fn main() -> Result<()> {
             input,
             topology,
             shots,
+            max_variants,
+            beam_width,
             backend,
             ibm_backend,
             no_optimize,
             crosstalk_weight,
             zne,
-        } => cmd_run(
-            &input,
-            &topology,
-            shots,
-            &backend,
-            ibm_backend.as_deref(),
-            no_optimize,
-            cli.format,
-            crosstalk_weight,
-            &zne,
-        ),
+            counts_out,
+        } => {
+            let file_config = load_file_config(cli.config.as_deref())?;
+            let settings = file_config.resolve(&CliOverrides {
+                topology,
+                shots,
+                max_variants,
+                beam_width,
+            });
+            cmd_run(
+                &input,
+                settings,
+                &backend,
+                ibm_backend.as_deref(),
+                no_optimize,
+                cli.format,
+                crosstalk_weight,
+                &zne,
+                counts_out.as_deref(),
+            )
+        },
         Commands::Benchmark {
             qubits,
             gates,
             iterations,
-        } => cmd_benchmark(qubits, gates, iterations, cli.format),
-        Commands::Profile { qubits } => cmd_profile(qubits, cli.format),
-        Commands::Info => cmd_info(cli.format),
+            per_iteration,
+        } => {
+            let config = load_pipeline_config(cli.config.as_deref())?;
+            cmd_benchmark(config, qubits, gates, iterations, per_iteration, cli.format)
+        },
+        Commands::Profile { qubits } => {
+            let config = load_pipeline_config(cli.config.as_deref())?;
+            cmd_profile(config, qubits, cli.format)
+        },
+        Commands::Info { input } => match input {
+            Some(input) => {
+                let config = load_pipeline_config(cli.config.as_deref())?;
+                cmd_circuit_info(&input, &config.rewirer.score_config, cli.format)
+            },
+            None => cmd_info(cli.format),
+        },
     }
 }
 
+/// Load the `--config` file, or `./qns.toml` if present
+fn load_file_config(explicit: Option<&std::path::Path>) -> Result<QnsFileConfig> {
+    Ok(match QnsFileConfig::discover(explicit)? {
+        Some((path, config)) => {
+            info!("Loaded config file {}", path.display());
+            config
+        },
+        None => QnsFileConfig::default(),
+    })
+}
+
+/// Pipeline settings from the config file, for commands without overrides
+fn load_pipeline_config(explicit: Option<&std::path::Path>) -> Result<PipelineConfig> {
+    Ok(load_file_config(explicit)?
+        .resolve(&CliOverrides::default())
+        .pipeline)
+}
+
+/// Read and parse a QASM file, resolving includes relative to its directory
+fn load_qasm(input: &std::path::Path) -> Result<CircuitGenome> {
+    let qasm_content = std::fs::read_to_string(input)
+        .with_context(|| format!("Failed to read QASM file: {}", input.display()))?;
+
+    let base_path = input.parent().unwrap_or(std::path::Path::new("."));
+    let resolved_content =
+        resolve_includes(&qasm_content, base_path).with_context(|| "Failed to resolve includes")?;
+
+    parse_qasm(&resolved_content).with_context(|| "Failed to parse QASM")
+}
+
 /// Run a QASM circuit through the pipeline
 #[allow(clippy::too_many_arguments)]
 fn cmd_run(
-    input: &PathBuf,
-    topology: &str,
-    shots: usize,
+    input: &std::path::Path,
+    settings: RunSettings,
     backend: &str,
     ibm_backend: Option<&str>,
     no_optimize: bool,
     format: OutputFormat,
     crosstalk_weight: f64,
     zne_method: &str,
+    counts_out: Option<&std::path::Path>,
 ) -> Result<()> {
     let start = Instant::now();
+    let RunSettings {
+        topology,
+        shots,
+        pipeline: mut config,
+    } = settings;
 
     // Handle Qiskit backends
     if backend != "simulator" {
+        if counts_out.is_some() {
+            warn!("--counts-out is only supported with the simulator backend");
+        }
         return cmd_run_qiskit(input, backend, ibm_backend, shots, format);
     }
 
-    // Read and parse QASM file
-    let qasm_content = std::fs::read_to_string(input)
-        .with_context(|| format!("Failed to read QASM file: {}", input.display()))?;
-
-    // Resolve includes relative to the input file's directory
-    let base_path = input.parent().unwrap_or(std::path::Path::new("."));
-    let resolved_content =
-        resolve_includes(&qasm_content, base_path).with_context(|| "Failed to resolve includes")?;
-
-    let circuit = parse_qasm(&resolved_content).with_context(|| "Failed to parse QASM")?;
+    let circuit = load_qasm(input)?;
 
     let original_gates = circuit.gates.len();
     let num_qubits = circuit.num_qubits;
@@ -192,7 +277,7 @@ fn cmd_run(
     );
 
     // Create hardware profile based on topology
-    let hardware = match topology {
+    let hardware = match topology.as_str() {
         "linear" => HardwareProfile::linear("qns-linear", num_qubits),
         "grid" => {
             let side = (num_qubits as f64).sqrt().ceil() as usize;
@@ -206,8 +291,6 @@ fn cmd_run(
     };
 
     // Create QNS system
-    let mut config = qns_cli::pipeline::PipelineConfig::default();
-
     // Configure crosstalk awareness
     if crosstalk_weight > 0.0 {
         config.rewirer.crosstalk_weight = crosstalk_weight;
@@ -222,9 +305,10 @@ fn cmd_run(
     let mut system = QnsSystem::with_config(config);
     system.set_hardware(hardware);
 
-    let result = if no_optimize {
+    let (result, measurement) = if no_optimize {
         // Just simulate without optimization
-        RunResult {
+        let measurement = system.sample(&circuit)?;
+        let result = RunResult {
             input_file: input.display().to_string(),
             num_qubits,
             original_gates,
@@ -238,7 +322,8 @@ fn cmd_run(
             topology: topology.to_string(),
             zne_method: "off".to_string(),
             zne_zero_noise_fidelity: None,
-        }
+        };
+        (result, measurement)
     } else {
         // Full optimization pipeline
         let pipeline_result = system
@@ -289,7 +374,7 @@ fn cmd_run(
             (None, "off".to_string())
         };
 
-        RunResult {
+        let result = RunResult {
             input_file: input.display().to_string(),
             num_qubits,
             original_gates,
@@ -303,9 +388,21 @@ fn cmd_run(
             topology: topology.to_string(),
             zne_method: zne_used,
             zne_zero_noise_fidelity: zne_fidelity,
-        }
+        };
+        (result, pipeline_result.measurement)
     };
 
+    if let Some(path) = counts_out {
+        std::fs::write(path, measurement.to_csv())
+            .with_context(|| format!("Failed to write counts CSV: {}", path.display()))?;
+        info!(
+            "Wrote {} outcomes ({} shots) to {}",
+            measurement.counts.len(),
+            measurement.shots,
+            path.display()
+        );
+    }
+
     // Output result
     match format {
         OutputFormat::Text => {
@@ -346,9 +443,11 @@ fn cmd_run(
 
 /// Run benchmark
 fn cmd_benchmark(
+    config: PipelineConfig,
     qubits: usize,
     gates: usize,
     iterations: usize,
+    per_iteration: bool,
     format: OutputFormat,
 ) -> Result<()> {
     info!(
@@ -356,10 +455,31 @@ fn cmd_benchmark(
         qubits, gates, iterations
     );
 
-    let mut system = QnsSystem::new();
-    let result = system.benchmark(qubits, gates, iterations);
+    let mut system = QnsSystem::with_config(config);
+    let mut line_error = None;
+    let result = system.benchmark_with(qubits, gates, iterations, |timing| {
+        if !per_iteration || line_error.is_some() {
+            return;
+        }
+        let line = BenchmarkIterationJson {
+            iteration: timing.iteration,
+            profile_ms: timing.profile.as_secs_f64() * 1000.0,
+            optimize_ms: timing.optimize.as_secs_f64() * 1000.0,
+            simulate_ms: timing.simulate.as_secs_f64() * 1000.0,
+        };
+        match serde_json::to_string(&line) {
+            Ok(json) => println!("{}", json),
+            Err(e) => line_error = Some(e),
+        }
+    });
+    if let Some(e) = line_error {
+        return Err(e.into());
+    }
 
     match format {
+        OutputFormat::Text if per_iteration => {
+            eprintln!("{}", result);
+        },
         OutputFormat::Text => {
             println!("{}", result);
         },
@@ -374,7 +494,11 @@ fn cmd_benchmark(
                 avg_optimize_ms: result.avg_optimize.as_secs_f64() * 1000.0,
                 avg_simulate_ms: result.avg_simulate.as_secs_f64() * 1000.0,
             };
-            println!("{}", serde_json::to_string_pretty(&json_result)?);
+            if per_iteration {
+                println!("{}", serde_json::to_string(&json_result)?);
+            } else {
+                println!("{}", serde_json::to_string_pretty(&json_result)?);
+            }
         },
     }
 
@@ -382,10 +506,10 @@ fn cmd_benchmark(
 }
 
 /// Profile noise characteristics
-fn cmd_profile(qubits: usize, format: OutputFormat) -> Result<()> {
+fn cmd_profile(config: PipelineConfig, qubits: usize, format: OutputFormat) -> Result<()> {
     info!("Profiling noise for {} qubits", qubits);
 
-    let mut system = QnsSystem::new();
+    let mut system = QnsSystem::with_config(config);
     let qubit_ids: Vec<usize> = (0..qubits).collect();
     let profiles = system.profile_noise(&qubit_ids)?;
 
@@ -465,6 +589,54 @@ fn cmd_info(format: OutputFormat) -> Result<()> {
     Ok(())
 }
 
+/// Show structural statistics of a QASM circuit
+fn cmd_circuit_info(
+    input: &std::path::Path,
+    score_config: &ScoreConfig,
+    format: OutputFormat,
+) -> Result<()> {
+    let circuit = load_qasm(input)?;
+
+    let mut gate_counts = BTreeMap::new();
+    for gate in &circuit.gates {
+        *gate_counts.entry(gate.name().to_string()).or_insert(0) += 1;
+    }
+    let info = CircuitInfo {
+        input_file: input.display().to_string(),
+        num_qubits: circuit.num_qubits,
+        gate_count: circuit.gate_count(),
+        gate_counts,
+        depth: circuit.depth(),
+        two_qubit_depth: circuit.two_qubit_depth(),
+        t_count: circuit.t_count(),
+        t_depth: circuit.t_depth(),
+        idle_time_ns: calculate_total_idle_time(&circuit, score_config),
+    };
+
+    match format {
+        OutputFormat::Text => {
+            println!("\n=== Circuit Info ===");
+            println!("Input:      {}", info.input_file);
+            println!("Qubits:     {}", info.num_qubits);
+            println!("Gates:      {}", info.gate_count);
+            for (name, count) in &info.gate_counts {
+                println!("  {:<8}  {}", name, count);
+            }
+            println!();
+            println!("Depth:      {}", info.depth);
+            println!("2Q Depth:   {}", info.two_qubit_depth);
+            println!("T-count:    {}", info.t_count);
+            println!("T-depth:    {}", info.t_depth);
+            println!("Idle Time:  {:.1} ns", info.idle_time_ns);
+        },
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&info)?);
+        },
+    }
+
+    Ok(())
+}
+
 // JSON output structures
 
 #[derive(serde::Serialize)]
@@ -485,6 +657,14 @@ struct RunResult {
     zne_zero_noise_fidelity: Option<f64>,
 }
 
+#[derive(serde::Serialize)]
+struct BenchmarkIterationJson {
+    iteration: usize,
+    profile_ms: f64,
+    optimize_ms: f64,
+    simulate_ms: f64,
+}
+
 #[derive(serde::Serialize)]
 struct BenchmarkJsonResult {
     iterations: usize,
@@ -507,6 +687,19 @@ struct NoiseProfileJson {
     readout_error: f64,
 }
 
+#[derive(serde::Serialize)]
+struct CircuitInfo {
+    input_file: String,
+    num_qubits: usize,
+    gate_count: usize,
+    gate_counts: BTreeMap<String, usize>,
+    depth: usize,
+    two_qubit_depth: usize,
+    t_count: usize,
+    t_depth: usize,
+    idle_time_ns: f64,
+}
+
 #[derive(serde::Serialize)]
 struct SystemInfo {
     name: String,
@@ -0,0 +1,53 @@
+//! Observer hooks for pipeline telemetry.
+//!
+//! Library users embedding QNS can receive structured events from
+//! [`QnsSystem`](crate::pipeline::QnsSystem) instead of parsing log output.
+//!
+//! ## Event Order
+//!
+//! For a single `optimize` call:
+//!
+//! ```text
+//! on_stage_start("profiling")    → on_stage_finish("profiling", ..)
+//! on_stage_start("optimization") → on_variant_scored(..)* → on_stage_finish("optimization", ..)
+//! on_stage_start("simulation")   → on_stage_finish("simulation", ..)
+//! on_complete(..)
+//! ```
+
+use qns_rewire::OptimizationResult;
+use std::time::Duration;
+
+/// Stage name for noise profiling.
+pub const STAGE_PROFILING: &str = "profiling";
+/// Stage name for circuit optimization.
+pub const STAGE_OPTIMIZATION: &str = "optimization";
+/// Stage name for simulation verification.
+pub const STAGE_SIMULATION: &str = "simulation";
+
+/// Receives structured events from the optimization pipeline.
+///
+/// All methods default to no-ops, so implementors only override the events
+/// they care about. Observers are shared across batch worker threads and
+/// must therefore be `Send + Sync`.
+pub trait OptimizationObserver: Send + Sync {
+    /// Called when a pipeline stage begins.
+    fn on_stage_start(&self, _stage: &str) {}
+
+    /// Called when a pipeline stage ends successfully.
+    fn on_stage_finish(&self, _stage: &str, _elapsed: Duration) {}
+
+    /// Called with the estimated fidelity of each variant the rewirer scores.
+    ///
+    /// Fires once per evaluated variant, possibly from several threads at
+    /// once when the rewirer scores in parallel.
+    fn on_variant_scored(&self, _fidelity: f64) {}
+
+    /// Called once the optimization result is available.
+    fn on_complete(&self, _result: &OptimizationResult) {}
+}
+
+/// Observer that ignores every event (the default).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopObserver;
+
+impl OptimizationObserver for NoopObserver {}
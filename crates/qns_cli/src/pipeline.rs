@@ -29,10 +29,15 @@
 //! Optimization Result
 //! ```
 
+use crate::observer::{
+    NoopObserver, OptimizationObserver, STAGE_OPTIMIZATION, STAGE_PROFILING, STAGE_SIMULATION,
+};
 use qns_core::prelude::*;
 use qns_profiler::{DriftScanner, ScanConfig};
 use qns_rewire::{LiveRewirer, OptimizationResult, RewireConfig as LiveRewireConfig};
 use qns_simulator::StateVectorSimulator;
+use rayon::prelude::*;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// Configuration for the QNS pipeline.
@@ -46,6 +51,16 @@ pub struct PipelineConfig {
     pub simulation_shots: usize,
     /// Enable detailed logging
     pub verbose: bool,
+    /// Re-profiles allowed when a scan reports an anomaly (0 = use the first scan).
+    ///
+    /// Each re-profile clears the history of the anomalous qubits, so a
+    /// transient bad calibration does not leak into the noise profile.
+    pub max_reprofiles: usize,
+    /// Distribute [`QnsSystem::optimize_batch`] circuits across threads.
+    ///
+    /// Independent of `rewirer.parallel`, which parallelizes variant
+    /// evaluation inside a single optimization.
+    pub parallel_batch: bool,
 }
 
 impl Default for PipelineConfig {
@@ -55,6 +70,8 @@ impl Default for PipelineConfig {
             rewirer: LiveRewireConfig::default(),
             simulation_shots: 1000,
             verbose: false,
+            max_reprofiles: 0,
+            parallel_batch: true,
         }
     }
 }
@@ -68,6 +85,9 @@ pub struct PipelineResult {
     pub optimized_circuit: CircuitGenome,
     /// Noise profile used for optimization
     pub noise_profile: NoiseVector,
+    /// Re-profiles triggered by scanner anomalies (see
+    /// [`PipelineConfig::max_reprofiles`]); 0 when the first scan was clean
+    pub anomaly_reprofiles: usize,
     /// Optimization details
     pub optimization: OptimizationResult,
     /// Original circuit fidelity estimate
@@ -76,6 +96,9 @@ pub struct PipelineResult {
     pub optimized_fidelity: f64,
     /// Fidelity improvement
     pub fidelity_improvement: f64,
+    /// Measurement histogram of the optimized circuit
+    /// (`simulation_shots` shots on the ideal simulator)
+    pub measurement: ExecutionResult,
     /// Total pipeline execution time
     pub total_time: Duration,
     /// Time breakdown
@@ -124,6 +147,8 @@ pub struct QnsSystem {
     scanner: DriftScanner,
     /// Live rewirer
     rewirer: LiveRewirer,
+    /// Telemetry observer
+    observer: Arc<dyn OptimizationObserver>,
 }
 
 impl QnsSystem {
@@ -135,6 +160,7 @@ impl QnsSystem {
             rewirer: LiveRewirer::new(),
             config,
             hardware: None,
+            observer: Arc::new(NoopObserver),
         }
     }
 
@@ -145,9 +171,25 @@ impl QnsSystem {
             rewirer: LiveRewirer::with_config(config.rewirer.clone()),
             config,
             hardware: None,
+            observer: Arc::new(NoopObserver),
         }
     }
 
+    /// Attaches an observer that receives structured pipeline events.
+    pub fn with_observer(mut self, observer: Box<dyn OptimizationObserver>) -> Self {
+        self.observer = Arc::from(observer);
+        self
+    }
+
+    /// Replaces the drift scanner, e.g. one carrying earlier scan history.
+    ///
+    /// `config.scanner` is updated to match, so batch workers scan alike.
+    pub fn with_scanner(mut self, scanner: DriftScanner) -> Self {
+        self.config.scanner = scanner.config().clone();
+        self.scanner = scanner;
+        self
+    }
+
     /// Sets the hardware profile for hardware-aware optimization.
     pub fn set_hardware(&mut self, hardware: HardwareProfile) {
         self.hardware = Some(hardware.clone());
@@ -165,6 +207,10 @@ impl QnsSystem {
     }
 
     /// Optimizes a circuit using the full pipeline.
+    ///
+    /// The pipeline only reorders gates, so every circuit keeps its qubit
+    /// assignment; circuits flagged [`pre_mapped`](qns_core::CircuitMetadata::pre_mapped)
+    /// are guaranteed never to be placed or routed.
     pub fn optimize(&mut self, circuit: CircuitGenome) -> Result<PipelineResult> {
         let start = Instant::now();
         let mut timing = PipelineTiming::default();
@@ -172,27 +218,41 @@ impl QnsSystem {
         let original_circuit = circuit.clone();
 
         // Step 1: Profile noise
+        self.observer.on_stage_start(STAGE_PROFILING);
         let profile_start = Instant::now();
         let qubit_ids: Vec<usize> = (0..circuit.num_qubits).collect();
-        let scan_results = self.scanner.scan_batch(&qubit_ids)?;
+        let (scan_results, anomaly_reprofiles) = self.scan_until_clean(&qubit_ids)?;
 
         // Aggregate noise profile
         let noise_profile = self.aggregate_noise(&scan_results);
         timing.profiling_time = profile_start.elapsed();
+        self.observer
+            .on_stage_finish(STAGE_PROFILING, timing.profiling_time);
 
         // Step 2: Optimize circuit
+        self.observer.on_stage_start(STAGE_OPTIMIZATION);
         let opt_start = Instant::now();
         self.rewirer.load(circuit)?;
-        let optimization = self
-            .rewirer
-            .optimize(&noise_profile, self.config.rewirer.max_variants)?;
+        let observer = &self.observer;
+        let optimization = self.rewirer.optimize_observed(
+            &noise_profile,
+            self.config.rewirer.max_variants,
+            |fidelity| observer.on_variant_scored(fidelity),
+        )?;
         timing.optimization_time = opt_start.elapsed();
+        self.observer
+            .on_stage_finish(STAGE_OPTIMIZATION, timing.optimization_time);
 
         // Step 3: Verify with simulation
+        self.observer.on_stage_start(STAGE_SIMULATION);
         let sim_start = Instant::now();
         let (original_fidelity, optimized_fidelity) =
             self.verify_optimization(&original_circuit, &optimization.circuit)?;
+        let measurement = self.sample(&optimization.circuit)?;
         timing.simulation_time = sim_start.elapsed();
+        self.observer
+            .on_stage_finish(STAGE_SIMULATION, timing.simulation_time);
+        self.observer.on_complete(&optimization);
 
         let fidelity_improvement = optimized_fidelity - original_fidelity;
 
@@ -200,20 +260,55 @@ impl QnsSystem {
             original_circuit,
             optimized_circuit: optimization.circuit.clone(),
             noise_profile,
+            anomaly_reprofiles,
             optimization,
             original_fidelity,
             optimized_fidelity,
             fidelity_improvement,
+            measurement,
             total_time: start.elapsed(),
             timing,
         })
     }
 
+    /// Optimizes a batch of circuits, e.g. the points of a parameter sweep.
+    ///
+    /// When `config.parallel_batch` is set the circuits are distributed across
+    /// threads; each worker gets its own rewirer, the same configuration,
+    /// hardware profile and observer, and a copy of the scanner as it was when
+    /// the batch started. Results are returned in input order.
+    ///
+    /// Sequentially, each circuit's scans feed the history seen by the next
+    /// one and stay in this system's scanner. Parallel workers only see the
+    /// history from before the batch, and their scans are discarded.
+    pub fn optimize_batch(&mut self, circuits: Vec<CircuitGenome>) -> Vec<Result<PipelineResult>> {
+        if !self.config.parallel_batch || circuits.len() < 2 {
+            return circuits.into_iter().map(|c| self.optimize(c)).collect();
+        }
+
+        let config = &self.config;
+        let hardware = self.hardware.as_ref();
+        let observer = &self.observer;
+        let scanner = &self.scanner;
+        circuits
+            .into_par_iter()
+            .map(|circuit| {
+                let mut worker = Self::with_config(config.clone());
+                worker.observer = Arc::clone(observer);
+                worker.scanner = scanner.clone();
+                if let Some(hw) = hardware {
+                    worker.set_hardware(hw.clone());
+                }
+                worker.optimize(circuit)
+            })
+            .collect()
+    }
+
     /// Quick optimization without simulation verification.
     pub fn quick_optimize(&mut self, circuit: CircuitGenome) -> Result<CircuitGenome> {
         // Profile noise
         let qubit_ids: Vec<usize> = (0..circuit.num_qubits).collect();
-        let scan_results = self.scanner.scan_batch(&qubit_ids)?;
+        let (scan_results, _) = self.scan_until_clean(&qubit_ids)?;
         let noise_profile = self.aggregate_noise(&scan_results);
 
         // Optimize
@@ -224,6 +319,32 @@ impl QnsSystem {
         Ok(result.circuit)
     }
 
+    /// Scans `qubit_ids`, re-profiling up to `config.max_reprofiles` times
+    /// while the scanner flags an anomaly.
+    ///
+    /// Returns the last scan and the number of re-profiles performed.
+    fn scan_until_clean(&mut self, qubit_ids: &[usize]) -> Result<(Vec<NoiseVector>, usize)> {
+        let mut results = self.scanner.scan_batch(qubit_ids)?;
+        let mut reprofiles = 0;
+        while reprofiles < self.config.max_reprofiles {
+            let anomalous: Vec<usize> = results
+                .iter()
+                .filter(|nv| self.scanner.is_anomaly(nv))
+                .map(|nv| nv.qubit_id)
+                .collect();
+            if anomalous.is_empty() {
+                break;
+            }
+            tracing::warn!("Noise anomaly on qubits {:?}; re-profiling", anomalous);
+            for &qubit in &anomalous {
+                self.scanner.clear_history(qubit);
+            }
+            results = self.scanner.scan_batch(qubit_ids)?;
+            reprofiles += 1;
+        }
+        Ok((results, reprofiles))
+    }
+
     /// Aggregates noise from multiple scan results.
     fn aggregate_noise(&self, results: &[NoiseVector]) -> NoiseVector {
         if results.is_empty() {
@@ -273,6 +394,17 @@ impl QnsSystem {
         Ok((orig_fidelity, opt_fidelity))
     }
 
+    /// Samples `circuit` on the ideal state-vector simulator with
+    /// `config.simulation_shots` shots.
+    pub fn sample(&self, circuit: &CircuitGenome) -> Result<ExecutionResult> {
+        let start = Instant::now();
+        let shots = self.config.simulation_shots;
+        let mut sim = StateVectorSimulator::new(circuit.num_qubits);
+        sim.run(circuit)?;
+        let counts = sim.measure(shots)?;
+        Ok(ExecutionResult::new(counts, shots, start.elapsed()))
+    }
+
     /// Runs a benchmark of the pipeline.
     pub fn benchmark(
         &mut self,
@@ -280,11 +412,27 @@ impl QnsSystem {
         num_gates: usize,
         iterations: usize,
     ) -> BenchmarkResult {
+        self.benchmark_with(num_qubits, num_gates, iterations, |_| {})
+    }
+
+    /// Runs a benchmark, calling `on_iteration` as soon as each iteration
+    /// finishes so timings can be streamed.
+    pub fn benchmark_with<F>(
+        &mut self,
+        num_qubits: usize,
+        num_gates: usize,
+        iterations: usize,
+        mut on_iteration: F,
+    ) -> BenchmarkResult
+    where
+        F: FnMut(&IterationTiming),
+    {
         let mut profile_time = Duration::ZERO;
         let mut optimize_time = Duration::ZERO;
         let mut simulate_time = Duration::ZERO;
+        let mut per_iteration = Vec::with_capacity(iterations);
 
-        for _ in 0..iterations {
+        for iteration in 0..iterations {
             // Create test circuit
             let circuit = Self::create_test_circuit(num_qubits, num_gates);
 
@@ -293,20 +441,33 @@ impl QnsSystem {
             let qubit_ids: Vec<usize> = (0..num_qubits).collect();
             let scan_results = self.scanner.scan_batch(&qubit_ids).unwrap_or_default();
             let noise = self.aggregate_noise(&scan_results);
-            profile_time += start.elapsed();
+            let profile = start.elapsed();
 
             // Optimize
             let start = Instant::now();
             self.rewirer.load(circuit.clone()).unwrap();
             let _ = self.rewirer.optimize(&noise, 10);
-            optimize_time += start.elapsed();
+            let optimize = start.elapsed();
 
             // Simulate
             let start = Instant::now();
             let mut sim = StateVectorSimulator::new(num_qubits);
             let _ = sim.run(&circuit);
             let _ = sim.measure(100);
-            simulate_time += start.elapsed();
+            let simulate = start.elapsed();
+
+            profile_time += profile;
+            optimize_time += optimize;
+            simulate_time += simulate;
+
+            let timing = IterationTiming {
+                iteration,
+                profile,
+                optimize,
+                simulate,
+            };
+            on_iteration(&timing);
+            per_iteration.push(timing);
         }
 
         let total_time = profile_time + optimize_time + simulate_time;
@@ -320,6 +481,7 @@ impl QnsSystem {
             avg_profile: profile_time / iterations as u32,
             avg_optimize: optimize_time / iterations as u32,
             avg_simulate: simulate_time / iterations as u32,
+            per_iteration,
         }
     }
 
@@ -369,6 +531,28 @@ pub struct BenchmarkResult {
     pub avg_optimize: Duration,
     /// Average simulation time
     pub avg_simulate: Duration,
+    /// Timings of each iteration, in order
+    pub per_iteration: Vec<IterationTiming>,
+}
+
+/// Stage timings of a single benchmark iteration.
+#[derive(Debug, Clone, Copy)]
+pub struct IterationTiming {
+    /// Zero-based iteration index
+    pub iteration: usize,
+    /// Profiling time
+    pub profile: Duration,
+    /// Optimization time
+    pub optimize: Duration,
+    /// Simulation time
+    pub simulate: Duration,
+}
+
+impl IterationTiming {
+    /// Total time of this iteration.
+    pub fn total(&self) -> Duration {
+        self.profile + self.optimize + self.simulate
+    }
 }
 
 impl std::fmt::Display for BenchmarkResult {
@@ -431,6 +615,190 @@ mod tests {
         let pipeline_result = result.unwrap();
         assert!(pipeline_result.original_fidelity >= 0.0);
         assert!(pipeline_result.optimized_fidelity >= 0.0);
+
+        // H(0), X(1), CNOT(0,1) yields |10⟩ and |01⟩ (qubit 0 rightmost)
+        let measurement = &pipeline_result.measurement;
+        assert_eq!(measurement.shots, system.config().simulation_shots);
+        assert_eq!(
+            measurement.counts.values().sum::<usize>(),
+            measurement.shots
+        );
+        let mut outcomes: Vec<&str> = measurement.counts.keys().map(String::as_str).collect();
+        outcomes.sort_unstable();
+        assert_eq!(outcomes, ["01", "10"]);
+    }
+
+    /// Three circuits of different widths for batch tests.
+    fn batch_circuits() -> Vec<CircuitGenome> {
+        let mut circuits = Vec::new();
+        for n in 2..5 {
+            let mut circuit = CircuitGenome::new(n);
+            circuit.add_gate(Gate::H(0)).unwrap();
+            for q in 1..n {
+                circuit.add_gate(Gate::CNOT(q - 1, q)).unwrap();
+                circuit.add_gate(Gate::Rz(q, 0.1 * q as f64)).unwrap();
+            }
+            circuits.push(circuit);
+        }
+        circuits
+    }
+
+    /// Pipeline config whose noise profiling is deterministic, so batch and
+    /// individual runs can be compared exactly.
+    fn noiseless_config(parallel_batch: bool) -> PipelineConfig {
+        PipelineConfig {
+            scanner: ScanConfig {
+                noise_level: 0.0,
+                burst_probability: 0.0,
+                ..Default::default()
+            },
+            parallel_batch,
+            ..Default::default()
+        }
+    }
+
+    /// Asserts that a batch result is the result of optimizing `circuit` alone.
+    fn assert_same_result(
+        batch: &PipelineResult,
+        single: &PipelineResult,
+        circuit: &CircuitGenome,
+    ) {
+        assert_eq!(batch.original_circuit.gates, circuit.gates);
+        assert_eq!(
+            batch.optimized_circuit.gates,
+            single.optimized_circuit.gates
+        );
+        assert_eq!(batch.noise_profile.t1_mean, single.noise_profile.t1_mean);
+        assert_eq!(batch.noise_profile.t2_mean, single.noise_profile.t2_mean);
+        assert!((batch.original_fidelity - single.original_fidelity).abs() < 1e-12);
+        assert!((batch.optimized_fidelity - single.optimized_fidelity).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_optimize_batch_matches_individual() {
+        let circuits = batch_circuits();
+
+        let mut system = QnsSystem::with_config(noiseless_config(true));
+        system.set_hardware(HardwareProfile::linear("test", 4));
+        let batch = system.optimize_batch(circuits.clone());
+        assert_eq!(batch.len(), 3);
+
+        // Each worker sees the same hardware and config as `optimize`
+        for (circuit, result) in circuits.iter().zip(batch) {
+            let single = system.optimize(circuit.clone()).unwrap();
+            assert_same_result(&result.unwrap(), &single, circuit);
+        }
+    }
+
+    #[test]
+    fn test_optimize_batch_sequential() {
+        let circuits = batch_circuits();
+
+        let mut system = QnsSystem::with_config(noiseless_config(false));
+        let results = system.optimize_batch(circuits.clone());
+        assert_eq!(results.len(), 3);
+
+        // Results come back in input order
+        for (circuit, result) in circuits.iter().zip(results) {
+            let single = system.optimize(circuit.clone()).unwrap();
+            assert_same_result(&result.unwrap(), &single, circuit);
+        }
+    }
+
+    #[test]
+    fn test_optimize_batch_scanner_history() {
+        let clean = ScanConfig {
+            burst_probability: 0.0,
+            ..Default::default()
+        };
+        // Qubits 0 and 1 carry a burst calibration, so their next scan is anomalous
+        let bursty_scanner = || {
+            let mut scanner = DriftScanner::new(ScanConfig {
+                burst_probability: 1.0,
+                ..clean.clone()
+            });
+            scanner.scan_batch(&[0, 1]).unwrap();
+            scanner.set_config(clean.clone());
+            scanner
+        };
+        let config = |parallel_batch| PipelineConfig {
+            max_reprofiles: 2,
+            parallel_batch,
+            ..Default::default()
+        };
+
+        // Every worker starts from the pre-batch history and leaves it untouched
+        let mut system = QnsSystem::with_config(config(true)).with_scanner(bursty_scanner());
+        let results = system.optimize_batch(batch_circuits());
+        for result in results {
+            assert_eq!(result.unwrap().anomaly_reprofiles, 1);
+        }
+        assert_eq!(system.scanner.scan_count(), 2);
+
+        // Sequentially the first re-profile cleans the history for the rest
+        let mut system = QnsSystem::with_config(config(false)).with_scanner(bursty_scanner());
+        let reprofiles: Vec<usize> = system
+            .optimize_batch(batch_circuits())
+            .into_iter()
+            .map(|r| r.unwrap().anomaly_reprofiles)
+            .collect();
+        assert_eq!(reprofiles, [1, 0, 0]);
+        assert_eq!(system.scanner.scan_count(), 2 + 2 + 2 + 3 + 4);
+    }
+
+    #[test]
+    fn test_observer_events_per_optimize() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct CountingObserver {
+            started: Mutex<Vec<String>>,
+            finished: Mutex<Vec<String>>,
+            scored: AtomicUsize,
+            completed: AtomicUsize,
+        }
+
+        impl OptimizationObserver for Arc<CountingObserver> {
+            fn on_stage_start(&self, stage: &str) {
+                self.started.lock().unwrap().push(stage.to_string());
+            }
+            fn on_stage_finish(&self, stage: &str, _elapsed: Duration) {
+                self.finished.lock().unwrap().push(stage.to_string());
+            }
+            fn on_variant_scored(&self, _fidelity: f64) {
+                self.scored.fetch_add(1, Ordering::SeqCst);
+            }
+            fn on_complete(&self, _result: &OptimizationResult) {
+                self.completed.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let counter = Arc::new(CountingObserver::default());
+        let mut system = QnsSystem::new().with_observer(Box::new(Arc::clone(&counter)));
+
+        // Commuting gates on disjoint qubits give several reorderings
+        let mut circuit = CircuitGenome::new(4);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::X(1),
+                Gate::CNOT(2, 3),
+                Gate::Rz(1, 0.3),
+                Gate::H(2),
+            ])
+            .unwrap();
+        let result = system.optimize(circuit).unwrap();
+
+        let expected = [STAGE_PROFILING, STAGE_OPTIMIZATION, STAGE_SIMULATION];
+        assert_eq!(*counter.started.lock().unwrap(), expected);
+        assert_eq!(*counter.finished.lock().unwrap(), expected);
+        assert!(result.optimization.variants_evaluated > 1);
+        assert_eq!(
+            counter.scored.load(Ordering::SeqCst),
+            result.optimization.variants_evaluated
+        );
+        assert_eq!(counter.completed.load(Ordering::SeqCst), 1);
     }
 
     #[test]
@@ -445,6 +813,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_anomaly_triggers_reprofile() {
+        let clean = ScanConfig {
+            burst_probability: 0.0,
+            ..Default::default()
+        };
+        // A scanner whose last calibration of both qubits hit a burst event
+        let bursty_scanner = || {
+            let mut scanner = DriftScanner::new(ScanConfig {
+                burst_probability: 1.0,
+                ..clean.clone()
+            });
+            scanner.scan_batch(&[0, 1]).unwrap();
+            scanner.set_config(clean.clone());
+            scanner
+        };
+
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+
+        let config = PipelineConfig {
+            max_reprofiles: 2,
+            ..Default::default()
+        };
+        let mut system = QnsSystem::with_config(config).with_scanner(bursty_scanner());
+        let result = system.optimize(circuit.clone()).unwrap();
+        assert_eq!(result.anomaly_reprofiles, 1);
+        // 2 seeding scans, the anomalous pipeline scan and the re-profile
+        assert_eq!(system.scanner.scan_count(), 6);
+        for qubit in 0..2 {
+            assert_eq!(system.scanner.t1_history(qubit).unwrap().len(), 1);
+        }
+        let last = system.scanner.last_scan().unwrap();
+        assert_eq!(last.burst_count, 0);
+        assert!(!system.scanner.is_anomaly(last));
+
+        // Disabled by default: the anomalous scan is used as is
+        let mut system = QnsSystem::new().with_scanner(bursty_scanner());
+        let result = system.optimize(circuit).unwrap();
+        assert_eq!(result.anomaly_reprofiles, 0);
+        assert_eq!(system.scanner.scan_count(), 4);
+    }
+
     #[test]
     fn test_with_hardware() {
         let mut system = QnsSystem::new();
@@ -454,6 +866,36 @@ mod tests {
         assert!(system.hardware.is_some());
     }
 
+    #[test]
+    fn test_optimize_pre_mapped_keeps_qubits() {
+        let mut hw = HardwareProfile::linear("line", 3);
+        hw.couplers[0].gate_fidelity = qns_core::types::Fidelity::new(0.8);
+
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::X(2)).unwrap();
+        circuit.add_gate(Gate::Rz(1, 0.3)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::H(2)).unwrap();
+        circuit.metadata.pre_mapped = true;
+
+        let mut system = QnsSystem::new();
+        system.set_hardware(hw);
+        let result = system.optimize(circuit.clone()).unwrap();
+
+        let optimized = &result.optimized_circuit;
+        assert!(optimized.metadata.pre_mapped);
+        // Commuting gates were reordered...
+        assert_ne!(optimized.gates, circuit.gates);
+        // ...but every gate kept its qubits
+        let sorted = |c: &CircuitGenome| {
+            let mut gates: Vec<String> = c.gates.iter().map(|g| g.to_string()).collect();
+            gates.sort();
+            gates
+        };
+        assert_eq!(sorted(optimized), sorted(&circuit));
+    }
+
     #[test]
     fn test_benchmark() {
         let mut system = QnsSystem::new();
@@ -462,6 +904,15 @@ mod tests {
         assert_eq!(result.iterations, 5);
         assert_eq!(result.num_qubits, 3);
         assert!(result.avg_total > Duration::ZERO);
+
+        assert_eq!(result.per_iteration.len(), 5);
+        let summed: Duration = result.per_iteration.iter().map(|t| t.total()).sum();
+        assert_eq!(summed, result.total_time);
+        assert!(result
+            .per_iteration
+            .iter()
+            .enumerate()
+            .all(|(i, t)| t.iteration == i));
     }
 
     #[test]
@@ -482,3 +482,186 @@ fn test_api_consistency() {
     circuit.add_gate(Gate::H(0)).unwrap();
     let _ = system.optimize(circuit).unwrap();
 }
+
+// ============================================================================
+// CLI Tests
+// ============================================================================
+
+#[test]
+fn test_benchmark_per_iteration_json_lines() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_qns"))
+        .args([
+            "--format",
+            "json",
+            "benchmark",
+            "-q",
+            "2",
+            "-g",
+            "5",
+            "-i",
+            "5",
+            "--per-iteration",
+        ])
+        .output()
+        .expect("failed to run qns binary");
+    assert!(output.status.success());
+
+    // Every stdout line is one JSON object: 5 iterations, then the summary
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("stdout line should be JSON"))
+        .collect();
+
+    assert_eq!(lines.len(), 6);
+    for (i, line) in lines[..5].iter().enumerate() {
+        assert_eq!(line["iteration"], i);
+        for key in ["profile_ms", "optimize_ms", "simulate_ms"] {
+            assert!(line[key].as_f64().unwrap() >= 0.0);
+        }
+    }
+    assert_eq!(lines[5]["iterations"], 5);
+    assert_eq!(lines[5]["num_qubits"], 2);
+
+    // Logs go to stderr
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Running benchmark"));
+
+    // The text summary moves to stderr so stdout stays JSON Lines
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_qns"))
+        .args([
+            "benchmark",
+            "-q",
+            "2",
+            "-g",
+            "5",
+            "-i",
+            "3",
+            "--per-iteration",
+        ])
+        .output()
+        .expect("failed to run qns binary");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 3);
+    for line in stdout.lines() {
+        serde_json::from_str::<serde_json::Value>(line).expect("stdout line should be JSON");
+    }
+    assert!(String::from_utf8_lossy(&output.stderr).contains("QNS Benchmark Results"));
+}
+
+#[test]
+fn test_info_reports_circuit_statistics() {
+    let path = std::env::temp_dir().join(format!("qns_info_{}.qasm", std::process::id()));
+    std::fs::write(
+        &path,
+        "OPENQASM 2.0;\n\
+         include \"qelib1.inc\";\n\
+         qreg q[3];\n\
+         h q[0];\n\
+         t q[1];\n\
+         cx q[0],q[1];\n\
+         t q[1];\n\
+         cx q[1],q[2];\n\
+         h q[2];\n",
+    )
+    .unwrap();
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_qns"))
+        .args(["--format", "json", "info"])
+        .arg(&path)
+        .output()
+        .expect("failed to run qns binary");
+    std::fs::remove_file(&path).ok();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json_start = stdout.find('{').expect("JSON output");
+    let info: serde_json::Value = serde_json::from_str(&stdout[json_start..]).unwrap();
+    assert_eq!(info["num_qubits"], 3);
+    assert_eq!(info["gate_count"], 6);
+    assert_eq!(
+        info["gate_counts"],
+        serde_json::json!({"H": 2, "T": 2, "CNOT": 2})
+    );
+    assert_eq!(info["depth"], 5);
+    assert_eq!(info["two_qubit_depth"], 2);
+    assert_eq!(info["t_count"], 2);
+    assert_eq!(info["t_depth"], 2);
+    assert!(info["idle_time_ns"].as_f64().unwrap() >= 0.0);
+}
+
+#[test]
+fn test_global_config_applies_to_every_subcommand() {
+    let dir = std::env::temp_dir().join(format!("qns_global_config_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let qasm = dir.join("circuit.qasm");
+    std::fs::write(
+        &qasm,
+        "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[2];\ncx q[0],q[1];\nh q[0];\nh q[0];\n",
+    )
+    .unwrap();
+    let slow = dir.join("slow.toml");
+    std::fs::write(&slow, "[gate_times]\nsingle_qubit = 500.0\n").unwrap();
+    let broken = dir.join("broken.toml");
+    std::fs::write(&broken, "[rewire]\nmax_variants = \"many\"\n").unwrap();
+
+    let qns = || {
+        let mut command = std::process::Command::new(env!("CARGO_BIN_EXE_qns"));
+        command.current_dir(&dir);
+        command
+    };
+    let idle_time = |output: std::process::Output| {
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let info: serde_json::Value =
+            serde_json::from_str(&stdout[stdout.find('{').unwrap()..]).unwrap();
+        info["idle_time_ns"].as_f64().unwrap()
+    };
+
+    // Qubit 1 idles through both H gates, so their duration shows up
+    let default = idle_time(
+        qns()
+            .args(["--format", "json", "info"])
+            .arg(&qasm)
+            .output()
+            .unwrap(),
+    );
+    let configured = idle_time(
+        qns()
+            .args(["--format", "json", "--config"])
+            .arg(&slow)
+            .arg("info")
+            .arg(&qasm)
+            .output()
+            .unwrap(),
+    );
+    assert!(
+        configured > default + 100.0,
+        "{} vs {}",
+        configured,
+        default
+    );
+
+    // A bad config file fails every command instead of being ignored
+    for command in [
+        &["profile", "-q", "2"][..],
+        &["benchmark", "-q", "2", "-i", "1"],
+    ] {
+        let output = qns()
+            .arg("--config")
+            .arg(&broken)
+            .args(command)
+            .output()
+            .unwrap();
+        assert!(
+            !output.status.success(),
+            "{:?} accepted a broken config",
+            command
+        );
+        assert!(String::from_utf8_lossy(&output.stderr).contains("broken.toml"));
+    }
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,68 @@
+//! Hardware profile lookup benchmark.
+//!
+//! Compares the coupler index against a linear scan of `couplers`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use qns_core::prelude::*;
+
+fn query_pairs(num_qubits: usize) -> Vec<(usize, usize)> {
+    (0..1000)
+        .map(|i| ((i * 37) % num_qubits, (i * 61 + 1) % num_qubits))
+        .collect()
+}
+
+fn scan_neighbors(hw: &HardwareProfile, qubit: usize) -> Vec<usize> {
+    hw.couplers
+        .iter()
+        .filter_map(|c| match (c.qubit1 == qubit, c.qubit2 == qubit) {
+            (true, _) => Some(c.qubit2),
+            (_, true) => Some(c.qubit1),
+            _ => None,
+        })
+        .collect()
+}
+
+fn scan_coupler(hw: &HardwareProfile, q1: usize, q2: usize) -> Option<&CouplerProperties> {
+    hw.couplers
+        .iter()
+        .find(|c| (c.qubit1 == q1 && c.qubit2 == q2) || (c.qubit1 == q2 && c.qubit2 == q1))
+}
+
+fn bench_coupler_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("coupler_lookup");
+
+    for num_qubits in [20, 100] {
+        let hw = HardwareProfile::all_to_all("full", num_qubits);
+        let pairs = query_pairs(num_qubits);
+
+        group.bench_with_input(
+            BenchmarkId::new("indexed", num_qubits),
+            &pairs,
+            |b, pairs| {
+                b.iter(|| {
+                    pairs
+                        .iter()
+                        .map(|&(q1, q2)| {
+                            hw.neighbors(q1).len() + hw.get_coupler(q1, q2).is_some() as usize
+                        })
+                        .sum::<usize>()
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("scan", num_qubits), &pairs, |b, pairs| {
+            b.iter(|| {
+                pairs
+                    .iter()
+                    .map(|&(q1, q2)| {
+                        scan_neighbors(&hw, q1).len() + scan_coupler(&hw, q1, q2).is_some() as usize
+                    })
+                    .sum::<usize>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_coupler_lookup);
+criterion_main!(benches);
@@ -30,6 +30,19 @@ impl ExecutionResult {
         }
         *self.counts.get(outcome).unwrap_or(&0) as f64 / self.shots as f64
     }
+
+    /// Renders the histogram as CSV with a `bitstring,count,probability`
+    /// header and one row per observed bitstring, sorted by bitstring.
+    pub fn to_csv(&self) -> String {
+        let mut outcomes: Vec<(&String, &usize)> = self.counts.iter().collect();
+        outcomes.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let mut csv = String::from("bitstring,count,probability\n");
+        for (bits, count) in outcomes {
+            csv.push_str(&format!("{},{},{}\n", bits, count, self.probability(bits)));
+        }
+        csv
+    }
 }
 
 pub trait HardwareBackend: Send + Sync {
@@ -38,4 +51,179 @@ pub trait HardwareBackend: Send + Sync {
     fn get_topology(&self) -> Option<HardwareProfile>;
     fn get_calibration(&self) -> Result<HashMap<usize, NoiseVector>>;
     fn execute(&self, circuit: &CircuitGenome, shots: usize) -> Result<ExecutionResult>;
+
+    /// Estimates the expectation value of a Pauli-string observable.
+    ///
+    /// `observable` has one character (`I`, `X`, `Y` or `Z`) per circuit
+    /// qubit, with qubit 0 as the rightmost character. The default runs
+    /// [`sampled_expectation`]; simulators may override it with an exact
+    /// computation.
+    fn expectation(&self, circuit: &CircuitGenome, observable: &str, shots: usize) -> Result<f64> {
+        sampled_expectation(self, circuit, observable, shots)
+    }
+}
+
+/// Estimates `⟨observable⟩` by executing `circuit` in the observable's
+/// eigenbasis and averaging the parity of the measured bits.
+///
+/// Terminal measurement gates in `circuit` are dropped; basis rotations are
+/// appended in their place (`H` for X, `S†·H` for Y). Circuits with
+/// mid-circuit measurements are rejected, see [`pauli_basis_circuit`].
+pub fn sampled_expectation<B: HardwareBackend + ?Sized>(
+    backend: &B,
+    circuit: &CircuitGenome,
+    observable: &str,
+    shots: usize,
+) -> Result<f64> {
+    let rotated = pauli_basis_circuit(circuit, observable)?;
+    let result = backend.execute(&rotated, shots)?;
+    pauli_expectation_from_counts(&result, observable)
+}
+
+/// Returns `circuit` without measurements, followed by the rotations that
+/// map each qubit's Pauli operator in `observable` onto Z.
+///
+/// # Errors
+/// [`QnsError::UnsupportedGate`] for a `Measure` followed by another gate on
+/// the same qubit: the expectation would then depend on the collapsed
+/// outcome, which dropping the measurement cannot reproduce.
+pub fn pauli_basis_circuit(circuit: &CircuitGenome, observable: &str) -> Result<CircuitGenome> {
+    let ops = parse_pauli(observable, circuit.num_qubits)?;
+    for (index, gate) in circuit.gates.iter().enumerate() {
+        if let Gate::Measure(q) = gate {
+            if circuit.gates[index + 1..]
+                .iter()
+                .any(|later| later.qubits().contains(q))
+            {
+                return Err(QnsError::unsupported_gate(
+                    gate,
+                    "pauli expectation",
+                    "mid-circuit measurement",
+                ));
+            }
+        }
+    }
+    let mut rotated = CircuitGenome::new(circuit.num_qubits);
+    rotated.add_gates(
+        circuit
+            .gates
+            .iter()
+            .filter(|g| !matches!(g, Gate::Measure(_)))
+            .cloned(),
+    )?;
+    for (q, op) in ops.into_iter().enumerate() {
+        match op {
+            'X' => rotated.add_gate(Gate::H(q))?,
+            'Y' => {
+                rotated.add_gate(Gate::Rz(q, -std::f64::consts::FRAC_PI_2))?;
+                rotated.add_gate(Gate::H(q))?;
+            },
+            _ => {},
+        }
+    }
+    Ok(rotated)
+}
+
+/// Computes `⟨observable⟩` from counts measured in the observable's
+/// eigenbasis: the average of `(-1)^(parity of the non-identity qubits)`.
+pub fn pauli_expectation_from_counts(result: &ExecutionResult, observable: &str) -> Result<f64> {
+    let ops = parse_pauli(observable, observable.chars().count())?;
+    let total: usize = result.counts.values().sum();
+    if total == 0 {
+        return Err(QnsError::ExecutionFailed(
+            "no measurement counts to estimate an expectation value from".to_string(),
+        ));
+    }
+
+    let mut sum = 0i64;
+    for (bits, &count) in &result.counts {
+        let parity = bits
+            .chars()
+            .rev()
+            .zip(&ops)
+            .filter(|&(bit, &op)| op != 'I' && bit == '1')
+            .count();
+        let sign = if parity % 2 == 0 { 1 } else { -1 };
+        sum += sign * count as i64;
+    }
+    Ok(sum as f64 / total as f64)
+}
+
+/// Parses a Pauli string into per-qubit operators, qubit 0 first.
+fn parse_pauli(observable: &str, num_qubits: usize) -> Result<Vec<char>> {
+    let len = observable.chars().count();
+    if len != num_qubits {
+        return Err(QnsError::DimensionMismatch(num_qubits, len));
+    }
+    observable
+        .chars()
+        .rev()
+        .map(|c| match c.to_ascii_uppercase() {
+            op @ ('I' | 'X' | 'Y' | 'Z') => Ok(op),
+            other => Err(QnsError::config(format!(
+                "invalid Pauli operator '{}' in \"{}\"",
+                other, observable
+            ))),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pauli_basis_circuit_measurements() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::Measure(0),
+                Gate::Measure(1),
+            ])
+            .unwrap();
+        // Qubit 0 is the rightmost character: Y on qubit 0, X on qubit 1
+        let rotated = pauli_basis_circuit(&circuit, "XY").unwrap();
+        assert_eq!(
+            rotated.gates,
+            [
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::Rz(0, -std::f64::consts::FRAC_PI_2),
+                Gate::H(0),
+                Gate::H(1),
+            ]
+        );
+
+        circuit.add_gate(Gate::X(1)).unwrap();
+        let err = pauli_basis_circuit(&circuit, "ZZ").unwrap_err();
+        assert!(
+            matches!(err, QnsError::UnsupportedGate { ref gate, .. } if gate == &Gate::Measure(1).to_string()),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_to_csv_rows_and_probabilities() {
+        let counts = HashMap::from([
+            ("11".to_string(), 480),
+            ("00".to_string(), 510),
+            ("01".to_string(), 10),
+        ]);
+        let result = ExecutionResult::new(counts, 1000, Duration::ZERO);
+        let csv = result.to_csv();
+
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("bitstring,count,probability"));
+        let rows: Vec<Vec<&str>> = lines.map(|l| l.split(',').collect()).collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], ["00", "510", "0.51"]);
+
+        let total: f64 = rows.iter().map(|r| r[2].parse::<f64>().unwrap()).sum();
+        assert!((total - 1.0).abs() < 1e-9, "{}", total);
+        let shots: usize = rows.iter().map(|r| r[1].parse::<usize>().unwrap()).sum();
+        assert_eq!(shots, result.shots);
+    }
 }
@@ -39,6 +39,18 @@ pub enum QnsError {
     #[error("Invalid gate sequence: {0}")]
     InvalidGateSequence(String),
 
+    /// A gate in a batch was rejected by a circuit
+    #[error("batch gate {index} ({gate}) rejected: {source}")]
+    InvalidBatchGate {
+        /// Position of the rejected gate within the batch
+        index: usize,
+        /// Display form of the rejected gate
+        gate: String,
+        /// Underlying validation error
+        #[source]
+        source: Box<QnsError>,
+    },
+
     // ============ Simulator Errors ============
     /// Generic simulator error
     #[error("Simulator error: {0}")]
@@ -52,6 +64,18 @@ pub enum QnsError {
     #[error("Invalid quantum state: {0}")]
     InvalidState(String),
 
+    /// A gate failed while executing a circuit
+    #[error("gate {index} ({gate}): {source}")]
+    CircuitExecution {
+        /// Position of the failing gate in the circuit
+        index: usize,
+        /// Display form of the failing gate
+        gate: String,
+        /// Underlying error
+        #[source]
+        source: Box<QnsError>,
+    },
+
     // ============ Backend Errors (v2.0) ============
     /// Generic backend error
     #[error("Backend error: {0}")]
@@ -73,11 +97,33 @@ pub enum QnsError {
     #[error("Unsupported operation: {0}")]
     Unsupported(String),
 
+    /// Gate cannot be applied by a specific backend
+    #[error("Gate {gate} unsupported by {backend}: {reason}")]
+    UnsupportedGate {
+        /// Display form of the rejected gate
+        gate: String,
+        /// Name of the backend that rejected it
+        backend: String,
+        /// Why the backend cannot apply it
+        reason: String,
+    },
+
     // ============ Config Errors ============
     /// Configuration error
     #[error("Configuration error: {0}")]
     Config(String),
 
+    /// Serialized data was written by a newer, unsupported QNS format
+    #[error("{kind} uses format version {found}, but this build supports up to version {supported}; upgrade QNS to load it")]
+    UnsupportedFormatVersion {
+        /// Name of the serialized type
+        kind: String,
+        /// Version tag found in the data
+        found: u32,
+        /// Newest version this build can load
+        supported: u32,
+    },
+
     // ============ I/O Errors ============
     /// IO error
     #[error("IO error: {0}")]
@@ -86,6 +132,10 @@ pub enum QnsError {
     /// JSON serialization error
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+
+    /// Binary (bincode) serialization error
+    #[error("Binary serialization error: {0}")]
+    BinarySerialization(#[from] bincode::Error),
 }
 
 /// Result type alias for QNS operations.
@@ -121,6 +171,28 @@ impl QnsError {
     pub fn execution_failed(msg: impl Into<String>) -> Self {
         Self::ExecutionFailed(msg.into())
     }
+
+    /// Wraps an error raised by the gate at `index` during circuit execution.
+    pub fn circuit_execution(index: usize, gate: impl ToString, source: QnsError) -> Self {
+        Self::CircuitExecution {
+            index,
+            gate: gate.to_string(),
+            source: Box::new(source),
+        }
+    }
+
+    /// Creates an unsupported-gate error for the given backend.
+    pub fn unsupported_gate(
+        gate: impl ToString,
+        backend: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::UnsupportedGate {
+            gate: gate.to_string(),
+            backend: backend.into(),
+            reason: reason.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -159,4 +231,22 @@ mod tests {
         let err = QnsError::CalibrationStale(3600);
         assert!(err.to_string().contains("3600"));
     }
+
+    #[test]
+    fn test_unsupported_gate_error() {
+        let err = QnsError::unsupported_gate("T(0)", "stabilizer", "non-Clifford");
+        match &err {
+            QnsError::UnsupportedGate {
+                gate,
+                backend,
+                reason,
+            } => {
+                assert_eq!(gate, "T(0)");
+                assert_eq!(backend, "stabilizer");
+                assert_eq!(reason, "non-Clifford");
+            },
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(err.to_string().contains("stabilizer"));
+    }
 }
@@ -4,10 +4,13 @@
 //! - Standard gate matrices (Pauli, Clifford, rotation gates)
 //! - Physical constants (typical T1/T2 values, gate times)
 //! - Utility functions for matrix operations
+//! - [`matrix`]: dynamically-sized complex matrix helpers
 
 use num_complex::Complex64;
 use std::f64::consts::FRAC_1_SQRT_2;
 
+pub mod matrix;
+
 /// Complex number type alias for readability.
 pub type C64 = Complex64;
 
@@ -159,6 +162,41 @@ pub fn rz(theta: f64) -> Matrix2x2 {
     ]
 }
 
+/// Computes the general single-qubit gate U(θ, φ, λ) (OpenQASM `u3`).
+/// U = [[cos(θ/2), -e^(iλ)·sin(θ/2)], [e^(iφ)·sin(θ/2), e^(i(φ+λ))·cos(θ/2)]]
+pub fn u3(theta: f64, phi: f64, lambda: f64) -> Matrix2x2 {
+    let cos = (theta / 2.0).cos();
+    let sin = (theta / 2.0).sin();
+    [
+        [c(cos, 0.0), -C64::from_polar(sin, lambda)],
+        [
+            C64::from_polar(sin, phi),
+            C64::from_polar(cos, phi + lambda),
+        ],
+    ]
+}
+
+/// Extracts the angles (θ, φ, λ) of a single-qubit unitary.
+///
+/// Returns angles with `u3(θ, φ, λ) = e^(-iα)·m` for some global phase α.
+/// When θ is 0 or π only φ + λ or φ - λ is determined, and φ is set to 0.
+pub fn u3_angles(m: &Matrix2x2) -> (f64, f64, f64) {
+    const EPS: f64 = 1e-12;
+    let theta = 2.0 * m[1][0].norm().atan2(m[0][0].norm());
+    if m[1][0].norm() < EPS {
+        // Diagonal: e^(iα)·diag(1, e^(iλ))
+        return (theta, 0.0, (m[1][1] / m[0][0]).arg());
+    }
+    if m[0][0].norm() < EPS {
+        // Anti-diagonal: e^(iα)·[[0, -e^(iλ)], [1, 0]]
+        return (theta, 0.0, (-m[0][1] / m[1][0]).arg());
+    }
+    let alpha = m[0][0].arg();
+    let phi = m[1][0].arg() - alpha;
+    let lambda = (-m[0][1]).arg() - alpha;
+    (theta, phi, lambda)
+}
+
 /// CNOT (CX) gate matrix (control=0, target=1).
 /// |00⟩ → |00⟩, |01⟩ → |01⟩, |10⟩ → |11⟩, |11⟩ → |10⟩
 pub const CNOT: Matrix4x4 = [
@@ -186,6 +224,33 @@ pub const SWAP: Matrix4x4 = [
     [ZERO, ZERO, ZERO, ONE],
 ];
 
+/// Controlled-phase gate matrix: `|11⟩` picks up phase e^{iλ}.
+///
+/// Symmetric in its two qubits; CPhase(π) is CZ.
+pub fn cphase(lambda: f64) -> Matrix4x4 {
+    [
+        [ONE, ZERO, ZERO, ZERO],
+        [ZERO, ONE, ZERO, ZERO],
+        [ZERO, ZERO, ONE, ZERO],
+        [ZERO, ZERO, ZERO, c(lambda.cos(), lambda.sin())],
+    ]
+}
+
+/// fSim(θ, φ) gate matrix (Google's excitation-preserving gate family).
+///
+/// `|01⟩ ↔ |10⟩` are mixed by angle θ and `|11⟩` picks up phase e^{-iφ}.
+/// fSim(π/2, 0) is iSWAP† and fSim(0, π) is CZ.
+pub fn fsim(theta: f64, phi: f64) -> Matrix4x4 {
+    let cos = c(theta.cos(), 0.0);
+    let minus_i_sin = c(0.0, -theta.sin());
+    [
+        [ONE, ZERO, ZERO, ZERO],
+        [ZERO, cos, minus_i_sin, ZERO],
+        [ZERO, minus_i_sin, cos, ZERO],
+        [ZERO, ZERO, ZERO, c(phi.cos(), -phi.sin())],
+    ]
+}
+
 // ============================================================================
 // Gate Classification for Commutativity
 // ============================================================================
@@ -201,8 +266,12 @@ pub enum GateType {
     YRotation,
     /// Hadamard gate
     Hadamard,
+    /// General single-qubit unitary (U) mixing several axes
+    General,
     /// Two-qubit gates
     TwoQubit,
+    /// Three-qubit gates
+    ThreeQubit,
     /// Measurement
     Measurement,
 }
@@ -357,4 +426,29 @@ mod tests {
     fn test_diagonal_gates_commute() {
         assert!(gate_types_commute(GateType::Diagonal, GateType::Diagonal));
     }
+
+    #[test]
+    fn test_u3_angles_round_trip() {
+        let cases = [
+            u3(0.7, -1.2, 2.5),
+            HADAMARD,
+            PAULI_Y,
+            T_GATE,
+            mul_2x2(&rx(0.4), &rz(1.1)),
+        ];
+        for m in &cases {
+            assert!(is_unitary_2x2(m, TOLERANCE));
+            let (theta, phi, lambda) = u3_angles(m);
+            let u = u3(theta, phi, lambda);
+            // u = e^(-iα)·m for a single global phase α
+            let (i, j) = if m[0][0].norm() > 0.1 { (0, 0) } else { (1, 0) };
+            let phase = u[i][j] / m[i][j];
+            assert!((phase.norm() - 1.0).abs() < TOLERANCE);
+            for r in 0..2 {
+                for col in 0..2 {
+                    assert!((u[r][col] - phase * m[r][col]).norm() < TOLERANCE);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,142 @@
+//! Dynamically-sized complex matrices.
+//!
+//! Row-major `Vec<Vec<C64>>` helpers for matrices whose size is only known at
+//! runtime (circuit unitaries, custom gates, density matrices). Fixed-size
+//! single- and two-qubit gates use [`Matrix2x2`](super::Matrix2x2) and
+//! [`Matrix4x4`](super::Matrix4x4) instead.
+
+use super::{C64, ONE, ZERO};
+use crate::error::{QnsError, Result};
+
+/// Row-major dynamically-sized complex matrix, `m[row][col]`.
+pub type DynMatrix = Vec<Vec<C64>>;
+
+/// Returns the `dim × dim` identity matrix.
+pub fn identity(dim: usize) -> DynMatrix {
+    (0..dim)
+        .map(|r| (0..dim).map(|c| if r == c { ONE } else { ZERO }).collect())
+        .collect()
+}
+
+/// Multiplies `a · b`.
+///
+/// Fails with [`QnsError::DimensionMismatch`] unless the column count of `a`
+/// equals the row count of `b`.
+pub fn matmul(a: &[Vec<C64>], b: &[Vec<C64>]) -> Result<DynMatrix> {
+    let inner = a.first().map_or(0, Vec::len);
+    if let Some(row) = a.iter().find(|row| row.len() != inner) {
+        return Err(QnsError::DimensionMismatch(inner, row.len()));
+    }
+    if b.len() != inner {
+        return Err(QnsError::DimensionMismatch(inner, b.len()));
+    }
+    let cols = b.first().map_or(0, Vec::len);
+    if let Some(row) = b.iter().find(|row| row.len() != cols) {
+        return Err(QnsError::DimensionMismatch(cols, row.len()));
+    }
+
+    Ok(a.iter()
+        .map(|a_row| {
+            (0..cols)
+                .map(|c| a_row.iter().zip(b).map(|(x, b_row)| x * b_row[c]).sum())
+                .collect()
+        })
+        .collect())
+}
+
+/// Returns the conjugate transpose `m†`.
+pub fn conjugate_transpose(m: &[Vec<C64>]) -> DynMatrix {
+    let cols = m.first().map_or(0, Vec::len);
+    (0..cols)
+        .map(|c| m.iter().map(|row| row[c].conj()).collect())
+        .collect()
+}
+
+/// Checks whether `m` is square and `m†·m ≈ I` within `tolerance` per entry.
+pub fn is_unitary(m: &[Vec<C64>], tolerance: f64) -> bool {
+    let dim = m.len();
+    if m.iter().any(|row| row.len() != dim) {
+        return false;
+    }
+    let Ok(product) = matmul(&conjugate_transpose(m), m) else {
+        return false;
+    };
+    product.iter().enumerate().all(|(r, row)| {
+        row.iter().enumerate().all(|(c, &v)| {
+            let expected = if r == c { ONE } else { ZERO };
+            (v - expected).norm() < tolerance
+        })
+    })
+}
+
+/// Kronecker product `a ⊗ b`.
+///
+/// With qubit 0 as the least significant index, `b` acts on the low-order
+/// qubits: `tensor(u1, u0)` applies `u0` to qubit 0 and `u1` to qubit 1.
+pub fn tensor(a: &[Vec<C64>], b: &[Vec<C64>]) -> DynMatrix {
+    a.iter()
+        .flat_map(|a_row| {
+            b.iter().map(move |b_row| {
+                a_row
+                    .iter()
+                    .flat_map(|&x| b_row.iter().map(move |&y| x * y))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::{c, HADAMARD, PAULI_X, S_GATE};
+
+    const TOLERANCE: f64 = 1e-10;
+
+    fn dyn_matrix<const N: usize>(m: &[[C64; N]; N]) -> DynMatrix {
+        m.iter().map(|row| row.to_vec()).collect()
+    }
+
+    fn approx_eq(a: &[Vec<C64>], b: &[Vec<C64>]) -> bool {
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(x, y)| {
+                x.len() == y.len() && x.iter().zip(y).all(|(p, q)| (p - q).norm() < TOLERANCE)
+            })
+    }
+
+    #[test]
+    fn test_is_unitary() {
+        let h = dyn_matrix(&HADAMARD);
+        assert!(is_unitary(&h, TOLERANCE));
+
+        let non_unitary = vec![vec![ONE, ONE], vec![ZERO, ONE]];
+        assert!(!is_unitary(&non_unitary, TOLERANCE));
+        assert!(!is_unitary(&[vec![ONE, ZERO]], TOLERANCE));
+    }
+
+    #[test]
+    fn test_matmul_with_conjugate_transpose() {
+        // H ⊗ S and (X ⊗ H)·(H ⊗ S) are unitary; U·U† = I
+        let hs = tensor(&dyn_matrix(&HADAMARD), &dyn_matrix(&S_GATE));
+        let product = matmul(&tensor(&dyn_matrix(&PAULI_X), &dyn_matrix(&HADAMARD)), &hs).unwrap();
+        for u in [hs, product] {
+            assert_eq!(u.len(), 4);
+            assert!(is_unitary(&u, TOLERANCE));
+            let identity_product = matmul(&u, &conjugate_transpose(&u)).unwrap();
+            assert!(approx_eq(&identity_product, &identity(4)));
+        }
+
+        // Kronecker layout: X ⊗ I flips the high-order index
+        let x_high = tensor(&dyn_matrix(&PAULI_X), &identity(2));
+        assert_eq!(x_high[2][0], ONE);
+        assert_eq!(x_high[1][0], ZERO);
+
+        // Conjugate transpose of a non-square matrix
+        let row = vec![vec![c(1.0, 2.0), c(0.0, -1.0), ONE]];
+        let column = conjugate_transpose(&row);
+        assert_eq!(column.len(), 3);
+        assert_eq!(column[0][0], c(1.0, -2.0));
+
+        assert!(matmul(&identity(2), &identity(3)).is_err());
+    }
+}
@@ -1,8 +1,13 @@
 //! Circuit genome data structure.
 
-use super::Gate;
+use super::gate_list::GateList;
+use super::{migrate_format_version, Gate, QNS_FORMAT_VERSION};
 use crate::error::{QnsError, Result};
 use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
 
 /// Metadata for a circuit genome.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -15,6 +20,154 @@ pub struct CircuitMetadata {
     pub fitness_score: f64,
     /// Parent circuit ID
     pub parent_id: Option<String>,
+    /// Indices into `gates` of gates inserted by routing (e.g. SWAPs).
+    ///
+    /// Routing artifacts are not part of the algorithm, so passes such as
+    /// noise-scale folding can treat them separately. Transformations that
+    /// move gates must keep these indices in sync or clear them.
+    #[serde(default)]
+    pub routing_gates: Vec<usize>,
+    /// Qubit indices are already physical qubits ("do not route").
+    ///
+    /// Placement and routing leave such circuits on their given qubits;
+    /// reordering and peephole passes still apply.
+    #[serde(default)]
+    pub pre_mapped: bool,
+    /// Gate index ranges (`start..end`) frozen by the user.
+    ///
+    /// Reordering passes keep the gates of a frozen region in their exact
+    /// order and never move other gates into or across it. Like
+    /// `routing_gates`, transformations that move gates must keep these in
+    /// sync or clear them.
+    #[serde(default)]
+    pub frozen_regions: Vec<Range<usize>>,
+}
+
+impl CircuitMetadata {
+    /// Returns true if gate `index` lies inside a frozen region.
+    pub fn is_frozen(&self, index: usize) -> bool {
+        self.frozen_regions.iter().any(|r| r.contains(&index))
+    }
+}
+
+/// Magic prefix of the versioned envelope written by
+/// [`CircuitGenome::save_bincode`], followed by the format version.
+const BINCODE_MAGIC: [u8; 4] = *b"QNSB";
+
+/// Circuit layout of bincode files written before the versioned envelope
+/// (format version 0). Bincode is not self-describing, so these need their
+/// own decoder.
+#[derive(Deserialize)]
+struct LegacyCircuitGenome {
+    num_qubits: usize,
+    gates: Vec<LegacyGate>,
+    metadata: LegacyCircuitMetadata,
+}
+
+/// Gate set of format version 0, in its original variant order (bincode
+/// stores the variant index).
+#[derive(Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
+enum LegacyGate {
+    H(usize),
+    X(usize),
+    Y(usize),
+    Z(usize),
+    S(usize),
+    T(usize),
+    Rx(usize, f64),
+    Ry(usize, f64),
+    Rz(usize, f64),
+    CNOT(usize, usize),
+    CZ(usize, usize),
+    SWAP(usize, usize),
+    Measure(usize),
+}
+
+#[derive(Deserialize)]
+struct LegacyCircuitMetadata {
+    species_id: Option<String>,
+    generation: usize,
+    fitness_score: f64,
+    parent_id: Option<String>,
+}
+
+impl From<LegacyGate> for Gate {
+    fn from(legacy: LegacyGate) -> Self {
+        match legacy {
+            LegacyGate::H(q) => Gate::H(q),
+            LegacyGate::X(q) => Gate::X(q),
+            LegacyGate::Y(q) => Gate::Y(q),
+            LegacyGate::Z(q) => Gate::Z(q),
+            LegacyGate::S(q) => Gate::S(q),
+            LegacyGate::T(q) => Gate::T(q),
+            LegacyGate::Rx(q, theta) => Gate::Rx(q, theta),
+            LegacyGate::Ry(q, theta) => Gate::Ry(q, theta),
+            LegacyGate::Rz(q, theta) => Gate::Rz(q, theta),
+            LegacyGate::CNOT(c, t) => Gate::CNOT(c, t),
+            LegacyGate::CZ(c, t) => Gate::CZ(c, t),
+            LegacyGate::SWAP(a, b) => Gate::SWAP(a, b),
+            LegacyGate::Measure(q) => Gate::Measure(q),
+        }
+    }
+}
+
+impl From<LegacyCircuitGenome> for CircuitGenome {
+    fn from(legacy: LegacyCircuitGenome) -> Self {
+        let metadata = legacy.metadata;
+        Self {
+            num_qubits: legacy.num_qubits,
+            gates: legacy.gates.into_iter().map(Gate::from).collect(),
+            metadata: CircuitMetadata {
+                species_id: metadata.species_id,
+                generation: metadata.generation,
+                fitness_score: metadata.fitness_score,
+                parent_id: metadata.parent_id,
+                ..Default::default()
+            },
+            qns_format_version: 0,
+        }
+    }
+}
+
+/// Circuit layout of format version 1, before `frozen_regions`. Its gates
+/// decode with the current [`Gate`], whose newer variants were appended.
+#[derive(Deserialize)]
+struct CircuitGenomeV1 {
+    num_qubits: usize,
+    gates: Vec<Gate>,
+    metadata: CircuitMetadataV1,
+    qns_format_version: u32,
+}
+
+#[derive(Deserialize)]
+struct CircuitMetadataV1 {
+    species_id: Option<String>,
+    generation: usize,
+    fitness_score: f64,
+    parent_id: Option<String>,
+    routing_gates: Vec<usize>,
+    pre_mapped: bool,
+}
+
+impl From<CircuitGenomeV1> for CircuitGenome {
+    fn from(v1: CircuitGenomeV1) -> Self {
+        let metadata = v1.metadata;
+        Self {
+            num_qubits: v1.num_qubits,
+            gates: v1.gates,
+            metadata: CircuitMetadata {
+                species_id: metadata.species_id,
+                generation: metadata.generation,
+                fitness_score: metadata.fitness_score,
+                parent_id: metadata.parent_id,
+                routing_gates: metadata.routing_gates,
+                pre_mapped: metadata.pre_mapped,
+                frozen_regions: Vec::new(),
+            },
+            qns_format_version: v1.qns_format_version,
+        }
+    }
 }
 
 /// Quantum circuit representation.
@@ -29,6 +182,9 @@ pub struct CircuitGenome {
     pub gates: Vec<Gate>,
     /// Circuit metadata
     pub metadata: CircuitMetadata,
+    /// Serialization format version (0 for files written before versioning)
+    #[serde(default)]
+    pub qns_format_version: u32,
 }
 
 impl CircuitGenome {
@@ -38,6 +194,7 @@ impl CircuitGenome {
             num_qubits,
             gates: Vec::new(),
             metadata: CircuitMetadata::default(),
+            qns_format_version: QNS_FORMAT_VERSION,
         }
     }
 
@@ -47,6 +204,7 @@ impl CircuitGenome {
             num_qubits,
             gates: Vec::with_capacity(gate_capacity),
             metadata: CircuitMetadata::default(),
+            qns_format_version: QNS_FORMAT_VERSION,
         }
     }
 
@@ -63,14 +221,230 @@ impl CircuitGenome {
         Ok(())
     }
 
-    /// Adds multiple gates to the circuit.
+    /// Adds a gate inserted by routing and tags it in `metadata.routing_gates`.
+    pub fn add_routing_gate(&mut self, gate: Gate) -> Result<()> {
+        self.add_gate(gate)?;
+        self.metadata.routing_gates.push(self.gates.len() - 1);
+        Ok(())
+    }
+
+    /// Returns true if the gate at `index` was inserted by routing.
+    pub fn is_routing_gate(&self, index: usize) -> bool {
+        self.metadata.routing_gates.contains(&index)
+    }
+
+    /// Adds multiple gates to the circuit, validating each.
+    ///
+    /// All-or-nothing: if any gate is invalid, the gates already added from
+    /// this batch are removed again and the circuit is left unchanged. The
+    /// error is [`QnsError::InvalidBatchGate`], carrying the offending gate's
+    /// position within the batch.
     pub fn add_gates(&mut self, gates: impl IntoIterator<Item = Gate>) -> Result<()> {
-        for gate in gates {
-            self.add_gate(gate)?;
+        let original_len = self.gates.len();
+        for (index, gate) in gates.into_iter().enumerate() {
+            let display = gate.to_string();
+            if let Err(source) = self.add_gate(gate) {
+                self.gates.truncate(original_len);
+                return Err(QnsError::InvalidBatchGate {
+                    index,
+                    gate: display,
+                    source: Box::new(source),
+                });
+            }
         }
         Ok(())
     }
 
+    /// Appends `other` after this circuit's gates.
+    ///
+    /// Qubit `i` of `other` acts on qubit `i` of `self`; the width grows to
+    /// the larger of the two circuits. Routing tags and frozen regions of
+    /// `other` are carried over.
+    pub fn compose(&mut self, other: &CircuitGenome) {
+        let offset = self.gates.len();
+        self.num_qubits = self.num_qubits.max(other.num_qubits);
+        self.gates.extend(other.gates.iter().cloned());
+        self.metadata
+            .routing_gates
+            .extend(other.metadata.routing_gates.iter().map(|&i| i + offset));
+        self.metadata.frozen_regions.extend(
+            other
+                .metadata
+                .frozen_regions
+                .iter()
+                .map(|r| r.start + offset..r.end + offset),
+        );
+    }
+
+    /// Returns the inverse circuit: gates reversed, each replaced by its
+    /// [`Gate::inverse`].
+    ///
+    /// Composing a circuit with its inverse yields the identity, which is the
+    /// basis of uncomputation and mirror circuits. Routing tags and frozen
+    /// regions follow their gates to the mirrored positions.
+    ///
+    /// # Errors
+    /// Returns [`QnsError::Unsupported`] if the circuit contains a
+    /// non-invertible operation such as a measurement.
+    pub fn inverse(&self) -> Result<CircuitGenome> {
+        let last = self.gates.len().saturating_sub(1);
+        let gates = self
+            .gates
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, gate)| {
+                gate.inverse().ok_or_else(|| {
+                    QnsError::Unsupported(format!("gate {} ({}) is not invertible", i, gate))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut metadata = self.metadata.clone();
+        for index in metadata.routing_gates.iter_mut() {
+            *index = last - *index;
+        }
+        metadata.routing_gates.sort_unstable();
+        let len = self.gates.len();
+        for region in metadata.frozen_regions.iter_mut() {
+            let (start, end) = (region.start.min(len), region.end.min(len));
+            *region = len - end..len - start;
+        }
+        Ok(Self {
+            num_qubits: self.num_qubits,
+            gates,
+            metadata,
+            qns_format_version: self.qns_format_version,
+        })
+    }
+
+    /// Returns the circuit with every Toffoli replaced by
+    /// [`Gate::ccx_decomposition`].
+    ///
+    /// Routers and hardware only handle one- and two-qubit gates, so
+    /// three-qubit gates are expanded before routing. Routing tags follow
+    /// their gates to the shifted positions, and frozen regions stretch over
+    /// the expansion of their gates.
+    pub fn decompose_toffoli(&self) -> CircuitGenome {
+        let mut gates = Vec::with_capacity(self.gates.len());
+        let mut metadata = self.metadata.clone();
+        metadata.routing_gates.clear();
+        // new_position[i] is where old gate i starts; the extra entry is the end
+        let mut new_position = Vec::with_capacity(self.gates.len() + 1);
+        for (i, gate) in self.gates.iter().enumerate() {
+            new_position.push(gates.len());
+            if self.is_routing_gate(i) {
+                metadata.routing_gates.push(gates.len());
+            }
+            match gate {
+                Gate::Toffoli(c1, c2, t) => gates.extend(Gate::ccx_decomposition(*c1, *c2, *t)),
+                _ => gates.push(gate.clone()),
+            }
+        }
+        new_position.push(gates.len());
+        let len = self.gates.len();
+        for region in metadata.frozen_regions.iter_mut() {
+            *region = new_position[region.start.min(len)]..new_position[region.end.min(len)];
+        }
+        Self {
+            num_qubits: self.num_qubits,
+            gates,
+            metadata,
+            qns_format_version: self.qns_format_version,
+        }
+    }
+
+    /// Three-qubit bit-flip repetition code with syndrome extraction.
+    ///
+    /// Data qubit 0 is encoded into data qubits 0-2 (`α|000⟩ + β|111⟩`),
+    /// `errors` are applied, and the parities `Z0Z1` and `Z1Z2` are copied
+    /// onto ancillas 3 and 4 with CNOTs and measured. The syndrome
+    /// `(ancilla 3, ancilla 4)` is `(0, 0)` without error and `(1, 0)`,
+    /// `(1, 1)`, `(0, 1)` for a single X on data qubit 0, 1, 2.
+    ///
+    /// # Errors
+    /// Returns [`QnsError::InvalidBatchGate`], indexed into `errors`, if an
+    /// error gate is outside the five qubits.
+    pub fn bit_flip_code_syndrome(errors: &[Gate]) -> Result<Self> {
+        Self::repetition_code_syndrome(errors, false)
+    }
+
+    /// Three-qubit phase-flip repetition code with syndrome extraction.
+    ///
+    /// Like [`bit_flip_code_syndrome`](Self::bit_flip_code_syndrome) with
+    /// the data qubits in the Hadamard basis (`α|+++⟩ + β|---⟩`): a Z error
+    /// between the two Hadamard layers shows up as an X error to the same
+    /// parity checks, with the same syndrome table.
+    pub fn phase_flip_code_syndrome(errors: &[Gate]) -> Result<Self> {
+        Self::repetition_code_syndrome(errors, true)
+    }
+
+    fn repetition_code_syndrome(errors: &[Gate], phase_flip: bool) -> Result<Self> {
+        let hadamards = || (0..3).map(Gate::H).filter(|_| phase_flip);
+        let mut circuit = Self::new(5);
+        circuit.add_gates([Gate::CNOT(0, 1), Gate::CNOT(0, 2)])?;
+        circuit.add_gates(hadamards())?;
+        circuit.add_gates(errors.iter().cloned())?;
+        circuit.add_gates(hadamards())?;
+        circuit.add_gates([
+            Gate::CNOT(0, 3),
+            Gate::CNOT(1, 3),
+            Gate::CNOT(1, 4),
+            Gate::CNOT(2, 4),
+            Gate::Measure(3),
+            Gate::Measure(4),
+        ])?;
+        Ok(circuit)
+    }
+
+    /// Returns a canonical form of the circuit for comparison.
+    ///
+    /// Each gate is placed in the earliest layer after every preceding gate it
+    /// does not commute with (see [`Gate::commutes_with`]); gates within a
+    /// layer are sorted by (gate kind, qubits, parameters). Circuits that differ
+    /// only by swapping adjacent commuting gates share a normal form, which
+    /// makes this a test oracle for reordering passes.
+    ///
+    /// Routing tags and frozen regions are dropped since gate positions change.
+    pub fn to_normal_form(&self) -> CircuitGenome {
+        let mut layers: Vec<usize> = Vec::with_capacity(self.gates.len());
+        for (i, gate) in self.gates.iter().enumerate() {
+            let layer = self.gates[..i]
+                .iter()
+                .zip(&layers)
+                .filter(|(prev, _)| !prev.commutes_with(gate))
+                .map(|(_, &l)| l + 1)
+                .max()
+                .unwrap_or(0);
+            layers.push(layer);
+        }
+
+        let mut ordered: Vec<(usize, &Gate)> = layers.into_iter().zip(&self.gates).collect();
+        ordered.sort_by(|(la, a), (lb, b)| {
+            la.cmp(lb)
+                .then_with(|| gate_kind_index(a).cmp(&gate_kind_index(b)))
+                .then_with(|| a.qubits().cmp(&b.qubits()))
+                .then_with(|| {
+                    a.params()
+                        .iter()
+                        .zip(b.params().iter())
+                        .map(|(x, y)| x.total_cmp(y))
+                        .find(|o| o.is_ne())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+
+        let mut metadata = self.metadata.clone();
+        metadata.routing_gates.clear();
+        metadata.frozen_regions.clear();
+        Self {
+            num_qubits: self.num_qubits,
+            gates: ordered.into_iter().map(|(_, g)| g.clone()).collect(),
+            metadata,
+            qns_format_version: self.qns_format_version,
+        }
+    }
+
     /// Returns the circuit depth (critical path length).
     ///
     /// The depth is the maximum number of gates that must be
@@ -93,6 +467,66 @@ impl CircuitGenome {
         qubit_depths.into_iter().max().unwrap_or(0)
     }
 
+    /// Groups gate indices into ASAP time layers.
+    ///
+    /// Each gate goes in the first layer after every earlier gate on its
+    /// qubits, so gates within a layer act on disjoint qubits and there are
+    /// [`depth`](Self::depth) layers.
+    pub fn layers(&self) -> Vec<Vec<usize>> {
+        let mut qubit_depths = vec![0usize; self.num_qubits];
+        let mut layers: Vec<Vec<usize>> = Vec::new();
+
+        for (index, gate) in self.gates.iter().enumerate() {
+            let qs = gate.qubits();
+            let layer = qs.iter().map(|&q| qubit_depths[q]).max().unwrap_or(0);
+            for &q in &qs {
+                qubit_depths[q] = layer + 1;
+            }
+            if layer == layers.len() {
+                layers.push(Vec::new());
+            }
+            layers[layer].push(index);
+        }
+
+        layers
+    }
+
+    /// Returns the depth counting only two-qubit gates.
+    ///
+    /// Single-qubit gates cost nothing but measurements and three-qubit gates
+    /// still order the wires they touch. Two-qubit layers usually dominate
+    /// runtime and error on hardware.
+    pub fn two_qubit_depth(&self) -> usize {
+        let mut qubit_depths = vec![0usize; self.num_qubits];
+        for gate in &self.gates {
+            let qs = gate.qubits();
+            let cost = usize::from(gate.is_two_qubit());
+            let depth = qs.iter().map(|&q| qubit_depths[q]).max().unwrap_or(0) + cost;
+            for &q in &qs {
+                qubit_depths[q] = depth;
+            }
+        }
+        qubit_depths.into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns the number of T and T† gates (see [`t_depth`](Self::t_depth)).
+    pub fn t_count(&self) -> usize {
+        self.gates.iter().filter(|g| is_t_like(g)).count()
+    }
+
+    /// Returns the number of layers that contain a T or T† gate.
+    ///
+    /// Uses the ASAP schedule of [`layers`](Self::layers). T† appears as
+    /// `Rz(-π/4)`, so any `Rz` by an odd multiple of π/4 counts. The T-depth
+    /// bounds how fast magic states must be supplied in fault-tolerant
+    /// execution.
+    pub fn t_depth(&self) -> usize {
+        self.layers()
+            .iter()
+            .filter(|layer| layer.iter().any(|&i| is_t_like(&self.gates[i])))
+            .count()
+    }
+
     /// Returns the total gate count.
     pub fn gate_count(&self) -> usize {
         self.gates.len()
@@ -103,6 +537,53 @@ impl CircuitGenome {
         self.gates.iter().filter(|g| g.is_two_qubit()).count()
     }
 
+    /// Checks if every gate preserves Hamming weight (particle number).
+    ///
+    /// Number-conserving circuits (common in quantum chemistry) can be
+    /// simulated within a fixed-weight subspace.
+    pub fn preserves_hamming_weight(&self) -> bool {
+        self.gates.iter().all(Gate::preserves_hamming_weight)
+    }
+
+    /// Returns the qubits touched by at least one gate, in ascending order.
+    pub fn used_qubits(&self) -> Vec<usize> {
+        let mut used = vec![false; self.num_qubits];
+        for gate in &self.gates {
+            for q in gate.qubits() {
+                used[q] = true;
+            }
+        }
+        (0..self.num_qubits).filter(|&q| used[q]).collect()
+    }
+
+    /// Drops unused qubits, re-indexing the remaining ones densely.
+    ///
+    /// Returns the compacted circuit together with `mapping`, where
+    /// `mapping[new_qubit] = old_qubit`. Gate order and metadata are kept.
+    pub fn compact(&self) -> (CircuitGenome, Vec<usize>) {
+        let mapping = self.used_qubits();
+        let mut new_index = vec![0; self.num_qubits];
+        for (new, &old) in mapping.iter().enumerate() {
+            new_index[old] = new;
+        }
+
+        let compacted = Self {
+            num_qubits: mapping.len(),
+            gates: self
+                .gates
+                .iter()
+                .map(|g| g.map_qubits(&new_index))
+                .collect(),
+            metadata: CircuitMetadata {
+                // Renumbered qubits are no longer physical
+                pre_mapped: false,
+                ..self.metadata.clone()
+            },
+            qns_format_version: self.qns_format_version,
+        };
+        (compacted, mapping)
+    }
+
     /// Clears all gates from the circuit.
     pub fn clear(&mut self) {
         self.gates.clear();
@@ -114,8 +595,90 @@ impl CircuitGenome {
             num_qubits: self.num_qubits,
             gates: self.gates.clone(),
             metadata: CircuitMetadata::default(),
+            qns_format_version: QNS_FORMAT_VERSION,
         }
     }
+
+    /// Saves the circuit to a compact binary (bincode) file.
+    ///
+    /// Gate parameters and metadata are stored bit-exactly, which makes this
+    /// suitable for caching large optimized circuits between runs. The
+    /// payload is preceded by a magic tag and the format version, so loaders
+    /// can tell layouts apart before decoding.
+    pub fn save_bincode<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(&BINCODE_MAGIC)?;
+        bincode::serialize_into(&mut writer, &QNS_FORMAT_VERSION)?;
+        bincode::serialize_into(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Loads a circuit previously written by [`CircuitGenome::save_bincode`].
+    ///
+    /// Files without the version envelope are decoded with the pre-versioning
+    /// layout, and older enveloped versions with their own layout.
+    ///
+    /// # Errors
+    /// Returns [`QnsError::UnsupportedFormatVersion`] for files written by a
+    /// newer QNS format.
+    pub fn load_bincode<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_bincode_bytes(&std::fs::read(path)?)
+    }
+
+    fn from_bincode_bytes(bytes: &[u8]) -> Result<Self> {
+        let Some(versioned) = bytes.strip_prefix(&BINCODE_MAGIC) else {
+            let legacy: LegacyCircuitGenome = bincode::deserialize(bytes)?;
+            return Self::from(legacy).migrated();
+        };
+        let version: u32 = bincode::deserialize(versioned)?;
+        migrate_format_version("CircuitGenome", version)?;
+        let payload = &versioned[std::mem::size_of::<u32>()..];
+        let circuit: Self = if version == 1 {
+            bincode::deserialize::<CircuitGenomeV1>(payload)?.into()
+        } else {
+            bincode::deserialize(payload)?
+        };
+        circuit.migrated()
+    }
+
+    /// Serializes the circuit to the canonical JSON gate list:
+    /// `{"num_qubits": n, "gates": [{"type": "CNOT", "qubits": [0, 1]}, ...]}`.
+    ///
+    /// Only the qubit count and gates are written; metadata is dropped. Use
+    /// `serde_json` on the circuit itself for a lossless snapshot.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&GateList::from_circuit(self))?)
+    }
+
+    /// Loads a circuit from JSON.
+    ///
+    /// Accepts the gate list written by [`CircuitGenome::to_json`] as well as
+    /// a full serialized `CircuitGenome` (recognized by its `metadata` field),
+    /// whose format version is checked.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        if value.get("metadata").is_some() {
+            let circuit: Self = serde_json::from_value(value)?;
+            return circuit.migrated();
+        }
+
+        let list: GateList = serde_json::from_value(value)?;
+        let gates = list
+            .gates
+            .iter()
+            .map(|entry| entry.to_gate())
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut circuit = Self::with_capacity(list.num_qubits, gates.len());
+        circuit.add_gates(gates)?;
+        Ok(circuit)
+    }
+
+    /// Rejects unsupported future versions and upgrades older ones.
+    fn migrated(mut self) -> Result<Self> {
+        self.qns_format_version = migrate_format_version("CircuitGenome", self.qns_format_version)?;
+        Ok(self)
+    }
 }
 
 impl Default for CircuitGenome {
@@ -124,6 +687,43 @@ impl Default for CircuitGenome {
     }
 }
 
+/// Fixed ordering of gate kinds used by [`CircuitGenome::to_normal_form`].
+fn gate_kind_index(gate: &Gate) -> u8 {
+    match gate {
+        Gate::H(_) => 0,
+        Gate::X(_) => 1,
+        Gate::Y(_) => 2,
+        Gate::Z(_) => 3,
+        Gate::S(_) => 4,
+        Gate::T(_) => 5,
+        Gate::Rx(_, _) => 6,
+        Gate::Ry(_, _) => 7,
+        Gate::Rz(_, _) => 8,
+        Gate::CNOT(_, _) => 9,
+        Gate::CZ(_, _) => 10,
+        Gate::SWAP(_, _) => 11,
+        Gate::FSim(_, _, _, _) => 12,
+        Gate::Measure(_) => 13,
+        Gate::U(_, _, _, _) => 14,
+        Gate::CPhase(_, _, _) => 15,
+        Gate::Toffoli(_, _, _) => 16,
+        Gate::Reset(_) => 17,
+    }
+}
+
+/// True for T, T† and other `Rz` rotations that need a magic state.
+fn is_t_like(gate: &Gate) -> bool {
+    match gate {
+        Gate::T(_) => true,
+        Gate::Rz(_, theta) => {
+            let eighths = theta / std::f64::consts::FRAC_PI_4;
+            let nearest = eighths.round();
+            (eighths - nearest).abs() < 1e-9 && nearest.rem_euclid(2.0) == 1.0
+        },
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +735,87 @@ mod tests {
         assert!(circuit.gates.is_empty());
     }
 
+    #[test]
+    fn test_repetition_code_syndrome_layout() {
+        let bit_flip = CircuitGenome::bit_flip_code_syndrome(&[]).unwrap();
+        assert_eq!(bit_flip.num_qubits, 5);
+        assert_eq!(bit_flip.gates.len(), 8);
+        assert_eq!(&bit_flip.gates[6..], &[Gate::Measure(3), Gate::Measure(4)]);
+
+        let phase_flip = CircuitGenome::phase_flip_code_syndrome(&[Gate::Z(1)]).unwrap();
+        assert_eq!(phase_flip.gates.len(), 8 + 6 + 1);
+        assert_eq!(phase_flip.gates[5], Gate::Z(1));
+
+        assert!(matches!(
+            CircuitGenome::bit_flip_code_syndrome(&[Gate::X(0), Gate::X(5)]),
+            Err(QnsError::InvalidBatchGate { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_layers() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::H(1),
+                Gate::CNOT(0, 1),
+                Gate::X(2),
+                Gate::H(0),
+            ])
+            .unwrap();
+        assert_eq!(circuit.layers(), vec![vec![0, 1, 3], vec![2], vec![4]]);
+        assert_eq!(circuit.layers().len(), circuit.depth());
+        assert!(CircuitGenome::new(2).layers().is_empty());
+    }
+
+    #[test]
+    fn test_t_depth() {
+        // T gates on different qubits share a layer
+        let mut parallel = CircuitGenome::new(2);
+        parallel.add_gates([Gate::T(0), Gate::T(1)]).unwrap();
+        assert_eq!(parallel.t_depth(), 1);
+
+        // A shared qubit forces T and T† into separate layers
+        let mut serial = CircuitGenome::new(2);
+        serial
+            .add_gates([
+                Gate::T(0),
+                Gate::CNOT(0, 1),
+                Gate::Rz(1, -std::f64::consts::FRAC_PI_4),
+                Gate::S(1),
+            ])
+            .unwrap();
+        assert_eq!(serial.depth(), 4);
+        assert_eq!(serial.t_depth(), 2);
+
+        assert_eq!(serial.t_count(), 2);
+        assert_eq!(serial.two_qubit_depth(), 1);
+
+        let mut clifford = CircuitGenome::new(1);
+        clifford
+            .add_gates([Gate::S(0), Gate::Rz(0, std::f64::consts::FRAC_PI_2)])
+            .unwrap();
+        assert_eq!(clifford.t_depth(), 0);
+    }
+
+    #[test]
+    fn test_two_qubit_depth() {
+        let mut circuit = CircuitGenome::new(4);
+        circuit
+            .add_gates([
+                Gate::CNOT(0, 1),
+                Gate::CNOT(2, 3),
+                Gate::H(1),
+                Gate::T(1),
+                Gate::CZ(1, 2),
+            ])
+            .unwrap();
+        assert_eq!(circuit.depth(), 4);
+        assert_eq!(circuit.two_qubit_depth(), 2);
+        assert_eq!(CircuitGenome::new(2).two_qubit_depth(), 0);
+    }
+
     #[test]
     fn test_add_gate() {
         let mut circuit = CircuitGenome::new(3);
@@ -143,6 +824,30 @@ mod tests {
         assert_eq!(circuit.gate_count(), 2);
     }
 
+    #[test]
+    fn test_compact() {
+        let mut circuit = CircuitGenome::new(10);
+        circuit.add_gate(Gate::H(7)).unwrap();
+        circuit.add_gate(Gate::CNOT(7, 2)).unwrap();
+        circuit.add_gate(Gate::Measure(2)).unwrap();
+        assert_eq!(circuit.used_qubits(), vec![2, 7]);
+
+        let (compacted, mapping) = circuit.compact();
+        assert_eq!(compacted.num_qubits, 2);
+        assert_eq!(mapping, vec![2, 7]);
+        assert_eq!(
+            compacted.gates,
+            vec![Gate::H(1), Gate::CNOT(1, 0), Gate::Measure(0)]
+        );
+        // Mapping back recovers the original gates
+        let restored: Vec<Gate> = compacted
+            .gates
+            .iter()
+            .map(|g| g.map_qubits(&mapping))
+            .collect();
+        assert_eq!(restored, circuit.gates);
+    }
+
     #[test]
     fn test_add_gate_invalid_qubit() {
         let mut circuit = CircuitGenome::new(2);
@@ -150,6 +855,37 @@ mod tests {
         assert!(circuit.add_gate(Gate::CNOT(0, 5)).is_err());
     }
 
+    #[test]
+    fn test_add_gates_batch() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([Gate::H(0), Gate::CNOT(0, 1), Gate::Measure(1)])
+            .unwrap();
+        assert_eq!(circuit.gates.len(), 3);
+
+        // A bad gate in the middle rolls back the whole batch
+        let err = circuit
+            .add_gates([Gate::X(0), Gate::CNOT(1, 4), Gate::Z(1)])
+            .unwrap_err();
+        match &err {
+            QnsError::InvalidBatchGate {
+                index,
+                gate,
+                source,
+            } => {
+                assert_eq!(*index, 1);
+                assert_eq!(gate, "CNOT(1, 4)");
+                assert!(matches!(**source, QnsError::InvalidQubit(4, 2)));
+            },
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(err.to_string().contains("batch gate 1"));
+        assert_eq!(
+            circuit.gates,
+            vec![Gate::H(0), Gate::CNOT(0, 1), Gate::Measure(1)]
+        );
+    }
+
     #[test]
     fn test_depth() {
         let mut circuit = CircuitGenome::new(3);
@@ -173,6 +909,73 @@ mod tests {
         assert_eq!(circuit.depth(), 3);
     }
 
+    #[test]
+    fn test_compose() {
+        let mut first = CircuitGenome::new(1);
+        first.add_gate(Gate::H(0)).unwrap();
+
+        let mut second = CircuitGenome::new(2);
+        second.add_gate(Gate::CNOT(0, 1)).unwrap();
+        second.add_routing_gate(Gate::SWAP(0, 1)).unwrap();
+
+        first.compose(&second);
+        assert_eq!(first.num_qubits, 2);
+        assert_eq!(
+            first.gates,
+            vec![Gate::H(0), Gate::CNOT(0, 1), Gate::SWAP(0, 1)]
+        );
+        assert_eq!(first.metadata.routing_gates, vec![2]);
+    }
+
+    #[test]
+    fn test_normal_form() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::X(2),
+                Gate::Rz(1, 0.3),
+                Gate::Z(1),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(1, 2),
+            ])
+            .unwrap();
+
+        // Legal commutations: disjoint H/X swap, diagonal Rz/Z swap on qubit 1
+        let mut reordered = CircuitGenome::new(3);
+        reordered
+            .add_gates([
+                Gate::X(2),
+                Gate::Z(1),
+                Gate::H(0),
+                Gate::Rz(1, 0.3),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(1, 2),
+            ])
+            .unwrap();
+        assert_eq!(
+            circuit.to_normal_form().gates,
+            reordered.to_normal_form().gates
+        );
+
+        // H(0) after CNOT(0, 1) is a genuinely different circuit
+        let mut different = CircuitGenome::new(3);
+        different
+            .add_gates([
+                Gate::X(2),
+                Gate::Rz(1, 0.3),
+                Gate::Z(1),
+                Gate::CNOT(0, 1),
+                Gate::H(0),
+                Gate::CNOT(1, 2),
+            ])
+            .unwrap();
+        assert_ne!(
+            circuit.to_normal_form().gates,
+            different.to_normal_form().gates
+        );
+    }
+
     #[test]
     fn test_two_qubit_gate_count() {
         let mut circuit = CircuitGenome::new(3);
@@ -183,4 +986,297 @@ mod tests {
 
         assert_eq!(circuit.two_qubit_gate_count(), 2);
     }
+
+    #[test]
+    fn test_preserves_hamming_weight() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::Rz(0, 0.4)).unwrap();
+        circuit.add_gate(Gate::SWAP(0, 1)).unwrap();
+        circuit.add_gate(Gate::CZ(1, 2)).unwrap();
+        circuit.add_gate(Gate::Rz(2, -1.1)).unwrap();
+        assert!(circuit.preserves_hamming_weight());
+
+        circuit.add_gate(Gate::H(1)).unwrap();
+        assert!(!circuit.preserves_hamming_weight());
+    }
+
+    #[test]
+    fn test_transforms_remap_frozen_regions() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::Toffoli(0, 1, 2),
+                Gate::S(1), // frozen
+                Gate::T(1), // frozen
+                Gate::X(2),
+            ])
+            .unwrap();
+        circuit.metadata.frozen_regions.push(2..4);
+        let frozen = |c: &CircuitGenome| -> Vec<Gate> {
+            c.metadata
+                .frozen_regions
+                .iter()
+                .flat_map(|r| c.gates[r.clone()].to_vec())
+                .collect()
+        };
+
+        let mut composed = circuit.clone();
+        composed.compose(&circuit);
+        assert_eq!(composed.metadata.frozen_regions, vec![2..4, 7..9]);
+
+        let inverse = circuit.inverse().unwrap();
+        assert_eq!(inverse.metadata.frozen_regions, vec![1..3]);
+        assert_eq!(
+            frozen(&inverse),
+            vec![Gate::T(1).inverse().unwrap(), Gate::S(1).inverse().unwrap()]
+        );
+
+        let decomposed = circuit.decompose_toffoli();
+        assert_eq!(frozen(&decomposed), vec![Gate::S(1), Gate::T(1)]);
+        assert!(circuit.to_normal_form().metadata.frozen_regions.is_empty());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::T(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_routing_gate(Gate::SWAP(0, 1)).unwrap();
+
+        let inverse = circuit.inverse().unwrap();
+        assert_eq!(
+            inverse.gates,
+            vec![
+                Gate::SWAP(0, 1),
+                Gate::CNOT(0, 1),
+                Gate::Rz(0, -std::f64::consts::FRAC_PI_4),
+            ]
+        );
+        assert_eq!(inverse.metadata.routing_gates, vec![0]);
+
+        circuit.add_gate(Gate::Measure(1)).unwrap();
+        let err = circuit.inverse().unwrap_err();
+        assert!(
+            matches!(&err, QnsError::Unsupported(msg) if msg == "gate 3 (Measure(1)) is not invertible"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_json_gate_list_round_trip() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::X(1),
+                Gate::Y(2),
+                Gate::Z(0),
+                Gate::S(1),
+                Gate::T(2),
+                Gate::Rx(0, 0.1 + 0.2),
+                Gate::Ry(1, -1e-9),
+                Gate::Rz(2, std::f64::consts::PI),
+                Gate::U(0, 0.4, -1.3, 2.2),
+                Gate::CNOT(0, 1),
+                Gate::CZ(1, 2),
+                Gate::CPhase(2, 0, 0.7),
+                Gate::SWAP(0, 2),
+                Gate::FSim(1, 0, 0.5, 1.0 / 3.0),
+                Gate::Toffoli(0, 1, 2),
+                Gate::Measure(1),
+            ])
+            .unwrap();
+
+        let json = circuit.to_json().unwrap();
+        assert!(
+            json.contains(r#"{"type":"CNOT","qubits":[0,1]}"#),
+            "{}",
+            json
+        );
+        let loaded = CircuitGenome::from_json(&json).unwrap();
+        assert_eq!(loaded.num_qubits, circuit.num_qubits);
+        assert_eq!(loaded.gates, circuit.gates);
+
+        let hand_written = r#"{"num_qubits": 2, "gates": [
+            {"type": "CNOT", "qubits": [0, 1]},
+            {"type": "Rz", "qubits": [0], "params": [0.5]}
+        ]}"#;
+        assert_eq!(
+            CircuitGenome::from_json(hand_written).unwrap().gates,
+            vec![Gate::CNOT(0, 1), Gate::Rz(0, 0.5)]
+        );
+
+        // Wrong arity, unknown type and out-of-range qubits are rejected
+        for bad in [
+            r#"{"num_qubits": 2, "gates": [{"type": "Rz", "qubits": [0]}]}"#,
+            r#"{"num_qubits": 2, "gates": [{"type": "CCZ", "qubits": [0, 1]}]}"#,
+            r#"{"num_qubits": 1, "gates": [{"type": "H", "qubits": [3]}]}"#,
+        ] {
+            assert!(CircuitGenome::from_json(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit
+            .add_gate(Gate::Rx(1, std::f64::consts::PI / 7.0))
+            .unwrap();
+        circuit
+            .add_gate(Gate::Rz(2, 1.234_567_890_123_456_7e-9))
+            .unwrap();
+        circuit.add_gate(Gate::Ry(0, -0.1 - 0.2)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+        circuit.add_gate(Gate::Measure(1)).unwrap();
+        circuit.metadata.species_id = Some("qaoa-p2".to_string());
+        circuit.metadata.generation = 7;
+        circuit.metadata.fitness_score = 0.987_654_321_012_345;
+
+        let path =
+            std::env::temp_dir().join(format!("qns_circuit_bincode_{}.bin", std::process::id()));
+        circuit.save_bincode(&path).unwrap();
+        let loaded = CircuitGenome::load_bincode(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.num_qubits, circuit.num_qubits);
+        // Exact equality: angles must survive bit-for-bit
+        assert_eq!(loaded.gates, circuit.gates);
+        for (a, b) in loaded.gates.iter().zip(&circuit.gates) {
+            if let (Some(x), Some(y)) = (a.rotation_angle(), b.rotation_angle()) {
+                assert_eq!(x.to_bits(), y.to_bits());
+            }
+        }
+        assert_eq!(loaded.metadata.species_id, circuit.metadata.species_id);
+        assert_eq!(loaded.metadata.generation, 7);
+        assert_eq!(
+            loaded.metadata.fitness_score.to_bits(),
+            circuit.metadata.fitness_score.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_bincode_version_envelope() {
+        // Written by `save_bincode` before the envelope existed: H(0),
+        // CNOT(0, 1), Rz(2, 0.25), SWAP(1, 2), CZ(0, 2), Measure(1) on 3
+        // qubits, species "legacy", generation 3, fitness 0.5, parent "p0"
+        #[rustfmt::skip]
+        let legacy: &[u8] = &[
+            3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            8, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 63, 11, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            1, 6, 0, 0, 0, 0, 0, 0, 0, 108, 101, 103, 97, 99, 121, 3, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 224, 63, 1, 2, 0, 0, 0, 0, 0, 0, 0, 112, 48,
+        ];
+        let loaded = CircuitGenome::from_bincode_bytes(legacy).unwrap();
+        assert_eq!(
+            loaded.gates,
+            vec![
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::Rz(2, 0.25),
+                Gate::SWAP(1, 2),
+                Gate::CZ(0, 2),
+                Gate::Measure(1),
+            ]
+        );
+        assert_eq!(loaded.metadata.species_id.as_deref(), Some("legacy"));
+        assert_eq!(loaded.metadata.generation, 3);
+        assert_eq!(loaded.metadata.fitness_score, 0.5);
+        assert_eq!(loaded.metadata.parent_id.as_deref(), Some("p0"));
+        assert!(loaded.metadata.routing_gates.is_empty());
+        assert!(loaded.metadata.frozen_regions.is_empty());
+        assert_eq!(loaded.qns_format_version, QNS_FORMAT_VERSION);
+
+        // New files carry the envelope and round-trip
+        let path = std::env::temp_dir().join(format!(
+            "qns_circuit_bincode_envelope_{}.bin",
+            std::process::id()
+        ));
+        loaded.save_bincode(&path).unwrap();
+        let mut bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(bytes[..4], BINCODE_MAGIC);
+        assert_eq!(bytes[4..8], QNS_FORMAT_VERSION.to_le_bytes());
+        let reloaded = CircuitGenome::from_bincode_bytes(&bytes).unwrap();
+        assert_eq!(reloaded.gates, loaded.gates);
+        assert_eq!(reloaded.metadata.parent_id.as_deref(), Some("p0"));
+
+        // Future versions are rejected before their payload is decoded
+        bytes[4..8].copy_from_slice(&(QNS_FORMAT_VERSION + 1).to_le_bytes());
+        bytes.truncate(8);
+        assert!(matches!(
+            CircuitGenome::from_bincode_bytes(&bytes),
+            Err(QnsError::UnsupportedFormatVersion { found, .. }) if found == QNS_FORMAT_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn test_bincode_v1_fixture() {
+        // Format version 1 envelope: H(0), CNOT(0, 1), FSim(1, 2, 0.5, 0.25),
+        // routing SWAP(0, 1), CZ(1, 2), Measure(2) on 3 qubits, species "v1",
+        // generation 4, fitness 0.75, pre-mapped
+        #[rustfmt::skip]
+        let v1: &[u8] = &[
+            81, 78, 83, 66, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 63, 0, 0, 0, 0, 0, 0, 208, 63, 11, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 1, 0,
+            0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+            0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 118, 49, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 232, 63, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+            0, 0, 0,
+        ];
+        let loaded = CircuitGenome::from_bincode_bytes(v1).unwrap();
+        assert_eq!(
+            loaded.gates,
+            vec![
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::FSim(1, 2, 0.5, 0.25),
+                Gate::SWAP(0, 1),
+                Gate::CZ(1, 2),
+                Gate::Measure(2),
+            ]
+        );
+        assert_eq!(loaded.metadata.species_id.as_deref(), Some("v1"));
+        assert_eq!(loaded.metadata.generation, 4);
+        assert_eq!(loaded.metadata.fitness_score, 0.75);
+        assert_eq!(loaded.metadata.routing_gates, vec![3]);
+        assert!(loaded.metadata.pre_mapped);
+        assert!(loaded.metadata.frozen_regions.is_empty());
+        assert_eq!(loaded.qns_format_version, QNS_FORMAT_VERSION);
+
+        // The current version round-trips gates added after version 1
+        let mut circuit = loaded.clone();
+        circuit
+            .add_gates([
+                Gate::U(0, 0.1, 0.2, 0.3),
+                Gate::CPhase(0, 2, 0.4),
+                Gate::Toffoli(0, 1, 2),
+                Gate::Reset(1),
+            ])
+            .unwrap();
+        circuit.metadata.frozen_regions = vec![0..2, 6..8];
+        let path = std::env::temp_dir().join(format!(
+            "qns_circuit_bincode_current_{}.bin",
+            std::process::id()
+        ));
+        circuit.save_bincode(&path).unwrap();
+        let reloaded = CircuitGenome::load_bincode(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(reloaded.gates, circuit.gates);
+        assert_eq!(reloaded.metadata.frozen_regions, vec![0..2, 6..8]);
+    }
+
+    #[test]
+    fn test_load_bincode_missing_file() {
+        let path = std::env::temp_dir().join("qns_circuit_bincode_does_not_exist.bin");
+        assert!(CircuitGenome::load_bincode(path).is_err());
+    }
 }
@@ -0,0 +1,95 @@
+//! Serialization format versioning.
+//!
+//! Every serialized QNS type carries a `qns_format_version` tag. Files written
+//! before the tag existed deserialize as version 0.
+
+use crate::error::{QnsError, Result};
+
+/// Format version written by this build.
+///
+/// - 0: untagged files written before versioning
+/// - 1: adds the `qns_format_version` tag
+/// - 2: adds `Gate::U`, `Gate::CPhase`, `Gate::Toffoli`, `Gate::Reset` and
+///   `CircuitMetadata::frozen_regions`
+///
+/// Bump this whenever the bincode layout of a serialized type changes, and
+/// keep a decoder for the previous layout.
+pub const QNS_FORMAT_VERSION: u32 = 2;
+
+/// Checks a loaded format version and migrates it to [`QNS_FORMAT_VERSION`].
+///
+/// Versions newer than this build understands are rejected with
+/// [`QnsError::UnsupportedFormatVersion`]. Version 0 (untagged) JSON files
+/// read with the current layout, since missing fields take their defaults, so
+/// migrating them only updates the tag. Version 0 bincode files use an older
+/// gate set and metadata layout and are decoded separately by
+/// [`CircuitGenome::load_bincode`](crate::types::CircuitGenome::load_bincode).
+pub fn migrate_format_version(kind: &str, found: u32) -> Result<u32> {
+    if found > QNS_FORMAT_VERSION {
+        return Err(QnsError::UnsupportedFormatVersion {
+            kind: kind.to_string(),
+            found,
+            supported: QNS_FORMAT_VERSION,
+        });
+    }
+    Ok(QNS_FORMAT_VERSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CircuitGenome, Gate, HardwareProfile, NoiseVector};
+
+    /// Re-tags serialized JSON with `version`, or drops the tag if `None`.
+    fn retag(json: &str, version: Option<u32>) -> String {
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let object = value.as_object_mut().unwrap();
+        match version {
+            Some(v) => object.insert("qns_format_version".to_string(), v.into()),
+            None => object.remove("qns_format_version"),
+        };
+        value.to_string()
+    }
+
+    #[test]
+    fn test_format_version_checked_on_load() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        let json = serde_json::to_string(&circuit).unwrap();
+        assert!(json.contains(&format!("\"qns_format_version\":{}", QNS_FORMAT_VERSION)));
+
+        // Current version loads cleanly
+        let loaded = CircuitGenome::from_json(&json).unwrap();
+        assert_eq!(loaded.gates, circuit.gates);
+        assert_eq!(loaded.qns_format_version, QNS_FORMAT_VERSION);
+
+        // Untagged files predate versioning and migrate
+        let legacy = CircuitGenome::from_json(&retag(&json, None)).unwrap();
+        assert_eq!(legacy.qns_format_version, QNS_FORMAT_VERSION);
+
+        // Future versions are rejected with a descriptive error
+        let future = QNS_FORMAT_VERSION + 1;
+        let err = CircuitGenome::from_json(&retag(&json, Some(future))).unwrap_err();
+        assert!(matches!(
+            err,
+            QnsError::UnsupportedFormatVersion { found, supported, .. }
+                if found == future && supported == QNS_FORMAT_VERSION
+        ));
+        let message = err.to_string();
+        assert!(message.contains("CircuitGenome"));
+        assert!(message.contains(&format!("version {}", future)));
+
+        let hw = HardwareProfile::linear("line", 3);
+        let json = serde_json::to_string(&hw).unwrap();
+        let loaded = HardwareProfile::from_json(&json).unwrap();
+        assert!(loaded.are_connected(1, 2));
+        let err = HardwareProfile::from_json(&retag(&json, Some(future))).unwrap_err();
+        assert!(err.to_string().contains("HardwareProfile"));
+
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let json = serde_json::to_string(&noise).unwrap();
+        assert_eq!(NoiseVector::from_json(&json).unwrap().t1_mean, 100.0);
+        let err = NoiseVector::from_json(&retag(&json, Some(future))).unwrap_err();
+        assert!(err.to_string().contains("NoiseVector"));
+    }
+}
@@ -10,10 +10,11 @@ use serde::{Deserialize, Serialize};
 
 /// Quantum gate enumeration.
 ///
-/// Supports 12 gate types:
-/// - Single-qubit: H, X, Y, Z, S, T, Rx, Ry, Rz
-/// - Two-qubit: CNOT, CZ, SWAP
-/// - Measurement: Measure
+/// Supports 17 gate types:
+/// - Single-qubit: H, X, Y, Z, S, T, Rx, Ry, Rz, U
+/// - Two-qubit: CNOT, CZ, CPhase, SWAP, FSim
+/// - Three-qubit: Toffoli
+/// - Non-unitary: Measure, Reset
 ///
 /// # Example
 ///
@@ -58,10 +59,23 @@ pub enum Gate {
     CZ(usize, usize),
     /// SWAP gate
     SWAP(usize, usize),
+    /// Google fSim(θ, φ) gate (qubit1, qubit2, θ, φ)
+    FSim(usize, usize, f64, f64),
 
     // Measurement
     /// Measurement in computational basis
     Measure(usize),
+
+    // Variants added after format version 1 go last: bincode stores the
+    // variant index, so inserting earlier would change existing files.
+    /// General single-qubit unitary U(θ, φ, λ) = Rz(φ)·Ry(θ)·Rz(λ) up to phase
+    U(usize, f64, f64, f64),
+    /// Controlled-phase gate (control, target, λ): phase e^{iλ} on |11⟩
+    CPhase(usize, usize, f64),
+    /// Toffoli (CCX) gate (control1, control2, target)
+    Toffoli(usize, usize, usize),
+    /// Reset to |0⟩, e.g. to reuse a measured qubit mid-circuit
+    Reset(usize),
 }
 
 impl Gate {
@@ -69,6 +83,7 @@ impl Gate {
     ///
     /// For single-qubit gates, returns a single-element vector.
     /// For two-qubit gates, returns [control, target] or [qubit1, qubit2].
+    /// For Toffoli, returns [control1, control2, target].
     pub fn qubits(&self) -> Vec<usize> {
         match self {
             Gate::H(q)
@@ -80,8 +95,39 @@ impl Gate {
             | Gate::Rx(q, _)
             | Gate::Ry(q, _)
             | Gate::Rz(q, _)
-            | Gate::Measure(q) => vec![*q],
-            Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => vec![*c, *t],
+            | Gate::U(q, _, _, _)
+            | Gate::Measure(q)
+            | Gate::Reset(q) => vec![*q],
+            Gate::CNOT(c, t)
+            | Gate::CZ(c, t)
+            | Gate::CPhase(c, t, _)
+            | Gate::SWAP(c, t)
+            | Gate::FSim(c, t, _, _) => vec![*c, *t],
+            Gate::Toffoli(c1, c2, t) => vec![*c1, *c2, *t],
+        }
+    }
+
+    /// Returns the variant name, e.g. `"CNOT"` or `"Rz"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gate::H(_) => "H",
+            Gate::X(_) => "X",
+            Gate::Y(_) => "Y",
+            Gate::Z(_) => "Z",
+            Gate::S(_) => "S",
+            Gate::T(_) => "T",
+            Gate::Rx(..) => "Rx",
+            Gate::Ry(..) => "Ry",
+            Gate::Rz(..) => "Rz",
+            Gate::U(..) => "U",
+            Gate::CNOT(..) => "CNOT",
+            Gate::CZ(..) => "CZ",
+            Gate::CPhase(..) => "CPhase",
+            Gate::SWAP(..) => "SWAP",
+            Gate::FSim(..) => "FSim",
+            Gate::Toffoli(..) => "Toffoli",
+            Gate::Measure(_) => "Measure",
+            Gate::Reset(_) => "Reset",
         }
     }
 
@@ -92,8 +138,14 @@ impl Gate {
             Gate::X(_) | Gate::Rx(_, _) => GateType::XRotation,
             Gate::Y(_) | Gate::Ry(_, _) => GateType::YRotation,
             Gate::H(_) => GateType::Hadamard,
-            Gate::CNOT(_, _) | Gate::CZ(_, _) | Gate::SWAP(_, _) => GateType::TwoQubit,
-            Gate::Measure(_) => GateType::Measurement,
+            Gate::U(_, _, _, _) => GateType::General,
+            Gate::CNOT(_, _)
+            | Gate::CZ(_, _)
+            | Gate::CPhase(_, _, _)
+            | Gate::SWAP(_, _)
+            | Gate::FSim(_, _, _, _) => GateType::TwoQubit,
+            Gate::Toffoli(_, _, _) => GateType::ThreeQubit,
+            Gate::Measure(_) | Gate::Reset(_) => GateType::Measurement,
         }
     }
 
@@ -104,6 +156,10 @@ impl Gate {
     /// 1. Gates on disjoint qubits always commute
     /// 2. Diagonal gates (Z, S, T, Rz) commute with each other
     /// 3. Same-axis rotations commute (Rx with Rx, etc.)
+    /// 4. FSim commutes with FSim, CZ and CPhase on the same qubit pair
+    /// 5. Diagonal gates commute with CZ, CPhase and a CNOT or Toffoli control,
+    ///    X rotations with a CNOT or Toffoli target, and CNOTs/Toffolis sharing
+    ///    only controls or only targets
     ///
     /// # Example
     ///
@@ -129,16 +185,53 @@ impl Gate {
             return true;
         }
 
-        // Measurement doesn't commute with anything on same qubit
-        if matches!(self, Gate::Measure(_)) || matches!(other, Gate::Measure(_)) {
+        // Measurement and reset don't commute with anything on same qubit
+        if self.is_non_unitary() || other.is_non_unitary() {
             return false;
         }
 
-        // Check gate type compatibility for same-qubit operations
-        let t1 = self.gate_type();
-        let t2 = other.gate_type();
+        // Excitation-preserving gates on the same pair are block-diagonal in
+        // {|00⟩}, {|01⟩, |10⟩}, {|11⟩} with commuting blocks
+        let same_pair = q1.len() == 2 && q2.len() == 2 && q1.iter().all(|q| q2.contains(q));
+        if same_pair
+            && matches!(
+                (self, other),
+                (
+                    Gate::FSim(..),
+                    Gate::FSim(..) | Gate::CZ(..) | Gate::CPhase(..)
+                ) | (Gate::CZ(..) | Gate::CPhase(..), Gate::FSim(..))
+            )
+        {
+            return true;
+        }
+
+        // Gates that act on every shared qubit as a function of the same Pauli
+        // axis commute, e.g. Rz on a CNOT control or Rx on a CNOT target
+        q1.iter()
+            .filter(|q| q2.contains(q))
+            .all(|&q| match (self.axis_on(q), other.axis_on(q)) {
+                (Some(a), Some(b)) => physics::gate_types_commute(a, b),
+                _ => false,
+            })
+    }
 
-        physics::gate_types_commute(t1, t2)
+    /// The Pauli axis this gate acts along on `qubit`, as the matching
+    /// single-qubit [`GateType`], or `None` if it mixes axes there.
+    ///
+    /// `CNOT` and `Toffoli` are Z-diagonal on their controls and X-like on
+    /// their target; `CZ` and `CPhase` are diagonal on both qubits.
+    fn axis_on(&self, qubit: usize) -> Option<GateType> {
+        match self {
+            Gate::CNOT(c, _) if *c == qubit => Some(GateType::Diagonal),
+            Gate::CNOT(_, _) => Some(GateType::XRotation),
+            Gate::Toffoli(_, _, t) if *t == qubit => Some(GateType::XRotation),
+            Gate::Toffoli(_, _, _) => Some(GateType::Diagonal),
+            Gate::CZ(_, _) | Gate::CPhase(_, _, _) => Some(GateType::Diagonal),
+            _ => match self.gate_type() {
+                t @ (GateType::Diagonal | GateType::XRotation | GateType::YRotation) => Some(t),
+                _ => None,
+            },
+        }
     }
 
     /// Returns true if this is a single-qubit gate.
@@ -154,12 +247,25 @@ impl Gate {
                 | Gate::Rx(_, _)
                 | Gate::Ry(_, _)
                 | Gate::Rz(_, _)
+                | Gate::U(_, _, _, _)
         )
     }
 
     /// Returns true if this is a two-qubit gate.
     pub fn is_two_qubit(&self) -> bool {
-        matches!(self, Gate::CNOT(_, _) | Gate::CZ(_, _) | Gate::SWAP(_, _))
+        matches!(
+            self,
+            Gate::CNOT(_, _)
+                | Gate::CZ(_, _)
+                | Gate::CPhase(_, _, _)
+                | Gate::SWAP(_, _)
+                | Gate::FSim(_, _, _, _)
+        )
+    }
+
+    /// Returns true if this is a three-qubit gate.
+    pub fn is_three_qubit(&self) -> bool {
+        matches!(self, Gate::Toffoli(_, _, _))
     }
 
     /// Returns true if this is a measurement operation.
@@ -167,6 +273,16 @@ impl Gate {
         matches!(self, Gate::Measure(_))
     }
 
+    /// Returns true if this is a reset operation.
+    pub fn is_reset(&self) -> bool {
+        matches!(self, Gate::Reset(_))
+    }
+
+    /// Returns true for operations without a unitary matrix (measure, reset).
+    pub fn is_non_unitary(&self) -> bool {
+        self.is_measurement() || self.is_reset()
+    }
+
     /// Returns true if this is a Clifford gate.
     ///
     /// Clifford gates are: H, S, CNOT, and their compositions.
@@ -185,6 +301,26 @@ impl Gate {
         )
     }
 
+    /// Checks if this gate preserves Hamming weight (excitation number).
+    ///
+    /// Diagonal gates (Z, S, T, Rz, CZ, CPhase), SWAP and FSim map each computational
+    /// basis state to a combination of states with the same number of 1s. Measurement in the
+    /// computational basis also keeps each weight sector intact.
+    pub fn preserves_hamming_weight(&self) -> bool {
+        matches!(
+            self,
+            Gate::Z(_)
+                | Gate::S(_)
+                | Gate::T(_)
+                | Gate::Rz(_, _)
+                | Gate::CZ(_, _)
+                | Gate::CPhase(_, _, _)
+                | Gate::SWAP(_, _)
+                | Gate::FSim(_, _, _, _)
+                | Gate::Measure(_)
+        )
+    }
+
     /// Returns the 2x2 matrix representation for single-qubit gates.
     ///
     /// Returns `None` for two-qubit gates and measurements.
@@ -199,6 +335,7 @@ impl Gate {
             Gate::Rx(_, theta) => Some(physics::rx(*theta)),
             Gate::Ry(_, theta) => Some(physics::ry(*theta)),
             Gate::Rz(_, theta) => Some(physics::rz(*theta)),
+            Gate::U(_, theta, phi, lambda) => Some(physics::u3(*theta, *phi, *lambda)),
             _ => None,
         }
     }
@@ -211,7 +348,9 @@ impl Gate {
         match self {
             Gate::CNOT(_, _) => Some(physics::CNOT),
             Gate::CZ(_, _) => Some(physics::CZ),
+            Gate::CPhase(_, _, lambda) => Some(physics::cphase(*lambda)),
             Gate::SWAP(_, _) => Some(physics::SWAP),
+            Gate::FSim(_, _, theta, phi) => Some(physics::fsim(*theta, *phi)),
             _ => None,
         }
     }
@@ -219,7 +358,7 @@ impl Gate {
     /// Returns the inverse (adjoint) of this gate.
     ///
     /// For unitary gates, the inverse satisfies U†U = I.
-    /// Returns `None` for measurements (not reversible).
+    /// Returns `None` for measurements and resets (not reversible).
     pub fn inverse(&self) -> Option<Gate> {
         match self {
             // Self-inverse gates
@@ -230,6 +369,7 @@ impl Gate {
             Gate::CNOT(c, t) => Some(Gate::CNOT(*c, *t)),
             Gate::CZ(c, t) => Some(Gate::CZ(*c, *t)),
             Gate::SWAP(a, b) => Some(Gate::SWAP(*a, *b)),
+            Gate::Toffoli(c1, c2, t) => Some(Gate::Toffoli(*c1, *c2, *t)),
 
             // S† and T†
             Gate::S(q) => Some(Gate::Rz(*q, -std::f64::consts::FRAC_PI_2)),
@@ -239,9 +379,52 @@ impl Gate {
             Gate::Rx(q, theta) => Some(Gate::Rx(*q, -theta)),
             Gate::Ry(q, theta) => Some(Gate::Ry(*q, -theta)),
             Gate::Rz(q, theta) => Some(Gate::Rz(*q, -theta)),
+            Gate::U(q, theta, phi, lambda) => Some(Gate::U(*q, -theta, -lambda, -phi)),
+            Gate::CPhase(c, t, lambda) => Some(Gate::CPhase(*c, *t, -lambda)),
+            Gate::FSim(a, b, theta, phi) => Some(Gate::FSim(*a, *b, -theta, -phi)),
+
+            // Measurement and reset are not reversible
+            Gate::Measure(_) | Gate::Reset(_) => None,
+        }
+    }
 
-            // Measurement is not reversible
-            Gate::Measure(_) => None,
+    /// Returns the number of continuous parameters this gate carries.
+    ///
+    /// Fixed gates (H, X, S, T, CNOT, ...) have none; Rx/Ry/Rz/CPhase have one angle,
+    /// FSim has two (θ, φ) and U has three (θ, φ, λ).
+    pub fn num_params(&self) -> usize {
+        match self {
+            Gate::Rx(_, _) | Gate::Ry(_, _) | Gate::Rz(_, _) | Gate::CPhase(_, _, _) => 1,
+            Gate::FSim(_, _, _, _) => 2,
+            Gate::U(_, _, _, _) => 3,
+            Gate::H(_)
+            | Gate::X(_)
+            | Gate::Y(_)
+            | Gate::Z(_)
+            | Gate::S(_)
+            | Gate::T(_)
+            | Gate::CNOT(_, _)
+            | Gate::CZ(_, _)
+            | Gate::SWAP(_, _)
+            | Gate::Toffoli(_, _, _)
+            | Gate::Measure(_)
+            | Gate::Reset(_) => 0,
+        }
+    }
+
+    /// Returns the continuous parameters of this gate, in declaration order.
+    ///
+    /// Unlike `rotation_angle`, fixed-angle gates such as S and T return no
+    /// parameters, so `params().len() == num_params()` always holds.
+    pub fn params(&self) -> Vec<f64> {
+        match self {
+            Gate::Rx(_, theta)
+            | Gate::Ry(_, theta)
+            | Gate::Rz(_, theta)
+            | Gate::CPhase(_, _, theta) => vec![*theta],
+            Gate::FSim(_, _, theta, phi) => vec![*theta, *phi],
+            Gate::U(_, theta, phi, lambda) => vec![*theta, *phi, *lambda],
+            _ => Vec::new(),
         }
     }
 
@@ -259,22 +442,30 @@ impl Gate {
     }
 
     /// Estimates the gate time in nanoseconds.
+    ///
+    /// Three-qubit gates are charged twice the two-qubit time.
     pub fn estimated_time_ns(&self) -> f64 {
         if self.is_single_qubit() {
             physics::gate_times::SINGLE_QUBIT
         } else if self.is_two_qubit() {
             physics::gate_times::TWO_QUBIT
+        } else if self.is_three_qubit() {
+            2.0 * physics::gate_times::TWO_QUBIT
         } else {
             physics::gate_times::MEASUREMENT
         }
     }
 
     /// Estimates the gate error rate.
+    ///
+    /// Three-qubit gates are charged twice the two-qubit error.
     pub fn estimated_error(&self) -> f64 {
         if self.is_single_qubit() {
             physics::gate_errors::SINGLE_QUBIT_TYPICAL
         } else if self.is_two_qubit() {
             physics::gate_errors::TWO_QUBIT_TYPICAL
+        } else if self.is_three_qubit() {
+            2.0 * physics::gate_errors::TWO_QUBIT_TYPICAL
         } else {
             physics::gate_errors::MEASUREMENT_TYPICAL
         }
@@ -293,11 +484,127 @@ impl Gate {
             Gate::Rx(q, theta) => Gate::Rx(mapping[*q], *theta),
             Gate::Ry(q, theta) => Gate::Ry(mapping[*q], *theta),
             Gate::Rz(q, theta) => Gate::Rz(mapping[*q], *theta),
+            Gate::U(q, theta, phi, lambda) => Gate::U(mapping[*q], *theta, *phi, *lambda),
             Gate::CNOT(c, t) => Gate::CNOT(mapping[*c], mapping[*t]),
             Gate::CZ(c, t) => Gate::CZ(mapping[*c], mapping[*t]),
+            Gate::CPhase(c, t, lambda) => Gate::CPhase(mapping[*c], mapping[*t], *lambda),
             Gate::SWAP(a, b) => Gate::SWAP(mapping[*a], mapping[*b]),
+            Gate::FSim(a, b, theta, phi) => Gate::FSim(mapping[*a], mapping[*b], *theta, *phi),
+            Gate::Toffoli(c1, c2, t) => Gate::Toffoli(mapping[*c1], mapping[*c2], mapping[*t]),
             Gate::Measure(q) => Gate::Measure(mapping[*q]),
+            Gate::Reset(q) => Gate::Reset(mapping[*q]),
+        }
+    }
+
+    /// Promotes a single-qubit gate to its controlled form on `control`.
+    ///
+    /// Returns the first-class two-qubit gate when one exists: X → CNOT,
+    /// Z → CZ, S → CPhase(π/2), T → CPhase(π/4). Returns `None` for gates
+    /// without one, for multi-qubit gates and measurements, and when
+    /// `control` is the gate's own qubit. See
+    /// [`controlled_decomposition`](Self::controlled_decomposition) for the
+    /// general case.
+    pub fn controlled(&self, control: usize) -> Option<Gate> {
+        if !self.is_single_qubit() || self.is_measurement() || self.qubits()[0] == control {
+            return None;
+        }
+        match self {
+            Gate::X(t) => Some(Gate::CNOT(control, *t)),
+            Gate::Z(t) => Some(Gate::CZ(control, *t)),
+            Gate::S(t) => Some(Gate::CPhase(control, *t, std::f64::consts::FRAC_PI_2)),
+            Gate::T(t) => Some(Gate::CPhase(control, *t, std::f64::consts::FRAC_PI_4)),
+            _ => None,
+        }
+    }
+
+    /// Controlled form of a single-qubit gate as a gate sequence.
+    ///
+    /// Uses [`controlled`](Self::controlled) when a first-class gate exists.
+    /// Otherwise the gate is written as `e^(iα)·U(θ, φ, λ)`: diagonal gates
+    /// become a CPhase plus an Rz on the control, and general gates use the
+    /// standard two-CNOT controlled-U3 construction. The sequence is exact
+    /// up to a global phase. Returns `None` in the same cases as
+    /// [`controlled`](Self::controlled), except for single-qubit gates that
+    /// merely lack a first-class controlled form.
+    pub fn controlled_decomposition(&self, control: usize) -> Option<Vec<Gate>> {
+        if let Some(gate) = self.controlled(control) {
+            return Some(vec![gate]);
         }
+        let m = self.matrix_2x2()?;
+        let target = self.qubits()[0];
+        if target == control {
+            return None;
+        }
+
+        let (theta, phi, lambda) = physics::u3_angles(&m);
+        // Global phase α of m = e^(iα)·u3(θ, φ, λ), read off the largest entry
+        let u = physics::u3(theta, phi, lambda);
+        let (r, c) = if m[0][0].norm() >= m[1][0].norm() {
+            (0, 0)
+        } else {
+            (1, 0)
+        };
+        let alpha = (m[r][c] / u[r][c]).arg();
+
+        // Phases on the control are Rz up to a global phase
+        if theta.abs() < 1e-12 {
+            return Some(vec![
+                Gate::CPhase(control, target, lambda),
+                Gate::Rz(control, alpha),
+            ]);
+        }
+        Some(vec![
+            Gate::Rz(control, alpha + (lambda + phi) / 2.0),
+            Gate::Rz(target, (lambda - phi) / 2.0),
+            Gate::CNOT(control, target),
+            Gate::U(target, -theta / 2.0, 0.0, -(phi + lambda) / 2.0),
+            Gate::CNOT(control, target),
+            Gate::U(target, theta / 2.0, phi, 0.0),
+        ])
+    }
+
+    /// Standard Toffoli (CCX) decomposition with 6 CNOTs.
+    ///
+    /// Flips `target` when both controls are |1⟩. `T†` is emitted as
+    /// `Rz(-π/4)`, so the sequence equals CCX up to a global phase.
+    pub fn ccx_decomposition(control1: usize, control2: usize, target: usize) -> Vec<Gate> {
+        let tdg = |q| Gate::Rz(q, -std::f64::consts::FRAC_PI_4);
+        vec![
+            Gate::H(target),
+            Gate::CNOT(control2, target),
+            tdg(target),
+            Gate::CNOT(control1, target),
+            Gate::T(target),
+            Gate::CNOT(control2, target),
+            tdg(target),
+            Gate::CNOT(control1, target),
+            Gate::T(control2),
+            Gate::T(target),
+            Gate::H(target),
+            Gate::CNOT(control1, control2),
+            Gate::T(control1),
+            tdg(control2),
+            Gate::CNOT(control1, control2),
+        ]
+    }
+
+    /// Relative-phase Toffoli (Margolus gate) with 3 CNOTs.
+    ///
+    /// Acts as CCX on computational basis states except for a -1 phase on
+    /// |c1=1, c2=0, t=1⟩, i.e. it equals `D · CCX` for a diagonal `D`. The
+    /// phase cancels when the gate is later uncomputed by its inverse, so it
+    /// can replace a compute/uncompute CCX pair at half the CNOT cost.
+    pub fn margolus_decomposition(control1: usize, control2: usize, target: usize) -> Vec<Gate> {
+        let quarter = std::f64::consts::FRAC_PI_4;
+        vec![
+            Gate::Ry(target, quarter),
+            Gate::CNOT(control2, target),
+            Gate::Ry(target, quarter),
+            Gate::CNOT(control1, target),
+            Gate::Ry(target, -quarter),
+            Gate::CNOT(control2, target),
+            Gate::Ry(target, -quarter),
+        ]
     }
 }
 
@@ -313,10 +620,19 @@ impl std::fmt::Display for Gate {
             Gate::Rx(q, theta) => write!(f, "Rx({}, {:.4})", q, theta),
             Gate::Ry(q, theta) => write!(f, "Ry({}, {:.4})", q, theta),
             Gate::Rz(q, theta) => write!(f, "Rz({}, {:.4})", q, theta),
+            Gate::U(q, theta, phi, lambda) => {
+                write!(f, "U({}, {:.4}, {:.4}, {:.4})", q, theta, phi, lambda)
+            },
             Gate::CNOT(c, t) => write!(f, "CNOT({}, {})", c, t),
             Gate::CZ(c, t) => write!(f, "CZ({}, {})", c, t),
+            Gate::CPhase(c, t, lambda) => write!(f, "CPhase({}, {}, {:.4})", c, t, lambda),
             Gate::SWAP(a, b) => write!(f, "SWAP({}, {})", a, b),
+            Gate::FSim(a, b, theta, phi) => {
+                write!(f, "FSim({}, {}, {:.4}, {:.4})", a, b, theta, phi)
+            },
+            Gate::Toffoli(c1, c2, t) => write!(f, "Toffoli({}, {}, {})", c1, c2, t),
             Gate::Measure(q) => write!(f, "Measure({})", q),
+            Gate::Reset(q) => write!(f, "Reset({})", q),
         }
     }
 }
@@ -372,6 +688,25 @@ mod tests {
         assert!(!Gate::Measure(0).commutes_with(&Gate::Z(0)));
     }
 
+    #[test]
+    fn test_commutes_through_two_qubit_gates() {
+        assert!(Gate::Rz(0, 0.3).commutes_with(&Gate::CNOT(0, 1)));
+        assert!(!Gate::Rz(1, 0.3).commutes_with(&Gate::CNOT(0, 1)));
+        assert!(Gate::X(1).commutes_with(&Gate::CNOT(0, 1)));
+        assert!(!Gate::X(0).commutes_with(&Gate::CNOT(0, 1)));
+        assert!(Gate::T(1).commutes_with(&Gate::CZ(0, 1)));
+        assert!(!Gate::H(1).commutes_with(&Gate::CZ(0, 1)));
+
+        assert!(Gate::CNOT(0, 1).commutes_with(&Gate::CNOT(0, 2)));
+        assert!(Gate::CNOT(0, 2).commutes_with(&Gate::CNOT(1, 2)));
+        assert!(!Gate::CNOT(0, 1).commutes_with(&Gate::CNOT(1, 2)));
+        assert!(!Gate::CNOT(0, 1).commutes_with(&Gate::CNOT(1, 0)));
+        assert!(Gate::CZ(0, 1).commutes_with(&Gate::CZ(1, 2)));
+        assert!(Gate::CZ(0, 2).commutes_with(&Gate::CNOT(0, 1)));
+        assert!(!Gate::CZ(1, 2).commutes_with(&Gate::CNOT(0, 1)));
+        assert!(!Gate::SWAP(0, 1).commutes_with(&Gate::Z(0)));
+    }
+
     #[test]
     fn test_matrix_single_qubit() {
         assert!(Gate::H(0).matrix_2x2().is_some());
@@ -408,6 +743,73 @@ mod tests {
         assert_eq!(Gate::Measure(0).inverse(), None);
     }
 
+    #[test]
+    fn test_controlled() {
+        assert_eq!(Gate::X(1).controlled(0), Some(Gate::CNOT(0, 1)));
+        assert_eq!(Gate::Z(1).controlled(0), Some(Gate::CZ(0, 1)));
+        assert_eq!(
+            Gate::T(2).controlled(0),
+            Some(Gate::CPhase(0, 2, std::f64::consts::FRAC_PI_4))
+        );
+
+        // No first-class form, or nothing to control
+        assert_eq!(Gate::H(1).controlled(0), None);
+        assert_eq!(Gate::X(0).controlled(0), None);
+        assert_eq!(Gate::CNOT(0, 1).controlled(2), None);
+        assert_eq!(Gate::Measure(1).controlled(0), None);
+
+        // Decomposition falls back to the first-class gate when available
+        assert_eq!(
+            Gate::X(1).controlled_decomposition(0),
+            Some(vec![Gate::CNOT(0, 1)])
+        );
+        let ch = Gate::H(1).controlled_decomposition(0).unwrap();
+        assert_eq!(
+            ch.iter().filter(|g| matches!(g, Gate::CNOT(0, 1))).count(),
+            2
+        );
+        assert!(Gate::H(0).controlled_decomposition(0).is_none());
+
+        // CPhase is diagonal with e^(iλ) on |11⟩
+        let m = Gate::CPhase(0, 1, 0.7).matrix_4x4().unwrap();
+        assert!((m[3][3] - physics::C64::from_polar(1.0, 0.7)).norm() < 1e-12);
+        assert!((m[2][2] - physics::ONE).norm() < 1e-12);
+        assert_eq!(
+            Gate::CPhase(0, 1, 0.7).inverse(),
+            Some(Gate::CPhase(0, 1, -0.7))
+        );
+    }
+
+    #[test]
+    fn test_toffoli() {
+        let ccx = Gate::Toffoli(0, 1, 2);
+        assert_eq!(ccx.qubits(), vec![0, 1, 2]);
+        assert!(!ccx.is_single_qubit());
+        assert!(!ccx.is_two_qubit());
+        assert!(ccx.is_three_qubit());
+        assert!(!ccx.is_clifford());
+        assert_eq!(ccx.inverse(), Some(ccx.clone()));
+        assert_eq!(ccx.map_qubits(&[2, 0, 1]), Gate::Toffoli(2, 0, 1));
+        assert_eq!(ccx.to_string(), "Toffoli(0, 1, 2)");
+        assert!((ccx.estimated_error() - 2.0 * Gate::CNOT(0, 1).estimated_error()).abs() < 1e-15);
+
+        // Disjoint qubits
+        assert!(ccx.commutes_with(&Gate::H(3)));
+        // Diagonal on the controls, X-like on the target
+        assert!(ccx.commutes_with(&Gate::Z(0)));
+        assert!(ccx.commutes_with(&Gate::Rz(1, 0.3)));
+        assert!(ccx.commutes_with(&Gate::Rx(2, 0.3)));
+        assert!(!ccx.commutes_with(&Gate::Z(2)));
+        assert!(!ccx.commutes_with(&Gate::X(0)));
+        // Shared controls or shared target with CNOT and Toffoli
+        assert!(ccx.commutes_with(&Gate::CNOT(0, 3)));
+        assert!(ccx.commutes_with(&Gate::CNOT(3, 2)));
+        assert!(!ccx.commutes_with(&Gate::CNOT(2, 3)));
+        assert!(ccx.commutes_with(&Gate::Toffoli(1, 0, 2)));
+        assert!(!ccx.commutes_with(&Gate::Toffoli(0, 2, 1)));
+        assert!(!ccx.commutes_with(&Gate::Measure(1)));
+    }
+
     #[test]
     fn test_is_clifford() {
         assert!(Gate::H(0).is_clifford());
@@ -417,6 +819,57 @@ mod tests {
         assert!(!Gate::Rx(0, 0.5).is_clifford());
     }
 
+    #[test]
+    fn test_preserves_hamming_weight() {
+        assert!(Gate::Rz(0, 0.3).preserves_hamming_weight());
+        assert!(Gate::CZ(0, 1).preserves_hamming_weight());
+        assert!(Gate::SWAP(0, 1).preserves_hamming_weight());
+        assert!(!Gate::H(0).preserves_hamming_weight());
+        assert!(!Gate::X(0).preserves_hamming_weight());
+        assert!(!Gate::CNOT(0, 1).preserves_hamming_weight());
+    }
+
+    #[test]
+    fn test_params() {
+        assert_eq!(Gate::Rz(0, 0.25).num_params(), 1);
+        assert_eq!(Gate::Rz(0, 0.25).params(), vec![0.25]);
+        assert_eq!(Gate::CNOT(0, 1).num_params(), 0);
+        assert!(Gate::CNOT(0, 1).params().is_empty());
+        assert_eq!(Gate::S(0).num_params(), 0);
+        assert!(Gate::S(0).params().is_empty());
+        assert_eq!(Gate::Measure(0).num_params(), 0);
+    }
+
+    #[test]
+    fn test_fsim() {
+        use crate::physics::{C64, ONE, ZERO};
+
+        // fSim(π/2, 0) is iSWAP with the opposite sign convention (iSWAP†)
+        let minus_i = C64::new(0.0, -1.0);
+        let iswap_dagger = [
+            [ONE, ZERO, ZERO, ZERO],
+            [ZERO, ZERO, minus_i, ZERO],
+            [ZERO, minus_i, ZERO, ZERO],
+            [ZERO, ZERO, ZERO, ONE],
+        ];
+        let m = Gate::FSim(0, 1, PI / 2.0, 0.0).matrix_4x4().unwrap();
+        for (row, expected) in m.iter().zip(&iswap_dagger) {
+            for (a, b) in row.iter().zip(expected) {
+                assert!((a - b).norm() < 1e-12);
+            }
+        }
+
+        let g = Gate::FSim(0, 1, 0.3, 0.7);
+        assert_eq!(g.params(), vec![0.3, 0.7]);
+        assert_eq!(g.inverse(), Some(Gate::FSim(0, 1, -0.3, -0.7)));
+        assert!(g.is_two_qubit());
+        assert!(g.preserves_hamming_weight());
+        assert!(g.commutes_with(&Gate::FSim(1, 0, 0.1, 0.2)));
+        assert!(g.commutes_with(&Gate::CZ(0, 1)));
+        assert!(!g.commutes_with(&Gate::CNOT(0, 1)));
+        assert!(!g.commutes_with(&Gate::Rz(0, 0.5)));
+    }
+
     #[test]
     fn test_rotation_angle() {
         assert_eq!(Gate::Rx(0, 0.5).rotation_angle(), Some(0.5));
@@ -0,0 +1,87 @@
+//! Canonical JSON gate-list format.
+//!
+//! A lightweight interchange form for scripts and web UIs:
+//!
+//! ```json
+//! {"num_qubits": 2, "gates": [{"type": "CNOT", "qubits": [0, 1]},
+//!                             {"type": "Rz", "qubits": [0], "params": [0.5]}]}
+//! ```
+//!
+//! `type` is the [`Gate`] variant name, `qubits` follows [`Gate::qubits`] and
+//! `params` follows [`Gate::params`] (omitted when empty).
+
+use super::{CircuitGenome, Gate};
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+
+/// One gate of the list.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GateEntry {
+    #[serde(rename = "type")]
+    kind: String,
+    qubits: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    params: Vec<f64>,
+}
+
+/// Top-level gate-list document.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GateList {
+    pub(crate) num_qubits: usize,
+    pub(crate) gates: Vec<GateEntry>,
+}
+
+impl GateList {
+    pub(crate) fn from_circuit(circuit: &CircuitGenome) -> Self {
+        Self {
+            num_qubits: circuit.num_qubits,
+            gates: circuit
+                .gates
+                .iter()
+                .map(|gate| GateEntry {
+                    kind: gate.name().to_string(),
+                    qubits: gate.qubits(),
+                    params: gate.params(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl GateEntry {
+    /// Rebuilds the gate, rejecting unknown types and wrong arities.
+    pub(crate) fn to_gate(&self) -> Result<Gate, serde_json::Error> {
+        let (q, p) = (self.qubits.as_slice(), self.params.as_slice());
+        let gate = match (self.kind.as_str(), q, p) {
+            ("H", &[a], []) => Gate::H(a),
+            ("X", &[a], []) => Gate::X(a),
+            ("Y", &[a], []) => Gate::Y(a),
+            ("Z", &[a], []) => Gate::Z(a),
+            ("S", &[a], []) => Gate::S(a),
+            ("T", &[a], []) => Gate::T(a),
+            ("Rx", &[a], &[theta]) => Gate::Rx(a, theta),
+            ("Ry", &[a], &[theta]) => Gate::Ry(a, theta),
+            ("Rz", &[a], &[theta]) => Gate::Rz(a, theta),
+            ("U", &[a], &[theta, phi, lambda]) => Gate::U(a, theta, phi, lambda),
+            ("CNOT", &[a, b], []) => Gate::CNOT(a, b),
+            ("CZ", &[a, b], []) => Gate::CZ(a, b),
+            ("CPhase", &[a, b], &[lambda]) => Gate::CPhase(a, b, lambda),
+            ("SWAP", &[a, b], []) => Gate::SWAP(a, b),
+            ("FSim", &[a, b], &[theta, phi]) => Gate::FSim(a, b, theta, phi),
+            ("Toffoli", &[a, b, c], []) => Gate::Toffoli(a, b, c),
+            ("Measure", &[a], []) => Gate::Measure(a),
+            ("Reset", &[a], []) => Gate::Reset(a),
+            _ => {
+                return Err(serde_json::Error::custom(format!(
+                    "invalid gate {:?} with {} qubits and {} params",
+                    self.kind,
+                    q.len(),
+                    p.len()
+                )))
+            },
+        };
+        Ok(gate)
+    }
+}
@@ -6,9 +6,12 @@
 //! - Gate error rates
 //! - Gate timings
 
+use super::ibm_backend::BackendProperties;
+use super::{migrate_format_version, QNS_FORMAT_VERSION};
 use crate::physics::{gate_errors, gate_times, t1_typical, t2_typical};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// Fidelity value constrained to [0.0, 1.0].
 ///
@@ -231,10 +234,19 @@ pub struct HardwareProfile {
     /// Connectivity graph as adjacency set
     #[serde(skip)]
     connectivity: HashSet<(usize, usize)>,
+    /// Neighbors of each qubit, in coupler order
+    #[serde(skip)]
+    adjacency: HashMap<usize, Vec<usize>>,
+    /// Position in `couplers` of each normalized edge
+    #[serde(skip)]
+    coupler_index: HashMap<(usize, usize), usize>,
     /// Calibration timestamp (Unix time)
     pub calibration_timestamp: Option<u64>,
     /// Crosstalk interaction matrix
     pub crosstalk: CrosstalkMatrix,
+    /// Serialization format version (0 for files written before versioning)
+    #[serde(default)]
+    pub qns_format_version: u32,
 }
 
 impl HardwareProfile {
@@ -242,18 +254,22 @@ impl HardwareProfile {
     pub fn new(name: impl Into<String>, num_qubits: usize, topology: Topology) -> Self {
         let qubit_properties = vec![QubitProperties::default(); num_qubits];
         let couplers = Self::generate_couplers(num_qubits, &topology);
-        let connectivity = couplers.iter().map(|c| c.edge()).collect();
 
-        Self {
+        let mut profile = Self {
             name: name.into(),
             num_qubits,
             topology,
             qubit_properties,
             couplers,
-            connectivity,
+            connectivity: HashSet::new(),
+            adjacency: HashMap::new(),
+            coupler_index: HashMap::new(),
             calibration_timestamp: None,
             crosstalk: CrosstalkMatrix::default(),
-        }
+            qns_format_version: QNS_FORMAT_VERSION,
+        };
+        profile.rebuild_connectivity();
+        profile
     }
 
     /// Creates a linear topology (chain).
@@ -316,18 +332,142 @@ impl HardwareProfile {
 
         let qubit_properties = vec![QubitProperties::default(); num_qubits];
         let couplers = Self::generate_heavy_hex_couplers(rows, cols, num_qubits);
-        let connectivity = couplers.iter().map(|c| c.edge()).collect();
 
-        Self {
+        let mut profile = Self {
             name: name.into(),
             num_qubits,
             topology: Topology::HeavyHex,
             qubit_properties,
             couplers,
-            connectivity,
+            connectivity: HashSet::new(),
+            adjacency: HashMap::new(),
+            coupler_index: HashMap::new(),
+            calibration_timestamp: None,
+            crosstalk: CrosstalkMatrix::default(),
+            qns_format_version: QNS_FORMAT_VERSION,
+        };
+        profile.rebuild_connectivity();
+        profile
+    }
+
+    /// Creates a profile from an explicit coupling map (edge list).
+    ///
+    /// Edges are undirected: Qiskit-style maps listing both `[0, 1]` and
+    /// `[1, 0]` produce a single coupler. The topology is `Topology::Custom`.
+    ///
+    /// # Example
+    /// ```
+    /// use qns_core::types::HardwareProfile;
+    /// let hw = HardwareProfile::from_coupling_map("t_shape", 4, &[[0, 1], [1, 2], [1, 3]]);
+    /// assert!(hw.are_connected(3, 1));
+    /// assert!(!hw.are_connected(0, 2));
+    /// ```
+    pub fn from_coupling_map(
+        name: impl Into<String>,
+        num_qubits: usize,
+        map: &[[usize; 2]],
+    ) -> Self {
+        let mut profile = Self {
+            name: name.into(),
+            num_qubits,
+            topology: Topology::Custom,
+            qubit_properties: vec![QubitProperties::default(); num_qubits],
+            couplers: Vec::new(),
+            connectivity: HashSet::new(),
+            adjacency: HashMap::new(),
+            coupler_index: HashMap::new(),
             calibration_timestamp: None,
             crosstalk: CrosstalkMatrix::default(),
+            qns_format_version: QNS_FORMAT_VERSION,
+        };
+        for &[q1, q2] in map {
+            profile.add_coupler(CouplerProperties::new(q1, q2));
         }
+        profile
+    }
+
+    /// Creates a profile from a coupling-map string such as `"[[0,1],[1,2]]"`.
+    ///
+    /// Returns an error if the string is not a JSON list of qubit pairs, or if
+    /// an edge is a self-loop or references a qubit outside `num_qubits`.
+    pub fn from_coupling_map_str(
+        name: impl Into<String>,
+        num_qubits: usize,
+        map: &str,
+    ) -> crate::Result<Self> {
+        let edges: Vec<[usize; 2]> = serde_json::from_str(map)?;
+        for &[q1, q2] in &edges {
+            Self::validate_edge(q1, q2, num_qubits)?;
+        }
+        Ok(Self::from_coupling_map(name, num_qubits, &edges))
+    }
+
+    /// Creates a custom profile from a measured edge list.
+    ///
+    /// Each edge gets a default [`CouplerProperties`]; repeated edges (in
+    /// either direction) are kept once.
+    ///
+    /// # Errors
+    /// Returns [`QnsError::InvalidQubit`](crate::QnsError::InvalidQubit) if an
+    /// endpoint is not below `num_qubits`, or a config error for a self-loop.
+    ///
+    /// # Example
+    /// ```
+    /// use qns_core::types::HardwareProfile;
+    /// let hw = HardwareProfile::from_edges("diamond", 4, &[(0, 1), (0, 2), (1, 3), (2, 3)]).unwrap();
+    /// assert_eq!(hw.shortest_path_distance(0, 3), Some(2));
+    /// assert!(HardwareProfile::from_edges("bad", 2, &[(0, 2)]).is_err());
+    /// ```
+    pub fn from_edges(
+        name: impl Into<String>,
+        num_qubits: usize,
+        edges: &[(usize, usize)],
+    ) -> crate::Result<Self> {
+        let map = edges
+            .iter()
+            .map(|&(q1, q2)| Self::validate_edge(q1, q2, num_qubits).map(|_| [q1, q2]))
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(Self::from_coupling_map(name, num_qubits, &map))
+    }
+
+    /// Like [`from_edges`](Self::from_edges), with a two-qubit gate fidelity
+    /// per edge.
+    ///
+    /// # Errors
+    /// As [`from_edges`](Self::from_edges), plus a config error for a
+    /// fidelity outside `[0, 1]`.
+    pub fn from_edges_with_fidelity(
+        name: impl Into<String>,
+        num_qubits: usize,
+        edges: &[(usize, usize, f64)],
+    ) -> crate::Result<Self> {
+        let pairs: Vec<(usize, usize)> = edges.iter().map(|&(q1, q2, _)| (q1, q2)).collect();
+        let mut profile = Self::from_edges(name, num_qubits, &pairs)?;
+        for &(q1, q2, fidelity) in edges {
+            if !(0.0..=1.0).contains(&fidelity) {
+                return Err(crate::QnsError::config(format!(
+                    "fidelity {} for edge ({}, {}) is outside [0, 1]",
+                    fidelity, q1, q2
+                )));
+            }
+            let index = profile.coupler_index[&(q1.min(q2), q1.max(q2))];
+            profile.couplers[index].gate_fidelity = Fidelity::new(fidelity);
+        }
+        Ok(profile)
+    }
+
+    /// Checks that an edge joins two distinct qubits of the device.
+    fn validate_edge(q1: usize, q2: usize, num_qubits: usize) -> crate::Result<()> {
+        if q1 == q2 {
+            return Err(crate::QnsError::config(format!(
+                "coupling map contains self-loop on qubit {}",
+                q1
+            )));
+        }
+        if q1.max(q2) >= num_qubits {
+            return Err(crate::QnsError::InvalidQubit(q1.max(q2), num_qubits));
+        }
+        Ok(())
     }
 
     /// Generates couplers for heavy-hex topology.
@@ -463,8 +603,49 @@ impl HardwareProfile {
         None
     }
 
+    /// Shortest-path distances for every unordered qubit pair.
+    ///
+    /// Returns `None` if some pair is disconnected.
+    fn pairwise_distances(&self) -> Option<Vec<usize>> {
+        let mut distances = Vec::new();
+        for q1 in 0..self.num_qubits {
+            for q2 in (q1 + 1)..self.num_qubits {
+                distances.push(self.shortest_path_distance(q1, q2)?);
+            }
+        }
+        Some(distances)
+    }
+
+    /// Returns the coupling-graph diameter (maximum shortest-path distance).
+    ///
+    /// Larger diameters predict more SWAPs during routing. Returns `None` if
+    /// the coupling graph is disconnected.
+    pub fn graph_diameter(&self) -> Option<usize> {
+        self.pairwise_distances()
+            .map(|d| d.into_iter().max().unwrap_or(0))
+    }
+
+    /// Returns the mean shortest-path distance over all qubit pairs.
+    ///
+    /// Returns `None` if the coupling graph is disconnected.
+    pub fn average_distance(&self) -> Option<f64> {
+        let distances = self.pairwise_distances()?;
+        if distances.is_empty() {
+            return Some(0.0);
+        }
+        Some(distances.iter().sum::<usize>() as f64 / distances.len() as f64)
+    }
+
     /// Returns all qubits connected to the given qubit.
+    ///
+    /// O(degree) via the adjacency index. Falls back to scanning `couplers`
+    /// if couplers were added or removed without
+    /// [`rebuild_connectivity`](Self::rebuild_connectivity); editing a coupler's
+    /// qubits in place requires a rebuild.
     pub fn neighbors(&self, qubit: usize) -> Vec<usize> {
+        if self.index_is_current() {
+            return self.adjacency.get(&qubit).cloned().unwrap_or_default();
+        }
         self.couplers
             .iter()
             .filter_map(|c| {
@@ -480,7 +661,13 @@ impl HardwareProfile {
     }
 
     /// Returns the coupler properties for a pair of qubits.
+    ///
+    /// O(1) via the coupler index, with the same fallback as [`neighbors`](Self::neighbors).
     pub fn get_coupler(&self, q1: usize, q2: usize) -> Option<&CouplerProperties> {
+        if self.index_is_current() {
+            let edge = if q1 <= q2 { (q1, q2) } else { (q2, q1) };
+            return self.coupler_index.get(&edge).map(|&i| &self.couplers[i]);
+        }
         self.couplers
             .iter()
             .find(|c| (c.qubit1 == q1 && c.qubit2 == q2) || (c.qubit1 == q2 && c.qubit2 == q1))
@@ -539,20 +726,72 @@ impl HardwareProfile {
         }
     }
 
-    /// Rebuilds the connectivity set from couplers.
+    /// Rebuilds the connectivity set and lookup indices from couplers.
+    ///
+    /// Call after editing `couplers` directly or after deserialization.
     pub fn rebuild_connectivity(&mut self) {
-        self.connectivity = self.couplers.iter().map(|c| c.edge()).collect();
+        self.connectivity.clear();
+        self.adjacency.clear();
+        self.coupler_index.clear();
+        for i in 0..self.couplers.len() {
+            self.index_coupler(i);
+        }
+    }
+
+    /// Loads a profile from JSON, checking its format version and rebuilding
+    /// the connectivity indices.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let mut profile: Self = serde_json::from_str(json)?;
+        profile.qns_format_version =
+            migrate_format_version("HardwareProfile", profile.qns_format_version)?;
+        profile.rebuild_connectivity();
+        Ok(profile)
+    }
+
+    /// Loads an IBM `backend_properties` calibration file.
+    ///
+    /// See [`from_ibm_backend_json_str`](Self::from_ibm_backend_json_str).
+    pub fn from_ibm_backend_json<P: AsRef<Path>>(path: P) -> crate::Result<Self> {
+        Self::from_ibm_backend_json_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses IBM `backend_properties` calibration JSON.
+    ///
+    /// Reads per-qubit `T1`, `T2` (μs), `readout_error`, `frequency` and
+    /// `anharmonicity`, the `sx` gate error as the single-qubit fidelity, and
+    /// `cx`/`ecr`/`cz` gate errors and lengths as per-edge coupler properties.
+    /// Edges come from the two-qubit gates plus an optional `coupling_map`;
+    /// `last_update_date` becomes the calibration timestamp. Missing values
+    /// keep the [`QubitProperties::default`] and [`CouplerProperties::new`]
+    /// defaults.
+    pub fn from_ibm_backend_json_str(json: &str) -> crate::Result<Self> {
+        serde_json::from_str::<BackendProperties>(json)?.into_profile()
     }
 
     /// Adds a custom coupler.
     pub fn add_coupler(&mut self, coupler: CouplerProperties) {
         let edge = coupler.edge();
         if !self.connectivity.contains(&edge) {
-            self.connectivity.insert(edge);
             self.couplers.push(coupler);
+            self.index_coupler(self.couplers.len() - 1);
         }
     }
 
+    /// Records `couplers[i]` in the connectivity set and lookup indices.
+    fn index_coupler(&mut self, i: usize) {
+        let c = &self.couplers[i];
+        let edge = c.edge();
+        self.connectivity.insert(edge);
+        self.adjacency.entry(c.qubit1).or_default().push(c.qubit2);
+        self.adjacency.entry(c.qubit2).or_default().push(c.qubit1);
+        self.coupler_index.entry(edge).or_insert(i);
+    }
+
+    /// Whether the lookup indices cover every coupler.
+    fn index_is_current(&self) -> bool {
+        self.coupler_index.len() == self.couplers.len()
+    }
+
     /// Validates that a circuit can execute on this hardware.
     ///
     /// Checks:
@@ -579,6 +818,14 @@ impl HardwareProfile {
                 }
             }
 
+            // Three-qubit gates have no native implementation
+            if gate.is_three_qubit() {
+                errors.push((
+                    idx,
+                    format!("{}: three-qubit gates must be decomposed", gate),
+                ));
+            }
+
             // Check connectivity for two-qubit gates
             if gate.is_two_qubit() && qubits.len() == 2 {
                 let (q1, q2) = (qubits[0], qubits[1]);
@@ -598,6 +845,87 @@ impl HardwareProfile {
     pub fn is_circuit_valid(&self, circuit: &super::CircuitGenome) -> bool {
         self.validate_circuit(circuit).is_empty()
     }
+
+    /// Exports the noise model as JSON accepted by Qiskit Aer's `NoiseModel.from_dict`.
+    ///
+    /// Emits, per qubit, a thermal-relaxation error on single-qubit gates
+    /// (`id`, `sx`, `x`) and a readout error on `measure`; per coupler, a
+    /// two-qubit depolarizing error on `cx` matching the coupler fidelity.
+    ///
+    /// Thermal relaxation is written as Aer's reset/phase-flip mixture, which
+    /// is exact for T2 ≤ T1; for T2 > T1 the extra dephasing term is clamped
+    /// to zero.
+    pub fn to_qiskit_noise_json(&self) -> String {
+        let op =
+            |name: &str, qubits: &[usize]| serde_json::json!({ "name": name, "qubits": qubits });
+        let mut errors = Vec::new();
+
+        let gate_time_us = gate_times::SINGLE_QUBIT / 1000.0;
+        for (q, props) in self.qubit_properties.iter().enumerate() {
+            // Aer thermal_relaxation_error (T2 <= T1 branch)
+            let p_reset = if props.t1 > 0.0 {
+                1.0 - (-gate_time_us / props.t1).exp()
+            } else {
+                1.0
+            };
+            let p_z = if props.t1 > 0.0 && props.t2 > 0.0 {
+                let ratio = (-gate_time_us / props.t2 + gate_time_us / props.t1).exp();
+                ((1.0 - p_reset) * (1.0 - ratio) / 2.0).max(0.0)
+            } else {
+                0.0
+            };
+            errors.push(serde_json::json!({
+                "type": "qerror",
+                "id": format!("thermal_relaxation_q{}", q),
+                "operations": ["id", "sx", "x"],
+                "instructions": [
+                    [op("id", &[q])],
+                    [op("z", &[q])],
+                    [op("reset", &[q])],
+                ],
+                "probabilities": [1.0 - p_z - p_reset, p_z, p_reset],
+                "gate_qubits": [[q]],
+            }));
+
+            let p_err = props.readout_fidelity.error_rate();
+            errors.push(serde_json::json!({
+                "type": "roerror",
+                "id": format!("readout_q{}", q),
+                "operations": ["measure"],
+                "probabilities": [[1.0 - p_err, p_err], [p_err, 1.0 - p_err]],
+                "gate_qubits": [[q]],
+            }));
+        }
+
+        const PAULIS: [&str; 4] = ["id", "x", "y", "z"];
+        for coupler in &self.couplers {
+            let (a, b) = (coupler.qubit1, coupler.qubit2);
+            // Depolarizing parameter from average gate fidelity (d = 4)
+            let lambda = (coupler.gate_fidelity.error_rate() * 4.0 / 3.0).min(16.0 / 15.0);
+            let mut instructions = Vec::with_capacity(16);
+            let mut probabilities = Vec::with_capacity(16);
+            for (i, pa) in PAULIS.iter().enumerate() {
+                for (j, pb) in PAULIS.iter().enumerate() {
+                    instructions.push(vec![op(pa, &[a]), op(pb, &[b])]);
+                    probabilities.push(if i == 0 && j == 0 {
+                        1.0 - lambda * 15.0 / 16.0
+                    } else {
+                        lambda / 16.0
+                    });
+                }
+            }
+            errors.push(serde_json::json!({
+                "type": "qerror",
+                "id": format!("depolarizing_{}_{}", a, b),
+                "operations": ["cx"],
+                "instructions": instructions,
+                "probabilities": probabilities,
+                "gate_qubits": [[a, b]],
+            }));
+        }
+
+        serde_json::json!({ "errors": errors, "x90_gates": [] }).to_string()
+    }
 }
 
 impl Default for HardwareProfile {
@@ -650,6 +978,56 @@ mod tests {
         assert!(!hw.are_connected(0, 4));
     }
 
+    #[test]
+    fn test_graph_diameter_and_average_distance() {
+        let line = HardwareProfile::linear("line", 5);
+        assert_eq!(line.graph_diameter(), Some(4));
+        // Pair distances: 4×1 + 3×2 + 2×3 + 1×4 = 20 over 10 pairs
+        assert!((line.average_distance().unwrap() - 2.0).abs() < 1e-10);
+
+        let full = HardwareProfile::all_to_all("full", 5);
+        assert_eq!(full.graph_diameter(), Some(1));
+        assert!((full.average_distance().unwrap() - 1.0).abs() < 1e-10);
+
+        let split = HardwareProfile::from_coupling_map("split", 4, &[[0, 1], [2, 3]]);
+        assert_eq!(split.graph_diameter(), None);
+        assert_eq!(split.average_distance(), None);
+    }
+
+    #[test]
+    fn test_to_qiskit_noise_json() {
+        let hw = HardwareProfile::linear("line", 3);
+        let json: serde_json::Value = serde_json::from_str(&hw.to_qiskit_noise_json()).unwrap();
+        let errors = json["errors"].as_array().unwrap();
+
+        // 3 thermal + 3 readout + 2 coupler errors
+        assert_eq!(errors.len(), 3 + 3 + 2);
+        for q in 0..3 {
+            let for_qubit = |kind: &str| {
+                errors
+                    .iter()
+                    .filter(|e| e["type"] == kind && e["gate_qubits"] == serde_json::json!([[q]]))
+                    .count()
+            };
+            assert_eq!(for_qubit("qerror"), 1);
+            assert_eq!(for_qubit("roerror"), 1);
+        }
+        for pair in [[0, 1], [1, 2]] {
+            let error = errors
+                .iter()
+                .find(|e| e["gate_qubits"] == serde_json::json!([pair]))
+                .unwrap();
+            assert_eq!(error["operations"], serde_json::json!(["cx"]));
+            let total: f64 = error["probabilities"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|p| p.as_f64().unwrap())
+                .sum();
+            assert!((total - 1.0).abs() < 1e-10);
+        }
+    }
+
     #[test]
     fn test_all_to_all_topology() {
         let hw = HardwareProfile::all_to_all("test", 4);
@@ -687,6 +1065,99 @@ mod tests {
         assert_eq!(hw.neighbors(4), vec![3]);
     }
 
+    /// Reference linear-scan lookups the indices must agree with.
+    fn scan_neighbors(hw: &HardwareProfile, qubit: usize) -> Vec<usize> {
+        hw.couplers
+            .iter()
+            .filter_map(|c| match (c.qubit1 == qubit, c.qubit2 == qubit) {
+                (true, _) => Some(c.qubit2),
+                (_, true) => Some(c.qubit1),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn scan_coupler(hw: &HardwareProfile, q1: usize, q2: usize) -> Option<&CouplerProperties> {
+        hw.couplers
+            .iter()
+            .find(|c| (c.qubit1 == q1 && c.qubit2 == q2) || (c.qubit1 == q2 && c.qubit2 == q1))
+    }
+
+    #[test]
+    fn test_lookup_index_matches_scan() {
+        let mut custom = HardwareProfile::from_coupling_map("t", 5, &[[0, 1], [1, 2], [3, 1]]);
+        custom.add_coupler(CouplerProperties::new(4, 0));
+        let profiles = [
+            HardwareProfile::linear("line", 6),
+            HardwareProfile::grid("grid", 3, 4),
+            HardwareProfile::heavy_hex("hex", 3, 5),
+            HardwareProfile::all_to_all("full", 5),
+            custom,
+        ];
+
+        for hw in &profiles {
+            // Compare the indexed path, not the fallback scan
+            assert!(hw.index_is_current(), "{}", hw.name);
+            for (i, c) in hw.couplers.iter().enumerate() {
+                assert_eq!(hw.coupler_index[&c.edge()], i);
+            }
+            for q1 in 0..hw.num_qubits + 1 {
+                assert_eq!(hw.neighbors(q1), scan_neighbors(hw, q1));
+                for q2 in 0..hw.num_qubits + 1 {
+                    let indexed = hw.get_coupler(q1, q2).map(|c| c.edge());
+                    assert_eq!(indexed, scan_coupler(hw, q1, q2).map(|c| c.edge()));
+                }
+            }
+        }
+
+        // Couplers pushed directly: stale index falls back, rebuild restores it
+        let mut hw = HardwareProfile::linear("line", 4);
+        let mut extra = CouplerProperties::new(0, 3);
+        extra.gate_time_ns = 123.0;
+        hw.couplers.push(extra);
+        assert!(!hw.index_is_current());
+        assert!(!hw.are_connected(0, 3));
+        assert_eq!(hw.neighbors(0), vec![1, 3]);
+        assert_eq!(hw.get_coupler(3, 0).map(|c| c.gate_time_ns), Some(123.0));
+        hw.rebuild_connectivity();
+        assert!(hw.index_is_current());
+        assert_eq!(hw.coupler_index[&(0, 3)], 3);
+        assert_eq!(hw.neighbors(0), vec![1, 3]);
+        assert_eq!(hw.get_coupler(3, 0).map(|c| c.gate_time_ns), Some(123.0));
+        assert!(hw.are_connected(0, 3));
+
+        // Deserialized profiles skip the index until rebuilt, with the same answers
+        let json = serde_json::to_string(&profiles[1]).unwrap();
+        let mut restored: HardwareProfile = serde_json::from_str(&json).unwrap();
+        assert!(!restored.index_is_current());
+        assert_eq!(restored.neighbors(5), profiles[1].neighbors(5));
+        restored.rebuild_connectivity();
+        assert!(restored.index_is_current());
+        for q in 0..restored.num_qubits {
+            assert_eq!(restored.neighbors(q), profiles[1].neighbors(q));
+        }
+    }
+
+    #[test]
+    fn test_lookup_index_large_profile() {
+        // Timing against the linear scan lives in benches/hardware_profile.rs
+        let hw = HardwareProfile::all_to_all("full", 100);
+        assert!(hw.index_is_current());
+        let pairs: Vec<(usize, usize)> = (0..1000)
+            .map(|i| ((i * 37) % 100, (i * 61 + 1) % 100))
+            .collect();
+
+        let mut indexed = 0;
+        let mut scanned = 0;
+        for &(q1, q2) in &pairs {
+            indexed += hw.neighbors(q1).len();
+            indexed += hw.get_coupler(q1, q2).is_some() as usize;
+            scanned += scan_neighbors(&hw, q1).len();
+            scanned += scan_coupler(&hw, q1, q2).is_some() as usize;
+        }
+        assert_eq!(indexed, scanned);
+    }
+
     #[test]
     fn test_qubit_properties() {
         let qp = QubitProperties::with_t1t2(100.0, 80.0);
@@ -785,4 +1256,142 @@ mod tests {
         // Total: 8 couplers
         assert!(hw.couplers.len() >= 4); // At least horizontal connections
     }
+
+    #[test]
+    fn test_from_coupling_map_str() {
+        let hw = HardwareProfile::from_coupling_map_str(
+            "qiskit_map",
+            4,
+            "[[0, 1], [1, 0], [1, 2], [2, 1], [2, 3], [3, 2]]",
+        )
+        .unwrap();
+
+        assert_eq!(hw.topology, Topology::Custom);
+        assert_eq!(hw.couplers.len(), 3);
+        for q1 in 0..4usize {
+            for q2 in 0..4usize {
+                let expected = q1 != q2 && q1.abs_diff(q2) == 1;
+                assert_eq!(hw.are_connected(q1, q2), expected, "({}, {})", q1, q2);
+            }
+        }
+
+        assert!(HardwareProfile::from_coupling_map_str("bad", 4, "[[0, 1], [1]]").is_err());
+        assert!(HardwareProfile::from_coupling_map_str("bad", 4, "[[0, 4]]").is_err());
+        assert!(HardwareProfile::from_coupling_map_str("bad", 4, "[[2, 2]]").is_err());
+    }
+
+    #[test]
+    fn test_from_edges_diamond() {
+        //   0
+        //  / \
+        // 1   2
+        //  \ /
+        //   3
+        let hw =
+            HardwareProfile::from_edges("diamond", 4, &[(0, 1), (0, 2), (3, 1), (2, 3), (1, 0)])
+                .unwrap();
+        assert_eq!(hw.topology, Topology::Custom);
+        assert_eq!(hw.couplers.len(), 4);
+        assert!(hw.are_connected(1, 3));
+        assert!(!hw.are_connected(0, 3));
+        assert!(!hw.are_connected(1, 2));
+
+        let mut neighbors = hw.neighbors(0);
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![1, 2]);
+        let mut neighbors = hw.neighbors(3);
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![1, 2]);
+
+        assert_eq!(hw.shortest_path_distance(0, 3), Some(2));
+        assert_eq!(hw.shortest_path_distance(1, 2), Some(2));
+        assert_eq!(hw.shortest_path_distance(2, 3), Some(1));
+        assert_eq!(hw.graph_diameter(), Some(2));
+
+        assert!(matches!(
+            HardwareProfile::from_edges("bad", 4, &[(0, 1), (2, 4)]),
+            Err(crate::QnsError::InvalidQubit(4, 4))
+        ));
+        assert!(HardwareProfile::from_edges("bad", 4, &[(1, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_from_edges_with_fidelity() {
+        let hw = HardwareProfile::from_edges_with_fidelity(
+            "diamond",
+            4,
+            &[(0, 1, 0.99), (0, 2, 0.98), (1, 3, 0.97), (3, 2, 0.96)],
+        )
+        .unwrap();
+        assert!(hw.are_connected(2, 3));
+        assert_eq!(hw.get_coupler(1, 0).unwrap().gate_fidelity.value(), 0.99);
+        assert_eq!(hw.get_coupler(2, 3).unwrap().gate_fidelity.value(), 0.96);
+        assert!((hw.avg_two_qubit_fidelity().value() - 0.975).abs() < 1e-12);
+
+        assert!(HardwareProfile::from_edges_with_fidelity("bad", 4, &[(0, 1, 1.5)]).is_err());
+        assert!(HardwareProfile::from_edges_with_fidelity("bad", 4, &[(0, 5, 0.9)]).is_err());
+    }
+
+    #[test]
+    fn test_from_ibm_backend_json() {
+        let json = r#"{
+            "backend_name": "ibm_fixture",
+            "backend_version": "1.2.3",
+            "last_update_date": "2024-03-01T12:00:00+00:00",
+            "qubits": [
+                [{"date": "2024-03-01T10:00:00+00:00", "name": "T1", "unit": "us", "value": 120.5},
+                 {"date": "2024-03-01T10:00:00+00:00", "name": "T2", "unit": "us", "value": 95.0},
+                 {"date": "2024-03-01T10:00:00+00:00", "name": "frequency", "unit": "GHz", "value": 4.95},
+                 {"date": "2024-03-01T10:00:00+00:00", "name": "readout_error", "unit": "", "value": 0.02}],
+                [{"date": "2024-03-01T10:00:00+00:00", "name": "T1", "unit": "us", "value": 80.0},
+                 {"date": "2024-03-01T10:00:00+00:00", "name": "T2", "unit": "us", "value": 60.0}],
+                [{"date": "2024-03-01T10:00:00+00:00", "name": "T1", "unit": "ms", "value": 0.2}]
+            ],
+            "gates": [
+                {"gate": "sx", "name": "sx0", "qubits": [0],
+                 "parameters": [{"name": "gate_error", "unit": "", "value": 0.0003},
+                                {"name": "gate_length", "unit": "ns", "value": 35.5}]},
+                {"gate": "cx", "name": "cx0_1", "qubits": [0, 1],
+                 "parameters": [{"name": "gate_error", "unit": "", "value": 0.007},
+                                {"name": "gate_length", "unit": "ns", "value": 400.0}]},
+                {"gate": "cx", "name": "cx1_0", "qubits": [1, 0],
+                 "parameters": [{"name": "gate_error", "unit": "", "value": 0.009}]},
+                {"gate": "cx", "name": "cx1_2", "qubits": [1, 2],
+                 "parameters": [{"name": "gate_error", "unit": "", "value": 0.012}]}
+            ],
+            "general": []
+        }"#;
+
+        let hw = HardwareProfile::from_ibm_backend_json_str(json).unwrap();
+        assert_eq!(hw.name, "ibm_fixture");
+        assert_eq!(hw.num_qubits, 3);
+        assert_eq!(hw.topology, Topology::Custom);
+        assert_eq!(hw.calibration_timestamp, Some(1_709_294_400));
+
+        let q = &hw.qubit_properties;
+        assert_eq!((q[0].t1, q[0].t2), (120.5, 95.0));
+        assert_eq!((q[1].t1, q[1].t2), (80.0, 60.0));
+        assert_eq!(q[0].frequency, Some(4.95));
+        assert!((q[0].readout_fidelity.value() - 0.98).abs() < 1e-12);
+        assert!((q[0].single_gate_fidelity.value() - 0.9997).abs() < 1e-12);
+        // Missing fields fall back to the defaults
+        let default = QubitProperties::default();
+        assert!((q[2].t1 - 200.0).abs() < 1e-9);
+        assert_eq!(q[2].t2, default.t2);
+        assert_eq!(q[1].readout_fidelity, default.readout_fidelity);
+        assert_eq!(q[1].single_gate_fidelity, default.single_gate_fidelity);
+
+        assert_eq!(hw.couplers.len(), 2);
+        assert!(hw.are_connected(2, 1));
+        assert!(!hw.are_connected(0, 2));
+        let c01 = hw.get_coupler(1, 0).unwrap();
+        assert!((c01.gate_fidelity.value() - 0.993).abs() < 1e-12);
+        assert_eq!(c01.gate_time_ns, 400.0);
+        assert_eq!(c01.native_gate, "CNOT");
+        let c12 = hw.get_coupler(1, 2).unwrap();
+        assert!((c12.gate_fidelity.value() - 0.988).abs() < 1e-12);
+        assert_eq!(c12.gate_time_ns, gate_times::TWO_QUBIT);
+
+        assert!(HardwareProfile::from_ibm_backend_json_str("{\"qubits\": 3}").is_err());
+    }
 }
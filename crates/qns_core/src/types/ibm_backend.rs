@@ -0,0 +1,281 @@
+//! IBM `backend_properties` calibration JSON.
+//!
+//! The shape IBM publishes per device:
+//!
+//! ```json
+//! {"backend_name": "ibm_x", "last_update_date": "2024-03-01T12:00:00+00:00",
+//!  "qubits": [[{"name": "T1", "unit": "us", "value": 120.5}, ...], ...],
+//!  "gates": [{"gate": "cx", "qubits": [0, 1],
+//!             "parameters": [{"name": "gate_error", "unit": "", "value": 0.007}, ...]}, ...]}
+//! ```
+//!
+//! `coupling_map` belongs to the backend configuration rather than its
+//! properties; it is read when present and otherwise taken from the
+//! two-qubit gate entries.
+
+use super::{CouplerProperties, Fidelity, HardwareProfile, QubitProperties};
+use crate::QnsError;
+use serde::Deserialize;
+
+/// A named measurement (IBM's "Nduv": name, date, unit, value).
+#[derive(Debug, Deserialize)]
+struct Nduv {
+    name: String,
+    #[serde(default)]
+    unit: String,
+    value: Option<f64>,
+}
+
+/// Calibration of one gate on specific qubits.
+#[derive(Debug, Deserialize)]
+struct GateProperties {
+    gate: String,
+    qubits: Vec<usize>,
+    #[serde(default)]
+    parameters: Vec<Nduv>,
+}
+
+/// Top-level `backend_properties` document; unknown fields are ignored.
+#[derive(Debug, Deserialize)]
+pub(crate) struct BackendProperties {
+    #[serde(default)]
+    backend_name: Option<String>,
+    #[serde(default)]
+    last_update_date: Option<String>,
+    #[serde(default)]
+    qubits: Vec<Vec<Nduv>>,
+    #[serde(default)]
+    gates: Vec<GateProperties>,
+    #[serde(default)]
+    coupling_map: Vec<[usize; 2]>,
+}
+
+/// Looks up a parameter by name.
+fn find<'a>(params: &'a [Nduv], name: &str) -> Option<&'a Nduv> {
+    params
+        .iter()
+        .find(|p| p.name == name && p.value.is_some_and(f64::is_finite))
+}
+
+/// Converts a time to μs (IBM reports T1/T2 in "us").
+fn to_us(p: &Nduv) -> f64 {
+    let value = p.value.unwrap_or_default();
+    match p.unit.as_str() {
+        "s" => value * 1e6,
+        "ms" => value * 1e3,
+        "ns" => value * 1e-3,
+        _ => value,
+    }
+}
+
+/// Converts a time to ns (IBM reports gate lengths in "ns").
+fn to_ns(p: &Nduv) -> f64 {
+    let value = p.value.unwrap_or_default();
+    match p.unit.as_str() {
+        "s" => value * 1e9,
+        "ms" => value * 1e6,
+        "us" | "µs" => value * 1e3,
+        _ => value,
+    }
+}
+
+/// Scales a frequency to `unit`, one of "GHz" or "MHz".
+fn to_hz_unit(p: &Nduv, unit: &str) -> f64 {
+    let hz = p.value.unwrap_or_default()
+        * match p.unit.as_str() {
+            "Hz" => 1.0,
+            "kHz" => 1e3,
+            "MHz" => 1e6,
+            _ => 1e9,
+        };
+    match unit {
+        "MHz" => hz / 1e6,
+        _ => hz / 1e9,
+    }
+}
+
+impl BackendProperties {
+    /// Builds a custom-topology profile, keeping the `QubitProperties` and
+    /// `CouplerProperties` defaults for anything the document omits.
+    pub(crate) fn into_profile(self) -> crate::Result<HardwareProfile> {
+        let edges: Vec<[usize; 2]> = self
+            .gates
+            .iter()
+            .filter_map(|g| match g.qubits[..] {
+                [a, b] => Some([a, b]),
+                _ => None,
+            })
+            .chain(self.coupling_map.iter().copied())
+            .collect();
+        let num_qubits = edges
+            .iter()
+            .flatten()
+            .chain(self.gates.iter().flat_map(|g| &g.qubits))
+            .map(|&q| q + 1)
+            .max()
+            .unwrap_or(0)
+            .max(self.qubits.len());
+
+        for &[a, b] in &edges {
+            if a == b {
+                return Err(QnsError::config(format!(
+                    "backend properties couple qubit {} to itself",
+                    a
+                )));
+            }
+        }
+        let mut profile = HardwareProfile::from_coupling_map(
+            self.backend_name
+                .unwrap_or_else(|| "ibm_backend".to_string()),
+            num_qubits,
+            &edges,
+        );
+        profile.calibration_timestamp = self.last_update_date.as_deref().and_then(parse_rfc3339);
+
+        for (props, params) in profile.qubit_properties.iter_mut().zip(&self.qubits) {
+            *props = qubit_properties(params);
+        }
+
+        for gate in &self.gates {
+            let error = find(&gate.parameters, "gate_error").and_then(|p| p.value);
+            match (gate.gate.as_str(), &gate.qubits[..]) {
+                ("sx", &[q]) => {
+                    if let Some(error) = error {
+                        profile.qubit_properties[q].single_gate_fidelity =
+                            Fidelity::from_error_rate(error);
+                    }
+                },
+                (name @ ("cx" | "ecr" | "cz"), &[a, b]) => {
+                    // One coupler per undirected edge: the first direction listed wins
+                    let Some(coupler) = profile
+                        .couplers
+                        .iter_mut()
+                        .find(|c| c.qubit1 == a && c.qubit2 == b)
+                    else {
+                        continue;
+                    };
+                    update_coupler(coupler, name, error, &gate.parameters);
+                },
+                _ => {},
+            }
+        }
+
+        Ok(profile)
+    }
+}
+
+/// Reads T1, T2, readout error, frequency and anharmonicity of one qubit.
+fn qubit_properties(params: &[Nduv]) -> QubitProperties {
+    let mut props = QubitProperties::default();
+    if let Some(p) = find(params, "T1") {
+        props.t1 = to_us(p);
+    }
+    if let Some(p) = find(params, "T2") {
+        props.t2 = to_us(p);
+    }
+    if let Some(p) = find(params, "readout_error") {
+        props.readout_fidelity = Fidelity::from_error_rate(p.value.unwrap_or_default());
+    }
+    if let Some(p) = find(params, "frequency") {
+        props.frequency = Some(to_hz_unit(p, "GHz"));
+    }
+    if let Some(p) = find(params, "anharmonicity") {
+        props.anharmonicity = Some(to_hz_unit(p, "MHz"));
+    }
+    props
+}
+
+/// Applies a two-qubit gate's calibration to its coupler.
+fn update_coupler(
+    coupler: &mut CouplerProperties,
+    gate: &str,
+    error: Option<f64>,
+    params: &[Nduv],
+) {
+    coupler.native_gate = match gate {
+        "cx" => "CNOT".to_string(),
+        other => other.to_uppercase(),
+    };
+    if let Some(error) = error {
+        coupler.gate_fidelity = Fidelity::from_error_rate(error);
+    }
+    if let Some(p) = find(params, "gate_length") {
+        coupler.gate_time_ns = to_ns(p);
+    }
+}
+
+/// Parses an RFC 3339 timestamp such as `2024-03-01T12:00:00.5+09:00` into
+/// Unix seconds. Returns `None` for anything else or dates before 1970.
+fn parse_rfc3339(s: &str) -> Option<u64> {
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let field = s.get(range)?;
+        field
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| field.parse().ok())?
+    };
+    if [(4, "-"), (7, "-"), (13, ":")]
+        .iter()
+        .any(|&(i, sep)| s.get(i..i + 1) != Some(sep))
+    {
+        return None;
+    }
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Skip fractional seconds, then read the UTC offset
+    let rest = s
+        .get(19..)?
+        .trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match rest {
+        "" | "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.get(3..4) == Some(":") => {
+            let sign = match rest.get(..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+            let hours: i64 = rest.get(1..3)?.parse().ok()?;
+            let minutes: i64 = rest.get(4..6)?.parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        },
+        _ => return None,
+    };
+
+    // Days since 1970-01-01 in the proleptic Gregorian calendar
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset;
+    u64::try_from(secs).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339("2024-03-01T12:00:00+00:00"),
+            Some(1_709_294_400)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-03-01T21:00:00.123+09:00"),
+            Some(1_709_294_400)
+        );
+        assert_eq!(parse_rfc3339("2024-13-01T00:00:00Z"), None);
+        assert_eq!(parse_rfc3339("yesterday"), None);
+        assert_eq!(parse_rfc3339("2024-03-01T12:00:00+0é:00"), None);
+        assert_eq!(parse_rfc3339("2024-03-01T12:00:0é"), None);
+    }
+}
@@ -10,10 +10,7 @@ pub struct CrosstalkLoader;
 impl CrosstalkLoader {
     /// Load HardwareProfile from a JSON file.
     pub fn load_profile<P: AsRef<Path>>(path: P) -> Result<HardwareProfile> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let profile = serde_json::from_reader(reader)?;
-        Ok(profile)
+        HardwareProfile::from_json(&std::fs::read_to_string(path)?)
     }
 
     /// Load CrosstalkMatrix from a JSON file.
@@ -1,15 +1,19 @@
 //! Core type definitions for QNS.
 
 mod circuit_genome;
+mod format_version;
 mod gate;
+mod gate_list;
 mod hardware_profile;
+mod ibm_backend;
 pub mod loader;
 mod noise_vector;
 
 pub use circuit_genome::{CircuitGenome, CircuitMetadata};
+pub use format_version::{migrate_format_version, QNS_FORMAT_VERSION};
 pub use gate::Gate;
 pub use hardware_profile::{
     CouplerProperties, CrosstalkMatrix, Fidelity, HardwareProfile, QubitProperties, Topology,
 };
 pub use loader::CrosstalkLoader;
-pub use noise_vector::{NoiseSource, NoiseVector};
+pub use noise_vector::{NoiseSource, NoiseVector, T1Schedule};
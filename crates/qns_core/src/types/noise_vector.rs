@@ -3,9 +3,15 @@
 //! This module provides the `NoiseVector` struct for representing qubit noise profiles,
 //! including T1/T2 times, gate errors, and readout errors.
 
+use super::{migrate_format_version, QubitProperties, QNS_FORMAT_VERSION};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Number of midpoint samples used when integrating a T1 schedule over a window.
+const T1_INTEGRATION_STEPS: usize = 32;
+
 /// Source of noise data.
 ///
 /// Indicates where the noise profile data originated from.
@@ -33,6 +39,30 @@ impl std::fmt::Display for NoiseSource {
     }
 }
 
+/// Time-dependent T1 profile: circuit time (ns) → T1 (μs).
+///
+/// Shared behind an `Arc` so that `NoiseVector` stays cheaply cloneable.
+#[derive(Clone)]
+pub struct T1Schedule(Arc<dyn Fn(f64) -> f64 + Send + Sync>);
+
+impl T1Schedule {
+    /// Wraps a T1 profile function.
+    pub fn new(schedule: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Self {
+        Self(Arc::new(schedule))
+    }
+
+    /// Evaluates T1 (μs) at `time_ns`.
+    pub fn t1_at(&self, time_ns: f64) -> f64 {
+        (self.0)(time_ns)
+    }
+}
+
+impl std::fmt::Debug for T1Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("T1Schedule(..)")
+    }
+}
+
 /// Noise profile vector for a qubit.
 ///
 /// Contains T1/T2 statistics, gate errors, readout errors, and metadata.
@@ -55,7 +85,7 @@ impl std::fmt::Display for NoiseSource {
 /// nv.gate_error_2q = 0.01;   // 1%
 /// nv.readout_error = 0.02;   // 2%
 /// ```
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NoiseVector {
     // ===== Qubit Identification =====
     /// Qubit ID
@@ -65,10 +95,12 @@ pub struct NoiseVector {
     /// Mean T1 relaxation time (μs)
     pub t1_mean: f64,
     /// Standard deviation of T1 (μs)
+    #[serde(default)]
     pub t1_std: f64,
     /// Mean T2 dephasing time (μs)
     pub t2_mean: f64,
     /// Standard deviation of T2 (μs)
+    #[serde(default)]
     pub t2_std: f64,
 
     // ===== Gate Errors (v2.0) =====
@@ -78,26 +110,76 @@ pub struct NoiseVector {
     pub gate_error_2q: f64,
     /// Readout (measurement) error rate (0.0 - 1.0)
     pub readout_error: f64,
+    /// Pairwise correlation of readout errors between qubits (0.0 - 1.0).
+    ///
+    /// Qubits sharing a multiplexed readout line tend to fail together;
+    /// 0.0 means independent readout errors.
+    #[serde(default)]
+    pub readout_correlation: f64,
 
     // ===== Drift Detection =====
     /// Drift rate (μs/hour)
+    #[serde(default)]
     pub drift_rate: f64,
     /// Number of burst events detected
+    #[serde(default)]
     pub burst_count: usize,
 
     // ===== Metadata =====
     /// Unix timestamp of data collection
+    #[serde(default)]
     pub timestamp: u64,
     /// Number of samples collected (for statistics)
+    #[serde(default)]
     pub sample_count: usize,
     /// Data source indicator
+    #[serde(default)]
     pub source: NoiseSource,
 
     // ===== Optional Hardware Info =====
     /// Qubit frequency in GHz (if available)
+    #[serde(default)]
     pub frequency: Option<f64>,
     /// Anharmonicity in MHz (if available)
+    #[serde(default)]
     pub anharmonicity: Option<f64>,
+
+    // ===== Time-Dependent Coherence =====
+    /// Optional T1 profile over circuit time, overriding `t1_mean` in scoring.
+    ///
+    /// Runtime-only: set via [`NoiseVector::with_t1_schedule`], never serialized.
+    #[serde(skip)]
+    pub t1_schedule: Option<T1Schedule>,
+
+    // ===== Serialization =====
+    /// Serialization format version (0 for files written before versioning)
+    #[serde(default)]
+    pub qns_format_version: u32,
+}
+
+impl Default for NoiseVector {
+    fn default() -> Self {
+        Self {
+            qubit_id: 0,
+            t1_mean: 0.0,
+            t1_std: 0.0,
+            t2_mean: 0.0,
+            t2_std: 0.0,
+            gate_error_1q: 0.0,
+            gate_error_2q: 0.0,
+            readout_error: 0.0,
+            readout_correlation: 0.0,
+            drift_rate: 0.0,
+            burst_count: 0,
+            timestamp: 0,
+            sample_count: 0,
+            source: NoiseSource::default(),
+            frequency: None,
+            anharmonicity: None,
+            t1_schedule: None,
+            qns_format_version: QNS_FORMAT_VERSION,
+        }
+    }
 }
 
 impl NoiseVector {
@@ -162,12 +244,101 @@ impl NoiseVector {
         }
     }
 
+    /// Loads a noise vector from JSON, checking its format version.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let mut noise: Self = serde_json::from_str(json)?;
+        noise.qns_format_version = migrate_format_version("NoiseVector", noise.qns_format_version)?;
+        Ok(noise)
+    }
+
+    /// Serializes the noise vector to JSON.
+    pub fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Writes the noise vector to a JSON file.
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> crate::Result<()> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    /// Loads a noise vector from a JSON calibration file.
+    ///
+    /// Only `qubit_id`, `t1_mean`, `t2_mean`, `gate_error_1q`,
+    /// `gate_error_2q` and `readout_error` are required; statistics and
+    /// metadata fall back to their defaults.
+    pub fn load_json<P: AsRef<Path>>(path: P) -> crate::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Converts the qubit properties of a [`HardwareProfile`](super::HardwareProfile).
+    ///
+    /// Two-qubit gate errors are per coupler, not per qubit, so
+    /// `gate_error_2q` is left at zero.
+    pub fn from_qubit_properties(qubit_id: usize, props: &QubitProperties) -> Self {
+        Self {
+            frequency: props.frequency,
+            anharmonicity: props.anharmonicity,
+            ..Self::comprehensive(
+                qubit_id,
+                props.t1,
+                props.t2,
+                props.single_gate_fidelity.error_rate(),
+                0.0,
+                props.readout_fidelity.error_rate(),
+            )
+            .with_source(NoiseSource::Calibration)
+        }
+    }
+
     /// Sets the data source.
     pub fn with_source(mut self, source: NoiseSource) -> Self {
         self.source = source;
         self
     }
 
+    /// Sets a time-dependent T1 profile (time in ns → T1 in μs).
+    ///
+    /// Models drift or non-Markovian relaxation over the circuit's duration,
+    /// letting the optimizer prefer idling during high-coherence windows.
+    pub fn with_t1_schedule(
+        mut self,
+        schedule: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        self.t1_schedule = Some(T1Schedule::new(schedule));
+        self
+    }
+
+    /// Returns T1 (μs) at circuit time `time_ns`.
+    ///
+    /// Falls back to `t1_mean` when no schedule is set.
+    pub fn t1_at(&self, time_ns: f64) -> f64 {
+        match &self.t1_schedule {
+            Some(schedule) => schedule.t1_at(time_ns),
+            None => self.t1_mean,
+        }
+    }
+
+    /// Integrated T1 decay exponent `∫ dt / T1(t)` over `[start_ns, end_ns]`.
+    ///
+    /// The T1 survival probability over the window is `exp(-exponent)`. With a
+    /// schedule the integral is evaluated with the midpoint rule; otherwise it
+    /// reduces to `duration / t1_mean`.
+    pub fn t1_decay_exponent(&self, start_ns: f64, end_ns: f64) -> f64 {
+        let duration_us = (end_ns - start_ns).max(0.0) / 1000.0;
+        match &self.t1_schedule {
+            None => duration_us / self.t1_mean,
+            Some(schedule) => {
+                let step_ns = (end_ns - start_ns).max(0.0) / T1_INTEGRATION_STEPS as f64;
+                let mean_rate = (0..T1_INTEGRATION_STEPS)
+                    .map(|i| 1.0 / schedule.t1_at(start_ns + (i as f64 + 0.5) * step_ns))
+                    .sum::<f64>()
+                    / T1_INTEGRATION_STEPS as f64;
+                duration_us * mean_rate
+            },
+        }
+    }
+
     /// Checks if the noise profile indicates an anomaly.
     ///
     /// An anomaly is detected when:
@@ -357,6 +528,7 @@ impl NoiseVector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Fidelity;
 
     #[test]
     fn test_new() {
@@ -407,6 +579,40 @@ mod tests {
         assert!(nv.is_anomaly(10.0));
     }
 
+    #[test]
+    fn test_t1_schedule() {
+        let nv = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        assert_eq!(nv.t1_at(500.0), 100.0);
+        assert!((nv.t1_decay_exponent(0.0, 1000.0) - 0.01).abs() < 1e-12);
+
+        // T1 = 10 μs for the first microsecond, 100 μs afterwards
+        let nv = nv.with_t1_schedule(|t| if t < 1000.0 { 10.0 } else { 100.0 });
+        assert_eq!(nv.t1_at(500.0), 10.0);
+        assert!((nv.t1_decay_exponent(0.0, 2000.0) - 0.11).abs() < 1e-12);
+
+        // Schedules are runtime-only
+        let json = serde_json::to_string(&nv).unwrap();
+        let restored: NoiseVector = serde_json::from_str(&json).unwrap();
+        assert!(restored.t1_schedule.is_none());
+        assert!(nv.clone().t1_schedule.is_some());
+    }
+
+    #[test]
+    fn test_default_is_current_format_version() {
+        assert_eq!(
+            NoiseVector::default().qns_format_version,
+            QNS_FORMAT_VERSION
+        );
+
+        // Untagged JSON still reads as version 0 before migration
+        let json = r#"{"qubit_id":0,"t1_mean":100.0,"t2_mean":80.0,
+            "gate_error_1q":0.001,"gate_error_2q":0.01,"readout_error":0.02}"#;
+        let raw: NoiseVector = serde_json::from_str(json).unwrap();
+        assert_eq!(raw.qns_format_version, 0);
+        let migrated = NoiseVector::from_json(json).unwrap();
+        assert_eq!(migrated.qns_format_version, QNS_FORMAT_VERSION);
+    }
+
     #[test]
     fn test_t2_t1_ratio() {
         let nv = NoiseVector::with_t1t2(0, 100.0, 80.0);
@@ -481,4 +687,63 @@ mod tests {
         assert_eq!(format!("{}", NoiseSource::Simulator), "Simulator");
         assert_eq!(format!("{}", NoiseSource::Calibration), "Calibration");
     }
+
+    #[test]
+    fn test_json_file_round_trip() {
+        let mut nv = NoiseVector::comprehensive(3, 87.5, 64.25, 3.1e-4, 7.7e-3, 0.0185)
+            .with_source(NoiseSource::Calibration);
+        nv.t1_std = 4.5;
+        nv.frequency = Some(5.12);
+
+        let path =
+            std::env::temp_dir().join(format!("qns_noise_vector_{}.json", std::process::id()));
+        nv.save_json(&path).unwrap();
+        let loaded = NoiseVector::load_json(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.qubit_id, nv.qubit_id);
+        assert_eq!(loaded.t1_mean, nv.t1_mean);
+        assert_eq!(loaded.t1_std, nv.t1_std);
+        assert_eq!(loaded.t2_mean, nv.t2_mean);
+        assert_eq!(loaded.gate_error_1q, nv.gate_error_1q);
+        assert_eq!(loaded.gate_error_2q, nv.gate_error_2q);
+        assert_eq!(loaded.readout_error, nv.readout_error);
+        assert_eq!(loaded.timestamp, nv.timestamp);
+        assert_eq!(loaded.source, nv.source);
+        assert_eq!(loaded.frequency, nv.frequency);
+    }
+
+    #[test]
+    fn test_from_json_calibration_fields_only() {
+        let json = r#"{"qubit_id": 2, "t1_mean": 120.0, "t2_mean": 95.0,
+            "gate_error_1q": 0.0002, "gate_error_2q": 0.006, "readout_error": 0.013}"#;
+        let nv = NoiseVector::from_json(json).unwrap();
+        assert_eq!(nv.qubit_id, 2);
+        assert_eq!(nv.t2_mean, 95.0);
+        assert_eq!(nv.gate_error_2q, 0.006);
+        assert_eq!(nv.sample_count, 0);
+
+        // The calibration fields themselves are required
+        assert!(NoiseVector::from_json(r#"{"qubit_id": 2, "t1_mean": 120.0}"#).is_err());
+    }
+
+    #[test]
+    fn test_from_qubit_properties() {
+        let props = QubitProperties {
+            t1: 110.0,
+            t2: 80.0,
+            readout_fidelity: Fidelity::from_error_rate(0.02),
+            single_gate_fidelity: Fidelity::from_error_rate(0.0005),
+            frequency: Some(4.9),
+            anharmonicity: None,
+        };
+        let nv = NoiseVector::from_qubit_properties(4, &props);
+        assert_eq!(nv.qubit_id, 4);
+        assert_eq!(nv.t1_mean, 110.0);
+        assert_eq!(nv.t2_mean, 80.0);
+        assert!((nv.gate_error_1q - 0.0005).abs() < 1e-12);
+        assert!((nv.readout_error - 0.02).abs() < 1e-12);
+        assert_eq!(nv.frequency, Some(4.9));
+        assert_eq!(nv.source, NoiseSource::Calibration);
+    }
 }
@@ -58,6 +58,10 @@ pub struct T1Measurement {
     pub num_samples: usize,
     /// Raw decay curve data points (time_us, probability)
     pub decay_curve: Vec<(f64, f64)>,
+    /// Coefficient of determination of the log-linear decay fit (1.0 = perfect)
+    pub r_squared: f64,
+    /// Residual standard error of the log-linear decay fit
+    pub residual_std_error: f64,
 }
 
 /// Result of T2 measurement simulation.
@@ -71,6 +75,24 @@ pub struct T2Measurement {
     pub num_samples: usize,
     /// Ramsey fringe data points (time_us, probability)
     pub ramsey_curve: Vec<(f64, f64)>,
+    /// Coefficient of determination of the log-linear envelope fit (1.0 = perfect)
+    pub r_squared: f64,
+    /// Residual standard error of the log-linear envelope fit
+    pub residual_std_error: f64,
+}
+
+impl T1Measurement {
+    /// Returns true if the decay fit explains at least `min_r_squared` of the variance.
+    pub fn is_good_fit(&self, min_r_squared: f64) -> bool {
+        self.r_squared >= min_r_squared
+    }
+}
+
+impl T2Measurement {
+    /// Returns true if the envelope fit explains at least `min_r_squared` of the variance.
+    pub fn is_good_fit(&self, min_r_squared: f64) -> bool {
+        self.r_squared >= min_r_squared
+    }
 }
 
 /// Simulates T1 measurement using inversion recovery protocol.
@@ -96,8 +118,6 @@ pub fn simulate_t1(
     let dt = t_max / num_time_points as f64;
 
     let mut decay_curve = Vec::with_capacity(num_time_points);
-    let mut log_probs = Vec::with_capacity(num_time_points);
-    let mut times = Vec::with_capacity(num_time_points);
 
     // Noise distribution for measurement
     let noise_dist =
@@ -114,31 +134,44 @@ pub fn simulate_t1(
         let p_measured = (p_true + noise).clamp(0.001, 0.999); // Avoid log(0)
 
         decay_curve.push((t, p_measured));
+    }
 
-        // For linear fit: ln(P) = -t/T1
-        log_probs.push(p_measured.ln());
-        times.push(t);
+    let mut result = fit_t1(decay_curve, num_samples);
+    if result.t1.is_nan() {
+        result.t1 = t1_true; // Fallback if slope is too small
     }
+    result
+}
+
+/// Fits T1 to an inversion-recovery decay curve of (time_us, P(|1⟩)) points.
+///
+/// Fits `ln P = -t/T1 + const` by least squares and reports the fit's R² and
+/// residual standard error alongside T1. Returns `t1 = NaN` if the curve has
+/// no measurable decay.
+pub fn fit_t1(decay_curve: Vec<(f64, f64)>, num_samples: usize) -> T1Measurement {
+    let (times, log_probs): (Vec<f64>, Vec<f64>) = decay_curve
+        .iter()
+        .map(|&(t, p)| (t, p.clamp(0.001, 0.999).ln()))
+        .unzip();
 
     // Linear regression to extract T1: ln(P) = -t/T1 + const
     // Using least squares: slope = -1/T1
-    let (slope, _intercept, std_error) = linear_regression(&times, &log_probs);
+    let fit = linear_regression(&times, &log_probs);
 
-    // T1 = -1/slope
-    let t1_measured = if slope.abs() > 1e-10 {
-        -1.0 / slope
+    // T1 = -1/slope, ensuring physical validity
+    let t1_measured = if fit.slope.abs() > 1e-10 {
+        (-1.0 / fit.slope).max(0.1)
     } else {
-        t1_true // Fallback if slope is too small
+        f64::NAN
     };
 
-    // Ensure physical validity
-    let t1_measured = t1_measured.max(0.1);
-
     T1Measurement {
         t1: t1_measured,
-        std_error: std_error / (slope * slope).max(1e-10), // Propagate error
+        std_error: fit.slope_std_error / (fit.slope * fit.slope).max(1e-10), // Propagate error
         num_samples,
         decay_curve,
+        r_squared: fit.r_squared,
+        residual_std_error: fit.residual_std_error,
     }
 }
 
@@ -200,10 +233,10 @@ pub fn simulate_t2(
     }
 
     // Fit envelope decay: ln(envelope) = -t/T2
-    let (slope, _intercept, std_error) = linear_regression(&times, &envelopes);
+    let fit = linear_regression(&times, &envelopes);
 
-    let t2_measured = if slope.abs() > 1e-10 {
-        -1.0 / slope
+    let t2_measured = if fit.slope.abs() > 1e-10 {
+        -1.0 / fit.slope
     } else {
         t2_physical
     };
@@ -213,19 +246,38 @@ pub fn simulate_t2(
 
     T2Measurement {
         t2: t2_measured,
-        std_error: std_error / (slope * slope).max(1e-10),
+        std_error: fit.slope_std_error / (fit.slope * fit.slope).max(1e-10),
         num_samples,
         ramsey_curve,
+        r_squared: fit.r_squared,
+        residual_std_error: fit.residual_std_error,
     }
 }
 
+/// Least-squares line fit with quality statistics.
+#[derive(Debug, Clone, Copy)]
+struct LinearFit {
+    slope: f64,
+    #[allow(dead_code)]
+    intercept: f64,
+    slope_std_error: f64,
+    /// Coefficient of determination (0.0 when y is constant)
+    r_squared: f64,
+    /// Residual standard error sqrt(SS_res / (n - 2))
+    residual_std_error: f64,
+}
+
 /// Performs simple linear regression y = slope * x + intercept.
-///
-/// Returns (slope, intercept, standard_error_of_slope)
-fn linear_regression(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
+fn linear_regression(x: &[f64], y: &[f64]) -> LinearFit {
     let n = x.len() as f64;
     if n < 2.0 {
-        return (0.0, 0.0, f64::INFINITY);
+        return LinearFit {
+            slope: 0.0,
+            intercept: 0.0,
+            slope_std_error: f64::INFINITY,
+            r_squared: 0.0,
+            residual_std_error: f64::INFINITY,
+        };
     }
 
     let sum_x: f64 = x.iter().sum();
@@ -235,7 +287,13 @@ fn linear_regression(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
 
     let denom = n * sum_x2 - sum_x * sum_x;
     if denom.abs() < 1e-20 {
-        return (0.0, sum_y / n, f64::INFINITY);
+        return LinearFit {
+            slope: 0.0,
+            intercept: sum_y / n,
+            slope_std_error: f64::INFINITY,
+            r_squared: 0.0,
+            residual_std_error: f64::INFINITY,
+        };
     }
 
     let slope = (n * sum_xy - sum_x * sum_y) / denom;
@@ -252,7 +310,22 @@ fn linear_regression(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
     let mse = residuals / (n - 2.0).max(1.0);
     let se_slope = (mse / (sum_x2 - sum_x * sum_x / n).max(1e-20)).sqrt();
 
-    (slope, intercept, se_slope)
+    // Fraction of the variance of y explained by the line
+    let mean_y = sum_y / n;
+    let total: f64 = y.iter().map(|yi| (yi - mean_y).powi(2)).sum();
+    let r_squared = if total > 1e-20 {
+        (1.0 - residuals / total).max(0.0)
+    } else {
+        0.0
+    };
+
+    LinearFit {
+        slope,
+        intercept,
+        slope_std_error: se_slope,
+        r_squared,
+        residual_std_error: mse.sqrt(),
+    }
 }
 
 /// Simulates a burst noise event.
@@ -336,9 +409,37 @@ mod tests {
         let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
         let y = vec![2.0, 4.0, 6.0, 8.0, 10.0]; // y = 2x
 
-        let (slope, intercept, _) = linear_regression(&x, &y);
-        assert!((slope - 2.0).abs() < 0.01);
-        assert!(intercept.abs() < 0.01);
+        let fit = linear_regression(&x, &y);
+        assert!((fit.slope - 2.0).abs() < 0.01);
+        assert!(fit.intercept.abs() < 0.01);
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+        assert!(fit.residual_std_error < 1e-9);
+    }
+
+    #[test]
+    fn test_fit_quality() {
+        // Clean exponential decay with T1 = 100 μs
+        let clean: Vec<(f64, f64)> = (0..20)
+            .map(|i| {
+                let t = (i as f64 + 0.5) * 25.0;
+                (t, (-t / 100.0).exp())
+            })
+            .collect();
+        let good = fit_t1(clean.clone(), 1000);
+        assert!((good.t1 - 100.0).abs() < 1.0, "T1 = {}", good.t1);
+        assert!(good.r_squared > 0.99, "R² = {}", good.r_squared);
+        assert!(good.is_good_fit(0.9));
+
+        // Large alternating readout noise swamps the decay
+        let noisy: Vec<(f64, f64)> = clean
+            .iter()
+            .enumerate()
+            .map(|(i, &(t, p))| (t, p + if i % 2 == 0 { 0.6 } else { -0.6 }))
+            .collect();
+        let bad = fit_t1(noisy, 1000);
+        assert!(bad.r_squared < 0.5, "R² = {}", bad.r_squared);
+        assert!(bad.residual_std_error > good.residual_std_error);
+        assert!(!bad.is_good_fit(0.9));
     }
 
     #[test]
@@ -17,4 +17,4 @@ pub use compute::{
     AnomalyResult, AnomalyType, DriftAnalysis, ExponentialMovingAverage, Statistics,
 };
 pub use measure::{T1Measurement, T2Measurement};
-pub use scanner::{AnomalyAnalysis, DriftScanner, ScanConfig};
+pub use scanner::{AnomalyAnalysis, DriftScanner, FitQuality, ScanConfig};
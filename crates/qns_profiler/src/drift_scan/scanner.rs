@@ -22,10 +22,13 @@
 
 use qns_core::prelude::*;
 use qns_core::types::CrosstalkMatrix;
+use rand_distr::{Distribution, StandardNormal};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use super::compute::{calculate_drift_rate, detect_anomaly, ExponentialMovingAverage, Statistics};
-use super::measure::{simulate_burst_event, simulate_t1, simulate_t2};
+use super::measure::{
+    simulate_burst_event, simulate_t1, simulate_t2, T1Measurement, T2Measurement,
+};
 
 /// Configuration for drift scanning.
 #[derive(Debug, Clone)]
@@ -50,6 +53,19 @@ pub struct ScanConfig {
     pub burst_probability: f64,
     /// Enable drift tracking
     pub track_drift: bool,
+    /// Relative scan-to-scan fluctuation of the true T1/T2 (0.0 = static device)
+    pub drift_amplitude: f64,
+    /// Fraction of the fluctuation shared by all qubits in a batch (0.0 - 1.0).
+    ///
+    /// Models device-wide causes such as fridge temperature changes. Per-scan
+    /// factors of two qubits have correlation coefficient `correlation`.
+    pub correlation: f64,
+    /// Minimum R² for a T1/T2 fit to count as good quality (`None` = no check).
+    ///
+    /// Fits below the threshold are reported by [`DriftScanner::last_fit_quality`].
+    pub min_fit_r_squared: Option<f64>,
+    /// Fail the scan instead of recording a fit below `min_fit_r_squared`
+    pub reject_poor_fits: bool,
 }
 
 impl Default for ScanConfig {
@@ -65,6 +81,10 @@ impl Default for ScanConfig {
             ramsey_detuning: 0.01,    // 10 kHz
             burst_probability: 0.001, // 0.1% per scan
             track_drift: true,
+            drift_amplitude: 0.0,
+            correlation: 0.0,
+            min_fit_r_squared: None,
+            reject_poor_fits: false,
         }
     }
 }
@@ -96,6 +116,8 @@ struct QubitHistory {
     t2_ema: Option<ExponentialMovingAverage>,
     /// Detected burst count
     burst_count: usize,
+    /// Fit statistics of the qubit's most recent scan
+    last_fit: Option<FitQuality>,
     /// Maximum history size
     max_history: usize,
 }
@@ -109,6 +131,7 @@ impl QubitHistory {
             t1_ema: Some(ExponentialMovingAverage::from_period(10)),
             t2_ema: Some(ExponentialMovingAverage::from_period(10)),
             burst_count: 0,
+            last_fit: None,
             max_history,
         }
     }
@@ -147,10 +170,13 @@ impl QubitHistory {
 ///
 /// Performs periodic measurements of T1 (energy relaxation) and T2 (dephasing)
 /// times, tracks drift over time, and detects anomalies.
+#[derive(Clone)]
 pub struct DriftScanner {
     config: ScanConfig,
     /// Last scanned noise vector
     last_vector: Option<NoiseVector>,
+    /// Fit statistics of the last scan
+    last_fit: Option<FitQuality>,
     /// Per-qubit history (indexed by qubit_id)
     qubit_histories: Vec<QubitHistory>,
     /// Total scan count
@@ -163,6 +189,7 @@ impl DriftScanner {
         Self {
             config: config.into(),
             last_vector: None,
+            last_fit: None,
             qubit_histories: Vec::new(),
             scan_count: 0,
         }
@@ -206,13 +233,40 @@ impl DriftScanner {
         }
     }
 
+    /// Draws the device-wide latent drift factor for one scan round.
+    fn sample_shared_drift(&self) -> f64 {
+        StandardNormal.sample(&mut rand::thread_rng())
+    }
+
+    /// Multiplicative T1/T2 drift factor for one qubit in a scan round.
+    ///
+    /// Mixes the shared latent factor with independent per-qubit noise so that
+    /// factors of different qubits have correlation `config.correlation`.
+    fn drift_factor(&self, shared: f64) -> f64 {
+        if self.config.drift_amplitude <= 0.0 {
+            return 1.0;
+        }
+        let rho = self.config.correlation.clamp(0.0, 1.0);
+        let own: f64 = StandardNormal.sample(&mut rand::thread_rng());
+        let z = rho.sqrt() * shared + (1.0 - rho).sqrt() * own;
+        (1.0 + self.config.drift_amplitude * z).max(0.1)
+    }
+
     /// Performs a drift scan on the specified qubit.
     ///
     /// Returns a NoiseVector containing T1/T2 statistics and drift information.
+    /// A single scan is its own drift round; use [`DriftScanner::scan_batch`]
+    /// for qubits that share a correlated drift factor.
     ///
     /// # Performance
     /// Target: <10ms with default configuration
     pub fn scan(&mut self, qubit_id: usize) -> Result<NoiseVector> {
+        let shared = self.sample_shared_drift();
+        self.scan_in_round(qubit_id, shared)
+    }
+
+    /// Scans one qubit using the given shared drift factor.
+    fn scan_in_round(&mut self, qubit_id: usize, shared_drift: f64) -> Result<NoiseVector> {
         self.ensure_history(qubit_id);
         self.scan_count += 1;
 
@@ -221,16 +275,18 @@ impl DriftScanner {
             .map(|d| d.as_secs())
             .unwrap_or(0);
 
+        // Apply scan-to-scan drift to the true coherence times
+        let drift = self.drift_factor(shared_drift);
+        let (t1_base, t2_base) = (self.config.t1_base * drift, self.config.t2_base * drift);
+
         // Check for burst event (simulated hardware anomaly)
-        let (effective_t1, effective_t2, is_burst) = if let Some((t1, t2)) = simulate_burst_event(
-            self.config.t1_base,
-            self.config.t2_base,
-            self.config.burst_probability,
-        ) {
+        let (effective_t1, effective_t2, is_burst) = if let Some((t1, t2)) =
+            simulate_burst_event(t1_base, t2_base, self.config.burst_probability)
+        {
             self.qubit_histories[qubit_id].burst_count += 1;
             (t1, t2, true)
         } else {
-            (self.config.t1_base, self.config.t2_base, false)
+            (t1_base, t2_base, false)
         };
 
         // Simulate T1 measurement
@@ -251,6 +307,17 @@ impl DriftScanner {
             self.config.ramsey_detuning,
         );
 
+        // Judge fit quality before the estimates enter the history
+        let fit = FitQuality::new(&t1_result, &t2_result, self.config.min_fit_r_squared);
+        self.last_fit = Some(fit);
+        self.qubit_histories[qubit_id].last_fit = Some(fit);
+        if fit.low_quality && self.config.reject_poor_fits {
+            return Err(QnsError::profiler(format!(
+                "low-quality fit on qubit {}: T1 R² = {:.3}, T2 R² = {:.3}",
+                qubit_id, fit.t1_r_squared, fit.t2_r_squared
+            )));
+        }
+
         // Update history
         let history = &mut self.qubit_histories[qubit_id];
         history.add(t1_result.t1, t2_result.t2, timestamp);
@@ -300,19 +367,43 @@ impl DriftScanner {
             gate_error_1q: qns_core::physics::gate_errors::SINGLE_QUBIT_TYPICAL,
             gate_error_2q: qns_core::physics::gate_errors::TWO_QUBIT_TYPICAL,
             readout_error: qns_core::physics::gate_errors::MEASUREMENT_TYPICAL,
+            readout_correlation: 0.0,
             frequency: None,
             anharmonicity: None,
+            t1_schedule: None,
+            qns_format_version: qns_core::types::QNS_FORMAT_VERSION,
         };
 
         self.last_vector = Some(noise_vector.clone());
         Ok(noise_vector)
     }
 
+    /// Returns the T1/T2 fit statistics of the most recent scan.
+    ///
+    /// Available even when the scan was rejected for a poor fit. After
+    /// [`scan_batch`](Self::scan_batch) this is the last qubit's fit; use
+    /// [`fit_quality`](Self::fit_quality) for the others.
+    pub fn last_fit_quality(&self) -> Option<&FitQuality> {
+        self.last_fit.as_ref()
+    }
+
+    /// Returns the T1/T2 fit statistics of the most recent scan of `qubit_id`.
+    pub fn fit_quality(&self, qubit_id: usize) -> Option<&FitQuality> {
+        self.qubit_histories
+            .get(qubit_id)
+            .and_then(|h| h.last_fit.as_ref())
+    }
+
     /// Performs a batch scan on multiple qubits.
     ///
-    /// More efficient than scanning qubits individually.
+    /// More efficient than scanning qubits individually. All qubits in the
+    /// batch share one latent drift factor (see [`ScanConfig::correlation`]).
     pub fn scan_batch(&mut self, qubit_ids: &[usize]) -> Result<Vec<NoiseVector>> {
-        qubit_ids.iter().map(|&id| self.scan(id)).collect()
+        let shared = self.sample_shared_drift();
+        qubit_ids
+            .iter()
+            .map(|&id| self.scan_in_round(id, shared))
+            .collect()
     }
 
     /// Checks if the given noise vector indicates an anomaly.
@@ -408,6 +499,11 @@ impl DriftScanner {
         &self.config
     }
 
+    /// Replaces the configuration, keeping the scan history.
+    pub fn set_config(&mut self, config: impl Into<ScanConfig>) {
+        self.config = config.into();
+    }
+
     /// Returns the total number of scans performed.
     pub fn scan_count(&self) -> usize {
         self.scan_count
@@ -434,6 +530,7 @@ impl DriftScanner {
             history.t2_history.clear();
             history.timestamps.clear();
             history.burst_count = 0;
+            history.last_fit = None;
             if let Some(ema) = &mut history.t1_ema {
                 ema.reset();
             }
@@ -447,6 +544,7 @@ impl DriftScanner {
     pub fn clear_all_history(&mut self) {
         self.qubit_histories.clear();
         self.last_vector = None;
+        self.last_fit = None;
         self.scan_count = 0;
     }
 
@@ -474,6 +572,35 @@ impl DriftScanner {
     }
 }
 
+/// Goodness of the T1/T2 fits behind a scan.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FitQuality {
+    /// R² of the T1 decay fit
+    pub t1_r_squared: f64,
+    /// Residual standard error of the T1 decay fit
+    pub t1_residual_std_error: f64,
+    /// R² of the T2 envelope fit
+    pub t2_r_squared: f64,
+    /// Residual standard error of the T2 envelope fit
+    pub t2_residual_std_error: f64,
+    /// Either fit fell below [`ScanConfig::min_fit_r_squared`]
+    pub low_quality: bool,
+}
+
+impl FitQuality {
+    fn new(t1: &T1Measurement, t2: &T2Measurement, min_r_squared: Option<f64>) -> Self {
+        let low_quality =
+            min_r_squared.is_some_and(|min| !t1.is_good_fit(min) || !t2.is_good_fit(min));
+        Self {
+            t1_r_squared: t1.r_squared,
+            t1_residual_std_error: t1.residual_std_error,
+            t2_r_squared: t2.r_squared,
+            t2_residual_std_error: t2.residual_std_error,
+            low_quality,
+        }
+    }
+}
+
 /// Detailed anomaly analysis result.
 #[derive(Debug, Clone, Default)]
 pub struct AnomalyAnalysis {
@@ -547,6 +674,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_low_quality_fit_flagged() {
+        let noisy = ScanConfig {
+            noise_level: 0.4,
+            burst_probability: 0.0,
+            min_fit_r_squared: Some(0.99),
+            ..Default::default()
+        };
+
+        // Flagged but still recorded
+        let mut scanner = DriftScanner::new(noisy.clone());
+        assert!(scanner.last_fit_quality().is_none());
+        scanner.scan(0).unwrap();
+        let fit = *scanner.last_fit_quality().unwrap();
+        assert!(fit.low_quality, "{:?}", fit);
+        assert!(fit.t1_r_squared < 0.99 || fit.t2_r_squared < 0.99);
+        assert_eq!(scanner.t1_history(0).unwrap().len(), 1);
+
+        // Rejected scans leave the history untouched
+        let mut strict = DriftScanner::new(ScanConfig {
+            reject_poor_fits: true,
+            ..noisy
+        });
+        assert!(strict.scan(0).is_err());
+        assert!(strict.last_fit_quality().unwrap().low_quality);
+        assert!(strict.t1_history(0).unwrap().is_empty());
+
+        // Without a threshold nothing is flagged
+        let mut lenient = DriftScanner::with_defaults();
+        lenient.scan(0).unwrap();
+        assert!(!lenient.last_fit_quality().unwrap().low_quality);
+    }
+
+    #[test]
+    fn test_fit_quality_per_qubit() {
+        let mut scanner = DriftScanner::new(ScanConfig {
+            noise_level: 0.2,
+            burst_probability: 0.0,
+            ..Default::default()
+        });
+        scanner.scan_batch(&[0, 1, 2]).unwrap();
+
+        let fits: Vec<FitQuality> = (0..3).map(|q| *scanner.fit_quality(q).unwrap()).collect();
+        assert_eq!(scanner.last_fit_quality(), Some(&fits[2]));
+        assert_ne!(fits[0], fits[1]);
+        assert_ne!(fits[1], fits[2]);
+        assert!(scanner.fit_quality(3).is_none());
+
+        // Rescanning one qubit leaves the others' fits alone
+        scanner.scan(1).unwrap();
+        assert_eq!(scanner.fit_quality(0), Some(&fits[0]));
+        assert_eq!(scanner.fit_quality(2), Some(&fits[2]));
+        assert_eq!(scanner.last_fit_quality(), scanner.fit_quality(1));
+        assert_ne!(scanner.fit_quality(1), Some(&fits[1]));
+
+        scanner.clear_history(0);
+        assert!(scanner.fit_quality(0).is_none());
+    }
+
     #[test]
     fn test_history_tracking() {
         let mut scanner = DriftScanner::fast();
@@ -626,6 +812,51 @@ mod tests {
         assert!(scanner.t1_history(0).map(|h| h.is_empty()).unwrap_or(true));
     }
 
+    #[test]
+    fn test_correlated_drift() {
+        let scanner_with = |correlation| {
+            DriftScanner::new(ScanConfig {
+                noise_level: 0.0,
+                burst_probability: 0.0,
+                drift_amplitude: 0.1,
+                correlation,
+                ..Default::default()
+            })
+        };
+        let pearson = |a: &[f64], b: &[f64]| {
+            let n = a.len() as f64;
+            let (ma, mb) = (a.iter().sum::<f64>() / n, b.iter().sum::<f64>() / n);
+            let cov: f64 = a.iter().zip(b).map(|(x, y)| (x - ma) * (y - mb)).sum();
+            let va: f64 = a.iter().map(|x| (x - ma).powi(2)).sum();
+            let vb: f64 = b.iter().map(|y| (y - mb).powi(2)).sum();
+            cov / (va * vb).sqrt()
+        };
+
+        // Fully shared drift: every qubit sees the same T1 in each round
+        let mut scanner = scanner_with(1.0);
+        for _ in 0..20 {
+            scanner.scan_batch(&[0, 1, 2]).unwrap();
+        }
+        let t1_q0 = scanner.t1_history(0).unwrap().to_vec();
+        for q in 1..3 {
+            for (a, b) in t1_q0.iter().zip(scanner.t1_history(q).unwrap()) {
+                assert!((a - b).abs() < 1e-9 * a, "{} vs {}", a, b);
+            }
+        }
+        assert!(t1_q0.iter().any(|&t1| (t1 - t1_q0[0]).abs() > 1e-6));
+
+        // Independent drift: rounds are uncorrelated across qubits
+        let mut scanner = scanner_with(0.0);
+        for _ in 0..100 {
+            scanner.scan_batch(&[0, 1]).unwrap();
+        }
+        let r = pearson(
+            scanner.t1_history(0).unwrap(),
+            scanner.t1_history(1).unwrap(),
+        );
+        assert!(r.abs() < 0.4, "independent qubits correlated: r = {}", r);
+    }
+
     #[test]
     fn test_from_profiler_config() {
         let profiler_config = ProfilerConfig {
@@ -39,5 +39,5 @@ pub mod drift_scan;
 // Re-export main types
 pub use drift_scan::{
     AnomalyAnalysis, AnomalyResult, AnomalyType, DriftAnalysis, DriftScanner,
-    ExponentialMovingAverage, ScanConfig, Statistics, T1Measurement, T2Measurement,
+    ExponentialMovingAverage, FitQuality, ScanConfig, Statistics, T1Measurement, T2Measurement,
 };
@@ -333,8 +333,8 @@ impl PyCircuit {
 
     #[staticmethod]
     fn from_json(json_str: &str) -> PyResult<Self> {
-        let inner: CircuitGenome =
-            serde_json::from_str(json_str).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let inner =
+            CircuitGenome::from_json(json_str).map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(Self { inner })
     }
 
@@ -1108,10 +1108,19 @@ impl PyConvert {
                 CoreGate::Rx(q, theta) => format!("rx({}) q[{}];", theta, q),
                 CoreGate::Ry(q, theta) => format!("ry({}) q[{}];", theta, q),
                 CoreGate::Rz(q, theta) => format!("rz({}) q[{}];", theta, q),
+                CoreGate::U(q, theta, phi, lambda) => {
+                    format!("u3({},{},{}) q[{}];", theta, phi, lambda, q)
+                },
                 CoreGate::CNOT(c, t) => format!("cx q[{}],q[{}];", c, t),
                 CoreGate::CZ(c, t) => format!("cz q[{}],q[{}];", c, t),
+                CoreGate::CPhase(c, t, lambda) => format!("cp({}) q[{}],q[{}];", lambda, c, t),
                 CoreGate::SWAP(a, b) => format!("swap q[{}],q[{}];", a, b),
+                CoreGate::FSim(a, b, theta, phi) => {
+                    format!("fsim({},{}) q[{}],q[{}];", theta, phi, a, b)
+                },
+                CoreGate::Toffoli(c1, c2, t) => format!("ccx q[{}],q[{}],q[{}];", c1, c2, t),
                 CoreGate::Measure(q) => format!("measure q[{}] -> c[{}];", q, q),
+                CoreGate::Reset(q) => format!("reset q[{}];", q),
             };
             qasm.push_str(&line);
             qasm.push('\n');
@@ -1183,10 +1192,17 @@ impl PyConvert {
                     CoreGate::Rx(q, theta) => ("RX", vec![*q], vec![*theta]),
                     CoreGate::Ry(q, theta) => ("RY", vec![*q], vec![*theta]),
                     CoreGate::Rz(q, theta) => ("RZ", vec![*q], vec![*theta]),
+                    CoreGate::U(q, theta, phi, lambda) => {
+                        ("U", vec![*q], vec![*theta, *phi, *lambda])
+                    },
                     CoreGate::CNOT(c, t) => ("CNOT", vec![*c, *t], vec![]),
                     CoreGate::CZ(c, t) => ("CZ", vec![*c, *t], vec![]),
+                    CoreGate::CPhase(c, t, lambda) => ("CPHASE", vec![*c, *t], vec![*lambda]),
                     CoreGate::SWAP(a, b) => ("SWAP", vec![*a, *b], vec![]),
+                    CoreGate::FSim(a, b, theta, phi) => ("FSIM", vec![*a, *b], vec![*theta, *phi]),
+                    CoreGate::Toffoli(c1, c2, t) => ("CCX", vec![*c1, *c2, *t], vec![]),
                     CoreGate::Measure(q) => ("MEASURE", vec![*q], vec![]),
+                    CoreGate::Reset(q) => ("RESET", vec![*q], vec![]),
                 };
                 gate_dict.insert("name".to_string(), name.to_object(py));
                 gate_dict.insert("qubits".to_string(), qubits.to_object(py));
@@ -1229,10 +1245,27 @@ impl PyConvert {
                     "RX" => CoreGate::Rx(qubits[0], *params.first().unwrap_or(&0.0)),
                     "RY" => CoreGate::Ry(qubits[0], *params.first().unwrap_or(&0.0)),
                     "RZ" => CoreGate::Rz(qubits[0], *params.first().unwrap_or(&0.0)),
+                    "U" => CoreGate::U(
+                        qubits[0],
+                        *params.first().unwrap_or(&0.0),
+                        *params.get(1).unwrap_or(&0.0),
+                        *params.get(2).unwrap_or(&0.0),
+                    ),
                     "CNOT" => CoreGate::CNOT(qubits[0], qubits[1]),
                     "CZ" => CoreGate::CZ(qubits[0], qubits[1]),
+                    "CPHASE" => {
+                        CoreGate::CPhase(qubits[0], qubits[1], *params.first().unwrap_or(&0.0))
+                    },
                     "SWAP" => CoreGate::SWAP(qubits[0], qubits[1]),
+                    "FSIM" => CoreGate::FSim(
+                        qubits[0],
+                        qubits[1],
+                        *params.first().unwrap_or(&0.0),
+                        *params.get(1).unwrap_or(&0.0),
+                    ),
+                    "CCX" => CoreGate::Toffoli(qubits[0], qubits[1], qubits[2]),
                     "MEASURE" => CoreGate::Measure(qubits[0]),
+                    "RESET" => CoreGate::Reset(qubits[0]),
                     _ => return Err(PyValueError::new_err(format!("Unknown gate: {}", name))),
                 };
                 inner
@@ -1260,6 +1293,25 @@ fn parse_gate_line(line: &str) -> Option<CoreGate> {
         s[start + 1..end].parse().ok()
     }
 
+    fn extract_angles(s: &str) -> Option<Vec<f64>> {
+        let start = s.find('(')?;
+        let end = s.find(')')?;
+        s[start + 1..end]
+            .split(',')
+            .map(|a| a.trim().parse().ok())
+            .collect()
+    }
+
+    /// Qubit operands of a two-qubit gate, after any parameter list.
+    fn extract_qubit_pair(s: &str) -> Option<(usize, usize)> {
+        let operands = &s[s.find(')').map_or(0, |end| end + 1)..];
+        let parts: Vec<&str> = operands.split(',').collect();
+        if parts.len() != 2 {
+            return None;
+        }
+        Some((extract_qubit(parts[0])?, extract_qubit(parts[1])?))
+    }
+
     if line.starts_with("h ") {
         Some(CoreGate::H(extract_qubit(line)?))
     } else if line.starts_with("x ") {
@@ -1278,6 +1330,30 @@ fn parse_gate_line(line: &str) -> Option<CoreGate> {
         Some(CoreGate::Ry(extract_qubit(line)?, extract_angle(line)?))
     } else if line.starts_with("rz") {
         Some(CoreGate::Rz(extract_qubit(line)?, extract_angle(line)?))
+    } else if line.starts_with("u3(") || line.starts_with("u(") {
+        match extract_angles(line)?.as_slice() {
+            &[theta, phi, lambda] => Some(CoreGate::U(extract_qubit(line)?, theta, phi, lambda)),
+            _ => None,
+        }
+    } else if line.starts_with("cp(") {
+        let (control, target) = extract_qubit_pair(line)?;
+        Some(CoreGate::CPhase(control, target, extract_angle(line)?))
+    } else if line.starts_with("fsim(") {
+        let (a, b) = extract_qubit_pair(line)?;
+        match extract_angles(line)?.as_slice() {
+            &[theta, phi] => Some(CoreGate::FSim(a, b, theta, phi)),
+            _ => None,
+        }
+    } else if line.starts_with("ccx ") {
+        let parts: Vec<&str> = line.split(',').collect();
+        if parts.len() != 3 {
+            return None;
+        }
+        Some(CoreGate::Toffoli(
+            extract_qubit(parts[0])?,
+            extract_qubit(parts[1])?,
+            extract_qubit(parts[2])?,
+        ))
     } else if line.starts_with("cx ") {
         let parts: Vec<&str> = line.split(',').collect();
         if parts.len() != 2 {
@@ -1307,6 +1383,8 @@ fn parse_gate_line(line: &str) -> Option<CoreGate> {
         ))
     } else if line.starts_with("measure") {
         Some(CoreGate::Measure(extract_qubit(line)?))
+    } else if line.starts_with("reset") {
+        Some(CoreGate::Reset(extract_qubit(line)?))
     } else {
         None
     }
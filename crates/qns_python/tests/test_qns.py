@@ -283,6 +283,37 @@ class TestConvert:
         restored = qns.convert.circuit_from_dict(d)
         assert len(restored) == len(circuit)
 
+    def test_roundtrip_every_gate(self):
+        import qns
+        # One entry per Gate variant, as written by circuit_to_dict
+        gates = [
+            {"name": "H", "qubits": [0], "params": []},
+            {"name": "X", "qubits": [1], "params": []},
+            {"name": "Y", "qubits": [2], "params": []},
+            {"name": "Z", "qubits": [0], "params": []},
+            {"name": "S", "qubits": [1], "params": []},
+            {"name": "T", "qubits": [2], "params": []},
+            {"name": "RX", "qubits": [0], "params": [0.25]},
+            {"name": "RY", "qubits": [1], "params": [-0.5]},
+            {"name": "RZ", "qubits": [2], "params": [1.125]},
+            {"name": "U", "qubits": [0], "params": [0.1, 0.2, 0.3]},
+            {"name": "CNOT", "qubits": [0, 1], "params": []},
+            {"name": "CZ", "qubits": [1, 2], "params": []},
+            {"name": "CPHASE", "qubits": [0, 2], "params": [0.75]},
+            {"name": "SWAP", "qubits": [2, 0], "params": []},
+            {"name": "FSIM", "qubits": [1, 0], "params": [0.4, 0.6]},
+            {"name": "CCX", "qubits": [0, 1, 2], "params": []},
+            {"name": "RESET", "qubits": [1], "params": []},
+            {"name": "MEASURE", "qubits": [2], "params": []},
+        ]
+        circuit = qns.convert.circuit_from_dict({"num_qubits": 3, "gates": gates})
+
+        assert qns.convert.circuit_to_dict(circuit)["gates"] == gates
+
+        qasm = qns.convert.circuit_to_qasm(circuit)
+        restored = qns.convert.circuit_from_qasm(qasm)
+        assert qns.convert.circuit_to_dict(restored)["gates"] == gates
+
 
 class TestUtilityFunctions:
     """Test utility functions."""
@@ -42,6 +42,8 @@ pub enum Statement {
         val: usize,
         body: Box<Statement>,
     },
+    /// `pragma <text>` (or `#pragma <text>`), with the text up to end of line
+    Pragma(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
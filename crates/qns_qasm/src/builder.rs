@@ -4,15 +4,90 @@ use qns_core::types::Gate;
 use qns_core::CircuitGenome;
 use std::collections::HashMap;
 
+/// Pragma marking a circuit as already mapped to physical qubits.
+///
+/// Sets [`CircuitMetadata::pre_mapped`](qns_core::types::CircuitMetadata::pre_mapped)
+/// so placement and routing are skipped.
+pub const PRE_MAPPED_PRAGMA: &str = "qns pre_mapped";
+
 pub fn build_circuit(program: &Program) -> Result<CircuitGenome> {
     let builder = CircuitBuilder::new();
     builder.build(program)
 }
 
+/// Emits `circuit` as an OpenQASM 2.0 program.
+///
+/// Qubits live in a single register `q`; measurements write to `c[0]`,
+/// `c[1]`, … in circuit order, and `c` is omitted when nothing is measured.
+/// Angles are printed in shortest round-trip form, so [`parse_qasm`] of the
+/// output reproduces the same gates. `FSim` has no qelib1.inc equivalent and
+/// is written as the non-standard `fsim(θ,φ)`, which only this parser accepts.
+///
+/// [`parse_qasm`]: crate::parse_qasm
+pub fn emit_qasm(circuit: &CircuitGenome) -> String {
+    let num_measures = circuit
+        .gates
+        .iter()
+        .filter(|g| matches!(g, Gate::Measure(_)))
+        .count();
+
+    let mut out = String::from("OPENQASM 2.0;\ninclude \"qelib1.inc\";\n");
+    if circuit.metadata.pre_mapped {
+        out.push_str(&format!("#pragma {};\n", PRE_MAPPED_PRAGMA));
+    }
+    out.push_str(&format!("qreg q[{}];\n", circuit.num_qubits));
+    if num_measures > 0 {
+        out.push_str(&format!("creg c[{}];\n", num_measures));
+    }
+
+    let mut cbit = 0;
+    for gate in &circuit.gates {
+        let line = match *gate {
+            Gate::H(q) => format!("h q[{}];", q),
+            Gate::X(q) => format!("x q[{}];", q),
+            Gate::Y(q) => format!("y q[{}];", q),
+            Gate::Z(q) => format!("z q[{}];", q),
+            Gate::S(q) => format!("s q[{}];", q),
+            Gate::T(q) => format!("t q[{}];", q),
+            Gate::Rx(q, theta) => format!("rx({}) q[{}];", theta, q),
+            Gate::Ry(q, theta) => format!("ry({}) q[{}];", theta, q),
+            Gate::Rz(q, theta) => format!("rz({}) q[{}];", theta, q),
+            Gate::U(q, theta, phi, lambda) => {
+                format!("u3({},{},{}) q[{}];", theta, phi, lambda, q)
+            },
+            Gate::CNOT(c, t) => format!("cx q[{}],q[{}];", c, t),
+            Gate::CZ(a, b) => format!("cz q[{}],q[{}];", a, b),
+            Gate::CPhase(a, b, lambda) => format!("cp({}) q[{}],q[{}];", lambda, a, b),
+            Gate::SWAP(a, b) => format!("swap q[{}],q[{}];", a, b),
+            Gate::FSim(a, b, theta, phi) => {
+                format!("fsim({},{}) q[{}],q[{}];", theta, phi, a, b)
+            },
+            Gate::Toffoli(c1, c2, t) => format!("ccx q[{}],q[{}],q[{}];", c1, c2, t),
+            Gate::Measure(q) => {
+                cbit += 1;
+                format!("measure q[{}] -> c[{}];", q, cbit - 1)
+            },
+            Gate::Reset(q) => format!("reset q[{}];", q),
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Body of a `gate name(params) args { ... }` definition.
+struct GateDefinition {
+    params: Vec<String>,
+    args: Vec<String>,
+    body: Vec<GateOperation>,
+}
+
 struct CircuitBuilder {
     qubit_map: HashMap<String, (usize, usize)>, // name -> (start_index, size)
     total_qubits: usize,
     gates: Vec<Gate>,
+    pre_mapped: bool,
+    definitions: HashMap<String, GateDefinition>,
 }
 
 impl CircuitBuilder {
@@ -21,19 +96,42 @@ impl CircuitBuilder {
             qubit_map: HashMap::new(),
             total_qubits: 0,
             gates: Vec::new(),
+            pre_mapped: false,
+            definitions: HashMap::new(),
         }
     }
 
     fn build(mut self, program: &Program) -> Result<CircuitGenome> {
-        // First pass: calculate total qubits and map registers
+        // First pass: calculate total qubits, map registers and collect gate definitions
         for stmt in &program.statements {
-            if let Statement::QRegDecl { name, size } = stmt {
-                if self.qubit_map.contains_key(name) {
-                    return Err(QasmError::BuildError(format!("Duplicate qreg '{}'", name)));
-                }
-                self.qubit_map
-                    .insert(name.clone(), (self.total_qubits, *size));
-                self.total_qubits += size;
+            match stmt {
+                Statement::QRegDecl { name, size } => {
+                    if self.qubit_map.contains_key(name) {
+                        return Err(QasmError::BuildError(format!("Duplicate qreg '{}'", name)));
+                    }
+                    self.qubit_map
+                        .insert(name.clone(), (self.total_qubits, *size));
+                    self.total_qubits += size;
+                },
+                Statement::GateDecl {
+                    name,
+                    params,
+                    args,
+                    body,
+                } => {
+                    let definition = GateDefinition {
+                        params: params.clone(),
+                        args: args.clone(),
+                        body: body.clone(),
+                    };
+                    if self.definitions.insert(name.clone(), definition).is_some() {
+                        return Err(QasmError::BuildError(format!(
+                            "Duplicate gate definition '{}'",
+                            name
+                        )));
+                    }
+                },
+                _ => {},
             }
         }
 
@@ -48,6 +146,8 @@ impl CircuitBuilder {
         // Second pass: generate gates
         for stmt in &program.statements {
             match stmt {
+                // Unexpanded `include`; the qelib1.inc/stdgates.inc gates are built in
+                Statement::GateCall { name, .. } if name == "include" => {},
                 Statement::GateCall { name, params, args } => {
                     self.process_gate(name, params, args)?;
                 },
@@ -58,6 +158,13 @@ impl CircuitBuilder {
                     let q = self.resolve_qubit(qubit)?;
                     self.gates.push(Gate::Measure(q));
                 },
+                Statement::Reset { qubit } => {
+                    let q = self.resolve_qubit(qubit)?;
+                    self.gates.push(Gate::Reset(q));
+                },
+                Statement::Pragma(text) if text == PRE_MAPPED_PRAGMA => {
+                    self.pre_mapped = true;
+                },
                 _ => {}, // Ignore other statements for MVP
             }
         }
@@ -66,6 +173,7 @@ impl CircuitBuilder {
         for gate in self.gates {
             circuit.add_gate(gate).map_err(QasmError::QnsError)?;
         }
+        circuit.metadata.pre_mapped = self.pre_mapped;
 
         Ok(circuit)
     }
@@ -75,28 +183,89 @@ impl CircuitBuilder {
             .iter()
             .map(|arg| self.resolve_qubit(arg))
             .collect::<Result<_>>()?;
+        self.apply_gate(name, params, &qubits, &mut Vec::new())
+    }
 
-        match (name, qubits.len(), params.len()) {
-            ("h", 1, 0) => self.gates.push(Gate::H(qubits[0])),
-            ("x", 1, 0) => self.gates.push(Gate::X(qubits[0])),
-            ("y", 1, 0) => self.gates.push(Gate::Y(qubits[0])),
-            ("z", 1, 0) => self.gates.push(Gate::Z(qubits[0])),
-            ("s", 1, 0) => self.gates.push(Gate::S(qubits[0])),
-            ("t", 1, 0) => self.gates.push(Gate::T(qubits[0])),
-            ("rx", 1, 1) => self.gates.push(Gate::Rx(qubits[0], params[0])),
-            ("ry", 1, 1) => self.gates.push(Gate::Ry(qubits[0], params[0])),
-            ("rz", 1, 1) => self.gates.push(Gate::Rz(qubits[0], params[0])),
-            ("cx", 2, 0) => self.gates.push(Gate::CNOT(qubits[0], qubits[1])),
-            ("cz", 2, 0) => self.gates.push(Gate::CZ(qubits[0], qubits[1])),
-            ("swap", 2, 0) => self.gates.push(Gate::SWAP(qubits[0], qubits[1])),
-            _ => {
-                return Err(QasmError::BuildError(format!(
-                    "Unknown or invalid gate: {} with {} args",
-                    name,
-                    qubits.len()
-                )))
-            },
+    /// Pushes a built-in gate, or inlines a user-defined one.
+    ///
+    /// Built-in names take precedence over definitions of the same name, so
+    /// an inlined copy of the standard library maps onto native gates.
+    /// `stack` holds the definitions being expanded, to reject recursion.
+    fn apply_gate(
+        &mut self,
+        name: &str,
+        params: &[f64],
+        qubits: &[usize],
+        stack: &mut Vec<String>,
+    ) -> Result<()> {
+        if let Some(gate) = builtin_gate(name, params, qubits) {
+            self.gates.push(gate);
+            return Ok(());
+        }
+        let Some(definition) = self.definitions.get(name) else {
+            return Err(QasmError::BuildError(format!(
+                "Unknown or invalid gate: {} with {} args",
+                name,
+                qubits.len()
+            )));
+        };
+        if stack.iter().any(|open| open == name) {
+            return Err(QasmError::BuildError(format!(
+                "Recursive gate definition: {} -> {}",
+                stack.join(" -> "),
+                name
+            )));
+        }
+        if definition.args.len() != qubits.len() || definition.params.len() != params.len() {
+            return Err(QasmError::BuildError(format!(
+                "Gate '{}' takes {} params and {} qubits, got {} and {}",
+                name,
+                definition.params.len(),
+                definition.args.len(),
+                params.len(),
+                qubits.len()
+            )));
+        }
+
+        let angles: HashMap<&str, f64> = definition
+            .params
+            .iter()
+            .map(String::as_str)
+            .zip(params.iter().copied())
+            .collect();
+        let wires: HashMap<&str, usize> = definition
+            .args
+            .iter()
+            .map(String::as_str)
+            .zip(qubits.iter().copied())
+            .collect();
+        let mut calls = Vec::with_capacity(definition.body.len());
+        for op in definition.body.iter().filter(|op| op.name != "barrier") {
+            let op_params = op
+                .params
+                .iter()
+                .map(|expr| eval_param(expr, &angles))
+                .collect::<Result<Vec<_>>>()?;
+            let op_qubits = op
+                .args
+                .iter()
+                .map(|arg| {
+                    wires.get(arg.as_str()).copied().ok_or_else(|| {
+                        QasmError::BuildError(format!(
+                            "Undefined qubit '{}' in gate '{}'",
+                            arg, name
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            calls.push((op.name.clone(), op_params, op_qubits));
+        }
+
+        stack.push(name.to_string());
+        for (op_name, op_params, op_qubits) in calls {
+            self.apply_gate(&op_name, &op_params, &op_qubits, stack)?;
         }
+        stack.pop();
         Ok(())
     }
 
@@ -134,3 +303,135 @@ impl CircuitBuilder {
         }
     }
 }
+
+/// Native gate for a QASM gate name, if `name` is built in with this arity.
+fn builtin_gate(name: &str, params: &[f64], qubits: &[usize]) -> Option<Gate> {
+    let gate = match (name, qubits, params) {
+        ("h", &[q], []) => Gate::H(q),
+        ("x", &[q], []) => Gate::X(q),
+        ("y", &[q], []) => Gate::Y(q),
+        ("z", &[q], []) => Gate::Z(q),
+        ("s", &[q], []) => Gate::S(q),
+        ("t", &[q], []) => Gate::T(q),
+        ("rx", &[q], &[theta]) => Gate::Rx(q, theta),
+        ("ry", &[q], &[theta]) => Gate::Ry(q, theta),
+        ("rz", &[q], &[theta]) => Gate::Rz(q, theta),
+        ("u3" | "u" | "U", &[q], &[theta, phi, lambda]) => Gate::U(q, theta, phi, lambda),
+        ("cx" | "CX", &[c, t], []) => Gate::CNOT(c, t),
+        ("cz", &[a, b], []) => Gate::CZ(a, b),
+        ("cp" | "cu1", &[a, b], &[lambda]) => Gate::CPhase(a, b, lambda),
+        ("swap", &[a, b], []) => Gate::SWAP(a, b),
+        ("fsim", &[a, b], &[theta, phi]) => Gate::FSim(a, b, theta, phi),
+        ("ccx", &[c1, c2, t], []) => Gate::Toffoli(c1, c2, t),
+        _ => return None,
+    };
+    Some(gate)
+}
+
+/// Evaluates a gate parameter expression.
+///
+/// Supports numbers, `pi`, the enclosing gate's parameters, `+ - * /`,
+/// unary minus and parentheses.
+pub(crate) fn eval_param(expr: &str, angles: &HashMap<&str, f64>) -> Result<f64> {
+    let error =
+        |reason: &str| QasmError::BuildError(format!("Invalid parameter '{}': {}", expr, reason));
+    let mut tokens: Vec<ExprToken> = Vec::new();
+    let mut chars = expr.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = start;
+            let mut previous = c;
+            while let Some(&(i, d)) = chars.peek() {
+                let exponent_sign = (d == '+' || d == '-') && matches!(previous, 'e' | 'E');
+                if !(d.is_ascii_alphanumeric() || d == '.' || exponent_sign) {
+                    break;
+                }
+                previous = d;
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            let value = expr[start..end]
+                .parse::<f64>()
+                .map_err(|_| error("bad number"))?;
+            tokens.push(ExprToken::Number(value));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                if !(d.is_alphanumeric() || d == '_') {
+                    break;
+                }
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            let name = &expr[start..end];
+            let value = match name {
+                "pi" => std::f64::consts::PI,
+                _ => *angles
+                    .get(name)
+                    .ok_or_else(|| error(&format!("unknown identifier '{}'", name)))?,
+            };
+            tokens.push(ExprToken::Number(value));
+        } else if "+-*/()".contains(c) {
+            tokens.push(ExprToken::Symbol(c));
+            chars.next();
+        } else {
+            return Err(error(&format!("unexpected '{}'", c)));
+        }
+    }
+
+    let mut pos = 0;
+    let value = parse_sum(&tokens, &mut pos).ok_or_else(|| error("malformed expression"))?;
+    if pos != tokens.len() {
+        return Err(error("malformed expression"));
+    }
+    Ok(value)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ExprToken {
+    Number(f64),
+    Symbol(char),
+}
+
+fn parse_sum(tokens: &[ExprToken], pos: &mut usize) -> Option<f64> {
+    let mut value = parse_product(tokens, pos)?;
+    while let Some(&ExprToken::Symbol(op @ ('+' | '-'))) = tokens.get(*pos) {
+        *pos += 1;
+        let rhs = parse_product(tokens, pos)?;
+        value = if op == '+' { value + rhs } else { value - rhs };
+    }
+    Some(value)
+}
+
+fn parse_product(tokens: &[ExprToken], pos: &mut usize) -> Option<f64> {
+    let mut value = parse_factor(tokens, pos)?;
+    while let Some(&ExprToken::Symbol(op @ ('*' | '/'))) = tokens.get(*pos) {
+        *pos += 1;
+        let rhs = parse_factor(tokens, pos)?;
+        value = if op == '*' { value * rhs } else { value / rhs };
+    }
+    Some(value)
+}
+
+fn parse_factor(tokens: &[ExprToken], pos: &mut usize) -> Option<f64> {
+    let token = *tokens.get(*pos)?;
+    *pos += 1;
+    match token {
+        ExprToken::Number(value) => Some(value),
+        ExprToken::Symbol('-') => parse_factor(tokens, pos).map(|v| -v),
+        ExprToken::Symbol('+') => parse_factor(tokens, pos),
+        ExprToken::Symbol('(') => {
+            let value = parse_sum(tokens, pos)?;
+            match tokens.get(*pos) {
+                Some(ExprToken::Symbol(')')) => {
+                    *pos += 1;
+                    Some(value)
+                },
+                _ => None,
+            }
+        },
+        ExprToken::Symbol(_) => None,
+    }
+}
@@ -9,7 +9,7 @@ pub mod error;
 pub mod parser;
 pub mod preprocessor;
 
-pub use builder::build_circuit;
+pub use builder::{build_circuit, emit_qasm, PRE_MAPPED_PRAGMA};
 pub use error::{QasmError, Result};
 pub use parser::parse_qasm_str;
 pub use preprocessor::resolve_includes;
@@ -22,6 +22,23 @@ pub fn parse_qasm(source: &str) -> Result<CircuitGenome> {
     build_circuit(&ast)
 }
 
+/// Parses several OpenQASM fragments and concatenates them in order.
+///
+/// Each fragment must be a complete program. Qubit `i` refers to the same
+/// wire in every fragment; the merged circuit is as wide as the widest one.
+pub fn parse_and_merge(sources: &[&str]) -> Result<CircuitGenome> {
+    let mut merged = CircuitGenome::new(0);
+    for (i, source) in sources.iter().enumerate() {
+        let fragment = parse_qasm(source).map_err(|e| match e {
+            QasmError::ParseError(msg) => QasmError::ParseError(format!("fragment {}: {}", i, msg)),
+            QasmError::BuildError(msg) => QasmError::BuildError(format!("fragment {}: {}", i, msg)),
+            other => other,
+        })?;
+        merged.compose(&fragment);
+    }
+    Ok(merged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +78,282 @@ mod tests {
             _ => panic!("Expected Measure gate"),
         }
     }
+
+    #[test]
+    fn test_parse_and_merge() {
+        let init = r#"
+            OPENQASM 2.0;
+            qreg q[3];
+            h q[0];
+        "#;
+        let entangle = r#"
+            OPENQASM 2.0;
+            qreg q[3];
+            cx q[0], q[1];
+            cx q[1], q[2];
+        "#;
+
+        let circuit = parse_and_merge(&[init, entangle]).unwrap();
+
+        assert_eq!(circuit.num_qubits, 3);
+        assert_eq!(
+            circuit.gates,
+            vec![Gate::H(0), Gate::CNOT(0, 1), Gate::CNOT(1, 2)]
+        );
+
+        let err = parse_and_merge(&[init, "qreg q[2]; foo q[0];"]).unwrap_err();
+        assert!(err.to_string().contains("fragment 1"));
+    }
+
+    #[test]
+    fn test_parse_ccx() {
+        let source = r#"
+            OPENQASM 2.0;
+            qreg a[2];
+            qreg b[1];
+            ccx a[0], a[1], b[0];
+        "#;
+        let circuit = parse_qasm(source).unwrap();
+        assert_eq!(circuit.gates, vec![Gate::Toffoli(0, 1, 2)]);
+
+        let wrong_arity = "OPENQASM 2.0;\nqreg q[2];\nccx q[0], q[1];\n";
+        assert!(parse_qasm(wrong_arity).is_err());
+    }
+
+    #[test]
+    fn test_pre_mapped_pragma() {
+        let source = r#"
+            OPENQASM 3.0;
+            pragma qns pre_mapped
+            qreg q[2];
+            cx q[1], q[0];
+        "#;
+        let circuit = parse_qasm(source).unwrap();
+        assert!(circuit.metadata.pre_mapped);
+        assert_eq!(circuit.gates, vec![Gate::CNOT(1, 0)]);
+
+        let hashed = "OPENQASM 2.0;\n#pragma qns pre_mapped;\nqreg q[1];\nh q[0];\n";
+        assert!(parse_qasm(hashed).unwrap().metadata.pre_mapped);
+
+        // Other pragmas are ignored
+        let other = "OPENQASM 2.0;\npragma something else\nqreg q[1];\nh q[0];\n";
+        assert!(!parse_qasm(other).unwrap().metadata.pre_mapped);
+    }
+
+    #[test]
+    fn test_qasm3_matches_qasm2() {
+        let v2 = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            qreg q[2];
+            qreg anc[1];
+            creg c[2];
+            creg flag[1];
+            h q[0];
+            cx q[0], q[1];
+            reset anc[0];
+            measure q[0] -> c[0];
+            measure q[1] -> c[1];
+            measure anc[0] -> flag[0];
+        "#;
+        let v3 = r#"
+            OPENQASM 3.0;
+            include "stdgates.inc";
+            qubit[2] q;
+            qubit anc;
+            bit[2] c;
+            bit flag;
+            h q[0];
+            CX q[0], q[1];
+            reset anc;
+            c[0] = measure q[0];
+            c[1] = measure q[1];
+            flag = measure anc;
+        "#;
+
+        let program = parse_qasm_str(v3).unwrap();
+        assert_eq!(program.version.as_deref(), Some("3.0"));
+        assert!(program.statements.contains(&ast::Statement::CRegDecl {
+            name: "flag".to_string(),
+            size: 1
+        }));
+
+        let (circuit2, circuit3) = (parse_qasm(v2).unwrap(), parse_qasm(v3).unwrap());
+        assert_eq!(circuit3.num_qubits, 3);
+        assert_eq!(
+            circuit3.gates,
+            vec![
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::Reset(2),
+                Gate::Measure(0),
+                Gate::Measure(1),
+                Gate::Measure(2),
+            ]
+        );
+        assert_eq!(format!("{:?}", circuit2), format!("{:?}", circuit3));
+
+        // Integer version, and the include survives preprocessing
+        let short = "OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[1] q;\nx q[0];\n";
+        let expanded = resolve_includes(short, std::path::Path::new("/nonexistent")).unwrap();
+        assert_eq!(parse_qasm(&expanded).unwrap().gates, vec![Gate::X(0)]);
+
+        // Declaration keywords need a size or a space before the name
+        let glued = parse_qasm_str("qubitq;").unwrap();
+        assert!(matches!(
+            &glued.statements[..],
+            [ast::Statement::GateCall { name, .. }] if name == "qubitq"
+        ));
+    }
+
+    #[test]
+    fn test_custom_gate_inlining() {
+        let custom = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            gate bell a, b { h a; cx a, b; }
+            gate myrz(theta) q { rz(theta) q; }
+            gate layer(alpha, beta) x, y {
+                bell y, x;
+                barrier x, y;
+                myrz(-(alpha + pi) / 2) x;
+                u3(alpha * 2, beta, 1e-3) y;
+            }
+            qreg q[3];
+            bell q[0], q[1];
+            myrz(0.25) q[2];
+            layer(0.5, -1.5) q[2], q[0];
+        "#;
+        let direct = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            qreg q[3];
+            h q[0];
+            cx q[0], q[1];
+            rz(0.25) q[2];
+            h q[0];
+            cx q[0], q[2];
+            rz(-1.8207963267948966) q[2];
+            u3(1, -1.5, 0.001) q[0];
+        "#;
+        let inlined = parse_qasm(custom).unwrap();
+        let expected = parse_qasm(direct).unwrap();
+        assert_eq!(inlined.num_qubits, expected.num_qubits);
+        assert_eq!(inlined.gates.len(), expected.gates.len());
+        for (got, want) in inlined.gates.iter().zip(&expected.gates) {
+            assert_eq!(got.name(), want.name());
+            assert_eq!(got.qubits(), want.qubits());
+            for (a, b) in got.params().iter().zip(want.params()) {
+                assert!((a - b).abs() < 1e-12, "{} vs {}", got, want);
+            }
+        }
+
+        let recursive = r#"
+            OPENQASM 2.0;
+            gate a q { b q; }
+            gate b q { a q; }
+            qreg q[1];
+            a q[0];
+        "#;
+        let err = parse_qasm(recursive).unwrap_err().to_string();
+        assert!(
+            err.contains("Recursive gate definition: a -> b -> a"),
+            "{}",
+            err
+        );
+
+        let wrong_arity = "OPENQASM 2.0;\ngate g(t) q { rz(t) q; }\nqreg q[2];\ng q[0];\n";
+        assert!(parse_qasm(wrong_arity)
+            .unwrap_err()
+            .to_string()
+            .contains("takes 1 params"));
+        let unknown = "OPENQASM 2.0;\ngate g q { rz(phi) q; }\nqreg q[1];\ng q[0];\n";
+        assert!(parse_qasm(unknown).unwrap_err().to_string().contains("phi"));
+    }
+
+    #[test]
+    fn test_top_level_param_expressions() {
+        let source = r#"
+            OPENQASM 2.0;
+            gate myrz(theta) q { rz(theta) q; }
+            qreg q[2];
+            myrz(pi/4) q[0];
+            rz( -(pi + 1) / 2 ) q[1];
+            u3(2*pi, 1e-3, -pi) q[0];
+        "#;
+        let program = parse_qasm_str(source).unwrap();
+        let params: Vec<&[f64]> = program
+            .statements
+            .iter()
+            .filter_map(|s| match s {
+                ast::Statement::GateCall { params, .. } => Some(&params[..]),
+                _ => None,
+            })
+            .collect();
+        let pi = std::f64::consts::PI;
+        assert_eq!(
+            params,
+            [
+                &[pi / 4.0][..],
+                &[-(pi + 1.0) / 2.0],
+                &[2.0 * pi, 1e-3, -pi]
+            ]
+        );
+
+        let circuit = parse_qasm(source).unwrap();
+        assert_eq!(circuit.gates[0], Gate::Rz(0, pi / 4.0));
+        assert_eq!(circuit.gates[1], Gate::Rz(1, -(pi + 1.0) / 2.0));
+
+        // Only constants are known outside a gate body
+        assert!(parse_qasm_str(
+            "qreg q[1];
+rz(theta) q[0];
+"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_emit_qasm_round_trip() {
+        let source = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            qreg a[2];
+            qreg b[2];
+            creg c[2];
+            h a[0];
+            rz(0.30000000000000004) a[1];
+            rx(-1e-7) b[0];
+            u3(3.141592653589793, 0.5, -2.25) b[1];
+            cx a[0], b[1];
+            cp(1.2345678901234567) a[1], b[0];
+            ccx a[0], a[1], b[0];
+            swap a[0], b[1];
+            measure b[0] -> c[1];
+            measure a[1] -> c[0];
+            reset a[1];
+        "#;
+        let mut circuit = parse_qasm(source).unwrap();
+        circuit
+            .add_gates([Gate::FSim(0, 3, 0.1 + 0.2, 2.0 / 3.0), Gate::T(2)])
+            .unwrap();
+
+        let emitted = emit_qasm(&circuit);
+        assert!(emitted.starts_with("OPENQASM 2.0;\n"));
+        assert!(emitted.contains("qreg q[4];"));
+        assert!(emitted.contains("creg c[2];"));
+        assert!(emitted.contains("measure q[2] -> c[0];"));
+
+        let reparsed = parse_qasm(&emitted).unwrap();
+        assert_eq!(reparsed.num_qubits, circuit.num_qubits);
+        assert_eq!(reparsed.gates, circuit.gates);
+
+        // No measurements, no classical register
+        let mut bare = CircuitGenome::new(1);
+        bare.add_gate(Gate::H(0)).unwrap();
+        bare.metadata.pre_mapped = true;
+        let emitted = emit_qasm(&bare);
+        assert!(!emitted.contains("creg"));
+        assert!(parse_qasm(&emitted).unwrap().metadata.pre_mapped);
+    }
 }
@@ -1,14 +1,16 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_while},
+    bytes::complete::{tag, take_while, take_while1},
     character::complete::{alpha1, alphanumeric1, char, digit1, multispace1},
     combinator::{map, map_res, opt, recognize, value},
-    multi::{many0, separated_list0},
+    multi::{many0, separated_list0, separated_list1},
     sequence::{delimited, pair, preceded, tuple},
     IResult,
 };
 
 use crate::ast::*;
+use crate::builder::eval_param;
+use std::collections::HashMap;
 
 // --- Whitespace & Comments ---
 
@@ -36,21 +38,6 @@ fn usize_literal(input: &str) -> IResult<&str, usize> {
     map_res(digit1, |s: &str| s.parse::<usize>())(input)
 }
 
-fn float_literal(input: &str) -> IResult<&str, f64> {
-    map_res(
-        recognize(tuple((
-            opt(char('-')),
-            digit1,
-            opt(pair(char('.'), digit1)),
-            opt(pair(
-                alt((char('e'), char('E'))),
-                pair(opt(alt((char('+'), char('-')))), digit1),
-            )),
-        ))),
-        |s: &str| s.parse::<f64>(),
-    )(input)
-}
-
 // --- Arguments ---
 
 fn argument(input: &str) -> IResult<&str, Argument> {
@@ -69,7 +56,7 @@ fn version_decl(input: &str) -> IResult<&str, String> {
     delimited(
         tuple((tag("OPENQASM"), sp)),
         map(
-            recognize(pair(digit1, pair(char('.'), digit1))),
+            recognize(pair(digit1, opt(pair(char('.'), digit1)))),
             |s: &str| s.to_string(),
         ),
         tuple((sp, char(';'))),
@@ -106,6 +93,39 @@ fn creg_decl(input: &str) -> IResult<&str, Statement> {
     )(input)
 }
 
+/// OpenQASM 3.0 declaration `<keyword>[size] name;` or `<keyword> name;`
+/// (size 1), returned as `(name, size)`.
+fn typed_register<'a>(
+    keyword: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (String, usize)> {
+    map(
+        tuple((
+            tag(keyword),
+            alt((
+                delimited(sp, delimited(char('['), usize_literal, char(']')), sp),
+                value(1, multispace1),
+            )),
+            identifier,
+            sp,
+            char(';'),
+        )),
+        |(_, size, name, _, _)| (name, size),
+    )
+}
+
+fn qubit_decl(input: &str) -> IResult<&str, Statement> {
+    map(typed_register("qubit"), |(name, size)| {
+        Statement::QRegDecl { name, size }
+    })(input)
+}
+
+fn bit_decl(input: &str) -> IResult<&str, Statement> {
+    map(typed_register("bit"), |(name, size)| Statement::CRegDecl {
+        name,
+        size,
+    })(input)
+}
+
 fn gate_call(input: &str) -> IResult<&str, Statement> {
     map(
         tuple((
@@ -113,7 +133,7 @@ fn gate_call(input: &str) -> IResult<&str, Statement> {
             sp,
             opt(delimited(
                 char('('),
-                separated_list0(tuple((sp, char(','), sp)), float_literal),
+                separated_list0(tuple((sp, char(','), sp)), constant_param),
                 char(')'),
             )),
             sp,
@@ -129,6 +149,100 @@ fn gate_call(input: &str) -> IResult<&str, Statement> {
     )(input)
 }
 
+/// Top-level gate-call parameter: an expression over numbers and `pi`,
+/// evaluated at parse time.
+fn constant_param(input: &str) -> IResult<&str, f64> {
+    map_res(param_expr, |expr| eval_param(&expr, &HashMap::new()))(input)
+}
+
+/// Raw text of one parameter expression inside a gate body, up to the next
+/// top-level `,` or the closing `)`. Evaluated when the gate is inlined.
+fn param_expr(input: &str) -> IResult<&str, String> {
+    let mut depth = 0usize;
+    let mut end = input.len();
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                end = i;
+                break;
+            },
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                end = i;
+                break;
+            },
+            ';' | '{' | '}' => {
+                end = i;
+                break;
+            },
+            _ => {},
+        }
+    }
+    let text = input[..end].trim();
+    if text.is_empty() {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::TakeWhile1,
+        )));
+    }
+    Ok((&input[end..], text.to_string()))
+}
+
+fn gate_operation(input: &str) -> IResult<&str, GateOperation> {
+    map(
+        tuple((
+            sp,
+            identifier,
+            sp,
+            opt(delimited(
+                char('('),
+                separated_list0(char(','), param_expr),
+                char(')'),
+            )),
+            sp,
+            separated_list1(tuple((sp, char(','), sp)), identifier),
+            sp,
+            char(';'),
+        )),
+        |(_, name, _, params, _, args, _, _)| GateOperation {
+            name,
+            params: params.unwrap_or_default(),
+            args,
+        },
+    )(input)
+}
+
+/// `gate name(p, ...) a, b, ... { body }`
+fn gate_decl(input: &str) -> IResult<&str, Statement> {
+    map(
+        tuple((
+            tag("gate"),
+            multispace1,
+            identifier,
+            sp,
+            opt(delimited(
+                char('('),
+                separated_list0(tuple((sp, char(','), sp)), preceded(sp, identifier)),
+                pair(sp, char(')')),
+            )),
+            sp,
+            separated_list1(tuple((sp, char(','), sp)), identifier),
+            sp,
+            char('{'),
+            many0(gate_operation),
+            sp,
+            char('}'),
+        )),
+        |(_, _, name, _, params, _, args, _, _, body, _, _)| Statement::GateDecl {
+            name,
+            params: params.unwrap_or_default(),
+            args,
+            body,
+        },
+    )(input)
+}
+
 fn measure_stmt(input: &str) -> IResult<&str, Statement> {
     map(
         tuple((
@@ -146,6 +260,24 @@ fn measure_stmt(input: &str) -> IResult<&str, Statement> {
     )(input)
 }
 
+/// OpenQASM 3.0 measurement `c[0] = measure q[0];`
+fn measure_assign_stmt(input: &str) -> IResult<&str, Statement> {
+    map(
+        tuple((
+            argument,
+            sp,
+            char('='),
+            sp,
+            tag("measure"),
+            sp,
+            argument,
+            sp,
+            char(';'),
+        )),
+        |(target, _, _, _, _, _, qubit, _, _)| Statement::Measure { qubit, target },
+    )(input)
+}
+
 fn reset_stmt(input: &str) -> IResult<&str, Statement> {
     map(
         tuple((tag("reset"), sp, argument, sp, char(';'))),
@@ -191,6 +323,20 @@ fn if_stmt(input: &str) -> IResult<&str, Statement> {
     )(input)
 }
 
+fn pragma_stmt(input: &str) -> IResult<&str, Statement> {
+    map(
+        tuple((
+            opt(char('#')),
+            tag("pragma"),
+            take_while1(|c| c == ' ' || c == '\t'),
+            take_while(|c| c != '\n'),
+        )),
+        |(_, _, _, text): (_, _, _, &str)| {
+            Statement::Pragma(text.trim().trim_end_matches(';').trim_end().to_string())
+        },
+    )(input)
+}
+
 // Include statement is handled by preprocessor, but we might want to parse it if it remains?
 // For now, assume preprocessor handles it. But let's add a parser just in case.
 fn include_stmt(input: &str) -> IResult<&str, Statement> {
@@ -221,11 +367,16 @@ fn statement(input: &str) -> IResult<&str, Statement> {
         alt((
             qreg_decl,
             creg_decl,
+            qubit_decl,
+            bit_decl,
+            gate_decl,
             measure_stmt,
+            measure_assign_stmt,
             reset_stmt,
             barrier_stmt,
             if_stmt,
             include_stmt,
+            pragma_stmt,
             gate_call, // Should be last as it matches generic identifiers
         )),
     )(input)
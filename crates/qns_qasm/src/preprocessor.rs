@@ -2,8 +2,16 @@ use crate::QasmError;
 use std::fs;
 use std::path::Path;
 
+/// Standard gate libraries whose gates the builder knows natively.
+///
+/// Includes of these are kept as-is rather than read from disk.
+pub const BUILTIN_INCLUDES: [&str; 2] = ["qelib1.inc", "stdgates.inc"];
+
 /// Recursively resolves `include` statements in QASM code.
 ///
+/// [`BUILTIN_INCLUDES`] (`qelib1.inc` for OpenQASM 2.0, `stdgates.inc` for
+/// 3.0) are left in place for the builder to skip.
+///
 /// # Arguments
 /// * `input` - The QASM code string.
 /// * `base_path` - The base path to resolve relative includes from.
@@ -19,7 +27,10 @@ pub fn resolve_includes(input: &str, base_path: &Path) -> Result<String, QasmErr
             // Parse include "filename";
             // Simple parsing: split by quotes
             let parts: Vec<&str> = trimmed.split('"').collect();
-            if parts.len() >= 2 {
+            if parts.len() >= 2 && BUILTIN_INCLUDES.contains(&parts[1]) {
+                output.push_str(line);
+                output.push('\n');
+            } else if parts.len() >= 2 {
                 let filename = parts[1];
                 let include_path = base_path.join(filename);
 
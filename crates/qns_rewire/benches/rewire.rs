@@ -48,6 +48,7 @@ fn bench_generate_reorderings(c: &mut Criterion) {
             max_variants: 50,
             max_depth: 3,
             deduplicate: true,
+            ..Default::default()
         });
 
         group.bench_with_input(
@@ -7,7 +7,7 @@
 use qns_core::types::{Fidelity, Gate};
 use qns_core::{CircuitGenome, HardwareProfile, NoiseVector};
 use qns_rewire::scoring::{
-    estimate_fidelity_with_hardware, gate_error_sum_with_hardware, ScoreConfig,
+    estimate_fidelity_with_hardware, gate_error_sum_with_hardware_config, ScoreConfig,
 };
 use qns_rewire::PlacementOptimizer;
 
@@ -115,12 +115,12 @@ fn run_placement_test(
     // Calculate original fidelity (identity mapping)
     let identity_mapping: Vec<usize> = (0..circuit.num_qubits).collect();
     let identity_circuit = optimizer.apply_mapping(circuit, &identity_mapping);
-    let original_error = gate_error_sum_with_hardware(&identity_circuit, noise, hw);
+    let original_error = gate_error_sum_with_hardware_config(&identity_circuit, noise, hw, config);
     let original_fidelity = estimate_fidelity_with_hardware(&identity_circuit, noise, hw, config);
 
     // Optimize placement
     let result = optimizer.optimize(circuit, hw);
-    let optimized_error = gate_error_sum_with_hardware(&result.circuit, noise, hw);
+    let optimized_error = gate_error_sum_with_hardware_config(&result.circuit, noise, hw, config);
     let optimized_fidelity = estimate_fidelity_with_hardware(&result.circuit, noise, hw, config);
 
     // Calculate improvement (handle near-zero original fidelity)
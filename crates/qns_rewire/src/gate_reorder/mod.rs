@@ -25,9 +25,21 @@
 //! For example, if T1 is lower at time t1 than at time t2, we might
 //! want to schedule noise-sensitive gates at t2.
 
+use crate::graph::DependencyGraph;
+use qns_core::physics;
 use qns_core::prelude::*;
 use std::collections::{HashSet, VecDeque};
 
+/// How rotation angles contribute to the deduplication hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashMode {
+    /// Angles are hashed bit-for-bit; only identical sequences collide
+    ExactSequence,
+    /// Angles are rounded to multiples of `angle_discretization`
+    #[default]
+    AngleDiscretized,
+}
+
 /// Configuration for gate reordering.
 #[derive(Debug, Clone)]
 pub struct ReorderConfig {
@@ -37,6 +49,10 @@ pub struct ReorderConfig {
     pub max_depth: usize,
     /// Whether to remove duplicate circuits
     pub deduplicate: bool,
+    /// How gate parameters are hashed for deduplication
+    pub hash_mode: HashMode,
+    /// Angle granularity in radians for [`HashMode::AngleDiscretized`]
+    pub angle_discretization: f64,
 }
 
 impl Default for ReorderConfig {
@@ -45,6 +61,8 @@ impl Default for ReorderConfig {
             max_variants: 100,
             max_depth: 5,
             deduplicate: true,
+            hash_mode: HashMode::AngleDiscretized,
+            angle_discretization: 1e-3,
         }
     }
 }
@@ -186,11 +204,17 @@ impl GateReorder {
     /// as non-adjacent swaps would require multiple operations.
     ///
     /// Returns a list of indices where gates[idx] and gates[idx+1] commute.
+    /// Pairs touching a [frozen region](CircuitMetadata::frozen_regions) are
+    /// excluded, so frozen gates never move.
     pub fn find_adjacent_commuting_pairs(&self, circuit: &CircuitGenome) -> Vec<usize> {
         let gates = &circuit.gates;
+        let metadata = &circuit.metadata;
         let mut swappable = Vec::new();
 
         for i in 0..gates.len().saturating_sub(1) {
+            if metadata.is_frozen(i) || metadata.is_frozen(i + 1) {
+                continue;
+            }
             if gates[i].commutes_with(&gates[i + 1]) {
                 swappable.push(i);
             }
@@ -312,6 +336,15 @@ impl GateReorder {
 
         if idx + 1 < new_circuit.gates.len() {
             new_circuit.gates.swap(idx, idx + 1);
+
+            // Routing tags follow their gates
+            for tagged in new_circuit.metadata.routing_gates.iter_mut() {
+                if *tagged == idx {
+                    *tagged = idx + 1;
+                } else if *tagged == idx + 1 {
+                    *tagged = idx;
+                }
+            }
         }
 
         new_circuit
@@ -532,10 +565,302 @@ impl GateReorder {
         }
     }
 
+    /// Pushes Z-axis phase gates to the end of the circuit (virtual-Z).
+    ///
+    /// `Rz`, `Z`, `S` and `T` are accumulated per qubit and commuted rightward
+    /// past every gate they commute with exactly: other diagonal gates, `CZ`,
+    /// `CPhase`, and the control of a `CNOT`. Passing through a `SWAP` moves the pending
+    /// phase to the other qubit. Gates that do not commute (H, X, Y, Rx, Ry, a
+    /// `CNOT` target, or a measurement) force the pending phase to be emitted
+    /// as a single merged `Rz` just before them.
+    ///
+    /// Remaining phases are emitted as one `Rz` per qubit at the tail, where
+    /// hardware can absorb them into the measurement basis. The result equals
+    /// the original unitary up to a global phase.
+    pub fn push_rz_to_end(&self, circuit: &CircuitGenome) -> CircuitGenome {
+        if !circuit.metadata.frozen_regions.is_empty() {
+            return map_unfrozen(circuit, |segment| self.push_rz_to_end(segment));
+        }
+        fn flush(pending: &mut [f64], q: usize, out: &mut Vec<Gate>) {
+            let angle = std::mem::take(&mut pending[q]);
+            let wrapped = angle.rem_euclid(2.0 * std::f64::consts::PI);
+            if wrapped > 1e-12 && (2.0 * std::f64::consts::PI - wrapped) > 1e-12 {
+                out.push(Gate::Rz(q, angle));
+            }
+        }
+
+        let mut pending = vec![0.0; circuit.num_qubits];
+        let mut out = Vec::with_capacity(circuit.gates.len());
+
+        for gate in &circuit.gates {
+            match gate {
+                Gate::Z(q) | Gate::S(q) | Gate::T(q) | Gate::Rz(q, _) => {
+                    // Z, S and T equal Rz by their angle up to a global phase
+                    pending[*q] += gate.rotation_angle().unwrap_or(0.0);
+                },
+                Gate::CZ(_, _) | Gate::CPhase(_, _, _) => out.push(gate.clone()),
+                Gate::CNOT(_, t) => {
+                    flush(&mut pending, *t, &mut out);
+                    out.push(gate.clone());
+                },
+                Gate::SWAP(a, b) => {
+                    pending.swap(*a, *b);
+                    out.push(gate.clone());
+                },
+                Gate::Toffoli(_, _, t) => {
+                    // Diagonal on both controls
+                    flush(&mut pending, *t, &mut out);
+                    out.push(gate.clone());
+                },
+                Gate::FSim(a, b, _, _) => {
+                    // Only equal Rz on both qubits would commute through
+                    flush(&mut pending, *a, &mut out);
+                    flush(&mut pending, *b, &mut out);
+                    out.push(gate.clone());
+                },
+                Gate::H(q)
+                | Gate::X(q)
+                | Gate::Y(q)
+                | Gate::Rx(q, _)
+                | Gate::Ry(q, _)
+                | Gate::U(q, _, _, _)
+                | Gate::Measure(q)
+                | Gate::Reset(q) => {
+                    flush(&mut pending, *q, &mut out);
+                    out.push(gate.clone());
+                },
+            }
+        }
+
+        for q in 0..circuit.num_qubits {
+            flush(&mut pending, q, &mut out);
+        }
+
+        // Gate positions change, so routing tags no longer apply
+        let mut metadata = circuit.metadata.clone();
+        metadata.routing_gates.clear();
+
+        CircuitGenome {
+            num_qubits: circuit.num_qubits,
+            gates: out,
+            metadata,
+            qns_format_version: circuit.qns_format_version,
+        }
+    }
+
+    /// Reorders gates for minimum depth (ASAP left-packing).
+    ///
+    /// Unlike the fidelity-driven reorderings, this only targets depth. Each
+    /// gate, in circuit order, is placed in the earliest layer after all of
+    /// its predecessors in the commutation-aware [`DependencyGraph`] where
+    /// its qubits are still free, and the output lists gates layer by layer.
+    /// Gates can thus move ahead of gates they commute with (e.g. an `Rz`
+    /// past a `CNOT` control) to fill idle slots. The depth never exceeds
+    /// the original and the unitary is unchanged.
+    pub fn pack_left(&self, circuit: &CircuitGenome) -> CircuitGenome {
+        if !circuit.metadata.frozen_regions.is_empty() {
+            return map_unfrozen(circuit, |segment| self.pack_left(segment));
+        }
+        let dag = DependencyGraph::with_commutation(circuit);
+        let mut earliest = vec![0usize; dag.num_gates];
+        let mut layers: Vec<usize> = Vec::with_capacity(dag.num_gates);
+        let mut busy: Vec<HashSet<usize>> = Vec::new();
+
+        for (idx, gate) in circuit.gates.iter().enumerate() {
+            let qubits = gate.qubits();
+            let mut layer = earliest[idx];
+            while busy
+                .get(layer)
+                .is_some_and(|used| qubits.iter().any(|q| used.contains(q)))
+            {
+                layer += 1;
+            }
+            if layer == busy.len() {
+                busy.push(HashSet::new());
+            }
+            busy[layer].extend(qubits);
+            layers.push(layer);
+
+            // Successors always come later in circuit order
+            for &next in &dag.successors[idx] {
+                earliest[next] = earliest[next].max(layer + 1);
+            }
+        }
+
+        let mut order: Vec<usize> = (0..circuit.gates.len()).collect();
+        order.sort_by_key(|&i| layers[i]);
+
+        // Gate positions change, so routing tags no longer apply
+        let mut metadata = circuit.metadata.clone();
+        metadata.routing_gates.clear();
+
+        CircuitGenome {
+            num_qubits: circuit.num_qubits,
+            gates: order
+                .into_iter()
+                .map(|i| circuit.gates[i].clone())
+                .collect(),
+            metadata,
+            qns_format_version: circuit.qns_format_version,
+        }
+    }
+
+    /// Replaces compute/uncompute Toffoli pairs with Margolus gates.
+    ///
+    /// Finds blocks matching [`Gate::ccx_decomposition`] (6 CNOTs) that are
+    /// later repeated on the same qubits to uncompute, and rewrites the pair
+    /// as a [`Gate::margolus_decomposition`] and its inverse (3 CNOTs each).
+    /// The Margolus relative phase is diagonal on the three qubits, so it
+    /// cancels exactly provided every gate in between acts on them only
+    /// diagonally: Z-axis phases, `CZ`, `CPhase`, or as the control of a `CNOT`.
+    /// Unpaired blocks are left untouched.
+    pub fn substitute_margolus(&self, circuit: &CircuitGenome) -> CircuitGenome {
+        if !circuit.metadata.frozen_regions.is_empty() {
+            return map_unfrozen(circuit, |segment| self.substitute_margolus(segment));
+        }
+        const CCX_LEN: usize = 15;
+        let gates = &circuit.gates;
+
+        let ccx_at = |i: usize| -> Option<(usize, usize, usize)> {
+            match (gates.get(i + 3), gates.get(i + 1)) {
+                (Some(Gate::CNOT(c1, t)), Some(Gate::CNOT(c2, _))) => {
+                    let block = Gate::ccx_decomposition(*c1, *c2, *t);
+                    (gates.get(i..i + CCX_LEN)? == block.as_slice()).then_some((*c1, *c2, *t))
+                },
+                _ => None,
+            }
+        };
+        let keeps_phase = |gate: &Gate, qubits: [usize; 3]| {
+            if gate.qubits().iter().all(|q| !qubits.contains(q)) {
+                return true;
+            }
+            match gate {
+                Gate::CZ(_, _) | Gate::CPhase(_, _, _) => true,
+                Gate::CNOT(_, t) => !qubits.contains(t),
+                _ => gate.gate_type() == GateType::Diagonal,
+            }
+        };
+
+        let mut uncompute_at = vec![false; gates.len()];
+        let mut out = Vec::with_capacity(gates.len());
+        let mut i = 0;
+
+        while i < gates.len() {
+            if uncompute_at[i] {
+                let (c1, c2, t) = ccx_at(i).expect("marked uncompute block");
+                out.extend(
+                    Gate::margolus_decomposition(c1, c2, t)
+                        .iter()
+                        .rev()
+                        .filter_map(Gate::inverse),
+                );
+                i += CCX_LEN;
+                continue;
+            }
+
+            if let Some((c1, c2, t)) = ccx_at(i) {
+                let qubits = [c1, c2, t];
+                let mut k = i + CCX_LEN;
+                while k < gates.len() {
+                    if ccx_at(k) == Some((c1, c2, t)) {
+                        uncompute_at[k] = true;
+                        break;
+                    }
+                    if !keeps_phase(&gates[k], qubits) {
+                        break;
+                    }
+                    k += 1;
+                }
+                if k < gates.len() && uncompute_at[k] {
+                    out.extend(Gate::margolus_decomposition(c1, c2, t));
+                    i += CCX_LEN;
+                    continue;
+                }
+            }
+
+            out.push(gates[i].clone());
+            i += 1;
+        }
+
+        // Gate positions change, so routing tags no longer apply
+        let mut metadata = circuit.metadata.clone();
+        metadata.routing_gates.clear();
+
+        CircuitGenome {
+            num_qubits: circuit.num_qubits,
+            gates: out,
+            metadata,
+            qns_format_version: circuit.qns_format_version,
+        }
+    }
+
+    /// Fuses each run of consecutive single-qubit gates on a wire into one `U`.
+    ///
+    /// A run ends at the next gate that touches its qubit (a two-qubit gate or
+    /// a measurement). Runs of two or more gates are replaced by a single
+    /// [`Gate::U`] with the run's product matrix, emitted where the run ends,
+    /// or dropped entirely when the product is the identity. Lone gates are
+    /// kept as they are. The result equals the original unitary up to a
+    /// global phase.
+    pub fn fuse_single_qubit_runs(&self, circuit: &CircuitGenome) -> CircuitGenome {
+        if !circuit.metadata.frozen_regions.is_empty() {
+            return map_unfrozen(circuit, |segment| self.fuse_single_qubit_runs(segment));
+        }
+        fn flush(runs: &mut [Vec<Gate>], q: usize, out: &mut Vec<Gate>) {
+            let run = std::mem::take(&mut runs[q]);
+            if run.len() < 2 {
+                out.extend(run);
+                return;
+            }
+            let product = run
+                .iter()
+                .filter_map(Gate::matrix_2x2)
+                .fold(physics::IDENTITY, |acc, m| physics::mul_2x2(&m, &acc));
+            let identity = product[1][0].norm() < 1e-12
+                && product[0][1].norm() < 1e-12
+                && (product[1][1] - product[0][0]).norm() < 1e-12;
+            if !identity {
+                let (theta, phi, lambda) = physics::u3_angles(&product);
+                out.push(Gate::U(q, theta, phi, lambda));
+            }
+        }
+
+        let mut runs: Vec<Vec<Gate>> = vec![Vec::new(); circuit.num_qubits];
+        let mut out = Vec::with_capacity(circuit.gates.len());
+
+        for gate in &circuit.gates {
+            if gate.is_single_qubit() {
+                runs[gate.qubits()[0]].push(gate.clone());
+            } else {
+                for q in gate.qubits() {
+                    flush(&mut runs, q, &mut out);
+                }
+                out.push(gate.clone());
+            }
+        }
+
+        for q in 0..circuit.num_qubits {
+            flush(&mut runs, q, &mut out);
+        }
+
+        // Gate positions change, so routing tags no longer apply
+        let mut metadata = circuit.metadata.clone();
+        metadata.routing_gates.clear();
+
+        CircuitGenome {
+            num_qubits: circuit.num_qubits,
+            gates: out,
+            metadata,
+            qns_format_version: circuit.qns_format_version,
+        }
+    }
+
     /// Computes a hash for the circuit's gate sequence.
     ///
-    /// Used for deduplication of equivalent circuits.
-    fn circuit_hash(&self, circuit: &CircuitGenome) -> u64 {
+    /// Used for deduplication of equivalent circuits. Parameters are hashed
+    /// according to the configured [`HashMode`]; by default angles are
+    /// discretized to 1e-3 rad, so nearly identical rotations hash equally.
+    pub fn circuit_hash(&self, circuit: &CircuitGenome) -> u64 {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
@@ -547,10 +872,15 @@ impl GateReorder {
             std::mem::discriminant(gate).hash(&mut hasher);
             gate.qubits().hash(&mut hasher);
 
-            // Hash rotation angle if present (discretized)
-            if let Some(angle) = gate.rotation_angle() {
-                let discretized = (angle * 1000.0).round() as i64;
-                discretized.hash(&mut hasher);
+            // Hash gate parameters
+            for param in gate.params() {
+                match self.config.hash_mode {
+                    HashMode::ExactSequence => param.to_bits().hash(&mut hasher),
+                    HashMode::AngleDiscretized => {
+                        let discretized = (param / self.config.angle_discretization).round() as i64;
+                        discretized.hash(&mut hasher);
+                    },
+                }
             }
         }
 
@@ -634,6 +964,61 @@ pub fn score_circuit_variant(circuit: &CircuitGenome, noise: &NoiseVector) -> f6
     1.0 - error
 }
 
+/// Applies `pass` to each stretch of gates between frozen regions.
+///
+/// Frozen gates are copied verbatim and the regions are re-indexed to
+/// their new positions, since `pass` may change the gate count.
+pub(crate) fn map_unfrozen<F>(circuit: &CircuitGenome, pass: F) -> CircuitGenome
+where
+    F: Fn(&CircuitGenome) -> CircuitGenome,
+{
+    let len = circuit.gates.len();
+    let mut regions: Vec<(usize, usize)> = circuit
+        .metadata
+        .frozen_regions
+        .iter()
+        .map(|r| (r.start.min(len), r.end.min(len)))
+        .filter(|(start, end)| start < end)
+        .collect();
+    regions.sort_unstable();
+
+    // Overlapping or touching regions merge
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(regions.len());
+    for (start, end) in regions {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let mut segment = CircuitGenome::new(circuit.num_qubits);
+    let mut out = Vec::with_capacity(len);
+    let mut frozen_regions = Vec::with_capacity(merged.len());
+    let mut cursor = 0;
+    for (start, end) in merged {
+        segment.gates = circuit.gates[cursor..start].to_vec();
+        out.extend(pass(&segment).gates);
+        let new_start = out.len();
+        out.extend_from_slice(&circuit.gates[start..end]);
+        frozen_regions.push(new_start..out.len());
+        cursor = end;
+    }
+    segment.gates = circuit.gates[cursor..].to_vec();
+    out.extend(pass(&segment).gates);
+
+    // Gate positions change, so routing tags no longer apply
+    let mut metadata = circuit.metadata.clone();
+    metadata.routing_gates.clear();
+    metadata.frozen_regions = frozen_regions;
+
+    CircuitGenome {
+        num_qubits: circuit.num_qubits,
+        gates: out,
+        metadata,
+        qns_format_version: circuit.qns_format_version,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -660,6 +1045,275 @@ mod tests {
         assert!(pairs.contains(&1), "X(1) and Z(0) should commute");
     }
 
+    #[test]
+    fn test_pack_left() {
+        use qns_simulator::assert_circuits_equivalent;
+
+        let reorder = GateReorder::default();
+
+        // H on a CNOT target does not commute with it, so nothing moves
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::H(1)).unwrap();
+        assert_eq!(reorder.pack_left(&circuit).gates, circuit.gates);
+
+        // A phase on the control slides ahead of the CNOT into an idle slot
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::H(1)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::T(0)).unwrap();
+        let packed = reorder.pack_left(&circuit);
+        assert_eq!(packed.gates, vec![Gate::H(1), Gate::T(0), Gate::CNOT(0, 1)]);
+        assert_eq!(circuit.depth(), 3);
+        assert_eq!(packed.depth(), 2);
+        assert_circuits_equivalent(&circuit, &packed, 1e-10);
+
+        // CNOTs sharing a control reorder around a busy target
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::X(2)).unwrap();
+        circuit.add_gate(Gate::H(2)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 3)).unwrap();
+        circuit.add_gate(Gate::Rx(1, 0.3)).unwrap();
+        let packed = reorder.pack_left(&circuit);
+        assert!(packed.depth() < circuit.depth());
+        assert_circuits_equivalent(&circuit, &packed, 1e-10);
+    }
+
+    #[test]
+    fn test_margolus_substitution() {
+        use qns_simulator::{assert_circuits_equivalent, StateVectorSimulator};
+
+        // Margolus matches CCX on every computational basis state up to sign
+        let mut ccx = CircuitGenome::new(3);
+        ccx.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+        let mut margolus = CircuitGenome::new(3);
+        margolus
+            .add_gates(Gate::margolus_decomposition(0, 1, 2))
+            .unwrap();
+        for input in 0..8 {
+            let run = |circuit: &CircuitGenome| {
+                let mut sim = StateVectorSimulator::new(3);
+                let mut basis = vec![qns_core::physics::ZERO; 8];
+                basis[input] = qns_core::physics::ONE;
+                sim.set_state(basis).unwrap();
+                sim.execute(circuit).unwrap();
+                sim.statevector().to_vec()
+            };
+            let (exact, relative) = (run(&ccx), run(&margolus));
+            let expected = if input & 0b011 == 0b011 {
+                input ^ 0b100
+            } else {
+                input
+            };
+            assert!((exact[expected].norm() - 1.0).abs() < 1e-10);
+            assert!((relative[expected].norm() - 1.0).abs() < 1e-10);
+        }
+        assert_eq!(
+            margolus.gates.iter().filter(|g| g.is_two_qubit()).count(),
+            3
+        );
+
+        // Compute, use the result as a control, uncompute
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+        circuit.add_gate(Gate::CNOT(2, 3)).unwrap();
+        circuit.add_gate(Gate::T(0)).unwrap();
+        circuit.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+
+        let reorder = GateReorder::default();
+        let substituted = reorder.substitute_margolus(&circuit);
+        let cnots = |c: &CircuitGenome| c.gates.iter().filter(|g| g.is_two_qubit()).count();
+        assert_eq!(cnots(&circuit), 13);
+        assert_eq!(cnots(&substituted), 7);
+        assert_circuits_equivalent(&circuit, &substituted, 1e-10);
+
+        // The uncompute pair alone is exactly CCX·CCX = I
+        let mut pair = CircuitGenome::new(3);
+        pair.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+        pair.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+        let pair = reorder.substitute_margolus(&pair);
+        assert_eq!(pair.gates.len(), 14);
+        assert_circuits_equivalent(&pair, &CircuitGenome::new(3), 1e-10);
+
+        // A non-diagonal gate on the target in between blocks the substitution
+        let mut blocked = CircuitGenome::new(3);
+        blocked.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+        blocked.add_gate(Gate::H(2)).unwrap();
+        blocked.add_gates(Gate::ccx_decomposition(0, 1, 2)).unwrap();
+        assert_eq!(reorder.substitute_margolus(&blocked).gates, blocked.gates);
+    }
+
+    #[test]
+    fn test_frozen_region_keeps_order() {
+        let mut circuit = CircuitGenome::new(5);
+        for gate in [
+            Gate::Z(0),
+            Gate::Z(1),
+            Gate::S(2), // frozen
+            Gate::T(2), // frozen
+            Gate::X(3),
+            Gate::H(4),
+        ] {
+            circuit.add_gate(gate).unwrap();
+        }
+        let reorder = GateReorder::new(100);
+
+        // Unfrozen, S and T commute with each other and their neighbours
+        let free = reorder.generate_reorderings(&circuit);
+        assert!(free.iter().any(|v| v.gates[2..4] != circuit.gates[2..4]));
+
+        circuit.metadata.frozen_regions.push(2..4);
+        assert_eq!(reorder.find_adjacent_commuting_pairs(&circuit), vec![0, 4]);
+        let variants = reorder.generate_reorderings(&circuit);
+        // Both unfrozen pairs still swap independently
+        assert_eq!(variants.len(), 4);
+        for variant in &variants {
+            assert_eq!(variant.gates[2..4], circuit.gates[2..4]);
+        }
+
+        let (best, _) =
+            reorder.beam_search_reorderings(&circuit, BeamSearchConfig::default(), |c| {
+                // Rewards moving T(2) first, which the region forbids
+                (c.gates[2] == Gate::T(2)) as u8 as f64
+            });
+        assert_eq!(best.gates[2..4], circuit.gates[2..4]);
+
+        // Rewrite passes leave the region intact and re-index it
+        let mut cancelling = CircuitGenome::new(2);
+        for gate in [
+            Gate::H(0),
+            Gate::H(0),
+            Gate::X(1), // frozen
+            Gate::X(1), // frozen
+            Gate::Y(1),
+            Gate::Y(1),
+        ] {
+            cancelling.add_gate(gate).unwrap();
+        }
+        cancelling.metadata.frozen_regions.push(2..4);
+        let fused = reorder.fuse_single_qubit_runs(&cancelling);
+        assert_eq!(fused.gates, vec![Gate::X(1), Gate::X(1)]);
+        assert_eq!(fused.metadata.frozen_regions.len(), 1);
+        assert_eq!(fused.metadata.frozen_regions[0], 0..2);
+    }
+
+    #[test]
+    fn test_fuse_single_qubit_runs() {
+        use qns_simulator::assert_circuits_equivalent;
+
+        let reorder = GateReorder::default();
+
+        // H·S·H fuses into a single U
+        let mut circuit = CircuitGenome::new(1);
+        circuit
+            .add_gates([Gate::H(0), Gate::S(0), Gate::H(0)])
+            .unwrap();
+        let fused = reorder.fuse_single_qubit_runs(&circuit);
+        assert_eq!(fused.gates.len(), 1);
+        assert!(matches!(fused.gates[0], Gate::U(0, _, _, _)));
+        assert_circuits_equivalent(&circuit, &fused, 1e-10);
+
+        // Runs end at two-qubit gates; lone gates stay; H·H vanishes
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::Rx(0, 0.3),
+                Gate::T(0),
+                Gate::H(1),
+                Gate::H(2),
+                Gate::H(2),
+                Gate::CNOT(0, 1),
+                Gate::Ry(1, 0.7),
+                Gate::Rz(1, -0.4),
+                Gate::X(1),
+                Gate::Measure(0),
+            ])
+            .unwrap();
+        let fused = reorder.fuse_single_qubit_runs(&circuit);
+        assert_eq!(
+            fused.gates.len(),
+            5,
+            "expected U(0), H(1), CNOT, Measure, U(1): {:?}",
+            fused.gates
+        );
+        assert!(fused.gates.contains(&Gate::H(1)));
+        assert!(!fused.gates.iter().any(|g| g.qubits() == vec![2]));
+        let unitary = |c: &CircuitGenome| {
+            let mut c = c.clone();
+            c.gates.retain(|g| !g.is_measurement());
+            c
+        };
+        assert_circuits_equivalent(&unitary(&circuit), &unitary(&fused), 1e-10);
+    }
+
+    #[test]
+    fn test_push_rz_to_end() {
+        use qns_simulator::StateVectorSimulator;
+
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::H(1)).unwrap();
+        circuit.add_gate(Gate::H(2)).unwrap();
+        circuit.add_gate(Gate::Rz(0, 0.3)).unwrap();
+        circuit.add_gate(Gate::CZ(0, 1)).unwrap();
+        circuit.add_gate(Gate::T(0)).unwrap();
+        circuit.add_gate(Gate::Rz(1, -0.7)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap(); // Rz on control commutes
+        circuit.add_gate(Gate::S(1)).unwrap();
+        circuit.add_gate(Gate::SWAP(1, 2)).unwrap(); // pending phase follows the SWAP
+        circuit.add_gate(Gate::Rz(0, 0.1)).unwrap();
+        circuit.add_gate(Gate::CZ(1, 2)).unwrap();
+
+        let reorder = GateReorder::default();
+        let pushed = reorder.push_rz_to_end(&circuit);
+
+        // The body (everything before the trailing Rz block) has no Z-axis gates
+        let tail_start = pushed
+            .gates
+            .iter()
+            .rposition(|g| !matches!(g, Gate::Rz(_, _)))
+            .map_or(0, |i| i + 1);
+        assert!(pushed.gates[..tail_start]
+            .iter()
+            .all(|g| g.gate_type() != GateType::Diagonal));
+
+        // One merged Rz per qubit that carried phase (qubits 0 and 2 after SWAP)
+        let tail = &pushed.gates[tail_start..];
+        assert_eq!(tail.len(), 2);
+        let angle_of = |q: usize| {
+            tail.iter()
+                .find_map(|g| match g {
+                    Gate::Rz(p, a) if *p == q => Some(*a),
+                    _ => None,
+                })
+                .unwrap()
+        };
+        assert!((angle_of(0) - (0.3 + std::f64::consts::FRAC_PI_4 + 0.1)).abs() < 1e-12);
+        assert!((angle_of(2) - (-0.7 + std::f64::consts::FRAC_PI_2)).abs() < 1e-12);
+
+        // Unitary unchanged (up to global phase) on a non-trivial input state
+        let mut prep = CircuitGenome::new(3);
+        prep.add_gate(Gate::Ry(0, 0.4)).unwrap();
+        prep.add_gate(Gate::Rx(1, 1.1)).unwrap();
+        prep.add_gate(Gate::Ry(2, -0.9)).unwrap();
+
+        let run = |body: &CircuitGenome| {
+            let mut sim = StateVectorSimulator::new(3);
+            sim.run(&prep).unwrap();
+            for gate in &body.gates {
+                sim.apply_gate(gate).unwrap();
+            }
+            sim.statevector().to_vec()
+        };
+        let expected = run(&circuit);
+        let mut sim = StateVectorSimulator::new(3);
+        sim.set_state(run(&pushed)).unwrap();
+        assert!((sim.fidelity(&expected).unwrap() - 1.0).abs() < 1e-10);
+    }
+
     #[test]
     fn test_find_commuting_pairs() {
         let mut circuit = CircuitGenome::new(3);
@@ -718,6 +1372,22 @@ mod tests {
         assert!(matches!(swapped.gates[1], Gate::H(0)));
     }
 
+    #[test]
+    fn test_swap_gates_moves_routing_tags() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_routing_gate(Gate::SWAP(1, 2)).unwrap();
+
+        let reorder = GateReorder::default();
+        let swapped = reorder.swap_gates(&circuit, 0);
+        assert!(matches!(swapped.gates[0], Gate::SWAP(1, 2)));
+
+        // The routing tag moves with the SWAP
+        assert_eq!(swapped.metadata.routing_gates, vec![0]);
+        assert!(swapped.is_routing_gate(0));
+        assert!(!swapped.is_routing_gate(1));
+    }
+
     #[test]
     fn test_circuit_hash_different() {
         let mut c1 = CircuitGenome::new(2);
@@ -732,6 +1402,34 @@ mod tests {
         assert_ne!(reorder.circuit_hash(&c1), reorder.circuit_hash(&c2));
     }
 
+    #[test]
+    fn test_hash_granularity() {
+        let mut c1 = CircuitGenome::new(1);
+        c1.add_gate(Gate::Rz(0, 0.1002)).unwrap();
+        let mut c2 = CircuitGenome::new(1);
+        c2.add_gate(Gate::Rz(0, 0.1007)).unwrap();
+
+        let with = |hash_mode, angle_discretization| {
+            GateReorder::with_config(ReorderConfig {
+                hash_mode,
+                angle_discretization,
+                ..Default::default()
+            })
+        };
+
+        assert!(with(HashMode::AngleDiscretized, 1e-2).circuits_equivalent(&c1, &c2));
+        assert!(!with(HashMode::AngleDiscretized, 1e-4).circuits_equivalent(&c1, &c2));
+        assert!(!with(HashMode::ExactSequence, 1e-2).circuits_equivalent(&c1, &c2));
+        assert!(with(HashMode::ExactSequence, 1e-2).circuits_equivalent(&c1, &c1.clone()));
+
+        // Both FSim angles participate in the hash
+        let mut f1 = CircuitGenome::new(2);
+        f1.add_gate(Gate::FSim(0, 1, 0.5, 0.1)).unwrap();
+        let mut f2 = CircuitGenome::new(2);
+        f2.add_gate(Gate::FSim(0, 1, 0.5, 0.2)).unwrap();
+        assert!(!with(HashMode::AngleDiscretized, 1e-3).circuits_equivalent(&f1, &f2));
+    }
+
     #[test]
     fn test_circuit_hash_same() {
         let mut c1 = CircuitGenome::new(2);
@@ -861,6 +1559,7 @@ mod tests {
             max_variants: 100,
             max_depth: 10,
             deduplicate: true,
+            ..Default::default()
         });
 
         let variants = reorder.generate_reorderings(&circuit);
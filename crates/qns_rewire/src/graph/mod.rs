@@ -45,6 +45,33 @@ impl DependencyGraph {
         }
     }
 
+    /// Builds a commutation-aware graph.
+    ///
+    /// A gate depends only on earlier gates that share a qubit and do not
+    /// commute with it (see [`Gate::commutes_with`]), so any topological
+    /// order of this graph implements the same unitary as the circuit.
+    pub fn with_commutation(circuit: &CircuitGenome) -> Self {
+        let gates = &circuit.gates;
+        let n = gates.len();
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut incoming_degree = vec![0; n];
+
+        for (idx, gate) in gates.iter().enumerate() {
+            for (prev_idx, prev) in gates[..idx].iter().enumerate() {
+                if !prev.commutes_with(gate) {
+                    successors[prev_idx].push(idx);
+                    incoming_degree[idx] += 1;
+                }
+            }
+        }
+
+        Self {
+            successors,
+            incoming_degree,
+            num_gates: n,
+        }
+    }
+
     /// Returns the initial front layer (gates with no dependencies).
     pub fn initial_front_layer(&self) -> Vec<usize> {
         self.incoming_degree
@@ -5,21 +5,32 @@ pub mod details;
 pub mod gate_reorder;
 pub mod graph;
 pub mod live_rewirer;
+pub mod pass_manager;
+pub mod peephole;
 pub mod router;
 pub mod scoring;
 
 pub use gate_reorder::{
     estimate_circuit_error, score_circuit_variant, BeamSearchConfig, CommutingPair, GateReorder,
-    ReorderAnalysis, ReorderConfig,
+    HashMode, ReorderAnalysis, ReorderConfig,
 };
 
 pub use live_rewirer::{
-    LiveRewirer, OptimizationResult, OptimizationStats, PlacementOptimizationResult, RewireConfig,
-    RoutingOptimizationResult,
+    FixedPointResult, LiveRewirer, OptimizationResult, OptimizationStats,
+    PlacementOptimizationResult, RewireConfig, RoutingOptimizationResult, MAX_FIXED_POINT_ROUNDS,
+};
+pub use pass_manager::{
+    CancelInversePairsPass, CircuitPass, MergeRotationsPass, PassContext, PassManager,
+    PlacementPass, PrunePostMeasurementPass, ReorderPass, RoutePass, SabreRoutePass,
+};
+pub use peephole::{cancel_inverse_pairs, merge_rotations, prune_post_measurement};
+pub use router::{
+    estimate_routing_overhead, route_diagonal_layer, swap_network, BasicRouter, NoiseAwareRouter,
+    PlacementOptimizer, PlacementResult, Router, RoutingEstimate,
 };
-pub use router::{BasicRouter, NoiseAwareRouter, PlacementOptimizer, PlacementResult, Router};
 pub use scoring::{
     // Idle-time aware functions
+    calculate_parallel_crosstalk,
     calculate_qubit_schedules,
     calculate_total_idle_time,
     critical_path,
@@ -27,11 +38,21 @@ pub use scoring::{
     decay_estimation_from_noise,
     estimate_fidelity_with_hardware,
     estimate_fidelity_with_idle_tracking,
+    estimate_fidelity_with_per_qubit_noise,
     estimate_fidelity_with_scheduling,
+    fidelity_depth_curve,
     gate_error_sum,
     // Hardware-aware functions (per-edge fidelity)
     gate_error_sum_with_hardware,
+    gate_error_sum_with_hardware_config,
+    log_decay_survival,
+    per_layer_error,
+    try_estimate_fidelity_with_hardware,
+    try_estimate_fidelity_with_idle_tracking,
+    try_estimate_fidelity_with_per_qubit_noise,
+    try_estimate_fidelity_with_scheduling,
     QubitSchedule,
     ScoreConfig,
     ScoringError,
+    FIDELITY_TOLERANCE,
 };
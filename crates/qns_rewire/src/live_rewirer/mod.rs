@@ -1,11 +1,14 @@
 // QNS v2.0 - live_rewirer/mod.rs
 // Phase 3 Journal Implementation: LiveRewirer with noise-adaptive optimization
 
-use crate::gate_reorder::{GateReorder, ReorderConfig};
+use crate::gate_reorder::{GateReorder, HashMode, ReorderConfig};
+use crate::peephole::{cancel_inverse_pairs, merge_rotations, prune_post_measurement};
 use crate::router::placement::PlacementOptimizer;
 use crate::router::{NoiseAwareRouter, SabreRouter};
 use crate::scoring::{
-    estimate_fidelity_with_hardware, estimate_fidelity_with_idle_tracking, ScoreConfig,
+    calculate_parallel_crosstalk, try_estimate_fidelity_with_hardware,
+    try_estimate_fidelity_with_idle_tracking, try_estimate_fidelity_with_per_qubit_noise,
+    ScoreConfig,
 };
 use qns_core::prelude::*;
 use rayon::prelude::*;
@@ -29,7 +32,7 @@ pub struct RewireConfig {
     pub beam_search_threshold: usize,
     /// Enable parallel evaluation
     pub parallel: bool,
-    /// Weight for crosstalk penalty in routing
+    /// Weight for crosstalk penalty in routing and hardware-aware scoring
     pub crosstalk_weight: f64,
     /// Use SABRE router instead of basic noise-aware router
     pub use_sabre: bool,
@@ -102,6 +105,7 @@ impl LiveRewirer {
             max_variants: config.max_variants,
             max_depth: config.max_depth,
             deduplicate: true,
+            ..Default::default()
         };
         Self {
             circuit: None,
@@ -141,16 +145,15 @@ impl LiveRewirer {
         &self,
         variants: &[CircuitGenome],
         noise: &NoiseVector,
-    ) -> Vec<ScoredVariant> {
+    ) -> Result<Vec<ScoredVariant>> {
         variants
             .iter()
             .map(|circuit| {
-                let fidelity =
-                    estimate_fidelity_with_idle_tracking(circuit, noise, &self.config.score_config);
-                ScoredVariant {
+                let fidelity = self.score_circuit(circuit, noise)?;
+                Ok(ScoredVariant {
                     circuit: circuit.clone(),
                     fidelity,
-                }
+                })
             })
             .collect()
     }
@@ -164,26 +167,37 @@ impl LiveRewirer {
         &self,
         variants: &[CircuitGenome],
         noise: &NoiseVector,
-    ) -> Vec<ScoredVariant> {
+    ) -> Result<Vec<ScoredVariant>> {
         variants
             .par_iter()
             .map(|circuit| {
-                let fidelity =
-                    estimate_fidelity_with_idle_tracking(circuit, noise, &self.config.score_config);
-                ScoredVariant {
+                let fidelity = self.score_circuit(circuit, noise)?;
+                Ok(ScoredVariant {
                     circuit: circuit.clone(),
                     fidelity,
-                }
+                })
             })
             .collect()
     }
 
+    /// Scores one circuit with idle tracking.
+    ///
+    /// Strict-mode rejections ([`ScoreConfig::strict`]) surface as errors
+    /// instead of panics.
+    fn score_circuit(&self, circuit: &CircuitGenome, noise: &NoiseVector) -> Result<f64> {
+        Ok(try_estimate_fidelity_with_idle_tracking(
+            circuit,
+            noise,
+            &self.config.score_config,
+        )?)
+    }
+
     /// Score all variants (chooses parallel or sequential based on config)
     fn score_all_variants(
         &self,
         variants: &[CircuitGenome],
         noise: &NoiseVector,
-    ) -> Vec<ScoredVariant> {
+    ) -> Result<Vec<ScoredVariant>> {
         if self.config.parallel && variants.len() > 4 {
             self.score_all_variants_parallel(variants, noise)
         } else {
@@ -191,17 +205,60 @@ impl LiveRewirer {
         }
     }
 
+    /// Scores variants with `score`, in parallel when the config allows
+    fn score_variants_by<F>(
+        &self,
+        variants: &[CircuitGenome],
+        score: &F,
+    ) -> Result<Vec<ScoredVariant>>
+    where
+        F: Fn(&CircuitGenome) -> Result<f64> + Sync,
+    {
+        let scored = |circuit: &CircuitGenome| {
+            Ok(ScoredVariant {
+                circuit: circuit.clone(),
+                fidelity: score(circuit)?,
+            })
+        };
+        if self.config.parallel && variants.len() > 4 {
+            variants.par_iter().map(scored).collect()
+        } else {
+            variants.iter().map(scored).collect()
+        }
+    }
+
     // ========================================================================
     // Task 2.2: find_best_variant - Find the variant with highest fidelity
     // ========================================================================
 
     /// Finds the variant with the highest fidelity score
+    ///
+    /// Ties go to the shallower circuit, then the one with fewer two-qubit
+    /// gates, then the lower exact-sequence hash, so the pick does not depend
+    /// on evaluation order and parallel scoring matches sequential scoring.
     fn find_best_variant(scored_variants: &[ScoredVariant]) -> Option<&ScoredVariant> {
-        scored_variants.iter().max_by(|a, b| {
-            a.fidelity
-                .partial_cmp(&b.fidelity)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        })
+        let exact = GateReorder::with_config(ReorderConfig {
+            hash_mode: HashMode::ExactSequence,
+            ..Default::default()
+        });
+        scored_variants
+            .iter()
+            .map(|v| {
+                let circuit = &v.circuit;
+                let key = (
+                    circuit.depth(),
+                    circuit.two_qubit_gate_count(),
+                    exact.circuit_hash(circuit),
+                );
+                (v, key)
+            })
+            .min_by(|(a, key_a), (b, key_b)| {
+                b.fidelity
+                    .partial_cmp(&a.fidelity)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| key_a.cmp(key_b))
+            })
+            .map(|(v, _)| v)
     }
 
     // ========================================================================
@@ -227,6 +284,107 @@ impl LiveRewirer {
         noise: &NoiseVector,
         max_iterations: usize,
     ) -> Result<OptimizationResult> {
+        self.optimize_scored(noise, max_iterations)
+            .map(|(result, _)| result)
+    }
+
+    /// Optimize the loaded circuit, calling `on_scored` with the fidelity of
+    /// each variant as it is scored
+    ///
+    /// Behaves like [`optimize`](Self::optimize); `on_scored` runs exactly
+    /// `variants_evaluated` times, from Rayon worker threads when scoring in
+    /// parallel, so callers can stream progress.
+    pub fn optimize_observed<G>(
+        &self,
+        noise: &NoiseVector,
+        max_iterations: usize,
+        on_scored: G,
+    ) -> Result<OptimizationResult>
+    where
+        G: Fn(f64) + Sync,
+    {
+        let score = |circuit: &CircuitGenome| self.score_circuit(circuit, noise);
+        self.optimize_scored_by(&score, &on_scored, max_iterations)
+            .map(|(result, _)| result)
+    }
+
+    /// Optimize the loaded circuit and report the score of every variant
+    ///
+    /// Behaves like [`optimize`](Self::optimize) but additionally returns
+    /// `(circuit_hash, fidelity)` for each scored variant, in evaluation order.
+    /// The list has `variants_evaluated` entries, so score distributions can
+    /// be analyzed without re-scoring.
+    pub fn optimize_verbose(
+        &self,
+        noise: &NoiseVector,
+        max_iterations: usize,
+    ) -> Result<(OptimizationResult, Vec<(u64, f64)>)> {
+        let (result, scored) = self.optimize_scored(noise, max_iterations)?;
+        let scores = scored
+            .iter()
+            .map(|v| (self.gate_reorder.circuit_hash(&v.circuit), v.fidelity))
+            .collect();
+        Ok((result, scores))
+    }
+
+    /// Optimize the loaded circuit against per-qubit noise profiles
+    ///
+    /// Like [`optimize`](Self::optimize), but variants are scored with
+    /// [`estimate_fidelity_with_per_qubit_noise`], so reorderings that shift
+    /// idle time onto longer-lived qubits are preferred.
+    ///
+    /// # Arguments
+    /// * `noise` - Noise profile of each qubit, indexed by qubit
+    /// * `max_iterations` - Maximum number of variants to evaluate
+    ///
+    /// # Errors
+    /// `QnsError::DimensionMismatch` if `noise` has fewer entries than the
+    /// loaded circuit has qubits.
+    pub fn optimize_with_noise_map(
+        &self,
+        noise: &[NoiseVector],
+        max_iterations: usize,
+    ) -> Result<OptimizationResult> {
+        if let Some(circuit) = &self.circuit {
+            if noise.len() < circuit.num_qubits {
+                return Err(QnsError::DimensionMismatch(circuit.num_qubits, noise.len()));
+            }
+        }
+        let score = |circuit: &CircuitGenome| {
+            Ok(try_estimate_fidelity_with_per_qubit_noise(
+                circuit,
+                noise,
+                &self.config.score_config,
+            )?)
+        };
+        self.optimize_scored_by(&score, &|_| {}, max_iterations)
+            .map(|(result, _)| result)
+    }
+
+    /// Shared implementation of `optimize` that also returns all scored variants
+    fn optimize_scored(
+        &self,
+        noise: &NoiseVector,
+        max_iterations: usize,
+    ) -> Result<(OptimizationResult, Vec<ScoredVariant>)> {
+        let score = |circuit: &CircuitGenome| self.score_circuit(circuit, noise);
+        self.optimize_scored_by(&score, &|_| {}, max_iterations)
+    }
+
+    /// Variant search of `optimize_scored` under an arbitrary scoring function
+    ///
+    /// `on_scored` sees the fidelity of every evaluated variant, not the
+    /// baseline score of the input circuit.
+    fn optimize_scored_by<F, G>(
+        &self,
+        score: &F,
+        on_scored: &G,
+        max_iterations: usize,
+    ) -> Result<(OptimizationResult, Vec<ScoredVariant>)>
+    where
+        F: Fn(&CircuitGenome) -> Result<f64> + Sync,
+        G: Fn(f64) + Sync,
+    {
         let circuit = self
             .circuit
             .as_ref()
@@ -234,33 +392,38 @@ impl LiveRewirer {
 
         // Handle empty circuit
         if circuit.gates.is_empty() {
-            return Ok(OptimizationResult {
+            let result = OptimizationResult {
                 circuit: circuit.clone(),
                 fidelity: 1.0,
                 variants_evaluated: 0,
                 improvement: 0.0,
                 improved: false,
                 strategy: "empty".to_string(),
-            });
+            };
+            return Ok((result, Vec::new()));
         }
 
         // Handle single-gate circuit
         if circuit.gates.len() == 1 {
-            let fidelity =
-                estimate_fidelity_with_idle_tracking(circuit, noise, &self.config.score_config);
-            return Ok(OptimizationResult {
+            let fidelity = score(circuit)?;
+            on_scored(fidelity);
+            let result = OptimizationResult {
                 circuit: circuit.clone(),
                 fidelity,
                 variants_evaluated: 1,
                 improvement: 0.0,
                 improved: false,
                 strategy: "single_gate".to_string(),
-            });
+            };
+            let scored = vec![ScoredVariant {
+                circuit: circuit.clone(),
+                fidelity,
+            }];
+            return Ok((result, scored));
         }
 
         // Calculate baseline fidelity
-        let base_fidelity =
-            estimate_fidelity_with_idle_tracking(circuit, noise, &self.config.score_config);
+        let base_fidelity = score(circuit)?;
 
         // Generate variants using gate reorder
         let all_variants = self.gate_reorder.generate_reorderings(circuit);
@@ -272,14 +435,20 @@ impl LiveRewirer {
 
         // Handle case where no variants were generated
         if variants.is_empty() {
-            return Ok(OptimizationResult {
+            on_scored(base_fidelity);
+            let result = OptimizationResult {
                 circuit: circuit.clone(),
                 fidelity: base_fidelity,
                 variants_evaluated: 1,
                 improvement: 0.0,
                 improved: false,
                 strategy: "no_variants".to_string(),
-            });
+            };
+            let scored = vec![ScoredVariant {
+                circuit: circuit.clone(),
+                fidelity: base_fidelity,
+            }];
+            return Ok((result, scored));
         }
 
         // Determine strategy based on variant count
@@ -290,37 +459,44 @@ impl LiveRewirer {
         };
 
         // Score all variants
-        let scored_variants = self.score_all_variants(&variants, noise);
+        let observed = |variant: &CircuitGenome| {
+            let fidelity = score(variant)?;
+            on_scored(fidelity);
+            Ok(fidelity)
+        };
+        let scored_variants = self.score_variants_by(&variants, &observed)?;
 
         // Find the best variant
         let best = Self::find_best_variant(&scored_variants);
 
-        match best {
+        let result = match best {
             Some(best_variant) => {
                 let improvement = best_variant.fidelity - base_fidelity;
                 let improved = improvement > 1e-9; // Small epsilon for floating point
 
-                Ok(OptimizationResult {
+                OptimizationResult {
                     circuit: best_variant.circuit.clone(),
                     fidelity: best_variant.fidelity,
                     variants_evaluated: num_variants,
                     improvement,
                     improved,
                     strategy: strategy.to_string(),
-                })
+                }
             },
             None => {
                 // Shouldn't happen if variants is non-empty, but handle gracefully
-                Ok(OptimizationResult {
+                OptimizationResult {
                     circuit: circuit.clone(),
                     fidelity: base_fidelity,
                     variants_evaluated: num_variants,
                     improvement: 0.0,
                     improved: false,
                     strategy: "fallback".to_string(),
-                })
+                }
             },
-        }
+        };
+
+        Ok((result, scored_variants))
     }
 
     /// Optimize with detailed statistics
@@ -351,13 +527,24 @@ impl LiveRewirer {
     // ========================================================================
 
     /// Score a single circuit using hardware-specific per-edge fidelities.
+    ///
+    /// Crosstalk between simultaneously executing gates is penalized by
+    /// `crosstalk_weight`, so placement and reordering avoid crosstalk hotspots.
     fn score_circuit_with_hardware(
         &self,
         circuit: &CircuitGenome,
         noise: &NoiseVector,
         hardware: &HardwareProfile,
-    ) -> f64 {
-        estimate_fidelity_with_hardware(circuit, noise, hardware, &self.config.score_config)
+    ) -> Result<f64> {
+        let score_config = &self.config.score_config;
+        let fidelity = try_estimate_fidelity_with_hardware(circuit, noise, hardware, score_config)?;
+
+        if self.config.crosstalk_weight <= 0.0 {
+            return Ok(fidelity);
+        }
+        let penalty = self.config.crosstalk_weight
+            * calculate_parallel_crosstalk(circuit, hardware, score_config);
+        Ok((fidelity * (1.0 - penalty.min(1.0))).clamp(0.0, 1.0))
     }
 
     /// Scores all variants using hardware-specific per-edge fidelities.
@@ -366,30 +553,10 @@ impl LiveRewirer {
         variants: &[CircuitGenome],
         noise: &NoiseVector,
         hardware: &HardwareProfile,
-    ) -> Vec<ScoredVariant> {
-        if self.config.parallel && variants.len() > 4 {
-            variants
-                .par_iter()
-                .map(|circuit| {
-                    let fidelity = self.score_circuit_with_hardware(circuit, noise, hardware);
-                    ScoredVariant {
-                        circuit: circuit.clone(),
-                        fidelity,
-                    }
-                })
-                .collect()
-        } else {
-            variants
-                .iter()
-                .map(|circuit| {
-                    let fidelity = self.score_circuit_with_hardware(circuit, noise, hardware);
-                    ScoredVariant {
-                        circuit: circuit.clone(),
-                        fidelity,
-                    }
-                })
-                .collect()
-        }
+    ) -> Result<Vec<ScoredVariant>> {
+        let score =
+            |circuit: &CircuitGenome| self.score_circuit_with_hardware(circuit, noise, hardware);
+        self.score_variants_by(variants, &score)
     }
 
     /// Optimize the loaded circuit using hardware-aware scoring.
@@ -430,7 +597,7 @@ impl LiveRewirer {
 
         // Handle single-gate circuit
         if circuit.gates.len() == 1 {
-            let fidelity = self.score_circuit_with_hardware(circuit, noise, hardware);
+            let fidelity = self.score_circuit_with_hardware(circuit, noise, hardware)?;
             return Ok(OptimizationResult {
                 circuit: circuit.clone(),
                 fidelity,
@@ -442,7 +609,7 @@ impl LiveRewirer {
         }
 
         // Calculate baseline fidelity with hardware-aware scoring
-        let base_fidelity = self.score_circuit_with_hardware(circuit, noise, hardware);
+        let base_fidelity = self.score_circuit_with_hardware(circuit, noise, hardware)?;
 
         // Generate variants using gate reorder
         let all_variants = self.gate_reorder.generate_reorderings(circuit);
@@ -472,7 +639,7 @@ impl LiveRewirer {
         };
 
         // Score all variants with hardware-aware scoring
-        let scored_variants = self.score_all_variants_with_hardware(&variants, noise, hardware);
+        let scored_variants = self.score_all_variants_with_hardware(&variants, noise, hardware)?;
 
         // Find the best variant
         let best = Self::find_best_variant(&scored_variants);
@@ -551,7 +718,7 @@ impl LiveRewirer {
         }
 
         // Calculate original fidelity with identity mapping
-        let original_fidelity = self.score_circuit_with_hardware(circuit, noise, hardware);
+        let original_fidelity = self.score_circuit_with_hardware(circuit, noise, hardware)?;
 
         // Step 1: Optimize placement (find best qubit mapping)
         let placement_optimizer = PlacementOptimizer::new(100, false); // Use local search
@@ -559,7 +726,7 @@ impl LiveRewirer {
 
         // Step 2: Score the placement-optimized circuit
         let placed_fidelity =
-            self.score_circuit_with_hardware(&placement_result.circuit, noise, hardware);
+            self.score_circuit_with_hardware(&placement_result.circuit, noise, hardware)?;
 
         // Step 3: Generate reordering variants on the placed circuit
         let all_variants = self
@@ -571,7 +738,7 @@ impl LiveRewirer {
             (placement_result.circuit.clone(), placed_fidelity)
         } else {
             // Score all variants
-            let scored = self.score_all_variants_with_hardware(&variants, noise, hardware);
+            let scored = self.score_all_variants_with_hardware(&variants, noise, hardware)?;
 
             // Find best
             if let Some(best) = Self::find_best_variant(&scored) {
@@ -674,13 +841,18 @@ impl LiveRewirer {
             });
         }
 
+        if circuit.metadata.pre_mapped {
+            return self.optimize_pre_mapped(noise, hardware, max_iterations);
+        }
+
         // Calculate original fidelity with identity mapping and routing
         // Use configured weights for router
         let router = NoiseAwareRouter::new(1.0, 0.5, self.config.crosstalk_weight);
         let identity_mapping: Vec<usize> = (0..circuit.num_qubits).collect();
 
         let identity_routed = router.route_with_mapping(circuit, hardware, &identity_mapping)?;
-        let original_fidelity = self.score_circuit_with_hardware(&identity_routed, noise, hardware);
+        let original_fidelity =
+            self.score_circuit_with_hardware(&identity_routed, noise, hardware)?;
         let original_swaps = count_swaps(&identity_routed);
 
         // Step 1 & 2: Routing Strategy Selection
@@ -701,7 +873,7 @@ impl LiveRewirer {
         };
 
         // Step 2.5: Calculate fidelity of routed circuit
-        let routed_fidelity = self.score_circuit_with_hardware(&routed_circuit, noise, hardware);
+        let routed_fidelity = self.score_circuit_with_hardware(&routed_circuit, noise, hardware)?;
 
         // Step 3: Generate reordering variants on the routed circuit
         let all_variants = self.gate_reorder.generate_reorderings(&routed_circuit);
@@ -711,7 +883,7 @@ impl LiveRewirer {
             (routed_circuit.clone(), routed_fidelity)
         } else {
             // Score all variants
-            let scored = self.score_all_variants_with_hardware(&variants, noise, hardware);
+            let scored = self.score_all_variants_with_hardware(&variants, noise, hardware)?;
 
             // Find best
             if let Some(best) = Self::find_best_variant(&scored) {
@@ -771,8 +943,145 @@ impl LiveRewirer {
             strategy,
         })
     }
+
+    /// Reorders a pre-mapped circuit in place, skipping placement and routing.
+    ///
+    /// Every two-qubit gate must already sit on a hardware coupler.
+    fn optimize_pre_mapped(
+        &self,
+        noise: &NoiseVector,
+        hardware: &HardwareProfile,
+        max_iterations: usize,
+    ) -> Result<RoutingOptimizationResult> {
+        let circuit = self
+            .circuit
+            .as_ref()
+            .ok_or_else(|| QnsError::Rewire("No circuit loaded".to_string()))?;
+
+        if let Some((idx, reason)) = hardware.validate_circuit(circuit).into_iter().next() {
+            return Err(QnsError::Rewire(format!(
+                "Pre-mapped circuit cannot run on {}: gate {}: {}",
+                hardware.name, idx, reason
+            )));
+        }
+
+        let result = self.optimize_with_hardware(noise, hardware, max_iterations)?;
+        Ok(RoutingOptimizationResult {
+            swaps_inserted: count_swaps(&result.circuit),
+            circuit: result.circuit,
+            mapping: (0..circuit.num_qubits).collect(),
+            fidelity: result.fidelity,
+            original_fidelity: result.fidelity - result.improvement,
+            improvement: result.improvement,
+            improved: result.improved,
+            strategy: "pre_mapped".to_string(),
+        })
+    }
+
+    // ========================================================================
+    // Fixed-point peephole optimization
+    // ========================================================================
+
+    /// Runs one round of every peephole pass followed by reorder scoring.
+    fn fixed_point_round(
+        &self,
+        circuit: &CircuitGenome,
+        noise: &NoiseVector,
+        hardware: Option<&HardwareProfile>,
+    ) -> Result<CircuitGenome> {
+        let simplified = prune_post_measurement(&merge_rotations(&cancel_inverse_pairs(circuit)));
+
+        if simplified.gates.len() < 2 {
+            return Ok(simplified);
+        }
+
+        let variants: Vec<_> = self
+            .gate_reorder
+            .generate_reorderings(&simplified)
+            .into_iter()
+            .take(self.config.max_variants)
+            .collect();
+        let scored = match hardware {
+            Some(hw) => self.score_all_variants_with_hardware(&variants, noise, hw)?,
+            None => self.score_all_variants(&variants, noise)?,
+        };
+
+        // Only accept a reordering that strictly improves the score, otherwise
+        // ties between equivalent orderings would keep the loop from settling.
+        let base = self.score_for_fixed_point(&simplified, noise, hardware)?;
+        Ok(match Self::find_best_variant(&scored) {
+            Some(best) if best.fidelity - base > 1e-9 => best.circuit.clone(),
+            _ => simplified,
+        })
+    }
+
+    fn score_for_fixed_point(
+        &self,
+        circuit: &CircuitGenome,
+        noise: &NoiseVector,
+        hardware: Option<&HardwareProfile>,
+    ) -> Result<f64> {
+        match hardware {
+            Some(hw) => self.score_circuit_with_hardware(circuit, noise, hw),
+            None => self.score_circuit(circuit, noise),
+        }
+    }
+
+    /// Optimize the loaded circuit by iterating all peephole passes to a fixed point.
+    ///
+    /// Each round applies inverse cancellation, rotation merging, post-measurement
+    /// pruning and reorder scoring. Running a single round misses cascades where
+    /// one simplification exposes another (e.g. a merge that creates an inverse
+    /// pair), so rounds repeat until the circuit stops changing or
+    /// `MAX_FIXED_POINT_ROUNDS` is reached.
+    ///
+    /// # Arguments
+    /// * `noise` - Noise parameters for scoring
+    /// * `hardware` - Optional hardware profile for per-edge scoring
+    ///
+    /// # Returns
+    /// `FixedPointResult` with the final circuit and the number of rounds applied
+    pub fn optimize_fixed_point(
+        &self,
+        noise: &NoiseVector,
+        hardware: Option<&HardwareProfile>,
+    ) -> Result<FixedPointResult> {
+        let original = self
+            .circuit
+            .as_ref()
+            .ok_or_else(|| QnsError::Rewire("No circuit loaded".to_string()))?;
+
+        let original_fidelity = self.score_for_fixed_point(original, noise, hardware)?;
+        let mut circuit = original.clone();
+        let mut rounds = 0;
+        let mut converged = false;
+
+        while rounds < MAX_FIXED_POINT_ROUNDS {
+            let next = self.fixed_point_round(&circuit, noise, hardware)?;
+            rounds += 1;
+            if next.gates == circuit.gates {
+                converged = true;
+                break;
+            }
+            circuit = next;
+        }
+
+        let fidelity = self.score_for_fixed_point(&circuit, noise, hardware)?;
+
+        Ok(FixedPointResult {
+            gates_removed: original.gates.len().saturating_sub(circuit.gates.len()),
+            circuit,
+            fidelity,
+            improvement: fidelity - original_fidelity,
+            rounds,
+            converged,
+        })
+    }
 }
 
+/// Upper bound on rounds run by `LiveRewirer::optimize_fixed_point`
+pub const MAX_FIXED_POINT_ROUNDS: usize = 16;
+
 /// Count SWAP gates in a circuit
 fn count_swaps(circuit: &CircuitGenome) -> usize {
     circuit
@@ -822,6 +1131,23 @@ pub struct RoutingOptimizationResult {
     pub strategy: String,
 }
 
+/// Result of fixed-point peephole optimization
+#[derive(Debug, Clone)]
+pub struct FixedPointResult {
+    /// The simplified circuit
+    pub circuit: CircuitGenome,
+    /// Estimated fidelity of the simplified circuit
+    pub fidelity: f64,
+    /// Improvement in fidelity (optimized - original)
+    pub improvement: f64,
+    /// Number of rounds applied (including the final no-change round)
+    pub rounds: usize,
+    /// Whether a fixed point was reached before the round cap
+    pub converged: bool,
+    /// Number of gates removed relative to the original circuit
+    pub gates_removed: usize,
+}
+
 /// Statistics about the optimization process
 #[derive(Debug, Clone)]
 pub struct OptimizationStats {
@@ -840,6 +1166,7 @@ pub struct OptimizationStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scoring::{estimate_fidelity_with_hardware, estimate_fidelity_with_per_qubit_noise};
 
     fn create_test_circuit() -> CircuitGenome {
         let mut circuit = CircuitGenome::new(2);
@@ -907,6 +1234,57 @@ mod tests {
         assert!(result.fidelity >= 0.0 && result.fidelity <= 1.0);
     }
 
+    #[test]
+    fn test_optimize_with_noise_map() {
+        let circuit = create_commuting_circuit();
+        let mut rewirer = LiveRewirer::new();
+        rewirer.load(circuit.clone()).unwrap();
+        let config = &rewirer.config().score_config;
+
+        let noise_map: Vec<NoiseVector> = [(150.0, 120.0), (40.0, 30.0), (90.0, 70.0)]
+            .iter()
+            .enumerate()
+            .map(|(q, &(t1, t2))| NoiseVector::with_t1t2(q, t1, t2))
+            .collect();
+        let result = rewirer.optimize_with_noise_map(&noise_map, 50).unwrap();
+        assert_eq!(result.circuit.gates.len(), circuit.gates.len());
+        let rescored = estimate_fidelity_with_per_qubit_noise(&result.circuit, &noise_map, config);
+        assert_eq!(result.fidelity, rescored);
+        assert!(
+            result.fidelity >= estimate_fidelity_with_per_qubit_noise(&circuit, &noise_map, config)
+        );
+
+        // A uniform map reproduces the aggregate optimization
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let uniform = rewirer
+            .optimize_with_noise_map(&[noise.clone(), noise.clone(), noise.clone()], 50)
+            .unwrap();
+        let aggregate = rewirer.optimize(&noise, 50).unwrap();
+        assert!((uniform.fidelity - aggregate.fidelity).abs() < 1e-12);
+
+        assert!(matches!(
+            rewirer.optimize_with_noise_map(&noise_map[..2], 50),
+            Err(QnsError::DimensionMismatch(3, 2))
+        ));
+    }
+
+    #[test]
+    fn test_optimize_verbose_reports_all_scores() {
+        let circuit = create_commuting_circuit();
+        let mut rewirer = LiveRewirer::new();
+        rewirer.load(circuit).unwrap();
+
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let (result, scores) = rewirer.optimize_verbose(&noise, 50).unwrap();
+
+        assert_eq!(scores.len(), result.variants_evaluated);
+        let max = scores.iter().map(|&(_, f)| f).fold(f64::MIN, f64::max);
+        assert_eq!(max, result.fidelity);
+
+        let best_hash = rewirer.gate_reorder.circuit_hash(&result.circuit);
+        assert!(scores.contains(&(best_hash, result.fidelity)));
+    }
+
     #[test]
     fn test_optimize_with_commuting_gates() {
         let circuit = create_commuting_circuit();
@@ -931,7 +1309,9 @@ mod tests {
         let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
         let variants = vec![circuit.clone(), circuit.clone()];
 
-        let scored = rewirer.score_all_variants_sequential(&variants, &noise);
+        let scored = rewirer
+            .score_all_variants_sequential(&variants, &noise)
+            .unwrap();
 
         assert_eq!(scored.len(), 2);
         for sv in &scored {
@@ -948,7 +1328,9 @@ mod tests {
         let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
         let variants: Vec<_> = (0..10).map(|_| circuit.clone()).collect();
 
-        let scored = rewirer.score_all_variants_parallel(&variants, &noise);
+        let scored = rewirer
+            .score_all_variants_parallel(&variants, &noise)
+            .unwrap();
 
         assert_eq!(scored.len(), 10);
         for sv in &scored {
@@ -980,6 +1362,79 @@ mod tests {
         assert!((best.unwrap().fidelity - 0.9).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_find_best_variant_tie_break() {
+        let scored = |gates: &[Gate], fidelity: f64| {
+            let mut circuit = CircuitGenome::new(2);
+            circuit.add_gates(gates.iter().cloned()).unwrap();
+            ScoredVariant { circuit, fidelity }
+        };
+
+        // Equal fidelity, deeper variant scored last: a plain max_by would
+        // return the last maximum, i.e. the deeper circuit
+        let shallow = scored(&[Gate::H(0), Gate::H(1)], 0.9);
+        let deep = scored(&[Gate::H(0), Gate::Z(0)], 0.9);
+        let worse = scored(&[Gate::X(0)], 0.8);
+        let variants = [worse.clone(), shallow.clone(), deep.clone()];
+        let best = LiveRewirer::find_best_variant(&variants).unwrap();
+        assert_eq!(best.circuit.gates, shallow.circuit.gates);
+        let variants = [deep, worse, shallow.clone()];
+        let best = LiveRewirer::find_best_variant(&variants).unwrap();
+        assert_eq!(best.circuit.gates, shallow.circuit.gates);
+
+        // Equal fidelity and depth: fewer two-qubit gates wins, wherever it sits
+        let one_cx = scored(&[Gate::CNOT(0, 1), Gate::H(0)], 0.7);
+        let two_cx = scored(&[Gate::CNOT(0, 1), Gate::CZ(0, 1)], 0.7);
+        assert_eq!(one_cx.circuit.depth(), two_cx.circuit.depth());
+        let variants = [one_cx.clone(), two_cx];
+        let best = LiveRewirer::find_best_variant(&variants).unwrap();
+        assert_eq!(best.circuit.gates, one_cx.circuit.gates);
+    }
+
+    #[test]
+    fn test_parallel_and_sequential_pick_same_variant() {
+        // Independent single-qubit gates: many reorderings score identically
+        let mut circuit = CircuitGenome::new(4);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::X(1),
+                Gate::H(2),
+                Gate::X(3),
+                Gate::CNOT(0, 1),
+                Gate::Z(2),
+                Gate::S(3),
+                Gate::CNOT(2, 3),
+            ])
+            .unwrap();
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+
+        let run = |parallel: bool| {
+            let mut rewirer = LiveRewirer::with_config(RewireConfig {
+                parallel,
+                ..Default::default()
+            });
+            rewirer.load(circuit.clone()).unwrap();
+            rewirer.optimize_verbose(&noise, 50).unwrap()
+        };
+        let (sequential, scores) = run(false);
+        let (parallel, _) = run(true);
+
+        let top = scores.iter().map(|(_, f)| *f).fold(f64::MIN, f64::max);
+        let tied = scores.iter().filter(|(_, f)| *f == top).count();
+        assert!(
+            tied > 1 && scores.len() > 4,
+            "{} tied of {}",
+            tied,
+            scores.len()
+        );
+        assert_eq!(
+            format!("{:?}", parallel.circuit.gates),
+            format!("{:?}", sequential.circuit.gates)
+        );
+        assert_eq!(parallel.fidelity.to_bits(), sequential.fidelity.to_bits());
+    }
+
     #[test]
     fn test_find_best_variant_empty() {
         let scored: Vec<ScoredVariant> = vec![];
@@ -1152,6 +1607,95 @@ mod tests {
         assert!(result.strategy.contains("placement"));
     }
 
+    #[test]
+    fn test_crosstalk_weight_steers_placement() {
+        use qns_core::types::Fidelity;
+
+        // Identity edges 0-1, 2-3 are mediocre but isolated; edges 4-5, 6-7 are
+        // better but physical qubits 5 and 6 crosstalk.
+        let mut hw =
+            HardwareProfile::from_coupling_map("xtalk", 8, &[[0, 1], [2, 3], [4, 5], [6, 7]]);
+        for coupler in hw.couplers.iter_mut() {
+            coupler.gate_fidelity = if coupler.qubit1 < 4 {
+                Fidelity::new(0.95)
+            } else {
+                Fidelity::new(0.99)
+            };
+        }
+        hw.crosstalk.set_interaction(5, 6, 0.03);
+
+        // Two CNOTs that execute in the same layer
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::CNOT(2, 3)).unwrap();
+
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let place = |weight: f64| {
+            let mut rewirer = LiveRewirer::with_config(RewireConfig {
+                crosstalk_weight: weight,
+                ..Default::default()
+            });
+            rewirer.load(circuit.clone()).unwrap();
+            rewirer.optimize_with_placement(&noise, &hw, 10).unwrap()
+        };
+
+        // Without the penalty, the high-fidelity crosstalking edges win
+        // (which logical pair lands on which edge is a tie)
+        let unweighted = place(0.0);
+        let mut physical = unweighted.mapping.clone();
+        physical.sort_unstable();
+        assert_eq!(physical, vec![4, 5, 6, 7]);
+
+        // A strong penalty keeps the CNOTs on the separated edges
+        let weighted = place(5.0);
+        assert_eq!(weighted.mapping, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_missing_edge_penalty_steers_placement() {
+        use qns_core::types::Fidelity;
+
+        // Identity edges 0-1, 1-2 are mediocre; the excellent edge 3-4 has no
+        // neighbour left for logical qubit 2 once 0 and 1 are placed on it.
+        let mut hw = HardwareProfile::from_coupling_map("sparse", 5, &[[0, 1], [1, 2], [3, 4]]);
+        for coupler in hw.couplers.iter_mut() {
+            coupler.gate_fidelity = if coupler.qubit1 < 3 {
+                Fidelity::new(0.9)
+            } else {
+                Fidelity::new(0.999)
+            };
+        }
+
+        let mut circuit = CircuitGenome::new(3);
+        for _ in 0..4 {
+            circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        }
+        circuit.add_gate(Gate::CNOT(1, 2)).unwrap();
+
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let place = |penalty: f64| {
+            let mut rewirer = LiveRewirer::with_config(RewireConfig {
+                score_config: ScoreConfig {
+                    missing_edge_penalty: penalty,
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+            rewirer.load(circuit.clone()).unwrap();
+            rewirer.optimize_with_placement(&noise, &hw, 10).unwrap()
+        };
+
+        // The default penalty is cheap enough to accept one unconnected CNOT
+        let lenient = place(0.15);
+        assert_eq!(lenient.mapping, vec![3, 4, 0]);
+        assert!(!hw.are_connected(4, 0));
+
+        // An impossible edge rules that placement out
+        let strict = place(f64::INFINITY);
+        assert_eq!(strict.mapping, vec![0, 1, 2]);
+        assert_eq!(strict.strategy, "fallback_identity");
+    }
+
     #[test]
     fn test_optimize_with_placement_preserves_optimal() {
         // Create hardware with best edge at 0-1
@@ -1345,4 +1889,189 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_pre_mapped_circuit_skips_placement_and_routing() {
+        use qns_core::types::Fidelity;
+
+        // Placement would prefer moving the CNOT off the bad 0-1 edge
+        let mut hw = HardwareProfile::linear("test", 4);
+        hw.couplers[0].gate_fidelity = Fidelity::new(0.80);
+        hw.couplers[2].gate_fidelity = Fidelity::new(0.999);
+
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::X(2)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::Rz(3, 0.4)).unwrap();
+        circuit.add_gate(Gate::CNOT(1, 0)).unwrap();
+        circuit.metadata.pre_mapped = true;
+
+        let placement = PlacementOptimizer::new(100, false).optimize(&circuit, &hw);
+        assert_eq!(placement.mapping, vec![0, 1, 2, 3]);
+
+        let mut rewirer = LiveRewirer::new();
+        rewirer.load(circuit.clone()).unwrap();
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let result = rewirer.optimize_with_routing(&noise, &hw, 50).unwrap();
+
+        assert_eq!(result.strategy, "pre_mapped");
+        assert_eq!(result.mapping, vec![0, 1, 2, 3]);
+        assert_eq!(result.swaps_inserted, 0);
+        // Same gates on the same qubits, but reordering still ran
+        let mut original: Vec<String> = circuit.gates.iter().map(|g| g.to_string()).collect();
+        let mut optimized: Vec<String> =
+            result.circuit.gates.iter().map(|g| g.to_string()).collect();
+        assert_ne!(original, optimized);
+        original.sort();
+        optimized.sort();
+        assert_eq!(original, optimized);
+
+        // Without the flag the same circuit is moved off the bad edge
+        let mut free = circuit.clone();
+        free.metadata.pre_mapped = false;
+        rewirer.load(free).unwrap();
+        let placed = rewirer.optimize_with_routing(&noise, &hw, 50).unwrap();
+        assert_ne!(placed.strategy, "pre_mapped");
+        assert_ne!(placed.mapping, vec![0, 1, 2, 3]);
+        assert!(!placed
+            .circuit
+            .gates
+            .iter()
+            .any(|g| matches!(g, Gate::CNOT(0, 1) | Gate::CNOT(1, 0))));
+
+        // A pre-mapped circuit off the coupling graph is rejected, not routed
+        let mut invalid = CircuitGenome::new(4);
+        invalid.add_gate(Gate::CNOT(0, 2)).unwrap();
+        invalid.add_gate(Gate::H(1)).unwrap();
+        invalid.metadata.pre_mapped = true;
+        rewirer.load(invalid).unwrap();
+        let err = rewirer.optimize_with_routing(&noise, &hw, 50).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Pre-mapped circuit cannot run on test: gate 0"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_optimize_fixed_point_cascades() {
+        use std::f64::consts::FRAC_PI_4;
+
+        // Merging the two Rz(-π/4) yields Rz(-π/2), which cancels the S gate,
+        // which in turn exposes the two Rx rotations for a final merge.
+        let mut circuit = CircuitGenome::new(1);
+        circuit.add_gate(Gate::Rx(0, 0.1)).unwrap();
+        circuit.add_gate(Gate::S(0)).unwrap();
+        circuit.add_gate(Gate::Rz(0, -FRAC_PI_4)).unwrap();
+        circuit.add_gate(Gate::Rz(0, -FRAC_PI_4)).unwrap();
+        circuit.add_gate(Gate::Rx(0, 0.2)).unwrap();
+
+        let single_round = crate::peephole::prune_post_measurement(
+            &crate::peephole::merge_rotations(&crate::peephole::cancel_inverse_pairs(&circuit)),
+        );
+
+        let mut rewirer = LiveRewirer::new();
+        rewirer.load(circuit).unwrap();
+
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let result = rewirer.optimize_fixed_point(&noise, None).unwrap();
+
+        assert!(result.converged);
+        assert!(
+            result.rounds > 2,
+            "Expected multiple rounds, got {}",
+            result.rounds
+        );
+        assert_eq!(single_round.gates.len(), 4);
+        assert!(result.circuit.gates.len() < single_round.gates.len());
+        assert_eq!(result.circuit.gates.len(), 1);
+        match result.circuit.gates[0] {
+            Gate::Rx(0, theta) => assert!((theta - 0.3).abs() < 1e-10),
+            ref other => panic!("Expected merged Rx, got {}", other),
+        }
+        assert_eq!(result.gates_removed, 4);
+        assert!(result.improvement >= 0.0);
+    }
+
+    #[test]
+    fn test_optimize_fixed_point_keeps_frozen_block() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::H(0),
+                Gate::X(1),        // frozen
+                Gate::X(1),        // frozen
+                Gate::Rz(2, 0.1),  // frozen
+                Gate::Rz(2, -0.1), // frozen
+                Gate::Rz(0, 0.2),
+                Gate::Rz(0, 0.3),
+                Gate::CNOT(1, 2),
+            ])
+            .unwrap();
+        circuit.metadata.frozen_regions.push(2..6);
+        let block = format!("{:?}", &circuit.gates[2..6]);
+
+        let mut rewirer = LiveRewirer::new();
+        rewirer.load(circuit).unwrap();
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let result = rewirer.optimize_fixed_point(&noise, None).unwrap();
+
+        // H·H cancels and the free Rz pair merges; the frozen block survives
+        assert_eq!(result.gates_removed, 3);
+        let regions = &result.circuit.metadata.frozen_regions;
+        assert_eq!(regions.len(), 1);
+        assert_eq!(
+            format!("{:?}", &result.circuit.gates[regions[0].clone()]),
+            block
+        );
+    }
+
+    #[test]
+    fn test_strict_scoring_errors_propagate_from_optimizer() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([Gate::H(0), Gate::CNOT(0, 1), Gate::X(1)])
+            .unwrap();
+
+        // A negative error rate pushes the raw estimate above 1
+        let mut noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        noise.gate_error_1q = -0.2;
+
+        let config = RewireConfig {
+            score_config: ScoreConfig {
+                strict: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut rewirer = LiveRewirer::with_config(config);
+        rewirer.load(circuit).unwrap();
+
+        assert!(matches!(
+            rewirer.optimize(&noise, 10),
+            Err(QnsError::Rewire(_))
+        ));
+        assert!(matches!(
+            rewirer.optimize_with_stats(&noise, 10),
+            Err(QnsError::Rewire(_))
+        ));
+        assert!(matches!(
+            rewirer.optimize_fixed_point(&noise, None),
+            Err(QnsError::Rewire(_))
+        ));
+        assert!(matches!(
+            rewirer.optimize_with_noise_map(&[noise.clone(), noise], 10),
+            Err(QnsError::Rewire(_))
+        ));
+    }
+
+    #[test]
+    fn test_optimize_fixed_point_no_circuit() {
+        let rewirer = LiveRewirer::new();
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        assert!(rewirer.optimize_fixed_point(&noise, None).is_err());
+    }
 }
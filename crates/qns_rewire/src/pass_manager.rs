@@ -0,0 +1,368 @@
+// QNS v2.0 - pass_manager.rs
+// Composable optimization pipeline: ordered circuit passes sharing a context
+
+use crate::gate_reorder::GateReorder;
+use crate::peephole::{cancel_inverse_pairs, merge_rotations, prune_post_measurement};
+use crate::router::{NoiseAwareRouter, PlacementOptimizer, SabreRouter};
+use crate::scoring::{
+    try_estimate_fidelity_with_hardware, try_estimate_fidelity_with_idle_tracking, ScoreConfig,
+};
+use qns_core::prelude::{CircuitGenome, HardwareProfile, NoiseVector};
+use qns_core::{QnsError, Result};
+
+/// State shared by the passes of a [`PassManager`].
+///
+/// Placement passes record the layout they choose here so a later routing
+/// pass can apply it.
+#[derive(Debug, Clone, Default)]
+pub struct PassContext {
+    /// Target hardware; required by placement and routing passes
+    pub hardware: Option<HardwareProfile>,
+    /// Noise model used to score candidate circuits
+    pub noise: NoiseVector,
+    /// Timing and scoring options
+    pub score_config: ScoreConfig,
+    /// Logical-to-physical layout chosen by a placement pass
+    pub initial_layout: Option<Vec<usize>>,
+    /// Logical-to-physical layout after the last routing pass
+    pub final_layout: Option<Vec<usize>>,
+}
+
+impl PassContext {
+    /// Creates a context scoring with `noise` and no target hardware.
+    pub fn new(noise: NoiseVector) -> Self {
+        Self {
+            noise,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the target hardware.
+    pub fn with_hardware(mut self, hardware: HardwareProfile) -> Self {
+        self.hardware = Some(hardware);
+        self
+    }
+
+    /// Returns the target hardware, or an error naming the pass that needs it.
+    fn require_hardware(&self, pass: &str) -> Result<&HardwareProfile> {
+        self.hardware
+            .as_ref()
+            .ok_or_else(|| QnsError::rewire(format!("{} pass requires target hardware", pass)))
+    }
+
+    /// Fidelity estimate used to compare candidate circuits.
+    fn score(&self, circuit: &CircuitGenome) -> Result<f64> {
+        let fidelity = match &self.hardware {
+            Some(hw) => {
+                try_estimate_fidelity_with_hardware(circuit, &self.noise, hw, &self.score_config)?
+            },
+            None => {
+                try_estimate_fidelity_with_idle_tracking(circuit, &self.noise, &self.score_config)?
+            },
+        };
+        Ok(fidelity)
+    }
+}
+
+/// A single step of an optimization pipeline.
+pub trait CircuitPass {
+    /// Short name used in error messages.
+    fn name(&self) -> &str;
+
+    /// Transforms `circuit`, reading and updating the shared context.
+    fn run(&self, circuit: &CircuitGenome, ctx: &mut PassContext) -> Result<CircuitGenome>;
+}
+
+/// Ordered list of passes that a circuit is threaded through.
+///
+/// # Example
+///
+/// ```
+/// use qns_core::prelude::*;
+/// use qns_rewire::pass_manager::{MergeRotationsPass, PassContext, PassManager, RoutePass};
+///
+/// let mut circuit = CircuitGenome::new(3);
+/// circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+///
+/// let ctx = PassContext::default().with_hardware(HardwareProfile::linear("line", 3));
+/// let mut manager = PassManager::new(ctx)
+///     .with_pass(MergeRotationsPass)
+///     .with_pass(RoutePass::default());
+/// let routed = manager.run(&circuit).unwrap();
+/// assert!(manager.context().hardware.as_ref().unwrap().is_circuit_valid(&routed));
+/// ```
+#[derive(Default)]
+pub struct PassManager {
+    passes: Vec<Box<dyn CircuitPass>>,
+    context: PassContext,
+}
+
+impl PassManager {
+    /// Creates an empty pipeline with the given context.
+    pub fn new(context: PassContext) -> Self {
+        Self {
+            passes: Vec::new(),
+            context,
+        }
+    }
+
+    /// Appends a pass (builder style).
+    pub fn with_pass(mut self, pass: impl CircuitPass + 'static) -> Self {
+        self.add_pass(pass);
+        self
+    }
+
+    /// Appends a pass.
+    pub fn add_pass(&mut self, pass: impl CircuitPass + 'static) {
+        self.passes.push(Box::new(pass));
+    }
+
+    /// Names of the passes, in execution order.
+    pub fn pass_names(&self) -> Vec<&str> {
+        self.passes.iter().map(|p| p.name()).collect()
+    }
+
+    /// Shared context, including layouts recorded by the last run.
+    pub fn context(&self) -> &PassContext {
+        &self.context
+    }
+
+    /// Runs every pass in order, feeding each the previous pass's output.
+    ///
+    /// Stops at the first failing pass; the error names it.
+    pub fn run(&mut self, circuit: &CircuitGenome) -> Result<CircuitGenome> {
+        let mut current = circuit.clone();
+        for pass in &self.passes {
+            current = pass
+                .run(&current, &mut self.context)
+                .map_err(|e| QnsError::rewire(format!("pass '{}' failed: {}", pass.name(), e)))?;
+        }
+        Ok(current)
+    }
+}
+
+/// Merges adjacent same-axis rotations ([`merge_rotations`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MergeRotationsPass;
+
+impl CircuitPass for MergeRotationsPass {
+    fn name(&self) -> &str {
+        "merge-rotations"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, _ctx: &mut PassContext) -> Result<CircuitGenome> {
+        Ok(merge_rotations(circuit))
+    }
+}
+
+/// Cancels adjacent inverse pairs ([`cancel_inverse_pairs`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CancelInversePairsPass;
+
+impl CircuitPass for CancelInversePairsPass {
+    fn name(&self) -> &str {
+        "cancel-inverse-pairs"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, _ctx: &mut PassContext) -> Result<CircuitGenome> {
+        Ok(cancel_inverse_pairs(circuit))
+    }
+}
+
+/// Drops gates after a qubit's final measurement ([`prune_post_measurement`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrunePostMeasurementPass;
+
+impl CircuitPass for PrunePostMeasurementPass {
+    fn name(&self) -> &str {
+        "prune-post-measurement"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, _ctx: &mut PassContext) -> Result<CircuitGenome> {
+        Ok(prune_post_measurement(circuit))
+    }
+}
+
+/// Replaces the circuit with its best-scoring commutation reordering.
+///
+/// The input is kept unless a variant scores strictly higher.
+#[derive(Default)]
+pub struct ReorderPass {
+    /// Variant generator
+    pub reorder: GateReorder,
+}
+
+impl ReorderPass {
+    /// Creates a reorder pass using `reorder` to generate variants.
+    pub fn new(reorder: GateReorder) -> Self {
+        Self { reorder }
+    }
+}
+
+impl CircuitPass for ReorderPass {
+    fn name(&self) -> &str {
+        "reorder"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, ctx: &mut PassContext) -> Result<CircuitGenome> {
+        let mut best = circuit.clone();
+        let mut best_score = ctx.score(circuit)?;
+        for variant in self.reorder.generate_reorderings(circuit) {
+            let score = ctx.score(&variant)?;
+            if score > best_score {
+                best = variant;
+                best_score = score;
+            }
+        }
+        Ok(best)
+    }
+}
+
+/// Chooses a layout with [`PlacementOptimizer`] and records it in
+/// [`PassContext::initial_layout`].
+///
+/// The circuit itself is returned unchanged; a following [`RoutePass`]
+/// applies the layout.
+#[derive(Default)]
+pub struct PlacementPass {
+    /// Layout search
+    pub optimizer: PlacementOptimizer,
+}
+
+impl PlacementPass {
+    /// Creates a placement pass using `optimizer`.
+    pub fn new(optimizer: PlacementOptimizer) -> Self {
+        Self { optimizer }
+    }
+}
+
+impl CircuitPass for PlacementPass {
+    fn name(&self) -> &str {
+        "placement"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, ctx: &mut PassContext) -> Result<CircuitGenome> {
+        let hardware = ctx.require_hardware(self.name())?;
+        let placement = self.optimizer.optimize(circuit, hardware);
+        ctx.initial_layout = Some(placement.mapping);
+        Ok(circuit.clone())
+    }
+}
+
+/// Routes with [`NoiseAwareRouter`], starting from the recorded initial
+/// layout (or the identity when no placement pass ran).
+#[derive(Default)]
+pub struct RoutePass {
+    /// Router
+    pub router: NoiseAwareRouter,
+}
+
+impl RoutePass {
+    /// Creates a routing pass using `router`.
+    pub fn new(router: NoiseAwareRouter) -> Self {
+        Self { router }
+    }
+}
+
+impl CircuitPass for RoutePass {
+    fn name(&self) -> &str {
+        "route"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, ctx: &mut PassContext) -> Result<CircuitGenome> {
+        let hardware = ctx.require_hardware(self.name())?;
+        let layout = ctx
+            .initial_layout
+            .clone()
+            .unwrap_or_else(|| (0..circuit.num_qubits).collect());
+        self.router.route_with_mapping(circuit, hardware, &layout)
+    }
+}
+
+/// Routes with [`SabreRouter`], which picks its own initial layout, and
+/// records the final layout in [`PassContext::final_layout`].
+#[derive(Default)]
+pub struct SabreRoutePass {
+    /// Router
+    pub router: SabreRouter,
+}
+
+impl SabreRoutePass {
+    /// Creates a SABRE routing pass using `router`.
+    pub fn new(router: SabreRouter) -> Self {
+        Self { router }
+    }
+}
+
+impl CircuitPass for SabreRoutePass {
+    fn name(&self) -> &str {
+        "sabre-route"
+    }
+
+    fn run(&self, circuit: &CircuitGenome, ctx: &mut PassContext) -> Result<CircuitGenome> {
+        let hardware = ctx.require_hardware(self.name())?;
+        let (routed, layout) = self.router.route(circuit, hardware)?;
+        ctx.final_layout = Some(layout);
+        Ok(routed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qns_core::prelude::Gate;
+
+    #[test]
+    fn test_pass_manager_pipeline() {
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::Rz(0, 0.1)).unwrap();
+        circuit.add_gate(Gate::Rz(0, 0.2)).unwrap();
+        circuit.add_gate(Gate::H(1)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 3)).unwrap();
+        circuit.add_gate(Gate::CNOT(1, 2)).unwrap();
+        circuit.add_gate(Gate::H(3)).unwrap();
+
+        let hw = HardwareProfile::linear("line", 4);
+        let ctx =
+            PassContext::new(NoiseVector::with_t1t2(0, 100.0, 80.0)).with_hardware(hw.clone());
+        let mut manager = PassManager::new(ctx)
+            .with_pass(MergeRotationsPass)
+            .with_pass(ReorderPass::default())
+            .with_pass(RoutePass::default());
+        assert_eq!(
+            manager.pass_names(),
+            ["merge-rotations", "reorder", "route"]
+        );
+
+        let out = manager.run(&circuit).unwrap();
+        assert!(hw.is_circuit_valid(&out), "{:?}", hw.validate_circuit(&out));
+        let rz: Vec<_> = out
+            .gates
+            .iter()
+            .filter(|g| matches!(g, Gate::Rz(..)))
+            .collect();
+        assert_eq!(rz.len(), 1);
+
+        // Routing without hardware names the failing pass
+        let mut headless = PassManager::default().with_pass(RoutePass::default());
+        let err = headless.run(&circuit).unwrap_err().to_string();
+        assert!(err.contains("'route'"), "{}", err);
+    }
+
+    #[test]
+    fn test_placement_layout_reaches_router() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+
+        let hw = HardwareProfile::linear("line", 3);
+        let mut manager = PassManager::new(PassContext::default().with_hardware(hw.clone()))
+            .with_pass(PlacementPass::default())
+            .with_pass(RoutePass::default());
+        let out = manager.run(&circuit).unwrap();
+
+        let layout = manager.context().initial_layout.clone().unwrap();
+        assert_eq!(layout.len(), 2);
+        assert_eq!(out.gates, vec![Gate::CNOT(layout[0], layout[1])]);
+        assert!(hw.is_circuit_valid(&out));
+    }
+}
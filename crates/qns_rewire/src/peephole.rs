@@ -0,0 +1,387 @@
+// QNS v2.0 - peephole.rs
+// Local simplification passes: inverse cancellation, rotation merging, dead-gate pruning
+
+use crate::gate_reorder::map_unfrozen;
+use qns_core::prelude::{CircuitGenome, Gate};
+use std::f64::consts::PI;
+use std::ops::Range;
+
+/// Tolerance for treating two angles as equal (or a rotation as identity)
+const ANGLE_TOLERANCE: f64 = 1e-10;
+
+/// Index of the last gate in `gates` that touches any of `qubits`.
+fn last_touching(gates: &[Gate], qubits: &[usize]) -> Option<usize> {
+    gates
+        .iter()
+        .rposition(|g| g.qubits().iter().any(|q| qubits.contains(q)))
+}
+
+/// Normalizes an angle into (-π, π].
+///
+/// Rotations differing by 2π only differ by a global phase.
+fn normalize_angle(theta: f64) -> f64 {
+    let mut a = theta % (2.0 * PI);
+    if a <= -PI {
+        a += 2.0 * PI;
+    } else if a > PI {
+        a -= 2.0 * PI;
+    }
+    a
+}
+
+/// Returns true if two gates are equal up to angle tolerance.
+fn gates_approx_equal(a: &Gate, b: &Gate) -> bool {
+    match (a, b) {
+        (Gate::Rx(q1, t1), Gate::Rx(q2, t2))
+        | (Gate::Ry(q1, t1), Gate::Ry(q2, t2))
+        | (Gate::Rz(q1, t1), Gate::Rz(q2, t2)) => {
+            q1 == q2 && normalize_angle(t1 - t2).abs() < ANGLE_TOLERANCE
+        },
+        _ => a == b,
+    }
+}
+
+/// Writes gates that are symmetric in their two qubits (SWAP, CZ, CPhase,
+/// FSim) with the lower qubit first, so `SWAP(1, 0)` compares equal to
+/// `SWAP(0, 1)`.
+fn symmetric_form(gate: &Gate) -> Gate {
+    match *gate {
+        Gate::SWAP(a, b) if a > b => Gate::SWAP(b, a),
+        Gate::CZ(a, b) if a > b => Gate::CZ(b, a),
+        Gate::CPhase(a, b, lambda) if a > b => Gate::CPhase(b, a, lambda),
+        Gate::FSim(a, b, theta, phi) if a > b => Gate::FSim(b, a, theta, phi),
+        _ => gate.clone(),
+    }
+}
+
+/// Returns true if `b` undoes `a` (up to global phase).
+///
+/// Qubit order is ignored for gates symmetric in their qubits.
+fn is_inverse_pair(a: &Gate, b: &Gate) -> bool {
+    let (a, b) = (&symmetric_form(a), &symmetric_form(b));
+    match (a.inverse(), b.inverse()) {
+        (Some(inv_a), _) if gates_approx_equal(&inv_a, b) => true,
+        (_, Some(inv_b)) => gates_approx_equal(&inv_b, a),
+        _ => false,
+    }
+}
+
+/// Merges two same-axis rotations on the same qubit.
+fn merge_pair(a: &Gate, b: &Gate) -> Option<Gate> {
+    match (a, b) {
+        (Gate::Rx(q1, t1), Gate::Rx(q2, t2)) if q1 == q2 => Some(Gate::Rx(*q1, t1 + t2)),
+        (Gate::Ry(q1, t1), Gate::Ry(q2, t2)) if q1 == q2 => Some(Gate::Ry(*q1, t1 + t2)),
+        (Gate::Rz(q1, t1), Gate::Rz(q2, t2)) if q1 == q2 => Some(Gate::Rz(*q1, t1 + t2)),
+        _ => None,
+    }
+}
+
+/// Returns true if the gate is a rotation by an angle equivalent to identity.
+fn is_identity_rotation(gate: &Gate) -> bool {
+    match gate {
+        Gate::Rx(_, theta) | Gate::Ry(_, theta) | Gate::Rz(_, theta) => {
+            normalize_angle(*theta).abs() < ANGLE_TOLERANCE
+        },
+        _ => false,
+    }
+}
+
+/// Builds a circuit with the same width and metadata as `circuit` but new gates.
+///
+/// Routing tags are dropped since gate positions change; `frozen_regions`
+/// gives the frozen regions re-indexed into `gates`.
+fn with_gates(
+    circuit: &CircuitGenome,
+    gates: Vec<Gate>,
+    frozen_regions: Vec<Range<usize>>,
+) -> CircuitGenome {
+    let mut metadata = circuit.metadata.clone();
+    metadata.routing_gates.clear();
+    metadata.frozen_regions = frozen_regions;
+    CircuitGenome {
+        num_qubits: circuit.num_qubits,
+        gates,
+        metadata,
+        qns_format_version: circuit.qns_format_version,
+    }
+}
+
+/// Removes pairs of mutually inverse gates that are adjacent on their wires.
+///
+/// Two gates are adjacent when no gate between them touches any of their qubits,
+/// e.g. `H(0) X(1) H(0)` reduces to `X(1)`. Cancellations cascade, so
+/// `H(0) X(0) X(0) H(0)` is fully removed in a single pass. Gates in
+/// [frozen regions](qns_core::CircuitMetadata::frozen_regions) are kept
+/// verbatim and nothing cancels across them.
+pub fn cancel_inverse_pairs(circuit: &CircuitGenome) -> CircuitGenome {
+    if !circuit.metadata.frozen_regions.is_empty() {
+        return map_unfrozen(circuit, cancel_inverse_pairs);
+    }
+    let mut out: Vec<Gate> = Vec::with_capacity(circuit.gates.len());
+
+    for gate in &circuit.gates {
+        let qubits = gate.qubits();
+        if let Some(idx) = last_touching(&out, &qubits) {
+            let prev = &out[idx];
+            let same_wires = prev.qubits().len() == qubits.len()
+                && prev.qubits().iter().all(|q| qubits.contains(q));
+            if same_wires && is_inverse_pair(prev, gate) {
+                out.remove(idx);
+                continue;
+            }
+        }
+        out.push(gate.clone());
+    }
+
+    with_gates(circuit, out, Vec::new())
+}
+
+/// Merges consecutive same-axis rotations on a qubit into a single rotation.
+///
+/// `Rz(0, a) Rz(0, b)` becomes `Rz(0, a + b)`; merged rotations equivalent to
+/// identity (angle ≡ 0 mod 2π) are dropped entirely. Frozen regions are
+/// kept verbatim and nothing merges across them.
+pub fn merge_rotations(circuit: &CircuitGenome) -> CircuitGenome {
+    if !circuit.metadata.frozen_regions.is_empty() {
+        return map_unfrozen(circuit, merge_rotations);
+    }
+    let mut out: Vec<Gate> = Vec::with_capacity(circuit.gates.len());
+
+    for gate in &circuit.gates {
+        let qubits = gate.qubits();
+        if let Some(idx) = last_touching(&out, &qubits) {
+            if let Some(merged) = merge_pair(&out[idx], gate) {
+                if is_identity_rotation(&merged) {
+                    out.remove(idx);
+                } else {
+                    out[idx] = merged;
+                }
+                continue;
+            }
+        }
+        if !is_identity_rotation(gate) {
+            out.push(gate.clone());
+        }
+    }
+
+    with_gates(circuit, out, Vec::new())
+}
+
+/// Removes gates that cannot influence any measurement outcome.
+///
+/// Walks the circuit backwards tracking which qubits are still "live": qubits
+/// that are never measured (their final state is the output) and qubits about
+/// to be measured. A gate touching no live qubit is dropped; a kept gate makes
+/// all of its qubits live, since entangling gates propagate information.
+/// Frozen gates are always kept.
+pub fn prune_post_measurement(circuit: &CircuitGenome) -> CircuitGenome {
+    let mut live = vec![true; circuit.num_qubits];
+    for gate in &circuit.gates {
+        if let Gate::Measure(q) = gate {
+            if let Some(slot) = live.get_mut(*q) {
+                *slot = false;
+            }
+        }
+    }
+
+    let mut keep = vec![false; circuit.gates.len()];
+    for (index, gate) in circuit.gates.iter().enumerate().rev() {
+        if let Gate::Measure(q) = gate {
+            if let Some(slot) = live.get_mut(*q) {
+                *slot = true;
+            }
+            keep[index] = true;
+            continue;
+        }
+
+        let qubits = gate.qubits();
+        if circuit.metadata.is_frozen(index)
+            || qubits.iter().any(|&q| live.get(q).copied().unwrap_or(true))
+        {
+            for &q in &qubits {
+                if let Some(slot) = live.get_mut(q) {
+                    *slot = true;
+                }
+            }
+            keep[index] = true;
+        }
+    }
+
+    // Kept gates before each old index give its new position
+    let mut new_position = Vec::with_capacity(keep.len() + 1);
+    let mut kept = Vec::with_capacity(keep.len());
+    for (gate, &keep) in circuit.gates.iter().zip(&keep) {
+        new_position.push(kept.len());
+        if keep {
+            kept.push(gate.clone());
+        }
+    }
+    new_position.push(kept.len());
+    let len = circuit.gates.len();
+    let frozen_regions = circuit
+        .metadata
+        .frozen_regions
+        .iter()
+        .map(|r| new_position[r.start.min(len)]..new_position[r.end.min(len)])
+        .collect();
+
+    with_gates(circuit, kept, frozen_regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circuit_of(num_qubits: usize, gates: &[Gate]) -> CircuitGenome {
+        let mut circuit = CircuitGenome::new(num_qubits);
+        circuit.add_gates(gates.iter().cloned()).unwrap();
+        circuit
+    }
+
+    #[test]
+    fn test_cancel_adjacent_self_inverse() {
+        let circuit = circuit_of(2, &[Gate::H(0), Gate::X(1), Gate::H(0)]);
+        let result = cancel_inverse_pairs(&circuit);
+        assert_eq!(result.gates, vec![Gate::X(1)]);
+    }
+
+    #[test]
+    fn test_passes_drop_stale_routing_tags() {
+        let mut circuit = circuit_of(3, &[Gate::H(0), Gate::H(0)]);
+        circuit.add_routing_gate(Gate::SWAP(1, 2)).unwrap();
+        assert_eq!(circuit.metadata.routing_gates, vec![2]);
+
+        // The SWAP moves to index 0, so the old index must not survive
+        let result = cancel_inverse_pairs(&circuit);
+        assert_eq!(result.gates, vec![Gate::SWAP(1, 2)]);
+        assert!(result.metadata.routing_gates.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_cascades() {
+        let circuit = circuit_of(1, &[Gate::H(0), Gate::X(0), Gate::X(0), Gate::H(0)]);
+        assert!(cancel_inverse_pairs(&circuit).gates.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_blocked_by_interleaved_gate() {
+        let circuit = circuit_of(2, &[Gate::CNOT(0, 1), Gate::X(1), Gate::CNOT(0, 1)]);
+        assert_eq!(cancel_inverse_pairs(&circuit).gates.len(), 3);
+
+        // Reversed CNOT is not the inverse
+        let circuit = circuit_of(2, &[Gate::CNOT(0, 1), Gate::CNOT(1, 0)]);
+        assert_eq!(cancel_inverse_pairs(&circuit).gates.len(), 2);
+    }
+
+    #[test]
+    fn test_cancel_symmetric_two_qubit_gates() {
+        let circuit = circuit_of(2, &[Gate::SWAP(0, 1), Gate::SWAP(1, 0)]);
+        assert!(cancel_inverse_pairs(&circuit).gates.is_empty());
+
+        let circuit = circuit_of(2, &[Gate::CZ(0, 1), Gate::CZ(1, 0)]);
+        assert!(cancel_inverse_pairs(&circuit).gates.is_empty());
+
+        let circuit = circuit_of(3, &[Gate::CPhase(2, 0, 0.4), Gate::CPhase(0, 2, -0.4)]);
+        assert!(cancel_inverse_pairs(&circuit).gates.is_empty());
+
+        // Different pairs and non-inverse angles are kept
+        let circuit = circuit_of(3, &[Gate::SWAP(0, 1), Gate::SWAP(1, 2)]);
+        assert_eq!(cancel_inverse_pairs(&circuit).gates.len(), 2);
+        let circuit = circuit_of(2, &[Gate::CPhase(1, 0, 0.4), Gate::CPhase(0, 1, 0.4)]);
+        assert_eq!(cancel_inverse_pairs(&circuit).gates.len(), 2);
+    }
+
+    #[test]
+    fn test_cancel_s_with_rz() {
+        let circuit = circuit_of(1, &[Gate::S(0), Gate::Rz(0, -PI / 2.0)]);
+        assert!(cancel_inverse_pairs(&circuit).gates.is_empty());
+    }
+
+    #[test]
+    fn test_merge_rotations() {
+        let circuit = circuit_of(2, &[Gate::Rz(0, 0.2), Gate::X(1), Gate::Rz(0, 0.3)]);
+        let result = merge_rotations(&circuit);
+        assert_eq!(result.gates.len(), 2);
+        assert!(gates_approx_equal(&result.gates[0], &Gate::Rz(0, 0.5)));
+    }
+
+    #[test]
+    fn test_merge_rotations_drops_identity() {
+        let circuit = circuit_of(1, &[Gate::Rx(0, PI), Gate::Rx(0, PI), Gate::H(0)]);
+        assert_eq!(merge_rotations(&circuit).gates, vec![Gate::H(0)]);
+    }
+
+    #[test]
+    fn test_prune_post_measurement() {
+        let circuit = circuit_of(
+            2,
+            &[
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::Measure(0),
+                Gate::X(0),
+                Gate::H(1),
+            ],
+        );
+        let result = prune_post_measurement(&circuit);
+        // X(0) after the final measurement of qubit 0 is dead; qubit 1 is unmeasured
+        assert_eq!(
+            result.gates,
+            vec![Gate::H(0), Gate::CNOT(0, 1), Gate::Measure(0), Gate::H(1)]
+        );
+    }
+
+    #[test]
+    fn test_prune_keeps_gates_feeding_later_measurement() {
+        let circuit = circuit_of(
+            2,
+            &[
+                Gate::Measure(0),
+                Gate::X(0),
+                Gate::CNOT(0, 1),
+                Gate::Measure(1),
+            ],
+        );
+        assert_eq!(prune_post_measurement(&circuit).gates.len(), 4);
+    }
+
+    #[test]
+    fn test_passes_keep_frozen_regions() {
+        let mut circuit = circuit_of(
+            2,
+            &[
+                Gate::H(0),
+                Gate::X(0),       // frozen
+                Gate::X(0),       // frozen
+                Gate::Rz(1, 0.2), // frozen
+                Gate::Rz(1, 0.3),
+                Gate::H(0),
+                Gate::Measure(0),
+                Gate::Z(0),
+            ],
+        );
+        circuit.metadata.frozen_regions.push(1..4);
+
+        // H·(X·X)·H would cancel entirely without the frozen block
+        let cancelled = cancel_inverse_pairs(&circuit);
+        assert_eq!(cancelled.gates, circuit.gates);
+        assert_eq!(cancelled.metadata.frozen_regions, vec![1..4]);
+
+        // The free Rz does not merge into the frozen one
+        assert_eq!(merge_rotations(&circuit).gates, circuit.gates);
+
+        // Dead gates go and the frozen region shifts with them
+        circuit.num_qubits = 3;
+        circuit.gates.splice(0..0, [Gate::Measure(2), Gate::X(2)]);
+        circuit.metadata.frozen_regions[0] = 3..6;
+        let pruned = prune_post_measurement(&circuit);
+        assert_eq!(pruned.gates.len(), circuit.gates.len() - 2);
+        assert_eq!(pruned.metadata.frozen_regions, vec![2..5]);
+        assert_eq!(pruned.gates[2..5], circuit.gates[3..6]);
+
+        // A frozen gate is kept even when dead
+        let mut dead = circuit_of(1, &[Gate::Measure(0), Gate::X(0)]);
+        dead.metadata.frozen_regions.push(1..2);
+        assert_eq!(prune_post_measurement(&dead).gates.len(), 2);
+    }
+}
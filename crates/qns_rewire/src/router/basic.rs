@@ -13,6 +13,7 @@ impl Router for BasicRouter {
         circuit: &CircuitGenome,
         hardware: &HardwareProfile,
     ) -> Result<CircuitGenome, QnsError> {
+        let circuit = &circuit.decompose_toffoli();
         let mut new_circuit = CircuitGenome::new(hardware.num_qubits);
 
         // Initial mapping: logical q -> physical q (trivial 1:1)
@@ -33,7 +34,11 @@ impl Router for BasicRouter {
             let gate = &circuit.gates[gate_idx];
 
             match gate {
-                Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
+                Gate::CNOT(c, t)
+                | Gate::CZ(c, t)
+                | Gate::CPhase(c, t, _)
+                | Gate::SWAP(c, t)
+                | Gate::FSim(c, t, _, _) => {
                     let phys_c = logical_to_physical[*c];
                     let phys_t = logical_to_physical[*t];
 
@@ -104,7 +109,7 @@ impl Router for BasicRouter {
 
                         if let Some((u, v)) = best_swap {
                             // Apply Best SWAP
-                            new_circuit.add_gate(Gate::SWAP(u, v))?;
+                            new_circuit.add_routing_gate(Gate::SWAP(u, v))?;
 
                             let log_u = physical_to_logical[u];
                             let log_v = physical_to_logical[v];
@@ -173,7 +178,11 @@ impl BasicRouter {
 
         for i in 0..limit {
             match &future_gates[i] {
-                Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
+                Gate::CNOT(c, t)
+                | Gate::CZ(c, t)
+                | Gate::CPhase(c, t, _)
+                | Gate::SWAP(c, t)
+                | Gate::FSim(c, t, _, _) => {
                     let phys_c = mapping[*c];
                     let phys_t = mapping[*t];
                     let dist = self.get_distance(phys_c, phys_t, hardware);
@@ -246,6 +255,13 @@ mod tests {
 
         // Should have at least one SWAP and one CNOT
         assert!(routed.gates.len() >= 2);
+
+        // Exactly the inserted SWAPs are tagged as routing gates
+        let swaps: Vec<usize> = (0..routed.gates.len())
+            .filter(|&i| matches!(routed.gates[i], Gate::SWAP(..)))
+            .collect();
+        assert!(!swaps.is_empty());
+        assert_eq!(routed.metadata.routing_gates, swaps);
     }
 
     #[test]
@@ -0,0 +1,242 @@
+use qns_core::types::Gate;
+use qns_core::{CircuitGenome, HardwareProfile, QnsError};
+use std::collections::BTreeMap;
+
+/// Path search steps before giving up on finding a chain of qubits.
+const CHAIN_SEARCH_BUDGET: usize = 100_000;
+
+/// Returns true for gates that are diagonal in the computational basis.
+fn is_diagonal(gate: &Gate) -> bool {
+    matches!(
+        gate,
+        Gate::Z(_) | Gate::S(_) | Gate::T(_) | Gate::Rz(..) | Gate::CZ(..) | Gate::CPhase(..)
+    )
+}
+
+/// Routes a layer of commuting diagonal gates (e.g. a QAOA cost layer) with
+/// the linear SWAP network instead of gate-by-gate routing.
+///
+/// Since every gate in the layer commutes with every other, each two-qubit
+/// gate can be applied whenever its qubits happen to be adjacent. Logical
+/// qubit `i` starts on the `i`-th qubit of a chain found in `hardware`, and
+/// alternating even/odd layers of nearest-neighbour SWAPs move the qubits
+/// along it until every interacting pair has met. For a complete interaction
+/// graph on `n` qubits this takes `(n-1)(n-2)/2` SWAPs, the minimum for a line.
+///
+/// # Returns
+/// The routed circuit on `hardware.num_qubits` qubits, with SWAPs tagged as
+/// routing gates, and the final logical-to-physical mapping.
+///
+/// # Errors
+/// Fails if any gate is not diagonal (Z, S, T, Rz, CZ, CPhase), or if
+/// `hardware` has no simple path through as many qubits as the layer uses.
+pub fn route_diagonal_layer(
+    gates: &[Gate],
+    hardware: &HardwareProfile,
+) -> Result<(CircuitGenome, Vec<usize>), QnsError> {
+    if let Some((index, gate)) = gates.iter().enumerate().find(|(_, g)| !is_diagonal(g)) {
+        return Err(QnsError::Rewire(format!(
+            "Not a diagonal layer: gate {} ({}) is not diagonal",
+            index, gate
+        )));
+    }
+
+    let num_logical = gates
+        .iter()
+        .flat_map(Gate::qubits)
+        .max()
+        .map_or(0, |q| q + 1);
+    if num_logical > hardware.num_qubits {
+        return Err(QnsError::InvalidQubit(num_logical - 1, hardware.num_qubits));
+    }
+    let chain = find_chain(hardware, num_logical).ok_or_else(|| {
+        QnsError::Rewire(format!(
+            "No path through {} connected qubits for the SWAP network",
+            num_logical
+        ))
+    })?;
+
+    // at[p] = logical qubit on chain position p; mapping[l] = physical qubit
+    let mut at: Vec<usize> = (0..num_logical).collect();
+    let mut mapping: Vec<usize> = chain.clone();
+    let mut routed = CircuitGenome::new(hardware.num_qubits);
+
+    // Two-qubit gates grouped by unordered pair, in their original order
+    let mut pending: BTreeMap<(usize, usize), Vec<&Gate>> = BTreeMap::new();
+    for gate in gates {
+        match gate.qubits()[..] {
+            [a, b] => pending.entry((a.min(b), a.max(b))).or_default().push(gate),
+            _ => routed.add_gate(gate.map_qubits(&mapping))?,
+        }
+    }
+
+    for position in 0..num_logical.saturating_sub(1) {
+        emit_pair(
+            &mut pending,
+            (at[position], at[position + 1]),
+            &mapping,
+            &mut routed,
+        )?;
+    }
+
+    // Odd-even transposition: a full reversal makes every pair adjacent once
+    'layers: for layer in 0..num_logical {
+        for position in (layer % 2..num_logical.saturating_sub(1)).step_by(2) {
+            if pending.is_empty() {
+                break 'layers;
+            }
+            let (p, q) = (chain[position], chain[position + 1]);
+            routed.add_routing_gate(Gate::SWAP(p, q))?;
+            at.swap(position, position + 1);
+            mapping[at[position]] = p;
+            mapping[at[position + 1]] = q;
+
+            // The swapped qubits each gained a new neighbour on either side
+            let first = position.saturating_sub(1);
+            let last = (position + 2).min(num_logical - 1);
+            for left in first..last {
+                emit_pair(
+                    &mut pending,
+                    (at[left], at[left + 1]),
+                    &mapping,
+                    &mut routed,
+                )?;
+            }
+        }
+    }
+
+    Ok((routed, mapping))
+}
+
+/// Emits every pending gate between logical qubits `pair`, now adjacent.
+fn emit_pair(
+    pending: &mut BTreeMap<(usize, usize), Vec<&Gate>>,
+    (a, b): (usize, usize),
+    mapping: &[usize],
+    routed: &mut CircuitGenome,
+) -> Result<(), QnsError> {
+    if let Some(pair_gates) = pending.remove(&(a.min(b), a.max(b))) {
+        for gate in pair_gates {
+            routed.add_gate(gate.map_qubits(mapping))?;
+        }
+    }
+    Ok(())
+}
+
+/// Finds a simple path through `len` physical qubits.
+///
+/// Prefers `0, 1, …, len-1` when those are connected in order, otherwise
+/// searches depth-first within [`CHAIN_SEARCH_BUDGET`] steps.
+fn find_chain(hardware: &HardwareProfile, len: usize) -> Option<Vec<usize>> {
+    if (1..len).all(|q| hardware.are_connected(q - 1, q)) {
+        return Some((0..len).collect());
+    }
+
+    fn extend(
+        hardware: &HardwareProfile,
+        path: &mut Vec<usize>,
+        used: &mut [bool],
+        len: usize,
+        budget: &mut usize,
+    ) -> bool {
+        if path.len() == len {
+            return true;
+        }
+        if *budget == 0 {
+            return false;
+        }
+        *budget -= 1;
+        let last = *path.last().expect("path starts non-empty");
+        for next in hardware.neighbors(last) {
+            if !used[next] {
+                used[next] = true;
+                path.push(next);
+                if extend(hardware, path, used, len, budget) {
+                    return true;
+                }
+                path.pop();
+                used[next] = false;
+            }
+        }
+        false
+    }
+
+    let mut budget = CHAIN_SEARCH_BUDGET;
+    for start in 0..hardware.num_qubits {
+        let mut used = vec![false; hardware.num_qubits];
+        used[start] = true;
+        let mut path = vec![start];
+        if extend(hardware, &mut path, &mut used, len, &mut budget) {
+            return Some(path);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::router::swap_network;
+    use qns_simulator::assert_circuits_equivalent;
+
+    fn complete_zz_layer(n: usize) -> Vec<Gate> {
+        let mut gates: Vec<Gate> = (0..n).map(|q| Gate::Rz(q, 0.1 * (q + 1) as f64)).collect();
+        for a in 0..n {
+            for b in a + 1..n {
+                gates.push(Gate::CPhase(a, b, 0.3 + 0.1 * (a * n + b) as f64));
+            }
+        }
+        gates
+    }
+
+    #[test]
+    fn test_complete_layer_uses_minimum_swaps() {
+        for n in 2..=7 {
+            let line = HardwareProfile::linear("line", n);
+            let layer = complete_zz_layer(n);
+            let (routed, mapping) = route_diagonal_layer(&layer, &line).unwrap();
+
+            // (n-1)(n-2)/2 matches an exhaustive search for n <= 6; each
+            // SWAP creates at most two new adjacencies
+            let swaps = routed.metadata.routing_gates.len();
+            assert_eq!(swaps, (n - 1) * (n - 2) / 2, "n = {}", n);
+            assert_eq!(routed.gates.len(), layer.len() + swaps);
+            for gate in &routed.gates {
+                if let [a, b] = gate.qubits()[..] {
+                    assert!(line.are_connected(a, b), "{} not on an edge", gate);
+                }
+            }
+
+            // Undo the final permutation; the result equals the layer
+            let mut permutation = vec![0; n];
+            for (logical, &physical) in mapping.iter().enumerate() {
+                permutation[physical] = logical;
+            }
+            let mut restored = routed.clone();
+            restored
+                .add_gates(swap_network(&permutation, &line).unwrap())
+                .unwrap();
+            let mut original = CircuitGenome::new(n);
+            original.add_gates(layer).unwrap();
+            assert_circuits_equivalent(&original, &restored, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_diagonal_and_unconnected() {
+        let line = HardwareProfile::linear("line", 3);
+        let err = route_diagonal_layer(&[Gate::CZ(0, 1), Gate::CNOT(1, 2)], &line).unwrap_err();
+        assert!(err.to_string().contains("gate 1"), "{}", err);
+
+        // A star has no path through all four qubits
+        let star = HardwareProfile::from_coupling_map("star", 4, &[[0, 1], [0, 2], [0, 3]]);
+        assert!(route_diagonal_layer(&complete_zz_layer(4), &star).is_err());
+        // but three of them lie on a path through the centre
+        let (routed, _) = route_diagonal_layer(&complete_zz_layer(3), &star).unwrap();
+        for gate in &routed.gates {
+            if let [a, b] = gate.qubits()[..] {
+                assert!(star.are_connected(a, b));
+            }
+        }
+    }
+}
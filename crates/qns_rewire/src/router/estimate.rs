@@ -0,0 +1,149 @@
+use qns_core::{CircuitGenome, HardwareProfile};
+
+/// Predicted routing overhead of a circuit on a topology.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RoutingEstimate {
+    /// Sum over two-qubit gates of `distance - 1` under the identity mapping.
+    ///
+    /// Assumes every gate is routed on its own; routers that reuse the
+    /// permuted layout for later gates usually need fewer SWAPs.
+    pub estimated_swaps: usize,
+    /// SWAPs any router starting from the identity mapping must insert.
+    ///
+    /// A SWAP moves each logical qubit by one edge, so it shortens the
+    /// distance of any gate by at most one. The gate with the largest
+    /// distance therefore needs at least `distance - 1` SWAPs.
+    pub swap_lower_bound: usize,
+    /// Depth of the circuit when each two-qubit gate also occupies its
+    /// qubits for `distance - 1` SWAP layers.
+    pub estimated_depth: usize,
+    /// `estimated_depth` minus the unrouted circuit depth.
+    pub depth_increase: usize,
+    /// Two-qubit gates whose qubits are missing from the hardware or lie in
+    /// different connected components; they are excluded from the other counts.
+    pub unroutable_gates: usize,
+}
+
+/// Predicts SWAP count and depth increase for routing `circuit` on `hardware`.
+///
+/// A cheap planning heuristic for comparing topologies: distances are taken
+/// under the identity mapping and no router is run. See [`RoutingEstimate`]
+/// for what each figure does and does not guarantee.
+pub fn estimate_routing_overhead(
+    circuit: &CircuitGenome,
+    hardware: &HardwareProfile,
+) -> RoutingEstimate {
+    let mut estimate = RoutingEstimate::default();
+    let mut qubit_depths = vec![0usize; circuit.num_qubits];
+
+    for gate in &circuit.gates {
+        let qubits = gate.qubits();
+        let mut layers = 1;
+        if let [a, b] = qubits[..] {
+            let distance = if a < hardware.num_qubits && b < hardware.num_qubits {
+                hardware.shortest_path_distance(a, b)
+            } else {
+                None
+            };
+            match distance {
+                Some(d) => {
+                    let swaps = d.saturating_sub(1);
+                    estimate.estimated_swaps += swaps;
+                    estimate.swap_lower_bound = estimate.swap_lower_bound.max(swaps);
+                    layers += swaps;
+                },
+                None => estimate.unroutable_gates += 1,
+            }
+        }
+
+        let start = qubits.iter().map(|&q| qubit_depths[q]).max().unwrap_or(0);
+        for &q in &qubits {
+            qubit_depths[q] = start + layers;
+        }
+    }
+
+    estimate.estimated_depth = qubit_depths.into_iter().max().unwrap_or(0);
+    estimate.depth_increase = estimate.estimated_depth.saturating_sub(circuit.depth());
+    estimate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::router::SabreRouter;
+    use qns_core::Gate;
+
+    fn circuit_of(num_qubits: usize, gates: &[Gate]) -> CircuitGenome {
+        let mut circuit = CircuitGenome::new(num_qubits);
+        circuit.add_gates(gates.iter().cloned()).unwrap();
+        circuit
+    }
+
+    #[test]
+    fn test_estimate_bounds_sabre_swaps() {
+        let line = HardwareProfile::linear("line", 5);
+        let circuits = [
+            // Already nearest-neighbour
+            circuit_of(5, &[Gate::H(0), Gate::CNOT(0, 1), Gate::CNOT(1, 2)]),
+            // One long-range gate
+            circuit_of(5, &[Gate::CNOT(0, 4)]),
+            // Repeated long-range gates: SABRE reuses the moved layout
+            circuit_of(5, &[Gate::CNOT(0, 3), Gate::CNOT(0, 3), Gate::CNOT(0, 3)]),
+            // Mixed
+            circuit_of(
+                5,
+                &[
+                    Gate::H(0),
+                    Gate::CNOT(0, 2),
+                    Gate::CZ(1, 4),
+                    Gate::CNOT(3, 0),
+                    Gate::SWAP(2, 4),
+                ],
+            ),
+        ];
+
+        for circuit in &circuits {
+            let estimate = estimate_routing_overhead(circuit, &line);
+            let (routed, _) = SabreRouter::default().route(circuit, &line).unwrap();
+            let actual = routed.metadata.routing_gates.len();
+            assert!(
+                estimate.swap_lower_bound <= actual,
+                "lower bound {} exceeds SABRE's {} SWAPs for {:?}",
+                estimate.swap_lower_bound,
+                actual,
+                circuit.gates
+            );
+            assert!(estimate.swap_lower_bound <= estimate.estimated_swaps);
+            assert_eq!(estimate.unroutable_gates, 0);
+        }
+
+        let long = estimate_routing_overhead(&circuits[1], &line);
+        assert_eq!(long.estimated_swaps, 3);
+        assert_eq!(long.swap_lower_bound, 3);
+        assert_eq!(long.estimated_depth, 4);
+        assert_eq!(long.depth_increase, 3);
+
+        // Repeated gates are each charged in the estimate, but not the bound
+        let repeated = estimate_routing_overhead(&circuits[2], &line);
+        assert_eq!(repeated.estimated_swaps, 6);
+        assert_eq!(repeated.swap_lower_bound, 2);
+
+        let nearest = estimate_routing_overhead(&circuits[0], &line);
+        assert_eq!(
+            nearest,
+            RoutingEstimate {
+                estimated_depth: 3,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_estimate_unroutable_gates() {
+        // Qubit 3 does not exist on a 3-qubit device
+        let circuit = circuit_of(4, &[Gate::CNOT(0, 3), Gate::CNOT(0, 2)]);
+        let estimate = estimate_routing_overhead(&circuit, &HardwareProfile::linear("line", 3));
+        assert_eq!(estimate.unroutable_gates, 1);
+        assert_eq!(estimate.estimated_swaps, 1);
+    }
+}
@@ -9,11 +9,17 @@ pub trait Router {
 }
 
 pub mod basic;
+pub mod diagonal_layer;
+pub mod estimate;
 pub mod noise_aware;
 pub mod placement;
 pub mod sabre;
+pub mod swap_network;
 
 pub use basic::BasicRouter;
+pub use diagonal_layer::route_diagonal_layer;
+pub use estimate::{estimate_routing_overhead, RoutingEstimate};
 pub use noise_aware::NoiseAwareRouter;
 pub use placement::{PlacementOptimizer, PlacementResult};
-pub use sabre::SabreRouter;
+pub use sabre::{SabreConfig, SabreRouter};
+pub use swap_network::swap_network;
@@ -4,6 +4,7 @@
 //! considers per-edge fidelity to minimize total error.
 
 use super::Router;
+use crate::graph::DependencyGraph;
 use qns_core::types::Gate;
 use qns_core::{CircuitGenome, HardwareProfile, QnsError};
 use std::cmp::Ordering;
@@ -18,6 +19,13 @@ use std::collections::{BinaryHeap, HashMap};
 ///
 /// Cost = α * distance + β * (1 - edge_fidelity)
 /// where α balances SWAP overhead vs edge quality
+///
+/// For a CNOT whose qubits are two hops apart, the router may instead insert
+/// a bridge gadget (4 CNOTs through the middle qubit) that leaves the mapping
+/// unchanged, whenever its estimated fidelity beats SWAP-then-SWAP-back. A
+/// bridge is skipped when the mapping a SWAP would produce shortens the
+/// upcoming two-qubit interactions (taken in dependency order), since the
+/// SWAP then pays for itself instead of being reverted.
 pub struct NoiseAwareRouter {
     /// Weight for distance component (SWAP count)
     pub distance_weight: f64,
@@ -27,6 +35,8 @@ pub struct NoiseAwareRouter {
     pub crosstalk_weight: f64,
     /// Lookahead window for cost calculation
     pub lookahead: usize,
+    /// Consider bridge gadgets for one-shot distance-2 CNOTs
+    pub use_bridges: bool,
 }
 
 impl Default for NoiseAwareRouter {
@@ -36,6 +46,7 @@ impl Default for NoiseAwareRouter {
             fidelity_weight: 0.5,
             crosstalk_weight: 0.5,
             lookahead: 5,
+            use_bridges: true,
         }
     }
 }
@@ -80,6 +91,7 @@ impl NoiseAwareRouter {
             fidelity_weight,
             crosstalk_weight,
             lookahead: 5,
+            use_bridges: true,
         }
     }
 
@@ -170,7 +182,11 @@ impl NoiseAwareRouter {
 
         for gate in active_slice {
             match gate {
-                Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
+                Gate::CNOT(c, t)
+                | Gate::CZ(c, t)
+                | Gate::CPhase(c, t, _)
+                | Gate::SWAP(c, t)
+                | Gate::FSim(c, t, _, _) => {
                     let phys_c = mapping[*c];
                     let phys_t = mapping[*t];
 
@@ -227,6 +243,191 @@ impl NoiseAwareRouter {
         cost
     }
 
+    /// Returns a bridge gadget replacing `gate` if bridging is enabled, the gate
+    /// is a CNOT, and the bridge beats SWAP routing.
+    fn try_bridge(
+        &self,
+        gate: &Gate,
+        (phys_c, phys_t): (usize, usize),
+        remaining_gates: &[Gate],
+        upcoming: &[Gate],
+        mapping: &[usize],
+        hardware: &HardwareProfile,
+    ) -> Option<Vec<Gate>> {
+        match gate {
+            Gate::CNOT(c, t) if self.use_bridges => {
+                if self.swap_shortens_upcoming(phys_c, phys_t, upcoming, mapping, hardware) {
+                    return None;
+                }
+                self.bridge_if_cheaper(phys_c, phys_t, (*c, *t), remaining_gates, mapping, hardware)
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether some SWAP that brings `phys_c` and `phys_t` together also
+    /// lowers the total distance of the `upcoming` two-qubit gates.
+    ///
+    /// If so the SWAP's mapping is worth keeping, and bridging (which is
+    /// equivalent to swapping and immediately reverting) would waste it.
+    fn swap_shortens_upcoming(
+        &self,
+        phys_c: usize,
+        phys_t: usize,
+        upcoming: &[Gate],
+        mapping: &[usize],
+        hardware: &HardwareProfile,
+    ) -> bool {
+        let total_distance = |mapping: &[usize]| -> usize {
+            upcoming
+                .iter()
+                .map(|g| {
+                    let qs = g.qubits();
+                    hardware
+                        .shortest_path_distance(mapping[qs[0]], mapping[qs[1]])
+                        .unwrap_or(hardware.num_qubits)
+                })
+                .sum()
+        };
+        let current = total_distance(mapping);
+        if current == 0 {
+            return false;
+        }
+
+        let mut candidates = hardware
+            .neighbors(phys_c)
+            .into_iter()
+            .filter(|&m| hardware.are_connected(m, phys_t))
+            .flat_map(|m| [(phys_c, m), (phys_t, m)]);
+        candidates.any(|(a, b)| {
+            let swapped: Vec<usize> = mapping
+                .iter()
+                .map(|&p| match p {
+                    p if p == a => b,
+                    p if p == b => a,
+                    p => p,
+                })
+                .collect();
+            total_distance(&swapped) < current
+        })
+    }
+
+    /// ASAP layer of every gate in the circuit's dependency graph.
+    fn dependency_layers(circuit: &CircuitGenome) -> Vec<usize> {
+        let dag = DependencyGraph::new(circuit);
+        let mut layers = vec![0; dag.num_gates];
+        for (idx, successors) in dag.successors.iter().enumerate() {
+            for &next in successors {
+                layers[next] = layers[next].max(layers[idx] + 1);
+            }
+        }
+        layers
+    }
+
+    /// The next `lookahead` two-qubit gates after `start`, soonest layer first.
+    ///
+    /// Candidates come from the next `4 * lookahead` two-qubit gates, so an
+    /// early-indexed gate deep in the dependency graph yields to parallel
+    /// gates that will execute before it.
+    fn upcoming_two_qubit_gates(
+        &self,
+        gates: &[Gate],
+        layers: &[usize],
+        start: usize,
+    ) -> Vec<Gate> {
+        let mut candidates: Vec<usize> = (start..gates.len())
+            .filter(|&i| gates[i].is_two_qubit())
+            .take(4 * self.lookahead)
+            .collect();
+        candidates.sort_by_key(|&i| (layers[i], i));
+        candidates
+            .into_iter()
+            .take(self.lookahead)
+            .map(|i| gates[i].clone())
+            .collect()
+    }
+
+    /// Returns the bridge gadget for `CNOT(phys_c, phys_t)` if it is preferable
+    /// to routing with SWAPs.
+    ///
+    /// Only applies when the qubits are exactly two hops apart and the logical
+    /// pair `(log_c, log_t)` does not interact again within the lookahead
+    /// window, so moving qubits would not pay off later. The bridge
+    /// `CNOT(c,m) CNOT(m,t) CNOT(c,m) CNOT(m,t)` has fidelity `F_cm² F_mt²`;
+    /// a SWAP (3 CNOTs) there and back around the CNOT costs `F_cm⁶ F_mt`
+    /// (or symmetrically via the target side).
+    ///
+    /// A bridge pins the interaction to `phys_c` and `phys_t`, so if any gadget
+    /// qubit has crosstalk with a qubit active in the lookahead window, the
+    /// crosstalk-aware SWAP search is used instead.
+    fn bridge_if_cheaper(
+        &self,
+        phys_c: usize,
+        phys_t: usize,
+        (log_c, log_t): (usize, usize),
+        remaining_gates: &[Gate],
+        mapping: &[usize],
+        hardware: &HardwareProfile,
+    ) -> Option<Vec<Gate>> {
+        let window = &remaining_gates[..remaining_gates.len().min(self.lookahead)];
+        let recurs = window.iter().filter(|g| g.is_two_qubit()).any(|g| {
+            let qs = g.qubits();
+            qs.contains(&log_c) && qs.contains(&log_t)
+        });
+        if recurs {
+            return None;
+        }
+
+        let edge_fidelity = |a: usize, b: usize| {
+            hardware
+                .get_coupler(a, b)
+                .map(|c| c.gate_fidelity.value())
+                .unwrap_or(0.99)
+        };
+
+        // Best middle qubit adjacent to both endpoints
+        let (mid, f_cm, f_mt) = (0..hardware.num_qubits)
+            .filter(|&m| hardware.are_connected(phys_c, m) && hardware.are_connected(m, phys_t))
+            .map(|m| (m, edge_fidelity(phys_c, m), edge_fidelity(m, phys_t)))
+            .max_by(|a, b| {
+                (a.1 * a.2)
+                    .partial_cmp(&(b.1 * b.2))
+                    .unwrap_or(Ordering::Equal)
+            })?;
+
+        let crosstalk = &hardware.crosstalk;
+        if !crosstalk.is_empty() {
+            let gadget = [phys_c, mid, phys_t];
+            let exposed = window
+                .iter()
+                .flat_map(|g| g.qubits())
+                .filter_map(|q| mapping.get(q).copied())
+                .filter(|p| !gadget.contains(p))
+                .any(|p| {
+                    gadget
+                        .iter()
+                        .any(|&g| crosstalk.get_interaction(g, p).is_some())
+                });
+            if exposed {
+                return None;
+            }
+        }
+
+        let bridge_fidelity = f_cm.powi(2) * f_mt.powi(2);
+        let swap_fidelity = (f_cm.powi(6) * f_mt).max(f_mt.powi(6) * f_cm);
+
+        if bridge_fidelity > swap_fidelity {
+            Some(vec![
+                Gate::CNOT(phys_c, mid),
+                Gate::CNOT(mid, phys_t),
+                Gate::CNOT(phys_c, mid),
+                Gate::CNOT(mid, phys_t),
+            ])
+        } else {
+            None
+        }
+    }
+
     /// Gets the best SWAP to apply for a given two-qubit gate.
     fn find_best_swap(
         &self,
@@ -315,6 +516,7 @@ impl NoiseAwareRouter {
         hardware: &HardwareProfile,
         initial_mapping: &[usize],
     ) -> Result<CircuitGenome, QnsError> {
+        let circuit = &circuit.decompose_toffoli();
         let mut new_circuit = CircuitGenome::new(hardware.num_qubits);
 
         // Use provided initial mapping
@@ -335,12 +537,17 @@ impl NoiseAwareRouter {
             ));
         }
 
+        let layers = Self::dependency_layers(circuit);
         let mut gate_idx = 0;
         while gate_idx < circuit.gates.len() {
             let gate = &circuit.gates[gate_idx];
 
             match gate {
-                Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
+                Gate::CNOT(c, t)
+                | Gate::CZ(c, t)
+                | Gate::CPhase(c, t, _)
+                | Gate::SWAP(c, t)
+                | Gate::FSim(c, t, _, _) => {
                     let phys_c = logical_to_physical[*c];
                     let phys_t = logical_to_physical[*t];
 
@@ -348,6 +555,17 @@ impl NoiseAwareRouter {
                         // Direct connection available
                         new_circuit.add_gate(gate.map_qubits(&logical_to_physical))?;
                         gate_idx += 1;
+                    } else if let Some(bridge) = self.try_bridge(
+                        gate,
+                        (phys_c, phys_t),
+                        &circuit.gates[gate_idx + 1..],
+                        &self.upcoming_two_qubit_gates(&circuit.gates, &layers, gate_idx + 1),
+                        &logical_to_physical,
+                        hardware,
+                    ) {
+                        // One-shot interaction: bridge without moving qubits
+                        new_circuit.add_gates(bridge)?;
+                        gate_idx += 1;
                     } else {
                         // Need to route
                         if let Some((u, v, _cost)) = self.find_best_swap(
@@ -359,7 +577,7 @@ impl NoiseAwareRouter {
                             hardware,
                         ) {
                             // Apply SWAP
-                            new_circuit.add_gate(Gate::SWAP(u, v))?;
+                            new_circuit.add_routing_gate(Gate::SWAP(u, v))?;
 
                             // Update mappings
                             let log_u = physical_to_logical[u];
@@ -447,6 +665,173 @@ mod tests {
         assert!(routed.gates.len() >= 2, "Should have at least one SWAP");
     }
 
+    #[test]
+    fn test_toffoli_decomposed_before_routing() {
+        let hw = HardwareProfile::linear("test", 3);
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::Toffoli(0, 1, 2)).unwrap();
+        assert!(!hw.is_circuit_valid(&circuit));
+
+        let routed = NoiseAwareRouter::default().route(&circuit, &hw).unwrap();
+        assert!(
+            hw.is_circuit_valid(&routed),
+            "{:?}",
+            hw.validate_circuit(&routed)
+        );
+        assert!(routed.gates.iter().all(|g| !g.is_three_qubit()));
+
+        let (sabre, _) = crate::router::SabreRouter::default()
+            .route(&circuit, &hw)
+            .unwrap();
+        assert!(hw.is_circuit_valid(&sabre));
+    }
+
+    #[test]
+    fn test_one_shot_cnot_uses_bridge() {
+        use qns_simulator::StateVectorSimulator;
+
+        let hw = HardwareProfile::linear("test", 3);
+        let router = NoiseAwareRouter::default();
+
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+        circuit.add_gate(Gate::H(1)).unwrap();
+
+        let routed = router.route(&circuit, &hw).unwrap();
+        assert!(hw.is_circuit_valid(&routed));
+        assert!(!routed.gates.iter().any(|g| matches!(g, Gate::SWAP(..))));
+        assert_eq!(
+            routed.gates,
+            vec![
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(1, 2),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(1, 2),
+                Gate::H(1),
+            ]
+        );
+
+        // Mapping unchanged: the bridge implements CNOT(0, 2) exactly
+        let mut reference = StateVectorSimulator::new(3);
+        reference.execute(&circuit).unwrap();
+        let mut sim = StateVectorSimulator::new(3);
+        sim.execute(&routed).unwrap();
+        assert!((sim.fidelity_with(&reference).unwrap() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_bridge_skipped_for_bad_middle_edge_or_repeated_pair() {
+        // Edge 1-2 is poor: SWAP over the good edge 0-1 and back is cheaper
+        let mut hw = HardwareProfile::linear("test", 3);
+        hw.couplers[0].gate_fidelity = Fidelity::new(0.999);
+        hw.couplers[1].gate_fidelity = Fidelity::new(0.9);
+        let router = NoiseAwareRouter::default();
+
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+        let routed = router.route(&circuit, &hw).unwrap();
+        assert!(routed.gates.iter().any(|g| matches!(g, Gate::SWAP(..))));
+
+        // Pair interacts again: moving the qubits pays off, so use SWAPs
+        let hw = HardwareProfile::linear("test", 3);
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+        circuit.add_gate(Gate::CNOT(2, 0)).unwrap();
+        let routed = router.route(&circuit, &hw).unwrap();
+        assert!(hw.is_circuit_valid(&routed));
+        assert!(routed.gates.iter().any(|g| matches!(g, Gate::SWAP(..))));
+
+        // Inserted SWAPs are tagged as routing artifacts
+        assert!(!routed.metadata.routing_gates.is_empty());
+        for (i, gate) in routed.gates.iter().enumerate() {
+            assert_eq!(routed.is_routing_gate(i), matches!(gate, Gate::SWAP(..)));
+        }
+    }
+
+    #[test]
+    fn test_swap_kept_when_it_shortens_later_gates() {
+        use qns_simulator::StateVectorSimulator;
+
+        // 0 -- 1 -- 2 -- 3: CNOT(0, 2) then CNOT(0, 3), each pair used once
+        let hw = HardwareProfile::linear("test", 4);
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 3)).unwrap();
+
+        // SWAP-equivalent cost: a SWAP is 3 CNOTs
+        let cost = |c: &CircuitGenome| -> usize {
+            c.gates
+                .iter()
+                .map(|g| match g {
+                    Gate::SWAP(..) => 3,
+                    g if g.is_two_qubit() => 1,
+                    _ => 0,
+                })
+                .sum()
+        };
+        let swaps = |c: &CircuitGenome| {
+            c.gates
+                .iter()
+                .filter(|g| matches!(g, Gate::SWAP(..)))
+                .count()
+        };
+
+        // Without lookahead the first CNOT is bridged (swap-and-revert), so
+        // the second still needs a SWAP plus another bridge
+        let myopic = NoiseAwareRouter {
+            lookahead: 0,
+            ..NoiseAwareRouter::default()
+        };
+        let reverted = myopic.route(&circuit, &hw).unwrap();
+        assert!(hw.is_circuit_valid(&reverted));
+        assert_eq!(cost(&reverted), 4 + 3 + 4);
+
+        // Keeping the first SWAP also brings qubit 0 closer to qubit 3
+        let router = NoiseAwareRouter::default();
+        let kept = router.route(&circuit, &hw).unwrap();
+        assert!(hw.is_circuit_valid(&kept));
+        assert!(matches!(kept.gates[1], Gate::SWAP(..)));
+        assert!(swaps(&kept) <= swaps(&reverted));
+        assert!(cost(&kept) < cost(&reverted));
+        assert_eq!(cost(&kept), 3 + 1 + 4);
+
+        // Same output distribution as the unrouted circuit
+        let probabilities = |c: &CircuitGenome, final_mapping: &[usize]| {
+            let mut sim = StateVectorSimulator::new(4);
+            sim.execute(c).unwrap();
+            let probs = sim.probabilities();
+            (0..16)
+                .map(|logical: usize| {
+                    let physical = (0..4)
+                        .filter(|&q| logical >> q & 1 == 1)
+                        .map(|q| 1 << final_mapping[q])
+                        .sum::<usize>();
+                    probs[physical]
+                })
+                .collect::<Vec<f64>>()
+        };
+        let mut mapping: Vec<usize> = (0..4).collect();
+        for gate in &kept.gates {
+            if let Gate::SWAP(a, b) = gate {
+                for p in mapping.iter_mut() {
+                    if *p == *a {
+                        *p = *b;
+                    } else if *p == *b {
+                        *p = *a;
+                    }
+                }
+            }
+        }
+        let expected = probabilities(&circuit, &[0, 1, 2, 3]);
+        let actual = probabilities(&kept, &mapping);
+        for (e, a) in expected.iter().zip(&actual) {
+            assert!((e - a).abs() < 1e-10);
+        }
+    }
+
     #[test]
     fn test_fidelity_aware_path_finding() {
         let hw = create_hardware_with_varying_fidelity();
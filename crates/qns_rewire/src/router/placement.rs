@@ -122,6 +122,7 @@ impl PlacementOptimizer {
 
         // Sort logical pairs by interaction count (most frequent first)
         let mut logical_pairs: Vec<_> = interactions.into_iter().collect();
+        #[allow(clippy::unnecessary_sort_by)]
         logical_pairs.sort_by(|a, b| b.1.cmp(&a.1));
 
         // Track which logical and physical qubits are assigned
@@ -327,10 +328,128 @@ impl PlacementOptimizer {
         score
     }
 
+    /// Finds a mapping that embeds the circuit's interaction graph into the
+    /// hardware connectivity, so every two-qubit gate lands on a coupler and
+    /// no routing SWAPs are needed.
+    ///
+    /// Performs a VF2-style backtracking search for a subgraph monomorphism:
+    /// logical qubits are matched in BFS order from the highest-degree qubit,
+    /// so each new qubit is usually adjacent to one already placed and only
+    /// hardware neighbours of that placement are tried. Candidates must be
+    /// unused, have at least the logical degree, and connect to the
+    /// placements of all mapped logical neighbours.
+    ///
+    /// Returns `None` when no zero-SWAP embedding exists. The search is
+    /// exhaustive, so it can be slow on large, dense interaction graphs.
+    pub fn find_embedding(
+        &self,
+        circuit: &CircuitGenome,
+        hardware: &HardwareProfile,
+    ) -> Option<Vec<usize>> {
+        let n = circuit.num_qubits;
+        if n > hardware.num_qubits {
+            return None;
+        }
+
+        let mut neighbors = self.build_logical_neighbors(circuit);
+        let adjacency: Vec<Vec<usize>> = (0..n)
+            .map(|l| neighbors.remove(&l).unwrap_or_default())
+            .collect();
+
+        // BFS order per connected component, highest degree first
+        let mut by_degree: Vec<usize> = (0..n).collect();
+        by_degree.sort_by_key(|&l| std::cmp::Reverse(adjacency[l].len()));
+        let mut order = Vec::with_capacity(n);
+        let mut queued = vec![false; n];
+        for &root in &by_degree {
+            if queued[root] {
+                continue;
+            }
+            queued[root] = true;
+            let start = order.len();
+            order.push(root);
+            let mut head = start;
+            while head < order.len() {
+                let l = order[head];
+                head += 1;
+                for &nb in &adjacency[l] {
+                    if !queued[nb] {
+                        queued[nb] = true;
+                        order.push(nb);
+                    }
+                }
+            }
+        }
+
+        let physical_degree: Vec<usize> = (0..hardware.num_qubits)
+            .map(|p| hardware.neighbors(p).len())
+            .collect();
+
+        fn extend(
+            depth: usize,
+            order: &[usize],
+            adjacency: &[Vec<usize>],
+            hardware: &HardwareProfile,
+            physical_degree: &[usize],
+            mapping: &mut [Option<usize>],
+            used: &mut [bool],
+        ) -> bool {
+            let Some(&l) = order.get(depth) else {
+                return true;
+            };
+            let placed: Vec<usize> = adjacency[l].iter().filter_map(|&nb| mapping[nb]).collect();
+            let candidates: Vec<usize> = match placed.first() {
+                Some(&anchor) => hardware.neighbors(anchor),
+                None => (0..hardware.num_qubits).collect(),
+            };
+
+            for p in candidates {
+                if used[p]
+                    || physical_degree[p] < adjacency[l].len()
+                    || !placed.iter().all(|&q| hardware.are_connected(p, q))
+                {
+                    continue;
+                }
+                mapping[l] = Some(p);
+                used[p] = true;
+                if extend(
+                    depth + 1,
+                    order,
+                    adjacency,
+                    hardware,
+                    physical_degree,
+                    mapping,
+                    used,
+                ) {
+                    return true;
+                }
+                mapping[l] = None;
+                used[p] = false;
+            }
+            false
+        }
+
+        let mut mapping = vec![None; n];
+        let mut used = vec![false; hardware.num_qubits];
+        if extend(
+            0,
+            &order,
+            &adjacency,
+            hardware,
+            &physical_degree,
+            &mut mapping,
+            &mut used,
+        ) {
+            mapping.into_iter().collect()
+        } else {
+            None
+        }
+    }
+
     /// Main optimization entry point.
     pub fn optimize(&self, circuit: &CircuitGenome, hardware: &HardwareProfile) -> PlacementResult {
-        if circuit.num_qubits > hardware.num_qubits {
-            // Can't fit circuit on hardware, return identity
+        if circuit.num_qubits > hardware.num_qubits || circuit.metadata.pre_mapped {
+            // Can't fit circuit on hardware, or it is already placed: return identity
             return PlacementResult {
                 mapping: (0..circuit.num_qubits).collect(),
                 circuit: circuit.clone(),
@@ -349,7 +468,11 @@ impl PlacementOptimizer {
 /// Extracts the qubit pair from a two-qubit gate.
 fn get_two_qubit_pair(gate: &Gate) -> Option<(usize, usize)> {
     match gate {
-        Gate::CNOT(q1, q2) | Gate::CZ(q1, q2) | Gate::SWAP(q1, q2) => Some((*q1, *q2)),
+        Gate::CNOT(q1, q2)
+        | Gate::CZ(q1, q2)
+        | Gate::CPhase(q1, q2, _)
+        | Gate::SWAP(q1, q2)
+        | Gate::FSim(q1, q2, _, _) => Some((*q1, *q2)),
         _ => None,
     }
 }
@@ -368,6 +491,44 @@ mod tests {
         hw
     }
 
+    #[test]
+    fn test_find_embedding() {
+        use crate::router::NoiseAwareRouter;
+
+        let optimizer = PlacementOptimizer::default();
+        let line = HardwareProfile::linear("line", 5);
+
+        // A 3-qubit line interaction graph embeds into a 5-qubit line
+        let mut chain = CircuitGenome::new(3);
+        chain.add_gate(Gate::CNOT(1, 0)).unwrap();
+        chain.add_gate(Gate::CNOT(1, 2)).unwrap();
+        chain.add_gate(Gate::CNOT(0, 1)).unwrap();
+        let mapping = optimizer.find_embedding(&chain, &line).unwrap();
+        assert_eq!(mapping.len(), 3);
+        let placed = optimizer.apply_mapping(&chain, &mapping);
+        assert!(placed.gates.iter().all(|g| {
+            let q = g.qubits();
+            line.are_connected(q[0], q[1])
+        }));
+        let routed = NoiseAwareRouter::default()
+            .route_with_mapping(&chain, &line, &mapping)
+            .unwrap();
+        assert!(!routed.gates.iter().any(|g| matches!(g, Gate::SWAP(_, _))));
+
+        // A triangle cannot be embedded into a line
+        let mut triangle = CircuitGenome::new(3);
+        triangle.add_gate(Gate::CNOT(0, 1)).unwrap();
+        triangle.add_gate(Gate::CNOT(1, 2)).unwrap();
+        triangle.add_gate(Gate::CNOT(2, 0)).unwrap();
+        assert_eq!(optimizer.find_embedding(&triangle, &line), None);
+
+        // Nor into a square grid; all-to-all hardware contains one
+        let grid = HardwareProfile::grid("grid", 2, 2);
+        assert_eq!(optimizer.find_embedding(&triangle, &grid), None);
+        let full = HardwareProfile::all_to_all("full", 3);
+        assert!(optimizer.find_embedding(&triangle, &full).is_some());
+    }
+
     #[test]
     fn test_analyze_interactions() {
         let mut circuit = CircuitGenome::new(3);
@@ -1,6 +1,40 @@
 use crate::graph::DependencyGraph;
 use qns_core::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
+
+/// Lookahead settings for [`SabreRouter`], applied with
+/// [`SabreRouter::with_lookahead`].
+///
+/// Candidate SWAPs are scored as `H = front_cost + w * lookahead_cost / |E|`,
+/// where `E` is the extended set of up to `lookahead_depth` upcoming
+/// two-qubit gates behind the front layer and `lookahead_cost` is the sum of
+/// their current distances. A depth of 0 gives the pure greedy router.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SabreConfig {
+    /// Maximum number of two-qubit gates in the extended set
+    pub lookahead_depth: usize,
+    /// Weight `w` of the extended set relative to the front layer
+    pub lookahead_weight: f64,
+}
+
+impl Default for SabreConfig {
+    fn default() -> Self {
+        Self {
+            lookahead_depth: 20,
+            lookahead_weight: 0.5,
+        }
+    }
+}
+
+impl SabreConfig {
+    /// Front-layer-only scoring.
+    pub fn greedy() -> Self {
+        Self {
+            lookahead_depth: 0,
+            ..Self::default()
+        }
+    }
+}
 
 /// SABRE: Swap-Based BidiREctional search router.
 ///
@@ -9,9 +43,17 @@ use std::collections::HashSet;
 ///
 /// # Cost Function v2
 /// Cost = W_dist * distance + W_err * error_rate + W_xtalk * crosstalk_penalty
+/// over the front layer, plus the [`SabreConfig`] lookahead term.
+///
+/// The lookahead is off by default (`lookahead_depth` 0), so routing matches
+/// the greedy router that predates it; enable it with
+/// `with_lookahead(SabreConfig::default())`. Struct literals must now set
+/// `lookahead_depth` as well (or end with `..Default::default()`).
 pub struct SabreRouter {
-    /// Lookahead weight (W)
+    /// Lookahead weight (W) of the extended set
     pub lookahead_weight: f64,
+    /// Maximum number of two-qubit gates in the extended set (0 = greedy)
+    pub lookahead_depth: usize,
     /// Decay factor for heuristic
     pub decay_rate: f64,
     /// Max iterations for bidirectional passes
@@ -29,6 +71,7 @@ impl Default for SabreRouter {
     fn default() -> Self {
         Self {
             lookahead_weight: 0.5,
+            lookahead_depth: 0,
             decay_rate: 0.001,
             max_iterations: 10,
             dist_weight: 1.0,
@@ -47,6 +90,7 @@ impl SabreRouter {
     ) -> Self {
         Self {
             lookahead_weight,
+            lookahead_depth: 0,
             decay_rate,
             max_iterations,
             dist_weight: 1.0,  // Standard distance weight
@@ -55,6 +99,21 @@ impl SabreRouter {
         }
     }
 
+    /// Sets the lookahead window.
+    pub fn with_lookahead(mut self, lookahead: SabreConfig) -> Self {
+        self.lookahead_depth = lookahead.lookahead_depth;
+        self.lookahead_weight = lookahead.lookahead_weight;
+        self
+    }
+
+    /// Current lookahead window.
+    pub fn lookahead(&self) -> SabreConfig {
+        SabreConfig {
+            lookahead_depth: self.lookahead_depth,
+            lookahead_weight: self.lookahead_weight,
+        }
+    }
+
     /// Route circuit using SABRE algorithm.
     pub fn route(
         &self,
@@ -62,6 +121,8 @@ impl SabreRouter {
         hardware: &HardwareProfile,
     ) -> Result<(CircuitGenome, Vec<usize>)> {
         // Returns (routed_circuit, final_mapping)
+        let circuit = &circuit.decompose_toffoli();
+
         // 1. Initialize mapping (trivial for now, can be optimized)
         let mut mapping: Vec<usize> = (0..circuit.num_qubits).collect(); // Logical -> Physical
 
@@ -81,11 +142,16 @@ impl SabreRouter {
         mapping: &mut [usize], // Logical -> Physical
     ) -> Result<(CircuitGenome, Vec<usize>)> {
         let mut routed_gates = Vec::new();
+        let mut swap_indices = Vec::new();
         let mut incoming_degree = dag.incoming_degree.clone();
 
         // Front Layer: Gates with no dependencies
         let mut front_layer: Vec<usize> = dag.initial_front_layer();
         let mut executed_gates = HashSet::new();
+        // Lookahead can trade front-layer progress for later gains; after
+        // this many SWAPs without executing a gate, fall back to greedy
+        let stall_limit = hardware.num_qubits.max(circuit.num_qubits);
+        let mut swaps_since_progress = 0;
 
         // While there are gates to execute
         while executed_gates.len() < circuit.gates.len() {
@@ -101,6 +167,7 @@ impl SabreRouter {
             }
 
             if !executable_gates.is_empty() {
+                swaps_since_progress = 0;
                 // Execute gates
                 for &gate_idx in &executable_gates {
                     let gate = &circuit.gates[gate_idx];
@@ -124,7 +191,14 @@ impl SabreRouter {
             } else {
                 // No executable gates -> Insert SWAP
                 // Heuristic: Choose SWAP that minimizes cost function
-                let best_swap = self.find_best_swap(&front_layer, circuit, mapping, hardware);
+                let extended_set = if swaps_since_progress < stall_limit {
+                    self.extended_set(&front_layer, circuit, dag)
+                } else {
+                    Vec::new()
+                };
+                let best_swap =
+                    self.find_best_swap(&front_layer, &extended_set, circuit, mapping, hardware);
+                swaps_since_progress += 1;
 
                 if let Some((p1, p2)) = best_swap {
                     // Update mapping (swap logical assignments)
@@ -134,6 +208,7 @@ impl SabreRouter {
                     mapping.swap(l1, l2);
 
                     // Add SWAP gate
+                    swap_indices.push(routed_gates.len());
                     routed_gates.push(Gate::SWAP(p1, p2));
                 } else {
                     return Err(QnsError::Rewire(
@@ -147,6 +222,7 @@ impl SabreRouter {
         for g in routed_gates {
             routed_circuit.add_gate(g)?;
         }
+        routed_circuit.metadata.routing_gates = swap_indices;
 
         Ok((routed_circuit, mapping.to_vec()))
     }
@@ -154,7 +230,11 @@ impl SabreRouter {
     /// Check if gate is executable on current mapping
     fn is_executable(&self, gate: &Gate, mapping: &[usize], hardware: &HardwareProfile) -> bool {
         match gate {
-            Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
+            Gate::CNOT(c, t)
+            | Gate::CZ(c, t)
+            | Gate::CPhase(c, t, _)
+            | Gate::SWAP(c, t)
+            | Gate::FSim(c, t, _, _) => {
                 let p_c = mapping[*c];
                 let p_t = mapping[*t];
                 hardware.are_connected(p_c, p_t)
@@ -175,17 +255,56 @@ impl SabreRouter {
             Gate::Rx(q, t) => Gate::Rx(mapping[*q], *t),
             Gate::Ry(q, t) => Gate::Ry(mapping[*q], *t),
             Gate::Rz(q, t) => Gate::Rz(mapping[*q], *t),
+            Gate::U(q, theta, phi, lambda) => Gate::U(mapping[*q], *theta, *phi, *lambda),
             Gate::CNOT(c, t) => Gate::CNOT(mapping[*c], mapping[*t]),
             Gate::CZ(c, t) => Gate::CZ(mapping[*c], mapping[*t]),
+            Gate::CPhase(c, t, lambda) => Gate::CPhase(mapping[*c], mapping[*t], *lambda),
             Gate::SWAP(c, t) => Gate::SWAP(mapping[*c], mapping[*t]),
+            Gate::FSim(a, b, theta, phi) => Gate::FSim(mapping[*a], mapping[*b], *theta, *phi),
+            Gate::Toffoli(c1, c2, t) => Gate::Toffoli(mapping[*c1], mapping[*c2], mapping[*t]),
             Gate::Measure(q) => Gate::Measure(mapping[*q]),
+            Gate::Reset(q) => Gate::Reset(mapping[*q]),
+        }
+    }
+
+    /// Collects up to `lookahead_depth` two-qubit gates following the front
+    /// layer, breadth-first through the dependency graph.
+    fn extended_set(
+        &self,
+        front_layer: &[usize],
+        circuit: &CircuitGenome,
+        dag: &DependencyGraph,
+    ) -> Vec<usize> {
+        let depth = self.lookahead_depth;
+        let mut extended = Vec::new();
+        if depth == 0 {
+            return extended;
         }
+
+        let mut seen: HashSet<usize> = front_layer.iter().copied().collect();
+        let mut queue: VecDeque<usize> = front_layer.iter().copied().collect();
+        while let Some(idx) = queue.pop_front() {
+            for &child in &dag.successors[idx] {
+                if !seen.insert(child) {
+                    continue;
+                }
+                if circuit.gates[child].is_two_qubit() {
+                    extended.push(child);
+                    if extended.len() == depth {
+                        return extended;
+                    }
+                }
+                queue.push_back(child);
+            }
+        }
+        extended
     }
 
     /// Find best SWAP to reduce heuristic cost
     fn find_best_swap(
         &self,
         front_layer: &[usize],
+        extended_set: &[usize],
         circuit: &CircuitGenome,
         mapping: &[usize],
         hardware: &HardwareProfile,
@@ -210,7 +329,14 @@ impl SabreRouter {
             // But standard Sabre tries all edges to escape local minima. We stick to standard all-edge trial for now.
             if let (Some(idx1), Some(idx2)) = (l1, l2) {
                 temp_mapping.swap(idx1, idx2);
-                let score = self.heuristic_score(front_layer, circuit, &temp_mapping, hardware);
+                let mut score = self.heuristic_score(front_layer, circuit, &temp_mapping, hardware);
+                if !extended_set.is_empty() {
+                    let lookahead_cost: f64 = extended_set
+                        .iter()
+                        .map(|&idx| gate_distance(&circuit.gates[idx], &temp_mapping, hardware))
+                        .sum();
+                    score += self.lookahead_weight * lookahead_cost / extended_set.len() as f64;
+                }
                 if score < best_score {
                     best_score = score;
                     best_swap = Some((p1, p2));
@@ -220,7 +346,7 @@ impl SabreRouter {
         best_swap
     }
 
-    /// Calculate H-score (Weighted sum of Distance + Error + Crosstalk)
+    /// Calculate front-layer H-score (Weighted sum of Distance + Error + Crosstalk)
     fn heuristic_score(
         &self,
         front_layer: &[usize],
@@ -238,7 +364,11 @@ impl SabreRouter {
         for &gate_idx in front_layer {
             let gate = &circuit.gates[gate_idx];
             match gate {
-                Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
+                Gate::CNOT(c, t)
+                | Gate::CZ(c, t)
+                | Gate::CPhase(c, t, _)
+                | Gate::SWAP(c, t)
+                | Gate::FSim(c, t, _, _) => {
                     let p_c = mapping[*c];
                     let p_t = mapping[*t];
 
@@ -294,3 +424,98 @@ impl SabreRouter {
         self.crosstalk_weight * total_xtalk * 100.0 // Scale crosstalk (0.001 ranges)
     }
 }
+
+/// Physical distance between the qubits of a two-qubit gate (0 otherwise).
+fn gate_distance(gate: &Gate, mapping: &[usize], hardware: &HardwareProfile) -> f64 {
+    match gate.qubits()[..] {
+        [a, b] => hardware
+            .shortest_path_distance(mapping[a], mapping[b])
+            .unwrap_or(100) as f64,
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qns_simulator::routed_circuit_fidelity;
+
+    #[test]
+    fn test_lookahead_reduces_swaps_on_grid() {
+        let grid = HardwareProfile::grid("grid", 3, 3);
+        let mut circuit = CircuitGenome::new(9);
+        circuit.add_gate(Gate::H(1)).unwrap();
+        circuit
+            .add_gates([
+                Gate::CNOT(1, 5),
+                Gate::CNOT(8, 5),
+                Gate::CNOT(5, 7),
+                Gate::CNOT(1, 3),
+                Gate::CNOT(2, 5),
+                Gate::CNOT(2, 4),
+                Gate::CNOT(1, 3),
+                Gate::CNOT(7, 5),
+                Gate::CNOT(8, 3),
+                Gate::CNOT(5, 4),
+                Gate::CNOT(2, 7),
+                Gate::CNOT(6, 1),
+            ])
+            .unwrap();
+
+        let greedy = SabreRouter::default().with_lookahead(SabreConfig::greedy());
+        let lookahead = SabreRouter::default().with_lookahead(SabreConfig {
+            lookahead_depth: 20,
+            lookahead_weight: 0.5,
+        });
+        let (greedy_routed, greedy_mapping) = greedy.route(&circuit, &grid).unwrap();
+        let (lookahead_routed, lookahead_mapping) = lookahead.route(&circuit, &grid).unwrap();
+
+        let greedy_swaps = greedy_routed.metadata.routing_gates.len();
+        let lookahead_swaps = lookahead_routed.metadata.routing_gates.len();
+        assert!(
+            lookahead_swaps < greedy_swaps,
+            "lookahead {} vs greedy {}",
+            lookahead_swaps,
+            greedy_swaps
+        );
+
+        for (routed, mapping) in [
+            (&greedy_routed, &greedy_mapping),
+            (&lookahead_routed, &lookahead_mapping),
+        ] {
+            assert!(grid.is_circuit_valid(routed));
+            // The input has no SWAPs, so every SWAP is a tagged routing gate
+            for (i, gate) in routed.gates.iter().enumerate() {
+                assert_eq!(routed.is_routing_gate(i), matches!(gate, Gate::SWAP(..)));
+            }
+            let fidelity = routed_circuit_fidelity(&circuit, routed, mapping).unwrap();
+            assert!((fidelity - 1.0).abs() < 1e-9, "{}", fidelity);
+        }
+    }
+
+    #[test]
+    fn test_lookahead_fields() {
+        // Greedy unless a window is requested, as before the lookahead existed
+        assert_eq!(SabreRouter::default().lookahead(), SabreConfig::greedy());
+        let router = SabreRouter::new(0.25, 0.001, 10, 0.5);
+        assert_eq!(router.lookahead_depth, 0);
+        assert_eq!(router.lookahead_weight, 0.25);
+
+        let mut router = router.with_lookahead(SabreConfig {
+            lookahead_depth: 5,
+            lookahead_weight: 0.1,
+        });
+        assert_eq!(router.lookahead_depth, 5);
+        assert_eq!(router.lookahead_weight, 0.1);
+
+        // The fields are the only copy of the settings
+        router.lookahead_weight = 2.0;
+        assert_eq!(
+            router.lookahead(),
+            SabreConfig {
+                lookahead_depth: 5,
+                lookahead_weight: 2.0,
+            }
+        );
+    }
+}
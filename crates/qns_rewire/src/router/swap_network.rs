@@ -0,0 +1,174 @@
+use qns_core::types::Gate;
+use qns_core::{HardwareProfile, QnsError};
+use std::collections::VecDeque;
+
+/// Synthesizes a SWAP network realizing a qubit permutation on the hardware.
+///
+/// `permutation[i]` is the physical qubit where the state currently on
+/// physical qubit `i` must end up. Qubits beyond `permutation.len()` stay put.
+///
+/// Tokens are routed along a BFS spanning tree of each connected component,
+/// filling tree leaves first (Approximate Token Swapping). On a line this is
+/// bubble sort and uses exactly as many SWAPs as the permutation has
+/// inversions, which is the minimum for nearest-neighbour swaps.
+pub fn swap_network(
+    permutation: &[usize],
+    hardware: &HardwareProfile,
+) -> Result<Vec<Gate>, QnsError> {
+    let n = hardware.num_qubits;
+    if permutation.len() > n {
+        return Err(QnsError::InvalidQubit(permutation.len() - 1, n));
+    }
+
+    let mut target: Vec<usize> = (0..n).collect();
+    let mut seen = vec![false; n];
+    for (src, &dst) in permutation.iter().enumerate() {
+        if dst >= n {
+            return Err(QnsError::InvalidQubit(dst, n));
+        }
+        if seen[dst] {
+            return Err(QnsError::Rewire(format!(
+                "Not a permutation: qubit {} is targeted twice",
+                dst
+            )));
+        }
+        seen[dst] = true;
+        target[src] = dst;
+    }
+    if let Some(dst) = (0..permutation.len()).find(|&q| !seen[q]) {
+        return Err(QnsError::Rewire(format!(
+            "Not a permutation: qubit {} is never targeted",
+            dst
+        )));
+    }
+
+    // token_at[p] = qubit whose state currently sits on physical p
+    let mut token_at: Vec<usize> = (0..n).collect();
+    let mut gates = Vec::new();
+    let mut visited = vec![false; n];
+
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+
+        // BFS spanning tree of this component
+        let mut order = Vec::new();
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![0usize; n];
+        let mut queue = VecDeque::from([root]);
+        visited[root] = true;
+        while let Some(q) = queue.pop_front() {
+            order.push(q);
+            for nb in hardware.neighbors(q) {
+                if !visited[nb] {
+                    visited[nb] = true;
+                    parent[nb] = q;
+                    depth[nb] = depth[q] + 1;
+                    queue.push_back(nb);
+                }
+            }
+        }
+
+        for &q in &order {
+            if !order.contains(&target[q]) {
+                return Err(QnsError::Rewire(format!(
+                    "Cannot move qubit {} to {}: no connecting path",
+                    q, target[q]
+                )));
+            }
+        }
+
+        // Reverse BFS order always removes a leaf of the remaining subtree
+        for &dest in order.iter().rev() {
+            let src = token_at
+                .iter()
+                .position(|&t| target[t] == dest)
+                .expect("every destination has a token");
+            if src == dest {
+                continue;
+            }
+
+            // Tree path src -> lca <- dest
+            let (mut up, mut down) = (vec![src], vec![dest]);
+            let (mut a, mut b) = (src, dest);
+            while a != b {
+                if depth[a] >= depth[b] {
+                    a = parent[a];
+                    up.push(a);
+                } else {
+                    b = parent[b];
+                    down.push(b);
+                }
+            }
+            down.pop();
+            up.extend(down.into_iter().rev());
+
+            for step in up.windows(2) {
+                token_at.swap(step[0], step[1]);
+                gates.push(Gate::SWAP(step[0], step[1]));
+            }
+        }
+    }
+
+    Ok(gates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies SWAPs to the identity layout, returning where each qubit ended up.
+    fn apply(num_qubits: usize, gates: &[Gate]) -> Vec<usize> {
+        let mut token_at: Vec<usize> = (0..num_qubits).collect();
+        for gate in gates {
+            match gate {
+                Gate::SWAP(a, b) => token_at.swap(*a, *b),
+                other => panic!("unexpected gate {}", other),
+            }
+        }
+        let mut position = vec![0; num_qubits];
+        for (p, &t) in token_at.iter().enumerate() {
+            position[t] = p;
+        }
+        position
+    }
+
+    #[test]
+    fn test_swap_network_realizes_permutation() {
+        let line = HardwareProfile::linear("line", 5);
+        let reversal = [4, 3, 2, 1, 0];
+        let gates = swap_network(&reversal, &line).unwrap();
+        assert_eq!(apply(5, &gates), reversal);
+        for gate in &gates {
+            let q = gate.qubits();
+            assert!(line.are_connected(q[0], q[1]));
+        }
+        // Inversion count of a full reversal: 5 * 4 / 2
+        assert_eq!(gates.len(), 10);
+
+        let grid = HardwareProfile::grid("grid", 2, 3);
+        let perm = [5, 0, 3, 4, 1, 2];
+        let gates = swap_network(&perm, &grid).unwrap();
+        assert_eq!(apply(6, &gates), perm);
+        assert!(gates.iter().all(|g| {
+            let q = g.qubits();
+            grid.are_connected(q[0], q[1])
+        }));
+
+        // Partial permutation leaves the remaining qubits alone
+        let gates = swap_network(&[1, 0], &line).unwrap();
+        assert_eq!(gates, vec![Gate::SWAP(0, 1)]);
+    }
+
+    #[test]
+    fn test_swap_network_rejects_invalid_input() {
+        let line = HardwareProfile::linear("line", 3);
+        assert!(swap_network(&[0, 0, 1], &line).is_err());
+        assert!(swap_network(&[0, 1, 3], &line).is_err());
+
+        let disconnected = HardwareProfile::from_coupling_map("split", 4, &[[0, 1], [2, 3]]);
+        assert!(swap_network(&[2, 1, 0, 3], &disconnected).is_err());
+        assert_eq!(swap_network(&[1, 0, 3, 2], &disconnected).unwrap().len(), 2);
+    }
+}
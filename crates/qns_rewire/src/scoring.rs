@@ -12,6 +12,41 @@ pub struct ScoreConfig {
     pub gate_time_2q: f64,
     /// Measurement time (ns) - typical: 1000ns
     pub measure_time: f64,
+    /// Combine error contributions in log-space instead of multiplying and clamping.
+    ///
+    /// Linear-space estimates saturate at 0.0 for very deep or noisy circuits,
+    /// so all such variants tie. Summing `-ln(1 - error)` keeps them ordered.
+    pub log_space: bool,
+    /// Maximum number of two-qubit gates the hardware can run simultaneously.
+    ///
+    /// Shared control electronics may prevent driving all couplers at once;
+    /// two-qubit gates beyond this limit are delayed. `None` means unlimited.
+    pub max_parallel_2q: Option<usize>,
+    /// Minimum error charged for a two-qubit gate on a non-existent edge.
+    ///
+    /// Such a gate would need SWAP routing before it could run. Set to
+    /// `f64::INFINITY` for devices where unconnected gates are impossible, so
+    /// placements that need them always score zero fidelity.
+    pub missing_edge_penalty: f64,
+    /// Multiple of the noise model's two-qubit error charged for a gate on a
+    /// non-existent edge, when larger than `missing_edge_penalty`.
+    pub missing_edge_multiplier: f64,
+    /// Reject fidelity estimates outside `[0, 1]` instead of clamping them.
+    ///
+    /// A raw estimate beyond [`FIDELITY_TOLERANCE`] of the unit interval
+    /// indicates a modeling error (e.g. a negative error rate) that clamping
+    /// would hide. Intended for development and tests.
+    ///
+    /// Only the `try_estimate_*` functions honor this; the plain
+    /// `estimate_*` wrappers always clamp.
+    pub strict: bool,
+    /// Per-qubit importance weights for the idle decoherence penalty.
+    ///
+    /// A qubit's idle log-survival is scaled by its weight, so ancillas that
+    /// are uncomputed before readout can be down-weighted (e.g. 0.1) and the
+    /// optimizer prefers orderings that protect the output qubits. Qubits
+    /// without an entry have weight 1.0.
+    pub qubit_weights: Vec<f64>,
 }
 
 impl Default for ScoreConfig {
@@ -20,10 +55,23 @@ impl Default for ScoreConfig {
             gate_time_1q: 35.0,
             gate_time_2q: 300.0,
             measure_time: 1000.0,
+            log_space: false,
+            max_parallel_2q: None,
+            missing_edge_penalty: 0.15,
+            missing_edge_multiplier: 3.0,
+            strict: false,
+            qubit_weights: Vec::new(),
         }
     }
 }
 
+impl ScoreConfig {
+    /// Importance weight of qubit `q` (1.0 unless set in [`Self::qubit_weights`]).
+    pub fn qubit_weight(&self, q: usize) -> f64 {
+        self.qubit_weights.get(q).copied().unwrap_or(1.0)
+    }
+}
+
 /// Error type for scoring operations
 #[derive(Debug, Clone, PartialEq)]
 pub enum ScoringError {
@@ -35,6 +83,10 @@ pub enum ScoringError {
     InvalidMakespan(f64),
     /// Physical constraint violation: T2 > 2*T1
     T2ExceedsPhysicalLimit { t1: f64, t2: f64 },
+    /// Raw fidelity estimate outside `[0, 1]` in strict mode
+    FidelityOutOfRange(f64),
+    /// Per-qubit noise map has no entry for this qubit
+    MissingQubitNoise(usize),
 }
 
 impl std::fmt::Display for ScoringError {
@@ -53,12 +105,24 @@ impl std::fmt::Display for ScoringError {
                     2.0 * t1
                 )
             },
+            ScoringError::FidelityOutOfRange(v) => {
+                write!(f, "Fidelity estimate {} is outside [0, 1]", v)
+            },
+            ScoringError::MissingQubitNoise(q) => {
+                write!(f, "No noise profile for qubit {}", q)
+            },
         }
     }
 }
 
 impl std::error::Error for ScoringError {}
 
+impl From<ScoringError> for qns_core::QnsError {
+    fn from(e: ScoringError) -> Self {
+        qns_core::QnsError::rewire(e.to_string())
+    }
+}
+
 // ============================================================================
 // Task 1.1: decay_estimation - T1/T2 based decay calculation
 // ============================================================================
@@ -84,7 +148,7 @@ impl std::error::Error for ScoringError {}
 /// # Edge Cases
 /// - `makespan = 0` → decay = 0 (no time for decoherence)
 /// - `T1 = ∞` or `T2 = ∞` → reduced/no decay (ideal qubit)
-/// - `T2 > 2*T1` → Warning logged, T2 clamped to 2*T1 (physical constraint)
+/// - `T2 > 2*T1` → `tracing` warning emitted, T2 clamped to 2*T1 (physical constraint)
 ///
 /// # Example
 /// ```ignore
@@ -92,6 +156,21 @@ impl std::error::Error for ScoringError {}
 /// assert!(decay > 0.0 && decay < 1.0);
 /// ```
 pub fn decay_estimation(makespan_ns: f64, t1_us: f64, t2_us: f64) -> Result<f64, ScoringError> {
+    let log_survival = log_decay_survival(makespan_ns, t1_us, t2_us)?;
+
+    // Decay probability = 1 - survival probability
+    let decay = 1.0 - log_survival.exp();
+
+    // Clamp to valid range (handles floating point edge cases)
+    Ok(decay.clamp(0.0, 1.0))
+}
+
+/// Natural log of the T1/T2 survival probability over `makespan_ns`.
+///
+/// `ln(survival) = -makespan / T1 - makespan / T2`, computed directly so that
+/// long makespans do not underflow to a survival of exactly zero. Inputs are
+/// validated and clamped exactly as in `decay_estimation`.
+pub fn log_decay_survival(makespan_ns: f64, t1_us: f64, t2_us: f64) -> Result<f64, ScoringError> {
     // Validate inputs
     if makespan_ns < 0.0 {
         return Err(ScoringError::InvalidMakespan(makespan_ns));
@@ -116,14 +195,17 @@ pub fn decay_estimation(makespan_ns: f64, t1_us: f64, t2_us: f64) -> Result<f64,
     }
 
     // Physical constraint: T2 <= 2*T1
-    // If violated, clamp T2 and log warning (in production, use proper logging)
+    // If violated, clamp T2 and report it as a structured tracing event
     let t2_clamped = if t2_us > 2.0 * t1_us && !t1_us.is_infinite() {
-        #[cfg(debug_assertions)]
-        eprintln!(
-            "[WARN] T2 ({} μs) > 2*T1 ({} μs): physically impossible, clamping to {}",
-            t2_us,
+        tracing::warn!(
             t1_us,
-            2.0 * t1_us
+            t2_us,
+            clamped_t2_us = 2.0 * t1_us,
+            "{}",
+            ScoringError::T2ExceedsPhysicalLimit {
+                t1: t1_us,
+                t2: t2_us
+            }
         );
         2.0 * t1_us
     } else {
@@ -133,27 +215,69 @@ pub fn decay_estimation(makespan_ns: f64, t1_us: f64, t2_us: f64) -> Result<f64,
     // Convert makespan from ns to μs for consistent units
     let makespan_us = makespan_ns / 1000.0;
 
-    // Calculate decay factors
-    let t1_factor = if t1_us.is_infinite() {
-        1.0 // No T1 decay for ideal qubit
+    // Log decay factors
+    let t1_term = if t1_us.is_infinite() {
+        0.0 // No T1 decay for ideal qubit
     } else {
-        (-makespan_us / t1_us).exp()
+        -makespan_us / t1_us
     };
 
-    let t2_factor = if t2_clamped.is_infinite() {
-        1.0 // No T2 decay for ideal qubit
+    let t2_term = if t2_clamped.is_infinite() {
+        0.0 // No T2 decay for ideal qubit
     } else {
-        (-makespan_us / t2_clamped).exp()
+        -makespan_us / t2_clamped
     };
 
-    // Combined survival probability
-    let survival = t1_factor * t2_factor;
+    Ok(t1_term + t2_term)
+}
 
-    // Decay probability = 1 - survival probability
-    let decay = 1.0 - survival;
+/// Log survival over the given time windows, honoring `noise.t1_schedule`.
+///
+/// Without a schedule this is `log_decay_survival(total_ns, T1, T2)`. With a
+/// schedule the T1 term is integrated over each window's actual position in
+/// the circuit, so the same idle time costs less during high-T1 periods.
+fn log_window_survival(
+    windows: impl Iterator<Item = (f64, f64)>,
+    total_ns: f64,
+    noise: &NoiseVector,
+) -> Result<f64, ScoringError> {
+    if noise.t1_schedule.is_none() {
+        return log_decay_survival(total_ns, noise.t1_mean, noise.t2_mean);
+    }
+    let t2_term = log_decay_survival(total_ns, f64::INFINITY, noise.t2_mean)?;
+    let t1_term: f64 = windows
+        .map(|(start, end)| noise.t1_decay_exponent(start, end))
+        .sum();
+    Ok(t2_term - t1_term)
+}
 
-    // Clamp to valid range (handles floating point edge cases)
-    Ok(decay.clamp(0.0, 1.0))
+/// Idle windows of a qubit: gaps before, between and after its gates.
+fn idle_windows(schedule: &QubitSchedule, makespan: f64) -> Vec<(f64, f64)> {
+    let mut windows = Vec::new();
+    let mut prev_end = 0.0;
+    for &(start, end) in &schedule.activities {
+        if start > prev_end {
+            windows.push((prev_end, start));
+        }
+        prev_end = end;
+    }
+    if makespan > prev_end {
+        windows.push((prev_end, makespan));
+    }
+    windows
+}
+
+/// Log survival of one qubit over its idle windows.
+fn log_qubit_idle_survival(
+    schedule: &QubitSchedule,
+    makespan: f64,
+    noise: &NoiseVector,
+) -> Result<f64, ScoringError> {
+    log_window_survival(
+        idle_windows(schedule, makespan).into_iter(),
+        schedule.idle_time,
+        noise,
+    )
 }
 
 /// Convenience function for decay estimation with NoiseVector
@@ -173,6 +297,9 @@ pub fn decay_estimation_from_noise(
 /// # Formula
 /// `total_error = count_1q * gate_error_1q + count_2q * gate_error_2q + count_measure * readout_error`
 ///
+/// Every qubit shares the same rates; use `gate_error_sum_with_hardware` for
+/// per-qubit and per-edge rates from a `HardwareProfile`.
+///
 /// # Arguments
 /// * `circuit` - The circuit to analyze
 /// * `noise` - Noise parameters including gate error rates
@@ -180,38 +307,45 @@ pub fn decay_estimation_from_noise(
 /// # Returns
 /// Total accumulated error (may exceed 1.0 for deep circuits)
 pub fn gate_error_sum(circuit: &CircuitGenome, noise: &NoiseVector) -> f64 {
-    let mut count_1q = 0usize;
-    let mut count_2q = 0usize;
-    let mut count_measure = 0usize;
+    circuit
+        .gates
+        .iter()
+        .map(|gate| gate_error_rate(gate, noise))
+        .sum()
+}
 
-    for gate in &circuit.gates {
-        match gate {
-            // Single-qubit gates
-            Gate::H(_)
-            | Gate::X(_)
-            | Gate::Y(_)
-            | Gate::Z(_)
-            | Gate::S(_)
-            | Gate::T(_)
-            | Gate::Rx(_, _)
-            | Gate::Ry(_, _)
-            | Gate::Rz(_, _) => {
-                count_1q += 1;
-            },
-            // Two-qubit gates
-            Gate::CNOT(_, _) | Gate::CZ(_, _) | Gate::SWAP(_, _) => {
-                count_2q += 1;
-            },
-            // Measurement
-            Gate::Measure(_) => {
-                count_measure += 1;
-            },
-        }
+/// Error rate of a single gate under the uniform noise model.
+fn gate_error_rate(gate: &Gate, noise: &NoiseVector) -> f64 {
+    match gate {
+        // Single-qubit gates
+        Gate::H(_)
+        | Gate::X(_)
+        | Gate::Y(_)
+        | Gate::Z(_)
+        | Gate::S(_)
+        | Gate::T(_)
+        | Gate::Rx(_, _)
+        | Gate::Ry(_, _)
+        | Gate::Rz(_, _)
+        | Gate::U(_, _, _, _) => noise.gate_error_1q,
+        // Two-qubit gates
+        Gate::CNOT(_, _)
+        | Gate::CZ(_, _)
+        | Gate::CPhase(_, _, _)
+        | Gate::SWAP(_, _)
+        | Gate::FSim(_, _, _, _) => noise.gate_error_2q,
+        // Three-qubit gates
+        Gate::Toffoli(_, _, _) => 2.0 * noise.gate_error_2q,
+        // Measurement, and reset as a measurement plus conditional flip
+        Gate::Measure(_) | Gate::Reset(_) => noise.readout_error,
     }
+}
 
-    (count_1q as f64) * noise.gate_error_1q
-        + (count_2q as f64) * noise.gate_error_2q
-        + (count_measure as f64) * noise.readout_error
+/// Natural log of the probability that no error occurs on a gate with `error` rate.
+///
+/// Error rates at or above 1.0 are floored so the result stays finite.
+fn log_success(error: f64) -> f64 {
+    (1.0 - error).max(f64::MIN_POSITIVE).ln()
 }
 
 // ============================================================================
@@ -222,7 +356,9 @@ pub fn gate_error_sum(circuit: &CircuitGenome, noise: &NoiseVector) -> f64 {
 ///
 /// Unlike `gate_error_sum`, this function uses the actual per-edge error rates
 /// from the HardwareProfile for two-qubit gates, enabling optimization based
-/// on routing through higher-fidelity edges.
+/// on routing through higher-fidelity edges. Single-qubit gates likewise use
+/// their qubit's `single_gate_fidelity`, falling back to `noise.gate_error_1q`
+/// for qubits without properties.
 ///
 /// # Arguments
 /// * `circuit` - The circuit to analyze
@@ -231,46 +367,66 @@ pub fn gate_error_sum(circuit: &CircuitGenome, noise: &NoiseVector) -> f64 {
 ///
 /// # Returns
 /// Total accumulated error using edge-specific error rates
+///
+/// Gates on non-existent edges use the default penalty model; see
+/// `gate_error_sum_with_hardware_config` to configure it.
 pub fn gate_error_sum_with_hardware(
     circuit: &CircuitGenome,
     noise: &NoiseVector,
     hardware: &HardwareProfile,
 ) -> f64 {
-    let mut total_error = 0.0;
+    gate_error_sum_with_hardware_config(circuit, noise, hardware, &ScoreConfig::default())
+}
 
-    for gate in &circuit.gates {
-        match gate {
-            // Single-qubit gates: use noise model
-            Gate::H(_)
-            | Gate::X(_)
-            | Gate::Y(_)
-            | Gate::Z(_)
-            | Gate::S(_)
-            | Gate::T(_)
-            | Gate::Rx(_, _)
-            | Gate::Ry(_, _)
-            | Gate::Rz(_, _) => {
-                total_error += noise.gate_error_1q;
-            },
-            // Two-qubit gates: use per-edge fidelity from hardware
-            Gate::CNOT(q1, q2) | Gate::CZ(q1, q2) | Gate::SWAP(q1, q2) => {
-                if let Some(coupler) = hardware.get_coupler(*q1, *q2) {
-                    // Error = 1 - fidelity
-                    total_error += coupler.gate_fidelity.error_rate();
-                } else {
-                    // Non-existent edge: high penalty (would require SWAP routing)
-                    // Use 3x the noise model's 2Q error as penalty, minimum 0.15
-                    total_error += (noise.gate_error_2q * 3.0).max(0.15);
-                }
-            },
-            // Measurement
-            Gate::Measure(_) => {
-                total_error += noise.readout_error;
-            },
-        }
-    }
+/// Like `gate_error_sum_with_hardware`, but takes the penalty model for gates
+/// on non-existent edges from `config`.
+pub fn gate_error_sum_with_hardware_config(
+    circuit: &CircuitGenome,
+    noise: &NoiseVector,
+    hardware: &HardwareProfile,
+    config: &ScoreConfig,
+) -> f64 {
+    circuit
+        .gates
+        .iter()
+        .map(|gate| gate_error_rate_with_hardware(gate, noise, hardware, config))
+        .sum()
+}
 
-    total_error
+/// Error rate of a single gate using per-qubit and per-edge fidelities where available.
+fn gate_error_rate_with_hardware(
+    gate: &Gate,
+    noise: &NoiseVector,
+    hardware: &HardwareProfile,
+    config: &ScoreConfig,
+) -> f64 {
+    match gate {
+        // Two-qubit gates: use per-edge fidelity from hardware
+        Gate::CNOT(q1, q2)
+        | Gate::CZ(q1, q2)
+        | Gate::CPhase(q1, q2, _)
+        | Gate::SWAP(q1, q2)
+        | Gate::FSim(q1, q2, _, _) => {
+            if let Some(coupler) = hardware.get_coupler(*q1, *q2) {
+                // Error = 1 - fidelity
+                coupler.gate_fidelity.error_rate()
+            } else {
+                // Non-existent edge: high penalty (would require SWAP routing)
+                (noise.gate_error_2q * config.missing_edge_multiplier)
+                    .max(config.missing_edge_penalty)
+            }
+        },
+        // Single-qubit gates: use the qubit's own fidelity
+        _ if gate.is_single_qubit() => gate
+            .qubits()
+            .first()
+            .and_then(|&q| hardware.qubit_properties.get(q))
+            .map_or(noise.gate_error_1q, |props| {
+                props.single_gate_fidelity.error_rate()
+            }),
+        // Measurement and remaining gates: use noise model
+        _ => gate_error_rate(gate, noise),
+    }
 }
 
 /// Calculates total error induced by crosstalk.
@@ -285,53 +441,135 @@ pub fn gate_error_sum_with_hardware(
 /// # Returns
 /// Total accumulated crosstalk error probability
 pub fn calculate_crosstalk_error(circuit: &CircuitGenome, hardware: &HardwareProfile) -> f64 {
-    let crosstalk = &hardware.crosstalk;
-    if crosstalk.is_empty() {
+    if hardware.crosstalk.is_empty() {
         return 0.0;
     }
 
-    let mut total_xtalk_error = 0.0;
-
-    // For each gate, determine active qubits and checking spectators
     // Note: This is a simplified model that assumes sequential-like accumulation
     // for scoring purposes. In a real schedule, parallel gates might trigger
     // crosstalk simultaneously, but the error sum remains roughly additive
     // for small probabilities.
-    for gate in &circuit.gates {
-        let active_qubits = gate.qubits();
-
-        // Check for interactions affecting spectators
-        for (&(q1, q2), &strength) in &crosstalk.interactions {
-            // Determine if this interaction is triggered
-            // Trigger condition: one qubit is active, the other is idle (spectator)
-            // If both are active, we assume the gate error covers interaction effects
-            // or that crosstalk is negligible vs gate error.
-
-            let is_q1_active = active_qubits.contains(&q1);
-            let is_q2_active = active_qubits.contains(&q2);
-
-            let spectator = if is_q1_active && !is_q2_active {
-                Some(q2)
-            } else if is_q2_active && !is_q1_active {
-                Some(q1)
-            } else {
-                None
-            };
+    circuit
+        .gates
+        .iter()
+        .map(|gate| gate_crosstalk_error(gate, hardware))
+        .sum()
+}
 
-            if spectator.is_some() {
-                // Add crosstalk error (strength = probability of error)
-                total_xtalk_error += strength;
-            }
+/// Crosstalk error probability induced on spectator qubits by a single gate.
+fn gate_crosstalk_error(gate: &Gate, hardware: &HardwareProfile) -> f64 {
+    let active_qubits = gate.qubits();
+    let mut total_xtalk_error = 0.0;
 
-            // Note: If both active (e.g. CNOT between q1, q2), no "crosstalk"
-            // penalty added here, as it's part of the gate error.
-            // If neither active, no crosstalk.
+    // Check for interactions affecting spectators
+    for (&(q1, q2), &strength) in &hardware.crosstalk.interactions {
+        // Determine if this interaction is triggered
+        // Trigger condition: one qubit is active, the other is idle (spectator)
+        // If both are active, we assume the gate error covers interaction effects
+        // or that crosstalk is negligible vs gate error.
+        let is_q1_active = active_qubits.contains(&q1);
+        let is_q2_active = active_qubits.contains(&q2);
+
+        if is_q1_active != is_q2_active {
+            // Add crosstalk error (strength = probability of error)
+            total_xtalk_error += strength;
         }
+
+        // Note: If both active (e.g. CNOT between q1, q2), no "crosstalk"
+        // penalty added here, as it's part of the gate error.
+        // If neither active, no crosstalk.
     }
 
     total_xtalk_error
 }
 
+/// Calculates crosstalk between gates that execute simultaneously.
+///
+/// Gates are scheduled as in `calculate_qubit_schedules`.
+/// For every pair of gates whose execution windows overlap, the crosstalk
+/// strengths between their qubits are summed. Unlike
+/// `calculate_crosstalk_error`, which charges idle spectators per gate, this
+/// captures hotspots created by running interacting gates in the same layer.
+///
+/// # Returns
+/// Total crosstalk strength between concurrently executing gates
+pub fn calculate_parallel_crosstalk(
+    circuit: &CircuitGenome,
+    hardware: &HardwareProfile,
+    config: &ScoreConfig,
+) -> f64 {
+    if hardware.crosstalk.is_empty() {
+        return 0.0;
+    }
+
+    let windows: Vec<(f64, f64, Vec<usize>)> = circuit
+        .gates
+        .iter()
+        .zip(schedule_gates(circuit, config))
+        .map(|(gate, (start, end))| (start, end, gate.qubits()))
+        .collect();
+
+    let mut total = 0.0;
+    for (i, (start1, end1, qubits1)) in windows.iter().enumerate() {
+        for (start2, end2, qubits2) in &windows[i + 1..] {
+            if start2 >= end1 || start1 >= end2 {
+                continue;
+            }
+            for &a in qubits1 {
+                for &b in qubits2 {
+                    if let Some(strength) = hardware.crosstalk.get_interaction(a, b) {
+                        total += strength;
+                    }
+                }
+            }
+        }
+    }
+
+    total
+}
+
+/// Execution time of a gate in nanoseconds.
+fn gate_duration(gate: &Gate, config: &ScoreConfig) -> f64 {
+    match gate {
+        Gate::H(_) | Gate::X(_) | Gate::Y(_) | Gate::Z(_) | Gate::S(_) | Gate::T(_) => {
+            config.gate_time_1q
+        },
+        Gate::Rx(_, _) | Gate::Ry(_, _) | Gate::Rz(_, _) | Gate::U(_, _, _, _) => {
+            config.gate_time_1q
+        },
+        Gate::CNOT(_, _)
+        | Gate::CZ(_, _)
+        | Gate::CPhase(_, _, _)
+        | Gate::SWAP(_, _)
+        | Gate::FSim(_, _, _, _) => config.gate_time_2q,
+        Gate::Toffoli(_, _, _) => 2.0 * config.gate_time_2q,
+        Gate::Measure(_) | Gate::Reset(_) => config.measure_time,
+    }
+}
+
+/// Slack allowed outside `[0, 1]` for floating-point error in strict mode.
+pub const FIDELITY_TOLERANCE: f64 = 1e-9;
+
+/// Clamps a raw fidelity estimate to `[0, 1]`, or rejects it in strict mode
+/// when it lies more than [`FIDELITY_TOLERANCE`] outside that range.
+fn check_fidelity(fidelity: f64, config: &ScoreConfig) -> Result<f64, ScoringError> {
+    let in_range = (-FIDELITY_TOLERANCE..=1.0 + FIDELITY_TOLERANCE).contains(&fidelity);
+    if config.strict && !in_range {
+        return Err(ScoringError::FidelityOutOfRange(fidelity));
+    }
+    Ok(fidelity.clamp(0.0, 1.0))
+}
+
+/// Unwraps a `try_estimate_*` result for the plain wrappers, clamping an
+/// out-of-range estimate as if [`ScoreConfig::strict`] were off.
+fn clamp_estimate(result: Result<f64, ScoringError>) -> f64 {
+    match result {
+        Ok(fidelity) => fidelity,
+        Err(ScoringError::FidelityOutOfRange(fidelity)) => fidelity.clamp(0.0, 1.0),
+        Err(e) => panic!("{}", e),
+    }
+}
+
 /// Estimates fidelity with hardware-specific per-edge error rates.
 ///
 /// This combines:
@@ -345,33 +583,68 @@ pub fn calculate_crosstalk_error(circuit: &CircuitGenome, hardware: &HardwarePro
 /// # Example Use Case
 /// On IBM Heron, edge (0,1) might have 99.5% fidelity while (1,2) has 98%.
 /// This function scores circuits that use (0,1) higher than those using (1,2).
+///
+/// Ignores [`ScoreConfig::strict`] and always clamps to `[0, 1]`; strict
+/// callers should use [`try_estimate_fidelity_with_hardware`].
 pub fn estimate_fidelity_with_hardware(
     circuit: &CircuitGenome,
     noise: &NoiseVector,
     hardware: &HardwareProfile,
     config: &ScoreConfig,
 ) -> f64 {
+    clamp_estimate(try_estimate_fidelity_with_hardware(
+        circuit, noise, hardware, config,
+    ))
+}
+
+/// Fallible form of [`estimate_fidelity_with_hardware`].
+///
+/// Returns [`ScoringError::FidelityOutOfRange`] in strict mode when the raw
+/// estimate falls outside `[0, 1]`; otherwise the clamped estimate.
+pub fn try_estimate_fidelity_with_hardware(
+    circuit: &CircuitGenome,
+    noise: &NoiseVector,
+    hardware: &HardwareProfile,
+    config: &ScoreConfig,
+) -> Result<f64, ScoringError> {
     if circuit.gates.is_empty() {
-        return 1.0;
+        return Ok(1.0);
     }
 
-    let (schedules, _makespan) = calculate_qubit_schedules(circuit, config);
+    let (schedules, makespan) = calculate_qubit_schedules(circuit, config);
+
+    if config.log_space {
+        let Some(log_idle) = log_idle_survival(&schedules, makespan, noise, config) else {
+            return Ok(0.0);
+        };
+        let log_gates: f64 = circuit
+            .gates
+            .iter()
+            .map(|gate| {
+                log_success(gate_error_rate_with_hardware(gate, noise, hardware, config))
+                    + log_success(gate_crosstalk_error(gate, hardware))
+            })
+            .sum();
+        return check_fidelity((log_idle + log_gates).exp(), config);
+    }
 
     // Calculate per-qubit survival probability based on idle time
     let mut total_survival = 1.0;
 
-    for schedule in &schedules {
+    for (q, schedule) in schedules.iter().enumerate() {
         if !schedule.activities.is_empty() && schedule.idle_time > 0.0 {
-            let decay = match decay_estimation(schedule.idle_time, noise.t1_mean, noise.t2_mean) {
-                Ok(d) => d,
-                Err(_) => return 0.0,
+            let decay = match log_qubit_idle_survival(schedule, makespan, noise) {
+                Ok(log_survival) => {
+                    (1.0 - (log_survival * config.qubit_weight(q)).exp()).clamp(0.0, 1.0)
+                },
+                Err(_) => return Ok(0.0),
             };
             total_survival *= 1.0 - decay;
         }
     }
 
     // Hardware-aware gate error (uses per-edge fidelities)
-    let gate_error = gate_error_sum_with_hardware(circuit, noise, hardware);
+    let gate_error = gate_error_sum_with_hardware_config(circuit, noise, hardware, config);
 
     // Crosstalk error
     let xtalk_error = calculate_crosstalk_error(circuit, hardware);
@@ -381,7 +654,7 @@ pub fn estimate_fidelity_with_hardware(
     let total_error_sum = gate_error + xtalk_error;
 
     let fidelity = total_survival * (1.0 - total_error_sum.min(1.0));
-    fidelity.clamp(0.0, 1.0)
+    check_fidelity(fidelity, config)
 }
 
 // ============================================================================
@@ -400,46 +673,85 @@ pub fn estimate_fidelity_with_hardware(
 /// # Returns
 /// Makespan in nanoseconds
 pub fn critical_path(circuit: &CircuitGenome, config: &ScoreConfig) -> f64 {
-    if circuit.gates.is_empty() {
-        return 0.0;
-    }
+    // Return the maximum end time (critical path)
+    schedule_gates(circuit, config)
+        .into_iter()
+        .map(|(_, end)| end)
+        .fold(0.0_f64, f64::max)
+}
 
-    // Track end time for each qubit
+/// Schedules every gate as soon as possible, in program order.
+///
+/// A gate starts once all of its qubits are free. If `max_parallel_2q` is set,
+/// two-qubit gates are further delayed until fewer than that many two-qubit
+/// gates are running (a limit of 0 is treated as 1).
+///
+/// # Returns
+/// `(start, end)` time of each gate in nanoseconds
+fn schedule_gates(circuit: &CircuitGenome, config: &ScoreConfig) -> Vec<(f64, f64)> {
     let mut qubit_end_times: Vec<f64> = vec![0.0; circuit.num_qubits];
+    let mut windows = Vec::with_capacity(circuit.gates.len());
+    let mut running_2q: Vec<(f64, f64)> = Vec::new();
 
     for gate in &circuit.gates {
-        let (qubits, gate_time) = match gate {
-            // Single-qubit gates
-            Gate::H(q) | Gate::X(q) | Gate::Y(q) | Gate::Z(q) | Gate::S(q) | Gate::T(q) => {
-                (vec![*q], config.gate_time_1q)
-            },
-            Gate::Rx(q, _) | Gate::Ry(q, _) | Gate::Rz(q, _) => (vec![*q], config.gate_time_1q),
-            // Two-qubit gates
-            Gate::CNOT(q1, q2) | Gate::CZ(q1, q2) | Gate::SWAP(q1, q2) => {
-                (vec![*q1, *q2], config.gate_time_2q)
-            },
-            // Measurement
-            Gate::Measure(q) => (vec![*q], config.measure_time),
-        };
+        let qubits = gate.qubits();
+        let duration = gate_duration(gate, config);
 
         // Find the latest end time among involved qubits
-        let start_time = qubits
+        let mut start_time = qubits
             .iter()
             .filter_map(|&q| qubit_end_times.get(q).copied())
             .fold(0.0_f64, f64::max);
 
-        let end_time = start_time + gate_time;
+        if gate.is_two_qubit() {
+            if let Some(limit) = config.max_parallel_2q {
+                start_time = earliest_2q_slot(&running_2q, start_time, duration, limit.max(1));
+                running_2q.push((start_time, start_time + duration));
+            }
+        }
+
+        let end_time = start_time + duration;
 
         // Update end times for all involved qubits
         for &q in &qubits {
-            if q < qubit_end_times.len() {
-                qubit_end_times[q] = end_time;
+            if let Some(t) = qubit_end_times.get_mut(q) {
+                *t = end_time;
             }
         }
+        windows.push((start_time, end_time));
     }
 
-    // Return the maximum end time (critical path)
-    qubit_end_times.into_iter().fold(0.0_f64, f64::max)
+    windows
+}
+
+/// Earliest start `>= ready` at which a two-qubit gate of `duration` keeps
+/// the number of concurrently running two-qubit gates below `limit`.
+fn earliest_2q_slot(running: &[(f64, f64)], ready: f64, duration: f64, limit: usize) -> f64 {
+    let concurrent_at = |t: f64| running.iter().filter(|&&(s, e)| s <= t && t < e).count();
+
+    // The slot can only open when a running gate ends
+    let mut candidates: Vec<f64> = std::iter::once(ready)
+        .chain(running.iter().map(|&(_, e)| e).filter(|&e| e > ready))
+        .collect();
+    candidates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    for t in candidates {
+        // Concurrency only rises at interval starts, so check those within the window
+        let peak = running
+            .iter()
+            .map(|&(s, _)| s)
+            .filter(|&s| s > t && s < t + duration)
+            .chain(std::iter::once(t))
+            .map(concurrent_at)
+            .max()
+            .unwrap_or(0);
+        if peak < limit {
+            return t;
+        }
+    }
+
+    // Unreachable: after the last running gate ends nothing overlaps
+    running.iter().map(|&(_, e)| e).fold(ready, f64::max)
 }
 
 // ============================================================================
@@ -482,31 +794,13 @@ pub fn calculate_qubit_schedules(
 
     let mut qubit_end_times: Vec<f64> = vec![0.0; n];
 
-    for gate in &circuit.gates {
-        let (qubits, gate_time) = match gate {
-            Gate::H(q) | Gate::X(q) | Gate::Y(q) | Gate::Z(q) | Gate::S(q) | Gate::T(q) => {
-                (vec![*q], config.gate_time_1q)
-            },
-            Gate::Rx(q, _) | Gate::Ry(q, _) | Gate::Rz(q, _) => (vec![*q], config.gate_time_1q),
-            Gate::CNOT(q1, q2) | Gate::CZ(q1, q2) | Gate::SWAP(q1, q2) => {
-                (vec![*q1, *q2], config.gate_time_2q)
-            },
-            Gate::Measure(q) => (vec![*q], config.measure_time),
-        };
-
-        // Start time is max of all involved qubits' end times
-        let start_time = qubits
-            .iter()
-            .filter_map(|&q| qubit_end_times.get(q).copied())
-            .fold(0.0_f64, f64::max);
-
-        let end_time = start_time + gate_time;
-
+    for (gate, (start_time, end_time)) in circuit.gates.iter().zip(schedule_gates(circuit, config))
+    {
         // Update each qubit's schedule
-        for &q in &qubits {
+        for q in gate.qubits() {
             if q < n {
                 schedules[q].activities.push((start_time, end_time));
-                schedules[q].active_time += gate_time;
+                schedules[q].active_time += end_time - start_time;
                 qubit_end_times[q] = end_time;
             }
         }
@@ -555,11 +849,17 @@ pub fn calculate_total_idle_time(circuit: &CircuitGenome, config: &ScoreConfig)
 ///
 /// # Formula
 /// For each qubit q:
-///   decay_q = 1 - exp(-idle_time_q / T1) * exp(-idle_time_q / T2)
+///   decay_q = 1 - (exp(-idle_time_q / T1) * exp(-idle_time_q / T2))^w_q
+///
+/// where `w_q` is the qubit's importance weight ([`ScoreConfig::qubit_weights`], default 1.0).
 ///
 /// Total survival = product of (1 - decay_q) for all active qubits
 /// Fidelity = total_survival * (1 - gate_error)
 ///
+/// With `noise.readout_correlation > 0`, the readout share of the estimate is
+/// corrected for correlated errors across the terminal measurements (see
+/// `correlated_readout_success`).
+///
 /// # Why this matters for reordering
 /// Consider circuit [H(0), CNOT(0,1), H(1)]:
 /// - Qubit 1 is idle for 35ns before CNOT
@@ -568,26 +868,55 @@ pub fn calculate_total_idle_time(circuit: &CircuitGenome, config: &ScoreConfig)
 /// - Qubit 1 has 0ns idle time (H(1) runs parallel with H(0))
 ///
 /// The second ordering has less total idle time → higher fidelity
+///
+/// Ignores [`ScoreConfig::strict`] and always clamps to `[0, 1]`; strict
+/// callers should use [`try_estimate_fidelity_with_idle_tracking`].
 pub fn estimate_fidelity_with_idle_tracking(
     circuit: &CircuitGenome,
     noise: &NoiseVector,
     config: &ScoreConfig,
 ) -> f64 {
+    clamp_estimate(try_estimate_fidelity_with_idle_tracking(
+        circuit, noise, config,
+    ))
+}
+
+/// Fallible form of [`estimate_fidelity_with_idle_tracking`].
+///
+/// Returns [`ScoringError::FidelityOutOfRange`] in strict mode when the raw
+/// estimate falls outside `[0, 1]`; otherwise the clamped estimate.
+pub fn try_estimate_fidelity_with_idle_tracking(
+    circuit: &CircuitGenome,
+    noise: &NoiseVector,
+    config: &ScoreConfig,
+) -> Result<f64, ScoringError> {
     if circuit.gates.is_empty() {
-        return 1.0;
+        return Ok(1.0);
     }
 
-    let (schedules, _makespan) = calculate_qubit_schedules(circuit, config);
+    let (schedules, makespan) = calculate_qubit_schedules(circuit, config);
+
+    if config.log_space {
+        let Some(log_idle) = log_idle_survival(&schedules, makespan, noise, config) else {
+            return Ok(0.0);
+        };
+        let log_fidelity = log_idle
+            + log_gate_survival(circuit, noise)
+            + log_readout_correlation_correction(circuit, noise);
+        return check_fidelity(log_fidelity.exp(), config);
+    }
 
     // Calculate per-qubit survival probability based on idle time
     let mut total_survival = 1.0;
 
-    for schedule in &schedules {
+    for (q, schedule) in schedules.iter().enumerate() {
         // Only consider qubits that have gates (are active in the circuit)
         if !schedule.activities.is_empty() && schedule.idle_time > 0.0 {
-            let decay = match decay_estimation(schedule.idle_time, noise.t1_mean, noise.t2_mean) {
-                Ok(d) => d,
-                Err(_) => return 0.0,
+            let decay = match log_qubit_idle_survival(schedule, makespan, noise) {
+                Ok(log_survival) => {
+                    (1.0 - (log_survival * config.qubit_weight(q)).exp()).clamp(0.0, 1.0)
+                },
+                Err(_) => return Ok(0.0),
             };
             total_survival *= 1.0 - decay;
         }
@@ -597,8 +926,254 @@ pub fn estimate_fidelity_with_idle_tracking(
     let gate_error = gate_error_sum(circuit, noise);
 
     // Combined fidelity
-    let fidelity = total_survival * (1.0 - gate_error.min(1.0));
-    fidelity.clamp(0.0, 1.0)
+    let readout_correction = log_readout_correlation_correction(circuit, noise).exp();
+    let fidelity = total_survival * (1.0 - gate_error.min(1.0)) * readout_correction;
+    check_fidelity(fidelity, config)
+}
+
+/// Estimates fidelity with idle tracking, using each qubit's own noise.
+///
+/// Same model as [`estimate_fidelity_with_idle_tracking`], but qubit `q`
+/// decoheres with `noise[q]`'s T1/T2 during its idle windows, and each gate's
+/// error rate is the mean of its qubits' rates. A slow-relaxing qubit can then
+/// absorb idle time that would cost more elsewhere, which a single aggregate
+/// `NoiseVector` cannot express. Readout correlation is not modeled.
+///
+/// Ignores [`ScoreConfig::strict`] and always clamps to `[0, 1]`; strict
+/// callers should use [`try_estimate_fidelity_with_per_qubit_noise`].
+///
+/// # Panics
+/// Panics if `noise` has fewer entries than the circuit has qubits; use
+/// [`try_estimate_fidelity_with_per_qubit_noise`] to handle that as an error.
+pub fn estimate_fidelity_with_per_qubit_noise(
+    circuit: &CircuitGenome,
+    noise: &[NoiseVector],
+    config: &ScoreConfig,
+) -> f64 {
+    clamp_estimate(try_estimate_fidelity_with_per_qubit_noise(
+        circuit, noise, config,
+    ))
+}
+
+/// Fallible form of [`estimate_fidelity_with_per_qubit_noise`].
+///
+/// Returns [`ScoringError::MissingQubitNoise`] for the first qubit without an
+/// entry in `noise`, and [`ScoringError::FidelityOutOfRange`] in strict mode.
+pub fn try_estimate_fidelity_with_per_qubit_noise(
+    circuit: &CircuitGenome,
+    noise: &[NoiseVector],
+    config: &ScoreConfig,
+) -> Result<f64, ScoringError> {
+    if noise.len() < circuit.num_qubits {
+        return Err(ScoringError::MissingQubitNoise(noise.len()));
+    }
+    if circuit.gates.is_empty() {
+        return Ok(1.0);
+    }
+
+    let (schedules, makespan) = calculate_qubit_schedules(circuit, config);
+    let mut log_idle = 0.0;
+    for (q, schedule) in schedules.iter().enumerate() {
+        if schedule.activities.is_empty() || schedule.idle_time <= 0.0 {
+            continue;
+        }
+        match log_qubit_idle_survival(schedule, makespan, &noise[q]) {
+            Ok(log_survival) => log_idle += log_survival * config.qubit_weight(q),
+            Err(_) => return Ok(0.0),
+        }
+    }
+
+    let gate_errors = circuit
+        .gates
+        .iter()
+        .map(|gate| per_qubit_gate_error_rate(gate, noise));
+    let fidelity = if config.log_space {
+        (log_idle + gate_errors.map(log_success).sum::<f64>()).exp()
+    } else {
+        log_idle.exp() * (1.0 - gate_errors.sum::<f64>().min(1.0))
+    };
+    check_fidelity(fidelity, config)
+}
+
+/// Mean error rate of a gate over the noise profiles of its qubits.
+fn per_qubit_gate_error_rate(gate: &Gate, noise: &[NoiseVector]) -> f64 {
+    let qubits = gate.qubits();
+    let total: f64 = qubits
+        .iter()
+        .map(|&q| gate_error_rate(gate, &noise[q]))
+        .sum();
+    total / qubits.len().max(1) as f64
+}
+
+/// Number of measurements that are the last operation on their qubit.
+fn terminal_measurement_count(circuit: &CircuitGenome) -> usize {
+    let mut last_gate: Vec<Option<&Gate>> = vec![None; circuit.num_qubits];
+    for gate in &circuit.gates {
+        for q in gate.qubits() {
+            if q < last_gate.len() {
+                last_gate[q] = Some(gate);
+            }
+        }
+    }
+    last_gate
+        .iter()
+        .filter(|gate| matches!(gate, Some(Gate::Measure(_))))
+        .count()
+}
+
+/// Probability that none of `n` readouts fails when each fails with
+/// probability `p` and failures have pairwise correlation `rho`.
+///
+/// Uses the beta-binomial model of exchangeable correlated errors:
+/// `P = Π_{k<n} (β + k) / (α + β + k)` with `α + β = (1 - ρ) / ρ` and
+/// `β = (1 - p)(α + β)`. Reduces to `(1 - p)^n` as `ρ → 0`; positive
+/// correlation clusters errors into fewer shots and raises the probability.
+fn correlated_readout_success(n: usize, p: f64, rho: f64) -> f64 {
+    let p = p.clamp(0.0, 1.0);
+    if rho <= 0.0 {
+        return (1.0 - p).powi(n as i32);
+    }
+    if rho >= 1.0 {
+        return if n == 0 { 1.0 } else { 1.0 - p };
+    }
+    let scale = (1.0 - rho) / rho;
+    let beta = (1.0 - p) * scale;
+    (0..n)
+        .map(|k| (beta + k as f64) / (scale + k as f64))
+        .product()
+}
+
+/// Log ratio of the correlated to the independent terminal readout success.
+///
+/// Zero when readout errors are independent or fewer than two qubits are
+/// measured at the end of the circuit.
+fn log_readout_correlation_correction(circuit: &CircuitGenome, noise: &NoiseVector) -> f64 {
+    let n = terminal_measurement_count(circuit);
+    if noise.readout_correlation <= 0.0 || n < 2 || noise.readout_error >= 1.0 {
+        return 0.0;
+    }
+    let correlated = correlated_readout_success(n, noise.readout_error, noise.readout_correlation);
+    correlated.ln() - n as f64 * (1.0 - noise.readout_error).ln()
+}
+
+/// Sum of per-qubit log survival probabilities over idle periods, each
+/// scaled by the qubit's [`ScoreConfig::qubit_weight`].
+///
+/// Returns `None` if the coherence parameters are invalid.
+fn log_idle_survival(
+    schedules: &[QubitSchedule],
+    makespan: f64,
+    noise: &NoiseVector,
+    config: &ScoreConfig,
+) -> Option<f64> {
+    schedules
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| !s.activities.is_empty() && s.idle_time > 0.0)
+        .map(|(q, s)| {
+            log_qubit_idle_survival(s, makespan, noise)
+                .ok()
+                .map(|log_survival| log_survival * config.qubit_weight(q))
+        })
+        .sum()
+}
+
+/// Sum of per-gate log success probabilities under the uniform noise model.
+fn log_gate_survival(circuit: &CircuitGenome, noise: &NoiseVector) -> f64 {
+    circuit
+        .gates
+        .iter()
+        .map(|gate| log_success(gate_error_rate(gate, noise)))
+        .sum()
+}
+
+/// Generates the fidelity-vs-depth decay curve for a circuit.
+///
+/// Evaluates `estimate_fidelity_with_idle_tracking` on successive prefixes
+/// `gates[0..k]` for `k = 0..=gate_count`, so the first point is the empty
+/// prefix (fidelity 1.0) and the last point is the full circuit.
+///
+/// The idle-tracking model only charges decoherence to idle windows, so a gate
+/// that fills a qubit's trailing idle window can slightly raise the raw
+/// estimate. Appending gates never improves a real circuit, so each point is
+/// clamped to the running minimum, keeping the curve non-increasing.
+///
+/// # Returns
+/// Vector of `(k, fidelity)` pairs, one per prefix length
+pub fn fidelity_depth_curve(
+    circuit: &CircuitGenome,
+    noise: &NoiseVector,
+    config: &ScoreConfig,
+) -> Vec<(usize, f64)> {
+    let mut prefix = CircuitGenome::with_capacity(circuit.num_qubits, circuit.gates.len());
+    let mut curve = Vec::with_capacity(circuit.gates.len() + 1);
+
+    let mut fidelity = estimate_fidelity_with_idle_tracking(&prefix, noise, config);
+    curve.push((0, fidelity));
+
+    for (k, gate) in circuit.gates.iter().enumerate() {
+        prefix.gates.push(gate.clone());
+        fidelity = fidelity.min(estimate_fidelity_with_idle_tracking(&prefix, noise, config));
+        curve.push((k + 1, fidelity));
+    }
+
+    curve
+}
+
+/// Itemizes the error accrued in each time layer of a circuit.
+///
+/// Layers come from [`CircuitGenome::layers`] and run back to back; a layer
+/// lasts as long as its slowest gate. Each entry is the layer's gate error
+/// plus the decoherence error of every used qubit over the part of the layer
+/// it spends idle, summed linearly as in [`gate_error_sum`]. Invalid
+/// coherence parameters charge an idle error of 1.0.
+///
+/// # Returns
+/// One error per layer, in circuit order
+pub fn per_layer_error(
+    circuit: &CircuitGenome,
+    noise: &NoiseVector,
+    config: &ScoreConfig,
+) -> Vec<f64> {
+    let used = circuit.used_qubits();
+    let mut busy = vec![0.0_f64; circuit.num_qubits];
+    let mut layer_start = 0.0;
+
+    circuit
+        .layers()
+        .into_iter()
+        .map(|layer| {
+            busy.iter_mut().for_each(|t| *t = 0.0);
+            let mut gate_error = 0.0;
+            for &index in &layer {
+                let gate = &circuit.gates[index];
+                let duration = gate_duration(gate, config);
+                for q in gate.qubits() {
+                    busy[q] = duration;
+                }
+                gate_error += gate_error_rate(gate, noise);
+            }
+
+            let layer_time = busy.iter().copied().fold(0.0_f64, f64::max);
+            let idle_error: f64 = used
+                .iter()
+                .map(|&q| {
+                    let window = (layer_start + busy[q], layer_start + layer_time);
+                    let idle = window.1 - window.0;
+                    if idle <= 0.0 {
+                        return 0.0;
+                    }
+                    match log_window_survival(std::iter::once(window), idle, noise) {
+                        Ok(log_survival) => 1.0 - (log_survival * config.qubit_weight(q)).exp(),
+                        Err(_) => 1.0,
+                    }
+                })
+                .sum();
+
+            layer_start += layer_time;
+            gate_error + idle_error
+        })
+        .collect()
 }
 
 // ============================================================================
@@ -621,25 +1196,54 @@ pub fn estimate_fidelity_with_idle_tracking(
 ///
 /// # Returns
 /// Estimated fidelity in range [0.0, 1.0]
+///
+/// Ignores [`ScoreConfig::strict`] and always clamps to `[0, 1]`; strict
+/// callers should use [`try_estimate_fidelity_with_scheduling`].
 pub fn estimate_fidelity_with_scheduling(
     circuit: &CircuitGenome,
     noise: &NoiseVector,
     config: &ScoreConfig,
 ) -> f64 {
+    clamp_estimate(try_estimate_fidelity_with_scheduling(
+        circuit, noise, config,
+    ))
+}
+
+/// Fallible form of [`estimate_fidelity_with_scheduling`].
+///
+/// Returns [`ScoringError::FidelityOutOfRange`] in strict mode when the raw
+/// estimate falls outside `[0, 1]`; otherwise the clamped estimate.
+pub fn try_estimate_fidelity_with_scheduling(
+    circuit: &CircuitGenome,
+    noise: &NoiseVector,
+    config: &ScoreConfig,
+) -> Result<f64, ScoringError> {
     // Empty circuit has perfect fidelity
     if circuit.gates.is_empty() {
-        return 1.0;
+        return Ok(1.0);
     }
 
     // Calculate makespan
     let makespan = critical_path(circuit, config);
 
+    let log_decay = log_window_survival(std::iter::once((0.0, makespan)), makespan, noise);
+
+    if config.log_space {
+        return match log_decay {
+            Ok(log_decay) => check_fidelity(
+                (log_decay + log_gate_survival(circuit, noise)).exp(),
+                config,
+            ),
+            Err(_) => Ok(0.0),
+        };
+    }
+
     // Calculate decay probability
-    let decay = match decay_estimation(makespan, noise.t1_mean, noise.t2_mean) {
-        Ok(d) => d,
+    let decay = match log_decay {
+        Ok(log_survival) => (1.0 - log_survival.exp()).clamp(0.0, 1.0),
         Err(_) => {
             // On error, assume worst case for safety
-            return 0.0;
+            return Ok(0.0);
         },
     };
 
@@ -650,8 +1254,7 @@ pub fn estimate_fidelity_with_scheduling(
     // Using multiplicative model for independent error sources
     let fidelity = (1.0 - decay) * (1.0 - gate_error.min(1.0));
 
-    // Clamp to valid range
-    fidelity.clamp(0.0, 1.0)
+    check_fidelity(fidelity, config)
 }
 
 // ============================================================================
@@ -664,6 +1267,25 @@ mod tests {
 
     // ---------- decay_estimation tests ----------
 
+    #[test]
+    fn test_per_layer_error_two_qubit_layer_dominates() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([Gate::H(0), Gate::H(1), Gate::H(2), Gate::CNOT(0, 1)])
+            .unwrap();
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let config = ScoreConfig::default();
+
+        let errors = per_layer_error(&circuit, &noise, &config);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[1] > errors[0], "{:?}", errors);
+        // Layer 0 has no idle time, so it is exactly the gate error
+        assert!((errors[0] - 3.0 * noise.gate_error_1q).abs() < 1e-12);
+        // Qubit 2 idles through the CNOT
+        assert!(errors[1] > noise.gate_error_2q);
+        assert!(errors.iter().sum::<f64>() > gate_error_sum(&circuit, &noise));
+    }
+
     #[test]
     fn test_decay_estimation_zero_makespan() {
         let decay = decay_estimation(0.0, 100.0, 80.0).unwrap();
@@ -708,6 +1330,63 @@ mod tests {
         assert_eq!(decay_clamped, decay_at_2t1, "T2 should be clamped to 2*T1");
     }
 
+    #[test]
+    fn test_t2_clamping_emits_one_warning() {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing_subscriber::layer::{Context, SubscriberExt};
+        use tracing_subscriber::Layer;
+
+        #[derive(Default)]
+        struct Fields(Vec<(String, String)>);
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .push((field.name().to_string(), format!("{:?}", value)));
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<(tracing::Level, Fields)>>>);
+        impl<S: tracing::Subscriber> Layer<S> for Capture {
+            fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+                let mut fields = Fields::default();
+                event.record(&mut fields);
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), fields));
+            }
+        }
+
+        let capture = Capture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        let (clamped, unclamped) = tracing::subscriber::with_default(subscriber, || {
+            (
+                decay_estimation(1000.0, 100.0, 300.0).unwrap(),
+                decay_estimation(1000.0, 100.0, 150.0).unwrap(),
+            )
+        });
+
+        assert_eq!(clamped, decay_estimation(1000.0, 100.0, 200.0).unwrap());
+        assert!(clamped < unclamped);
+
+        let events = capture.0.lock().unwrap();
+        assert_eq!(events.len(), 1, "only the T2 > 2*T1 call warns");
+        let (level, Fields(fields)) = &events[0];
+        assert_eq!(*level, tracing::Level::WARN);
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(field("t1_us"), Some("100.0"));
+        assert_eq!(field("t2_us"), Some("300.0"));
+        assert_eq!(field("clamped_t2_us"), Some("200.0"));
+        assert!(field("message").unwrap().contains("physically impossible"));
+    }
+
     #[test]
     fn test_decay_estimation_invalid_t1() {
         let result = decay_estimation(1000.0, -50.0, 80.0);
@@ -925,6 +1604,26 @@ mod tests {
 
     // ---------- idle time tracking tests ----------
 
+    #[test]
+    fn test_parallel_crosstalk_only_counts_overlapping_gates() {
+        let mut hw = HardwareProfile::linear("test", 4);
+        hw.crosstalk.set_interaction(1, 2, 0.05);
+        let config = ScoreConfig::default();
+
+        // CNOT(0,1) and CNOT(2,3) run in the same layer
+        let mut parallel = CircuitGenome::new(4);
+        parallel.add_gate(Gate::CNOT(0, 1)).unwrap();
+        parallel.add_gate(Gate::CNOT(2, 3)).unwrap();
+        let xtalk = calculate_parallel_crosstalk(&parallel, &hw, &config);
+        assert!((xtalk - 0.05).abs() < 1e-12);
+
+        // Serialized through qubit 1: no overlap
+        let mut serial = CircuitGenome::new(4);
+        serial.add_gate(Gate::CNOT(0, 1)).unwrap();
+        serial.add_gate(Gate::CNOT(1, 2)).unwrap();
+        assert_eq!(calculate_parallel_crosstalk(&serial, &hw, &config), 0.0);
+    }
+
     #[test]
     fn test_qubit_schedules_parallel_gates() {
         // H(0), H(1) should execute in parallel
@@ -976,6 +1675,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_max_parallel_2q_serializes_cnots() {
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::CNOT(2, 3)).unwrap();
+
+        let unlimited = ScoreConfig::default();
+        let limited = ScoreConfig {
+            max_parallel_2q: Some(1),
+            ..ScoreConfig::default()
+        };
+
+        let (_, parallel_makespan) = calculate_qubit_schedules(&circuit, &unlimited);
+        let (schedules, serial_makespan) = calculate_qubit_schedules(&circuit, &limited);
+
+        assert!((parallel_makespan - unlimited.gate_time_2q).abs() < 1e-10);
+        assert!((serial_makespan - 2.0 * parallel_makespan).abs() < 1e-10);
+        assert!((critical_path(&circuit, &limited) - serial_makespan).abs() < 1e-10);
+
+        // Second CNOT waits for the first; its qubits idle meanwhile
+        assert_eq!(schedules[2].activities, vec![(300.0, 600.0)]);
+        assert!((schedules[2].idle_time - 300.0).abs() < 1e-10);
+
+        // Single-qubit gates are unaffected by the limit
+        let mut mixed = CircuitGenome::new(4);
+        mixed.add_gate(Gate::CNOT(0, 1)).unwrap();
+        mixed.add_gate(Gate::H(2)).unwrap();
+        let (_, makespan) = calculate_qubit_schedules(&mixed, &limited);
+        assert!((makespan - limited.gate_time_2q).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_qubit_schedules_shared_qubit_alternation() {
+        // CNOT(0,1), CNOT(1,2), CNOT(0,1) - qubit 1 is shared by all three gates
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::CNOT(1, 2)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+
+        let config = ScoreConfig::default();
+        let g = config.gate_time_2q;
+        let (schedules, makespan) = calculate_qubit_schedules(&circuit, &config);
+
+        // Fully serialized through qubit 1
+        assert!((makespan - 3.0 * g).abs() < 1e-10);
+
+        // Qubit 0: CNOT(0-g), idle(g-2g) during CNOT(1,2), CNOT(2g-3g)
+        assert_eq!(schedules[0].activities, vec![(0.0, g), (2.0 * g, 3.0 * g)]);
+        assert!(
+            (schedules[0].idle_time - g).abs() < 1e-10,
+            "Q0 should idle for the middle CNOT: {}",
+            schedules[0].idle_time
+        );
+
+        // Qubit 1: never idle
+        assert!(schedules[1].idle_time.abs() < 1e-10);
+
+        // Qubit 2: idle(0-g), CNOT(g-2g), idle(2g-3g)
+        assert_eq!(schedules[2].activities, vec![(g, 2.0 * g)]);
+        assert!(
+            (schedules[2].idle_time - 2.0 * g).abs() < 1e-10,
+            "Q2 should idle before and after its CNOT: {}",
+            schedules[2].idle_time
+        );
+    }
+
     #[test]
     fn test_idle_time_reordering_effect() {
         let config = ScoreConfig::default();
@@ -1034,6 +1799,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_per_qubit_noise_prefers_idling_on_high_t1_qubit() {
+        let config = ScoreConfig::default();
+        let mut noise = vec![
+            NoiseVector::with_t1t2(0, 200.0, 150.0),
+            NoiseVector::with_t1t2(1, 50.0, 40.0),
+        ];
+
+        // A run of gates on one qubit leaves the other idle until the CNOT
+        let idle_on = |idle: usize| {
+            let busy = 1 - idle;
+            let mut circuit = CircuitGenome::new(2);
+            for _ in 0..8 {
+                circuit.add_gate(Gate::X(busy)).unwrap();
+            }
+            circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+            circuit
+        };
+        let (idle_high_t1, idle_low_t1) = (idle_on(0), idle_on(1));
+
+        let high = estimate_fidelity_with_per_qubit_noise(&idle_high_t1, &noise, &config);
+        let low = estimate_fidelity_with_per_qubit_noise(&idle_low_t1, &noise, &config);
+        assert!(high - low > 1e-3, "{} vs {}", high, low);
+
+        // An aggregate profile cannot tell the two apart
+        let mean = NoiseVector::with_t1t2(0, 125.0, 95.0);
+        let a = estimate_fidelity_with_idle_tracking(&idle_high_t1, &mean, &config);
+        let b = estimate_fidelity_with_idle_tracking(&idle_low_t1, &mean, &config);
+        assert!((a - b).abs() < 1e-12);
+
+        // Identical entries reduce to the uniform model, in both modes
+        noise[1] = NoiseVector::with_t1t2(1, 200.0, 150.0);
+        let uniform = NoiseVector::with_t1t2(0, 200.0, 150.0);
+        for log_space in [false, true] {
+            let config = ScoreConfig {
+                log_space,
+                ..ScoreConfig::default()
+            };
+            let per_qubit = estimate_fidelity_with_per_qubit_noise(&idle_low_t1, &noise, &config);
+            let aggregate = estimate_fidelity_with_idle_tracking(&idle_low_t1, &uniform, &config);
+            assert!((per_qubit - aggregate).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            try_estimate_fidelity_with_per_qubit_noise(&idle_low_t1, &noise[..1], &config),
+            Err(ScoringError::MissingQubitNoise(1))
+        );
+    }
+
     #[test]
     fn test_idle_fidelity_empty_circuit() {
         let circuit = CircuitGenome::new(2);
@@ -1044,6 +1858,326 @@ mod tests {
         assert_eq!(fidelity, 1.0, "Empty circuit should have perfect fidelity");
     }
 
+    #[test]
+    fn test_t1_schedule_prefers_idling_in_high_coherence_window() {
+        let config = ScoreConfig::default();
+
+        // X on the CNOT target commutes with it; both orders leave qubit 0
+        // idle for 4 single-qubit gate times, before vs. after the CNOT.
+        let early_idle = {
+            let mut c = CircuitGenome::new(2);
+            c.add_gates([
+                Gate::X(1),
+                Gate::X(1),
+                Gate::X(1),
+                Gate::X(1),
+                Gate::CNOT(0, 1),
+            ])
+            .unwrap();
+            c
+        };
+        let late_idle = {
+            let mut c = CircuitGenome::new(2);
+            c.add_gates([
+                Gate::CNOT(0, 1),
+                Gate::X(1),
+                Gate::X(1),
+                Gate::X(1),
+                Gate::X(1),
+            ])
+            .unwrap();
+            c
+        };
+
+        let constant = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let f_early = estimate_fidelity_with_idle_tracking(&early_idle, &constant, &config);
+        let f_late = estimate_fidelity_with_idle_tracking(&late_idle, &constant, &config);
+        assert!((f_early - f_late).abs() < 1e-12);
+
+        // T1 collapses during the first 200ns, then recovers
+        let drifting = constant.with_t1_schedule(|t| if t < 200.0 { 5.0 } else { 100.0 });
+        let f_early = estimate_fidelity_with_idle_tracking(&early_idle, &drifting, &config);
+        let f_late = estimate_fidelity_with_idle_tracking(&late_idle, &drifting, &config);
+        assert!(
+            f_late > f_early,
+            "idling after the low-T1 window should score higher: {} > {}",
+            f_late,
+            f_early
+        );
+    }
+
+    #[test]
+    fn test_qubit_weights_prefer_protecting_output_qubit() {
+        use crate::gate_reorder::GateReorder;
+
+        // Qubit 2 holds the answer, qubit 0 is an ancilla; qubit 1 is busy
+        // throughout. The diagonal gates commute, so the optimizer chooses
+        // which of qubits 0 and 2 waits through the low-T1 window.
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([Gate::CZ(0, 1), Gate::CZ(1, 2), Gate::CPhase(1, 2, 0.3)])
+            .unwrap();
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0).with_t1_schedule(|t| {
+            if t < 600.0 {
+                5.0
+            } else {
+                100.0
+            }
+        });
+
+        let best = |config: &ScoreConfig| {
+            let mut candidates = GateReorder::default().generate_reorderings(&circuit);
+            candidates.push(circuit.clone());
+            candidates
+                .into_iter()
+                .max_by(|a, b| {
+                    estimate_fidelity_with_idle_tracking(a, &noise, config)
+                        .total_cmp(&estimate_fidelity_with_idle_tracking(b, &noise, config))
+                })
+                .unwrap()
+        };
+        let output_first = |c: &CircuitGenome| c.gates.last() == Some(&Gate::CZ(0, 1));
+
+        // Equal weights: idling the output qubit for 300ns beats idling the
+        // ancilla for 600ns
+        let uniform = ScoreConfig::default();
+        assert_eq!(uniform.qubit_weight(2), 1.0);
+        assert!(!output_first(&best(&uniform)));
+
+        // Down-weighting the ancilla moves the output qubit's gates first
+        for log_space in [false, true] {
+            let weighted = ScoreConfig {
+                qubit_weights: vec![0.1, 1.0, 1.0],
+                log_space,
+                ..Default::default()
+            };
+            let chosen = best(&weighted);
+            assert!(output_first(&chosen), "{:?}", chosen.gates);
+        }
+    }
+
+    #[test]
+    fn test_log_space_orders_deep_circuits() {
+        let mut noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        noise.gate_error_2q = 0.01;
+
+        let deep = |layers: usize| {
+            let mut circuit = CircuitGenome::new(2);
+            for _ in 0..layers {
+                circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+            }
+            circuit
+        };
+        let shallower = deep(2000);
+        let deeper = deep(3000);
+
+        // Linear space saturates: both variants tie at 0.0
+        let linear = ScoreConfig::default();
+        let lin_a = estimate_fidelity_with_idle_tracking(&shallower, &noise, &linear);
+        let lin_b = estimate_fidelity_with_idle_tracking(&deeper, &noise, &linear);
+        assert!(lin_a < 1e-6 && lin_b < 1e-6);
+        assert_eq!(lin_a, lin_b);
+
+        // Log space keeps them strictly ordered
+        let log = ScoreConfig {
+            log_space: true,
+            ..ScoreConfig::default()
+        };
+        let log_a = estimate_fidelity_with_idle_tracking(&shallower, &noise, &log);
+        let log_b = estimate_fidelity_with_idle_tracking(&deeper, &noise, &log);
+        assert!(log_b > 0.0);
+        assert!(log_a > log_b, "{} should exceed {}", log_a, log_b);
+
+        let sched_a = estimate_fidelity_with_scheduling(&shallower, &noise, &log);
+        let sched_b = estimate_fidelity_with_scheduling(&deeper, &noise, &log);
+        assert!(sched_b > 0.0 && sched_a > sched_b);
+
+        let hw = HardwareProfile::linear("test", 2);
+        let hw_a = estimate_fidelity_with_hardware(&shallower, &noise, &hw, &log);
+        let hw_b = estimate_fidelity_with_hardware(&deeper, &noise, &hw, &log);
+        assert!(hw_b > 0.0 && hw_a > hw_b);
+    }
+
+    #[test]
+    fn test_log_space_matches_linear_for_shallow_circuit() {
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::H(1)).unwrap();
+
+        let linear =
+            estimate_fidelity_with_idle_tracking(&circuit, &noise, &ScoreConfig::default());
+        let log = estimate_fidelity_with_idle_tracking(
+            &circuit,
+            &noise,
+            &ScoreConfig {
+                log_space: true,
+                ..ScoreConfig::default()
+            },
+        );
+        assert!((linear - log).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_out_of_range_fidelity() {
+        let mut circuit = CircuitGenome::new(1);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::X(0)).unwrap();
+
+        // A negative error rate is a modeling bug: raw fidelity exceeds 1
+        let mut noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        noise.gate_error_1q = -0.2;
+
+        let lenient = ScoreConfig::default();
+        assert_eq!(
+            estimate_fidelity_with_scheduling(&circuit, &noise, &lenient),
+            1.0
+        );
+        assert_eq!(
+            estimate_fidelity_with_idle_tracking(&circuit, &noise, &lenient),
+            1.0
+        );
+
+        for log_space in [false, true] {
+            let strict = ScoreConfig {
+                strict: true,
+                log_space,
+                ..Default::default()
+            };
+            // The plain wrappers clamp even in strict mode
+            assert_eq!(
+                estimate_fidelity_with_scheduling(&circuit, &noise, &strict),
+                1.0
+            );
+            assert_eq!(
+                estimate_fidelity_with_idle_tracking(&circuit, &noise, &strict),
+                1.0
+            );
+            for result in [
+                try_estimate_fidelity_with_scheduling(&circuit, &noise, &strict),
+                try_estimate_fidelity_with_idle_tracking(&circuit, &noise, &strict),
+            ] {
+                assert!(
+                    matches!(result, Err(ScoringError::FidelityOutOfRange(v)) if v > 1.0),
+                    "log_space = {}: {:?}",
+                    log_space,
+                    result
+                );
+            }
+
+            // Well-formed inputs are unaffected
+            let valid = NoiseVector::with_t1t2(0, 100.0, 80.0);
+            let fidelity =
+                try_estimate_fidelity_with_scheduling(&circuit, &valid, &strict).unwrap();
+            assert_eq!(
+                fidelity,
+                estimate_fidelity_with_scheduling(&circuit, &valid, &lenient)
+            );
+        }
+    }
+
+    #[test]
+    fn test_correlated_readout_changes_success() {
+        let mut circuit = CircuitGenome::new(4);
+        for q in 0..4 {
+            circuit.add_gate(Gate::H(q)).unwrap();
+        }
+        for q in 0..4 {
+            circuit.add_gate(Gate::Measure(q)).unwrap();
+        }
+
+        let mut noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        noise.readout_error = 0.05;
+        let config = ScoreConfig::default();
+        let independent = estimate_fidelity_with_idle_tracking(&circuit, &noise, &config);
+
+        noise.readout_correlation = 0.3;
+        let correlated = estimate_fidelity_with_idle_tracking(&circuit, &noise, &config);
+
+        // Clustered readout errors leave more shots error-free
+        let expected_ratio = correlated_readout_success(4, 0.05, 0.3) / 0.95f64.powi(4);
+        assert!(correlated > independent);
+        assert!((correlated / independent - expected_ratio).abs() < 1e-9);
+
+        let log = estimate_fidelity_with_idle_tracking(
+            &circuit,
+            &noise,
+            &ScoreConfig {
+                log_space: true,
+                ..ScoreConfig::default()
+            },
+        );
+        assert!(log > 0.0);
+
+        // A single terminal measurement has nothing to correlate with
+        let mut single = CircuitGenome::new(2);
+        single.add_gate(Gate::H(0)).unwrap();
+        single.add_gate(Gate::Measure(0)).unwrap();
+        let with = estimate_fidelity_with_idle_tracking(&single, &noise, &config);
+        noise.readout_correlation = 0.0;
+        let without = estimate_fidelity_with_idle_tracking(&single, &noise, &config);
+        assert_eq!(with, without);
+    }
+
+    #[test]
+    fn test_fidelity_depth_curve_monotonic() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::T(2)).unwrap();
+        circuit.add_gate(Gate::CNOT(1, 2)).unwrap();
+        circuit.add_gate(Gate::Rz(0, 0.3)).unwrap();
+        circuit.add_gate(Gate::Measure(0)).unwrap();
+
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let config = ScoreConfig::default();
+
+        let curve = fidelity_depth_curve(&circuit, &noise, &config);
+        assert_eq!(curve.len(), circuit.gates.len() + 1);
+        assert_eq!(curve[0].0, 0);
+        assert!(
+            (curve[0].1 - 1.0).abs() < 1e-9,
+            "Empty prefix should be ~1.0"
+        );
+
+        for pair in curve.windows(2) {
+            assert_eq!(pair[1].0, pair[0].0 + 1);
+            assert!(
+                pair[1].1 <= pair[0].1 + 1e-12,
+                "Curve should be non-increasing: {} -> {}",
+                pair[0].1,
+                pair[1].1
+            );
+        }
+
+        // Each point is the running minimum of the per-prefix estimates
+        let raw: Vec<f64> = (0..=circuit.gates.len())
+            .map(|k| {
+                let mut prefix = CircuitGenome::new(3);
+                prefix
+                    .add_gates(circuit.gates[..k].iter().cloned())
+                    .unwrap();
+                estimate_fidelity_with_idle_tracking(&prefix, &noise, &config)
+            })
+            .collect();
+        let mut running_min = f64::INFINITY;
+        for (k, &(_, fidelity)) in curve.iter().enumerate() {
+            running_min = running_min.min(raw[k]);
+            assert_eq!(fidelity, running_min, "point {}", k);
+        }
+
+        // Rz(0) fills qubit 0's trailing idle window and raises the raw
+        // estimate; the curve stays flat there instead
+        assert!(raw[5] > raw[4]);
+        assert_eq!(curve[5].1, curve[4].1);
+
+        // Two-qubit gates and measurement visibly decay the curve
+        assert!(curve[2].1 < curve[1].1);
+        assert!(curve[4].1 < curve[3].1);
+        assert!(curve[6].1 < 0.97, "{}", curve[6].1);
+    }
+
     // ---------- hardware-aware scoring tests ----------
 
     fn create_hardware_with_varying_fidelity() -> HardwareProfile {
@@ -1057,6 +2191,41 @@ mod tests {
         hw
     }
 
+    #[test]
+    fn test_gate_error_sum_with_hardware_uses_qubit_fidelity() {
+        use qns_core::types::Fidelity;
+
+        let mut hw = HardwareProfile::linear("test", 3);
+        for (q, props) in hw.qubit_properties.iter_mut().enumerate() {
+            let error = if q == 0 { 0.02 } else { 0.0005 };
+            props.single_gate_fidelity = Fidelity::from_error_rate(error);
+        }
+        let noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        let config = ScoreConfig::default();
+
+        // Six gates on the noisy qubit vs. spread over the good ones
+        let mut concentrated = CircuitGenome::new(3);
+        let mut spread = CircuitGenome::new(3);
+        for i in 0..6 {
+            concentrated.add_gate(Gate::H(0)).unwrap();
+            spread.add_gate(Gate::H(1 + i % 2)).unwrap();
+        }
+
+        let concentrated_error = gate_error_sum_with_hardware(&concentrated, &noise, &hw);
+        let spread_error = gate_error_sum_with_hardware(&spread, &noise, &hw);
+        assert!((concentrated_error - 6.0 * 0.02).abs() < 1e-12);
+        assert!((spread_error - 6.0 * 0.0005).abs() < 1e-12);
+        assert!(
+            estimate_fidelity_with_hardware(&concentrated, &noise, &hw, &config)
+                < estimate_fidelity_with_hardware(&spread, &noise, &hw, &config)
+        );
+
+        // Without per-qubit properties the scalar rate applies
+        hw.qubit_properties.clear();
+        let fallback = gate_error_sum_with_hardware(&concentrated, &noise, &hw);
+        assert!((fallback - 6.0 * noise.gate_error_1q).abs() < 1e-12);
+    }
+
     #[test]
     fn test_gate_error_sum_with_hardware_uses_edge_fidelity() {
         let hw = create_hardware_with_varying_fidelity();
@@ -1105,8 +2274,13 @@ mod tests {
 
         let mut noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
         noise.gate_error_2q = 0.02; // 2% base error
+        let config = ScoreConfig::default();
 
         let error = gate_error_sum_with_hardware(&circuit, &noise, &hw);
+        assert_eq!(
+            error,
+            gate_error_sum_with_hardware_config(&circuit, &noise, &hw, &config)
+        );
 
         // Non-existent edge penalty: max(0.02 * 3, 0.15) = 0.15
         assert!(
@@ -1114,6 +2288,34 @@ mod tests {
             "Should use penalty for non-existent edge: {}",
             error
         );
+
+        // Configured penalty model: max(0.02 * 10, 0.05) = 0.2
+        let custom = ScoreConfig {
+            missing_edge_penalty: 0.05,
+            missing_edge_multiplier: 10.0,
+            ..Default::default()
+        };
+        let error = gate_error_sum_with_hardware_config(&circuit, &noise, &hw, &custom);
+        assert!((error - 0.2).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_fsim_uses_edge_error_with_hardware() {
+        let hw = create_hardware_with_varying_fidelity();
+        let mut noise = NoiseVector::with_t1t2(0, 100.0, 80.0);
+        noise.gate_error_2q = 0.02;
+        let config = ScoreConfig::default();
+        let fsim = |a, b| Gate::FSim(a, b, 0.3, 0.1);
+
+        // Calibrated edges use their own error, in either direction
+        for (a, b, want) in [(0, 1, 0.01), (2, 1, 0.05)] {
+            let error = gate_error_rate_with_hardware(&fsim(a, b), &noise, &hw, &config);
+            assert!((error - want).abs() < 1e-12, "{} vs {}", error, want);
+        }
+
+        // Missing edge: max(0.02 * 3, 0.15) = 0.15
+        let error = gate_error_rate_with_hardware(&fsim(0, 2), &noise, &hw, &config);
+        assert!((error - 0.15).abs() < 1e-10, "{}", error);
     }
 
     #[test]
@@ -3,7 +3,8 @@
 
 use crate::noise::NoiseModel;
 use crate::noisy::NoisySimulator;
-use qns_core::backend::{ExecutionResult, HardwareBackend};
+use crate::StateVectorSimulator;
+use qns_core::backend::{sampled_expectation, ExecutionResult, HardwareBackend};
 use qns_core::prelude::*;
 use std::collections::HashMap;
 use std::time::Instant;
@@ -98,4 +99,61 @@ impl HardwareBackend for SimulatorBackend {
         let duration = start.elapsed();
         Ok(ExecutionResult::new(counts, shots, duration))
     }
+
+    /// Exact `⟨observable⟩` from the final state vector when the noise model
+    /// is noise-free; otherwise estimated from `shots` samples.
+    fn expectation(&self, circuit: &CircuitGenome, observable: &str, shots: usize) -> Result<f64> {
+        let noise = self.simulator.noise_model();
+        let noiseless = !noise.gate_errors
+            && !noise.thermal_relaxation
+            && (!noise.measurement_errors || noise.readout_error == 0.0)
+            && noise.crosstalk.is_none();
+        if !noiseless {
+            return sampled_expectation(self, circuit, observable, shots);
+        }
+
+        let mut sim = StateVectorSimulator::new(circuit.num_qubits);
+        sim.execute(circuit)?;
+        sim.expectation_pauli(observable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qns_core::backend::pauli_expectation_from_counts;
+
+    #[test]
+    fn test_expectation() {
+        let zero = CircuitGenome::new(1);
+        let mut plus = CircuitGenome::new(1);
+        plus.add_gate(Gate::H(0)).unwrap();
+        let mut bell = CircuitGenome::new(2);
+        bell.add_gates([
+            Gate::H(0),
+            Gate::CNOT(0, 1),
+            Gate::Measure(0),
+            Gate::Measure(1),
+        ])
+        .unwrap();
+
+        // Exact path of the ideal simulator
+        let ideal = SimulatorBackend::ideal(1);
+        assert!((ideal.expectation(&zero, "Z", 100).unwrap() - 1.0).abs() < 1e-10);
+        assert!((ideal.expectation(&plus, "X", 100).unwrap() - 1.0).abs() < 1e-10);
+        assert!(ideal.expectation(&plus, "Z", 100).unwrap().abs() < 1e-10);
+
+        // Default sampling path: eigenstates give ±1 on every shot
+        assert_eq!(sampled_expectation(&ideal, &zero, "Z", 200).unwrap(), 1.0);
+        assert_eq!(sampled_expectation(&ideal, &plus, "X", 200).unwrap(), 1.0);
+        let pair = SimulatorBackend::ideal(2);
+        assert_eq!(sampled_expectation(&pair, &bell, "YY", 200).unwrap(), -1.0);
+        assert_eq!(sampled_expectation(&pair, &bell, "XX", 200).unwrap(), 1.0);
+
+        assert!(ideal.expectation(&plus, "ZZ", 100).is_err());
+        assert!(sampled_expectation(&ideal, &plus, "Q", 100).is_err());
+
+        let empty = ExecutionResult::new(HashMap::new(), 0, Default::default());
+        assert!(pauli_expectation_from_counts(&empty, "Z").is_err());
+    }
 }
@@ -0,0 +1,108 @@
+//! Measurement-outcome correlations.
+
+use std::collections::HashMap;
+
+/// Pairwise Z covariance matrix `⟨ZᵢZⱼ⟩ - ⟨Zᵢ⟩⟨Zⱼ⟩` from a measurement histogram.
+///
+/// Bitstrings use the simulator convention (qubit 0 is the rightmost bit);
+/// characters other than `0` and `1`, such as register separators, are
+/// skipped. Outcome `0` counts as Z = +1 and `1` as Z = -1. The diagonal
+/// holds the variances `1 - ⟨Zᵢ⟩²`. Correlated qubits (e.g. a Bell pair)
+/// give entries near +1, independent ones near 0.
+///
+/// Returns an empty matrix for an empty histogram.
+pub fn qubit_correlations(counts: &HashMap<String, usize>) -> Vec<Vec<f64>> {
+    let outcomes: Vec<(Vec<f64>, f64)> = counts
+        .iter()
+        .filter(|(_, &count)| count > 0)
+        .map(|(bits, &count)| {
+            let z = bits
+                .chars()
+                .rev()
+                .filter_map(|c| match c {
+                    '0' => Some(1.0),
+                    '1' => Some(-1.0),
+                    _ => None,
+                })
+                .collect();
+            (z, count as f64)
+        })
+        .collect();
+
+    let n = outcomes.iter().map(|(z, _)| z.len()).max().unwrap_or(0);
+    let total: f64 = outcomes.iter().map(|(_, count)| count).sum();
+    if n == 0 || total == 0.0 {
+        return Vec::new();
+    }
+
+    let mut mean = vec![0.0; n];
+    let mut second = vec![vec![0.0; n]; n];
+    for (bits, count) in &outcomes {
+        let weight = count / total;
+        // Missing leading bits are treated as outcome 0
+        let z: Vec<f64> = (0..n)
+            .map(|q| bits.get(q).copied().unwrap_or(1.0))
+            .collect();
+        for (i, zi) in z.iter().enumerate() {
+            mean[i] += weight * zi;
+            for (j, zj) in z.iter().enumerate() {
+                second[i][j] += weight * zi * zj;
+            }
+        }
+    }
+
+    second
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, zz)| zz - mean[i] * mean[j])
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateVectorSimulator;
+    use qns_core::prelude::*;
+
+    #[test]
+    fn test_qubit_correlations() {
+        // Bell state: outcomes perfectly correlated
+        let mut bell = CircuitGenome::new(2);
+        bell.add_gate(Gate::H(0)).unwrap();
+        bell.add_gate(Gate::CNOT(0, 1)).unwrap();
+        let mut sim = StateVectorSimulator::new(2);
+        sim.run(&bell).unwrap();
+        let counts = sim.measure(4000).unwrap();
+
+        let cov = qubit_correlations(&counts);
+        assert_eq!(cov.len(), 2);
+        assert!(cov[0][1] > 0.9, "Bell covariance {}", cov[0][1]);
+        assert_eq!(cov[0][1], cov[1][0]);
+
+        // Product state |+⟩|+⟩: independent qubits
+        let mut product = CircuitGenome::new(2);
+        product.add_gate(Gate::H(0)).unwrap();
+        product.add_gate(Gate::H(1)).unwrap();
+        let mut sim = StateVectorSimulator::new(2);
+        sim.run(&product).unwrap();
+        let counts = sim.measure(4000).unwrap();
+
+        let cov = qubit_correlations(&counts);
+        assert!(cov[0][1].abs() < 0.1, "product covariance {}", cov[0][1]);
+        assert!((cov[0][0] - 1.0).abs() < 0.1);
+
+        // Exact histogram: qubit 0 always 1, qubit 1 random -> no variance on 0
+        let counts: HashMap<String, usize> = [("01".to_string(), 5), ("11".to_string(), 5)]
+            .into_iter()
+            .collect();
+        let cov = qubit_correlations(&counts);
+        assert_eq!(cov, vec![vec![0.0, 0.0], vec![0.0, 1.0]]);
+
+        assert!(qubit_correlations(&HashMap::new()).is_empty());
+    }
+}
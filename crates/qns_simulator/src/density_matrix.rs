@@ -0,0 +1,410 @@
+//! Density-matrix simulation for exact noisy results.
+//!
+//! The state is a `2^n × 2^n` density matrix `ρ`. Gates act as `U ρ U†` and
+//! noise channels as `Σ Kᵢ ρ Kᵢ†`, so noisy probabilities come out exactly
+//! instead of being estimated from Monte Carlo trajectories.
+//!
+//! `ρ` is stored row-major and treated as a vector over `2n` qubits: the row
+//! index supplies the high `n` bits and the column index the low `n` bits.
+//! `U ρ U†` is then `U` on the row bits and `conj(U)` on the column bits.
+
+use qns_core::physics::{Matrix2x2, Matrix4x4, C64, ONE, ZERO};
+use qns_core::prelude::*;
+
+use crate::noise::{DepolarizingChannel, KrausOperator, NoiseModel};
+
+/// Largest register a [`DensityMatrixSimulator`] accepts (4^n complex entries).
+pub const MAX_DENSITY_MATRIX_QUBITS: usize = 8;
+
+/// Exact mixed-state simulator.
+///
+/// Without a noise model, [`execute`](Self::execute) applies gates ideally;
+/// with one, each gate is followed by the same channels as in
+/// [`NoisySimulator`](crate::NoisySimulator) — thermal relaxation for the
+/// gate's duration, then depolarizing error on each of its qubits — applied
+/// exactly. Crosstalk and readout error are not modelled.
+#[derive(Debug, Clone)]
+pub struct DensityMatrixSimulator {
+    num_qubits: usize,
+    dimension: usize,
+    /// Row-major `ρ[i][j]` at `i * dimension + j`
+    rho: Vec<C64>,
+    noise: Option<NoiseModel>,
+}
+
+impl DensityMatrixSimulator {
+    /// Creates a simulator in `|0…0⟩⟨0…0|`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_qubits` exceeds [`MAX_DENSITY_MATRIX_QUBITS`].
+    pub fn new(num_qubits: usize) -> Self {
+        assert!(
+            num_qubits <= MAX_DENSITY_MATRIX_QUBITS,
+            "DensityMatrixSimulator supports at most {} qubits, got {} \
+             (the density matrix has 4^n entries)",
+            MAX_DENSITY_MATRIX_QUBITS,
+            num_qubits
+        );
+
+        let dimension = 1 << num_qubits;
+        let mut rho = vec![ZERO; dimension * dimension];
+        rho[0] = ONE;
+        Self {
+            num_qubits,
+            dimension,
+            rho,
+            noise: None,
+        }
+    }
+
+    /// Applies `noise` after every gate in [`execute`](Self::execute).
+    pub fn with_noise(mut self, noise: NoiseModel) -> Self {
+        self.noise = Some(noise);
+        self
+    }
+
+    /// Returns the number of qubits.
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Returns the dimension of the state space (2^n).
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the density matrix, row-major.
+    pub fn density_matrix(&self) -> &[C64] {
+        &self.rho
+    }
+
+    /// Returns `ρ[row][col]`.
+    pub fn element(&self, row: usize, col: usize) -> C64 {
+        if row < self.dimension && col < self.dimension {
+            self.rho[row * self.dimension + col]
+        } else {
+            ZERO
+        }
+    }
+
+    /// Resets to `|0…0⟩⟨0…0|`.
+    pub fn reset(&mut self) {
+        self.rho.fill(ZERO);
+        self.rho[0] = ONE;
+    }
+
+    /// Executes a circuit, applying the noise model after each gate if set.
+    ///
+    /// Gate failures are reported as `QnsError::CircuitExecution` with the
+    /// gate's position and display form.
+    pub fn execute(&mut self, circuit: &CircuitGenome) -> Result<()> {
+        if circuit.num_qubits != self.num_qubits {
+            return Err(QnsError::DimensionMismatch(
+                self.num_qubits,
+                circuit.num_qubits,
+            ));
+        }
+
+        for (index, gate) in circuit.gates.iter().enumerate() {
+            self.apply_gate(gate)
+                .and_then(|()| self.apply_gate_noise(gate))
+                .map_err(|e| QnsError::circuit_execution(index, gate, e))?;
+        }
+        Ok(())
+    }
+
+    /// Applies a gate without noise.
+    ///
+    /// `Measure` is a non-selective measurement: it removes the coherences
+    /// between the qubit's `|0⟩` and `|1⟩` branches.
+    pub fn apply_gate(&mut self, gate: &Gate) -> Result<()> {
+        for q in gate.qubits() {
+            self.validate_qubit(q)?;
+        }
+
+        let n = self.num_qubits;
+        match *gate {
+            Gate::Measure(q) => {
+                let mask = 1 << q;
+                for i in 0..self.dimension {
+                    for j in 0..self.dimension {
+                        if (i ^ j) & mask != 0 {
+                            self.rho[i * self.dimension + j] = ZERO;
+                        }
+                    }
+                }
+            },
+            // Full amplitude damping sends both |0⟩ and |1⟩ to |0⟩
+            Gate::Reset(q) => self.apply_kraus(q, &KrausOperator::amplitude_damping(1.0))?,
+            Gate::Toffoli(c1, c2, t) => {
+                // A permutation with real entries: conj(U) = U
+                for (c1, c2, t) in [(c1 + n, c2 + n, t + n), (c1, c2, t)] {
+                    let controls = (1 << c1) | (1 << c2);
+                    for index in 0..self.rho.len() {
+                        if index & controls == controls && index & (1 << t) == 0 {
+                            self.rho.swap(index, index | (1 << t));
+                        }
+                    }
+                }
+            },
+            Gate::CNOT(a, b)
+            | Gate::CZ(a, b)
+            | Gate::CPhase(a, b, _)
+            | Gate::SWAP(a, b)
+            | Gate::FSim(a, b, _, _) => {
+                let matrix = gate.matrix_4x4().ok_or_else(|| {
+                    QnsError::unsupported_gate(gate, "density-matrix", "no 4x4 matrix")
+                })?;
+                self.apply_two_qubit_matrix(a + n, b + n, &matrix);
+                self.apply_two_qubit_matrix(a, b, &conj_4x4(&matrix));
+            },
+            _ => {
+                let q = gate.qubits()[0];
+                let matrix = gate.matrix_2x2().ok_or_else(|| {
+                    QnsError::unsupported_gate(gate, "density-matrix", "no 2x2 matrix")
+                })?;
+                self.apply_single_qubit_matrix(q + n, &matrix);
+                self.apply_single_qubit_matrix(q, &conj_2x2(&matrix));
+            },
+        }
+        Ok(())
+    }
+
+    /// Applies the channel `ρ → Σ Kᵢ ρ Kᵢ†` on `qubit`.
+    ///
+    /// Only the Kraus matrices are used; their `prob` weights are ignored.
+    /// The operators should satisfy `Σ Kᵢ†Kᵢ = I` for the trace to be kept.
+    pub fn apply_kraus(&mut self, qubit: usize, operators: &[KrausOperator]) -> Result<()> {
+        self.validate_qubit(qubit)?;
+        let len = self.rho.len();
+        let original = std::mem::replace(&mut self.rho, vec![ZERO; len]);
+        for operator in operators {
+            let mut branch = Self {
+                num_qubits: self.num_qubits,
+                dimension: self.dimension,
+                rho: original.clone(),
+                noise: None,
+            };
+            branch.apply_single_qubit_matrix(qubit + self.num_qubits, &operator.matrix);
+            branch.apply_single_qubit_matrix(qubit, &conj_2x2(&operator.matrix));
+            for (acc, value) in self.rho.iter_mut().zip(&branch.rho) {
+                *acc += value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Probabilities of the computational basis states, the diagonal of `ρ`.
+    pub fn probabilities(&self) -> Vec<f64> {
+        (0..self.dimension)
+            .map(|i| self.rho[i * self.dimension + i].re.max(0.0))
+            .collect()
+    }
+
+    /// Purity `Tr(ρ²)`: 1 for pure states, `1/2^n` for the maximally mixed state.
+    pub fn purity(&self) -> f64 {
+        // ρ is Hermitian, so Tr(ρ²) = Σ |ρ_ij|²
+        self.rho.iter().map(|a| a.norm_sqr()).sum()
+    }
+
+    /// Returns `⟨Z⟩ = P(0) - P(1)` on `qubit`.
+    pub fn expectation_z(&self, qubit: usize) -> Result<f64> {
+        self.validate_qubit(qubit)?;
+        let mask = 1 << qubit;
+        Ok(self
+            .probabilities()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| if i & mask == 0 { *p } else { -p })
+            .sum())
+    }
+
+    /// Applies the noise model's channels for `gate`, if a model is set.
+    fn apply_gate_noise(&mut self, gate: &Gate) -> Result<()> {
+        let Some(noise) = self.noise.clone() else {
+            return Ok(());
+        };
+        let (time_ns, error_rate) = match *gate {
+            Gate::Measure(_) | Gate::Reset(_) => return Ok(()),
+            Gate::Toffoli(c1, c2, t) => (
+                2.0 * noise.two_gate_time_ns,
+                noise.get_edge_error(c1, t) + noise.get_edge_error(c2, t),
+            ),
+            Gate::CNOT(a, b)
+            | Gate::CZ(a, b)
+            | Gate::CPhase(a, b, _)
+            | Gate::SWAP(a, b)
+            | Gate::FSim(a, b, _, _) => (noise.two_gate_time_ns, noise.get_edge_error(a, b)),
+            _ => (noise.single_gate_time_ns, noise.single_gate_error),
+        };
+
+        for q in gate.qubits() {
+            if noise.thermal_relaxation {
+                let gamma = noise.amplitude_damping_prob(time_ns);
+                if gamma > 0.0 {
+                    self.apply_kraus(q, &KrausOperator::amplitude_damping(gamma))?;
+                }
+                let lambda = noise.phase_damping_prob(time_ns);
+                if lambda > 0.0 {
+                    self.apply_kraus(q, &KrausOperator::phase_damping(lambda))?;
+                }
+            }
+            if noise.gate_errors && error_rate > 0.0 {
+                let channel = DepolarizingChannel::new(error_rate);
+                self.apply_kraus(q, &channel.kraus_operators())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_qubit(&self, qubit: usize) -> Result<()> {
+        if qubit >= self.num_qubits {
+            return Err(QnsError::InvalidQubit(qubit, self.num_qubits));
+        }
+        Ok(())
+    }
+
+    /// Applies `matrix` to bit `bit` of the flattened `ρ`.
+    fn apply_single_qubit_matrix(&mut self, bit: usize, matrix: &Matrix2x2) {
+        let mask = 1 << bit;
+        for i in 0..self.rho.len() {
+            if i & mask == 0 {
+                let j = i | mask;
+                let (a0, a1) = (self.rho[i], self.rho[j]);
+                self.rho[i] = matrix[0][0] * a0 + matrix[0][1] * a1;
+                self.rho[j] = matrix[1][0] * a0 + matrix[1][1] * a1;
+            }
+        }
+    }
+
+    /// Applies `matrix` to bits `(bit1, bit2)` of the flattened `ρ`, with
+    /// `bit1` as the more significant index of the matrix.
+    fn apply_two_qubit_matrix(&mut self, bit1: usize, bit2: usize, matrix: &Matrix4x4) {
+        let (mask1, mask2) = (1 << bit1, 1 << bit2);
+        for base in 0..self.rho.len() {
+            if base & (mask1 | mask2) == 0 {
+                let indices = [base, base | mask2, base | mask1, base | mask1 | mask2];
+                let amps = indices.map(|i| self.rho[i]);
+                for (row, &index) in matrix.iter().zip(&indices) {
+                    self.rho[index] = row.iter().zip(&amps).map(|(m, a)| m * a).sum();
+                }
+            }
+        }
+    }
+}
+
+fn conj_2x2(matrix: &Matrix2x2) -> Matrix2x2 {
+    matrix.map(|row| row.map(|v| v.conj()))
+}
+
+fn conj_4x4(matrix: &Matrix4x4) -> Matrix4x4 {
+    matrix.map(|row| row.map(|v| v.conj()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateVectorSimulator;
+
+    const TOLERANCE: f64 = 1e-10;
+
+    fn bell() -> CircuitGenome {
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gates([Gate::H(0), Gate::CNOT(0, 1)]).unwrap();
+        circuit
+    }
+
+    #[test]
+    fn test_depolarized_bell_state() {
+        let mut sim = DensityMatrixSimulator::new(2);
+        sim.execute(&bell()).unwrap();
+        assert!((sim.purity() - 1.0).abs() < TOLERANCE);
+
+        // X, Y, Z each with p/3 map Φ+ to Ψ+, Ψ-, Φ- respectively
+        let p = 0.3;
+        sim.apply_kraus(0, &DepolarizingChannel::new(p).kraus_operators())
+            .unwrap();
+
+        let probs = sim.probabilities();
+        let same = (1.0 - 2.0 * p / 3.0) / 2.0;
+        let flipped = p / 3.0;
+        for (i, expected) in [same, flipped, flipped, same].into_iter().enumerate() {
+            assert!(
+                (probs[i] - expected).abs() < TOLERANCE,
+                "P({:02b}) = {}, expected {}",
+                i,
+                probs[i],
+                expected
+            );
+        }
+        assert!((probs.iter().sum::<f64>() - 1.0).abs() < TOLERANCE);
+        // Orthogonal mixture with weights (1-p, p/3, p/3, p/3)
+        let purity = (1.0 - p).powi(2) + 3.0 * (p / 3.0).powi(2);
+        assert!((sim.purity() - purity).abs() < TOLERANCE);
+        assert!(sim.expectation_z(0).unwrap().abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_ideal_matches_state_vector() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::Y(1),
+                Gate::U(2, 0.4, -1.3, 2.2),
+                Gate::CNOT(0, 2),
+                Gate::FSim(1, 0, 0.5, 0.9),
+                Gate::Toffoli(2, 0, 1),
+                Gate::S(1),
+                Gate::CPhase(1, 2, 0.6),
+                Gate::Rx(0, 1.1),
+            ])
+            .unwrap();
+
+        let mut dm = DensityMatrixSimulator::new(3);
+        dm.execute(&circuit).unwrap();
+        let mut sv = StateVectorSimulator::new(3);
+        sv.execute(&circuit).unwrap();
+
+        let amps = sv.statevector();
+        for i in 0..8 {
+            for j in 0..8 {
+                let expected = amps[i] * amps[j].conj();
+                assert!((dm.element(i, j) - expected).norm() < TOLERANCE);
+            }
+        }
+        for q in 0..3 {
+            let z = dm.expectation_z(q).unwrap();
+            assert!((z - sv.expectation_z(q).unwrap()).abs() < TOLERANCE);
+        }
+
+        // Measuring keeps the populations but removes coherence
+        dm.apply_gate(&Gate::Measure(0)).unwrap();
+        let probs = dm.probabilities();
+        for (p, a) in probs.iter().zip(amps) {
+            assert!((p - a.norm_sqr()).abs() < TOLERANCE);
+        }
+        assert!(dm.purity() < 1.0 - 1e-3);
+    }
+
+    #[test]
+    fn test_noise_model_mixes_state() {
+        let mut sim = DensityMatrixSimulator::new(2).with_noise(NoiseModel::new());
+        sim.execute(&bell()).unwrap();
+        let purity = sim.purity();
+        assert!(purity < 1.0 && purity > 0.9, "purity {}", purity);
+        assert!((sim.probabilities().iter().sum::<f64>() - 1.0).abs() < TOLERANCE);
+
+        // Deterministic: a second run gives the identical matrix
+        let mut again = DensityMatrixSimulator::new(2).with_noise(NoiseModel::new());
+        again.execute(&bell()).unwrap();
+        assert_eq!(sim.density_matrix(), again.density_matrix());
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 8 qubits, got 9")]
+    fn test_qubit_cap() {
+        DensityMatrixSimulator::new(9);
+    }
+}
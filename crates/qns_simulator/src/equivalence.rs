@@ -0,0 +1,341 @@
+//! Circuit equivalence assertions.
+//!
+//! Replaces ad-hoc `(amp - expected).norm() < TOLERANCE` checks in tests with a
+//! single helper that compares two circuits as unitaries, and verifies routed
+//! circuits against their originals.
+
+use crate::StateVectorSimulator;
+use num_complex::Complex64;
+use qns_core::{CircuitGenome, QnsError};
+
+/// Panics unless `a` and `b` implement the same unitary up to a global phase.
+///
+/// Both circuits are simulated on every computational basis input and the
+/// output amplitudes compared within `tol`, after aligning a single global
+/// phase shared by all inputs. Measurements are ignored. The panic message
+/// names the input and the first basis state whose amplitudes differ.
+///
+/// Cost grows as 4^n, so this is intended for small test circuits.
+///
+/// # Example
+///
+/// ```
+/// use qns_core::prelude::*;
+/// use qns_simulator::assert_circuits_equivalent;
+///
+/// let mut s = CircuitGenome::new(1);
+/// s.add_gate(Gate::S(0)).unwrap();
+/// let mut rz = CircuitGenome::new(1);
+/// rz.add_gate(Gate::Rz(0, std::f64::consts::FRAC_PI_2)).unwrap();
+///
+/// assert_circuits_equivalent(&s, &rz, 1e-10);
+/// ```
+#[track_caller]
+pub fn assert_circuits_equivalent(a: &CircuitGenome, b: &CircuitGenome, tol: f64) {
+    if let Err(msg) = check_equivalent(a, b, tol) {
+        panic!("circuits are not equivalent: {}", msg);
+    }
+}
+
+/// Fidelity between the outputs of `original` and its routed form, with
+/// the routed output read through `final_mapping`.
+///
+/// `final_mapping[l]` is the physical qubit holding logical qubit `l` after
+/// `routed` runs. Both circuits start from `|0…0⟩`, so the initial layout
+/// needs no correction. The routed state is permuted back to logical order
+/// and compared with `|⟨ψ_original|ψ_routed⟩|²`; amplitude left on physical
+/// qubits outside the mapping (nonzero ancillas) counts as infidelity.
+/// Measurements are ignored.
+///
+/// # Errors
+/// [`QnsError::DimensionMismatch`] if the mapping does not cover every
+/// logical qubit, [`QnsError::InvalidQubit`] for a physical qubit outside
+/// `routed`, a config error for repeated physical qubits, and simulation
+/// errors from either circuit.
+///
+/// # Example
+///
+/// ```
+/// use qns_core::prelude::*;
+/// use qns_simulator::routed_circuit_fidelity;
+///
+/// let mut original = CircuitGenome::new(2);
+/// original.add_gates([Gate::X(0)]).unwrap();
+/// // Routing moved logical 0 onto physical 1
+/// let mut routed = CircuitGenome::new(2);
+/// routed.add_gates([Gate::X(0), Gate::SWAP(0, 1)]).unwrap();
+///
+/// let fidelity = routed_circuit_fidelity(&original, &routed, &[1, 0]).unwrap();
+/// assert!((fidelity - 1.0).abs() < 1e-12);
+/// ```
+pub fn routed_circuit_fidelity(
+    original: &CircuitGenome,
+    routed: &CircuitGenome,
+    final_mapping: &[usize],
+) -> qns_core::Result<f64> {
+    let n = original.num_qubits;
+    if final_mapping.len() != n {
+        return Err(QnsError::DimensionMismatch(n, final_mapping.len()));
+    }
+    let mut used = vec![false; routed.num_qubits];
+    for &physical in final_mapping {
+        if physical >= routed.num_qubits {
+            return Err(QnsError::InvalidQubit(physical, routed.num_qubits));
+        }
+        if std::mem::replace(&mut used[physical], true) {
+            return Err(QnsError::config(format!(
+                "final mapping assigns physical qubit {} twice",
+                physical
+            )));
+        }
+    }
+
+    let mut original_sim = StateVectorSimulator::new(n);
+    original_sim.execute_unitary(original)?;
+    let mut routed_sim = StateVectorSimulator::new(routed.num_qubits);
+    routed_sim.execute_unitary(routed)?;
+    let physical_state = routed_sim.statevector();
+
+    // Logical basis index -> physical basis index with all ancillas at 0
+    let logical_state: Vec<Complex64> = (0..1usize << n)
+        .map(|index| {
+            let physical = final_mapping
+                .iter()
+                .enumerate()
+                .filter(|(logical, _)| (index >> logical) & 1 == 1)
+                .fold(0usize, |acc, (_, &p)| acc | (1 << p));
+            physical_state[physical]
+        })
+        .collect();
+
+    original_sim.fidelity(&logical_state)
+}
+
+/// Compares two circuits column by column, returning a description of the
+/// first mismatch.
+fn check_equivalent(a: &CircuitGenome, b: &CircuitGenome, tol: f64) -> Result<(), String> {
+    if a.num_qubits != b.num_qubits {
+        return Err(format!(
+            "qubit count differs ({} vs {})",
+            a.num_qubits, b.num_qubits
+        ));
+    }
+
+    let n = a.num_qubits;
+    let dim = 1usize << n;
+    let mut sim_a = StateVectorSimulator::new(n);
+    let mut sim_b = StateVectorSimulator::new(n);
+    let mut phase: Option<Complex64> = None;
+
+    for input in 0..dim {
+        let mut basis = vec![Complex64::new(0.0, 0.0); dim];
+        basis[input] = Complex64::new(1.0, 0.0);
+        sim_a.set_state(basis.clone()).map_err(|e| e.to_string())?;
+        sim_b.set_state(basis).map_err(|e| e.to_string())?;
+        sim_a
+            .execute_unitary(a)
+            .map_err(|e| format!("left circuit failed: {}", e))?;
+        sim_b
+            .execute_unitary(b)
+            .map_err(|e| format!("right circuit failed: {}", e))?;
+
+        let (left, right) = (sim_a.statevector(), sim_b.statevector());
+
+        // Global phase from the first largest left amplitude of the first column
+        let phase = *phase.get_or_insert_with(|| {
+            let i = (1..dim).fold(0, |best, i| {
+                if left[i].norm_sqr() > left[best].norm_sqr() {
+                    i
+                } else {
+                    best
+                }
+            });
+            if right[i].norm() > tol {
+                let ratio = left[i] / right[i];
+                ratio / ratio.norm()
+            } else {
+                Complex64::new(1.0, 0.0)
+            }
+        });
+
+        if let Some(i) = (0..dim).find(|&i| (left[i] - phase * right[i]).norm() > tol) {
+            return Err(format!(
+                "on input |{}⟩, amplitude of basis state |{}⟩ differs: left = {:.6}, right = {:.6} (after global phase {:.6})",
+                bitstring(input, n),
+                bitstring(i, n),
+                left[i],
+                right[i],
+                phase
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Formats a basis index with qubit 0 as the rightmost bit.
+fn bitstring(index: usize, num_qubits: usize) -> String {
+    (0..num_qubits)
+        .rev()
+        .map(|q| if (index >> q) & 1 == 1 { '1' } else { '0' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qns_core::Gate;
+
+    fn circuit_of(num_qubits: usize, gates: &[Gate]) -> CircuitGenome {
+        let mut circuit = CircuitGenome::new(num_qubits);
+        circuit.add_gates(gates.iter().cloned()).unwrap();
+        circuit
+    }
+
+    #[test]
+    fn test_equivalent_circuits_pass() {
+        // Commuting gates in either order
+        let a = circuit_of(2, &[Gate::H(0), Gate::X(1), Gate::CZ(0, 1)]);
+        let b = circuit_of(2, &[Gate::X(1), Gate::H(0), Gate::CZ(1, 0)]);
+        assert_circuits_equivalent(&a, &b, 1e-10);
+
+        // CNOT = (I⊗H) CZ (I⊗H)
+        let a = circuit_of(2, &[Gate::CNOT(0, 1)]);
+        let b = circuit_of(2, &[Gate::H(1), Gate::CZ(0, 1), Gate::H(1)]);
+        assert_circuits_equivalent(&a, &b, 1e-10);
+
+        // Mid-circuit measurements never collapse the compared states
+        let a = circuit_of(2, &[Gate::H(0), Gate::Measure(0), Gate::H(0), Gate::X(1)]);
+        let b = circuit_of(2, &[Gate::X(1)]);
+        for _ in 0..16 {
+            assert_circuits_equivalent(&a, &b, 1e-10);
+            let fidelity = routed_circuit_fidelity(&a, &b, &[0, 1]).unwrap();
+            assert!((fidelity - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_inverse_composes_to_identity() {
+        let mut circuit = circuit_of(
+            3,
+            &[
+                Gate::H(0),
+                Gate::T(1),
+                Gate::CNOT(0, 2),
+                Gate::Rx(1, 0.3),
+                Gate::S(2),
+                Gate::FSim(1, 2, 0.4, 0.7),
+                Gate::CZ(0, 1),
+            ],
+        );
+        circuit.compose(&circuit.inverse().unwrap());
+        assert_circuits_equivalent(&circuit, &CircuitGenome::new(3), 1e-10);
+    }
+
+    #[test]
+    fn test_controlled_decomposition_matches_controlled_unitary() {
+        // Apply the gate to target 1 only on inputs whose control (qubit 0) is set
+        let gates = [
+            Gate::H(1),
+            Gate::Y(1),
+            Gate::S(1),
+            Gate::Rx(1, 0.3),
+            Gate::Ry(1, -1.1),
+            Gate::Rz(1, 0.8),
+            Gate::U(1, 0.4, 1.3, -0.6),
+        ];
+        for gate in gates {
+            let decomposition = gate.controlled_decomposition(0).unwrap();
+            let circuit = circuit_of(2, &decomposition);
+            let single = circuit_of(2, std::slice::from_ref(&gate));
+            let mut sim = StateVectorSimulator::new(2);
+            let mut reference = StateVectorSimulator::new(2);
+            let mut phase: Option<Complex64> = None;
+
+            for input in 0..4 {
+                let mut basis = vec![Complex64::new(0.0, 0.0); 4];
+                basis[input] = Complex64::new(1.0, 0.0);
+                sim.set_state(basis.clone()).unwrap();
+                sim.execute(&circuit).unwrap();
+                reference.set_state(basis).unwrap();
+                if input & 1 == 1 {
+                    reference.execute(&single).unwrap();
+                }
+
+                let (got, want) = (sim.statevector(), reference.statevector());
+                let i = (0..4).find(|&i| want[i].norm() > 1e-6).unwrap();
+                let phase = *phase.get_or_insert(got[i] / want[i]);
+                for i in 0..4 {
+                    assert!(
+                        (got[i] - phase * want[i]).norm() < 1e-10,
+                        "controlled {} differs on input {} at {}",
+                        gate,
+                        input,
+                        i
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_toffoli_matches_decomposition() {
+        for (c1, c2, t) in [(0, 1, 2), (2, 0, 1)] {
+            let toffoli = circuit_of(3, &[Gate::Toffoli(c1, c2, t)]);
+            let decomposed = circuit_of(3, &Gate::ccx_decomposition(c1, c2, t));
+            assert_circuits_equivalent(&toffoli, &decomposed, 1e-10);
+            assert_circuits_equivalent(&toffoli.decompose_toffoli(), &toffoli, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_routed_circuit_fidelity_accounts_for_mapping() {
+        // CNOT(0, 2) on the line 0-1-2: swap logical 0 next to qubit 2
+        let original = circuit_of(3, &[Gate::H(0), Gate::T(0), Gate::X(1), Gate::CNOT(0, 2)]);
+        let routed = circuit_of(
+            3,
+            &[
+                Gate::H(0),
+                Gate::T(0),
+                Gate::X(1),
+                Gate::SWAP(0, 1),
+                Gate::CNOT(1, 2),
+            ],
+        );
+        let mapping = [1, 0, 2];
+        let fidelity = routed_circuit_fidelity(&original, &routed, &mapping).unwrap();
+        assert!((fidelity - 1.0).abs() < 1e-12, "{}", fidelity);
+
+        // Ignoring the permutation makes correct routing look wrong
+        let naive = routed_circuit_fidelity(&original, &routed, &[0, 1, 2]).unwrap();
+        assert!(naive < 0.5, "{}", naive);
+
+        // Extra physical qubits must end in |0⟩
+        let mut wide = routed.clone();
+        wide.num_qubits = 4;
+        let fidelity = routed_circuit_fidelity(&original, &wide, &mapping).unwrap();
+        assert!((fidelity - 1.0).abs() < 1e-12);
+        wide.add_gate(Gate::H(3)).unwrap();
+        let fidelity = routed_circuit_fidelity(&original, &wide, &mapping).unwrap();
+        assert!((fidelity - 0.5).abs() < 1e-12);
+
+        assert!(matches!(
+            routed_circuit_fidelity(&original, &routed, &[1, 0]),
+            Err(QnsError::DimensionMismatch(3, 2))
+        ));
+        assert!(routed_circuit_fidelity(&original, &routed, &[1, 1, 2]).is_err());
+        assert!(matches!(
+            routed_circuit_fidelity(&original, &routed, &[0, 1, 3]),
+            Err(QnsError::InvalidQubit(3, 3))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "basis state |11⟩")]
+    fn test_inequivalent_circuits_panic() {
+        // Agree on |00⟩ but differ in the sign of |11⟩
+        let a = circuit_of(2, &[Gate::H(0), Gate::CNOT(0, 1)]);
+        let b = circuit_of(2, &[Gate::H(0), Gate::CNOT(0, 1), Gate::CZ(0, 1)]);
+        assert_circuits_equivalent(&a, &b, 1e-10);
+    }
+}
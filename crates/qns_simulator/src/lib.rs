@@ -6,18 +6,49 @@
 //! - **StateVectorSimulator**: Exact full state vector simulation.
 //! - **MpsSimulator**: Matrix Product State simulation for larger, lower-entanglement circuits.
 //! - **NoisySimulator**: Simulation with noise models.
+//! - **DensityMatrixSimulator**: Exact mixed-state simulation of small noisy circuits.
+//! - **PauliFrameSimulator**: Fast noisy sampling of Clifford circuits under Pauli noise.
+//! - **StabilizerSimulator**: Exact tableau simulation of Clifford circuits on hundreds of qubits.
 //! - **MockBackend**: Helper for testing and calibration mocking.
+//! - **assert_circuits_equivalent**: Unitary equivalence assertion for tests.
+//! - **routed_circuit_fidelity**: Verifies a routed circuit through its final qubit mapping.
+//! - **unitary_infidelity**: Process infidelity against a target unitary.
+//! - **shots_for_precision**: Shot planning for a target standard error.
+//! - **qubit_correlations**: Pairwise Z covariance from measurement counts.
+//! - **metrics**: Fidelities and distances between outcome distributions.
 
 pub mod backend;
+pub mod correlations;
+pub mod density_matrix;
+pub mod equivalence;
+pub mod metrics;
 pub mod mock;
 pub mod mps;
 pub mod noise;
 pub mod noisy;
+pub mod pauli_frame;
+pub mod shots;
+pub mod stabilizer;
 pub mod state_vector;
+mod tableau;
+pub mod unitary;
 
 pub use backend::SimulatorBackend;
+pub use correlations::qubit_correlations;
+pub use density_matrix::{DensityMatrixSimulator, MAX_DENSITY_MATRIX_QUBITS};
+pub use equivalence::{assert_circuits_equivalent, routed_circuit_fidelity};
+pub use metrics::{
+    classical_fidelity, hellinger_fidelity, kl_divergence, total_variation_distance,
+    OutcomeDistribution,
+};
 pub use mock::{MockBackend, MockConfig};
 pub use mps::MpsSimulator;
-pub use noise::{DepolarizingChannel, KrausOperator, MeasurementError, NoiseModel};
-pub use noisy::{estimate_circuit_fidelity, estimate_gate_fidelity, NoisySimulator};
+pub use noise::{
+    DepolarizingChannel, KrausOperator, MeasurementError, NoiseModel, MAX_FIDELITY_QUBITS,
+};
+pub use noisy::{estimate_circuit_fidelity, estimate_gate_fidelity, GateKind, NoisySimulator};
+pub use pauli_frame::PauliFrameSimulator;
+pub use shots::{pauli_variance, shots_for_pauli, shots_for_precision};
+pub use stabilizer::StabilizerSimulator;
 pub use state_vector::StateVectorSimulator;
+pub use unitary::{circuit_unitary, unitary_infidelity, MAX_UNITARY_QUBITS};
@@ -0,0 +1,166 @@
+//! Distances and fidelities between measurement-outcome distributions.
+//!
+//! Every metric accepts either count histograms (`HashMap<String, usize>`, as
+//! returned by `measure`) or probability maps (`HashMap<String, f64>`); both
+//! are normalized to unit mass first. A bitstring present in only one
+//! distribution has probability zero in the other.
+
+use std::collections::{BTreeSet, HashMap};
+
+/// A measurement-outcome distribution keyed by bitstring.
+pub trait OutcomeDistribution {
+    /// Probability of each outcome, normalized to sum to 1.
+    ///
+    /// A distribution with no mass yields an empty map.
+    fn probabilities(&self) -> HashMap<String, f64>;
+}
+
+impl OutcomeDistribution for HashMap<String, usize> {
+    fn probabilities(&self) -> HashMap<String, f64> {
+        let total: usize = self.values().sum();
+        if total == 0 {
+            return HashMap::new();
+        }
+        self.iter()
+            .map(|(bits, &count)| (bits.clone(), count as f64 / total as f64))
+            .collect()
+    }
+}
+
+impl OutcomeDistribution for HashMap<String, f64> {
+    fn probabilities(&self) -> HashMap<String, f64> {
+        let total: f64 = self.values().filter(|p| **p > 0.0).sum();
+        if total <= 0.0 {
+            return HashMap::new();
+        }
+        self.iter()
+            .map(|(bits, &p)| (bits.clone(), p.max(0.0) / total))
+            .collect()
+    }
+}
+
+/// Probability pairs `(p(x), q(x))` over the union of both supports.
+fn aligned(p: &impl OutcomeDistribution, q: &impl OutcomeDistribution) -> Vec<(f64, f64)> {
+    let (p, q) = (p.probabilities(), q.probabilities());
+    let outcomes: BTreeSet<&String> = p.keys().chain(q.keys()).collect();
+    outcomes
+        .into_iter()
+        .map(|bits| {
+            (
+                p.get(bits).copied().unwrap_or(0.0),
+                q.get(bits).copied().unwrap_or(0.0),
+            )
+        })
+        .collect()
+}
+
+/// Classical fidelity (Bhattacharyya coefficient) `Σ √(p(x)·q(x))`.
+///
+/// 1.0 for identical distributions, 0.0 for disjoint ones.
+pub fn classical_fidelity(p: &impl OutcomeDistribution, q: &impl OutcomeDistribution) -> f64 {
+    aligned(p, q)
+        .iter()
+        .map(|(a, b)| (a * b).sqrt())
+        .sum::<f64>()
+        .min(1.0)
+}
+
+/// Hellinger fidelity `(1 - H²)²`, where `H² = 1 - Σ √(p(x)·q(x))` is the
+/// squared Hellinger distance.
+///
+/// Equals the square of [`classical_fidelity`]; this is the convention used
+/// by Qiskit's `hellinger_fidelity` for comparing device and ideal counts.
+pub fn hellinger_fidelity(p: &impl OutcomeDistribution, q: &impl OutcomeDistribution) -> f64 {
+    classical_fidelity(p, q).powi(2)
+}
+
+/// Total variation distance `½ Σ |p(x) - q(x)|`.
+///
+/// 0.0 for identical distributions, 1.0 for disjoint ones.
+pub fn total_variation_distance(p: &impl OutcomeDistribution, q: &impl OutcomeDistribution) -> f64 {
+    0.5 * aligned(p, q)
+        .iter()
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f64>()
+}
+
+/// Kullback-Leibler divergence `D(P‖Q) = Σ p(x)·ln(p(x) / q(x))` in nats.
+///
+/// Not symmetric. Infinite when `P` puts mass on an outcome `Q` never
+/// produces; outcomes only `Q` produces contribute nothing.
+pub fn kl_divergence(p: &impl OutcomeDistribution, q: &impl OutcomeDistribution) -> f64 {
+    aligned(p, q)
+        .iter()
+        .filter(|(a, _)| *a > 0.0)
+        .map(|(a, b)| {
+            if *b > 0.0 {
+                a * (a / b).ln()
+            } else {
+                f64::INFINITY
+            }
+        })
+        .sum::<f64>()
+        .max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-12;
+
+    fn counts(entries: &[(&str, usize)]) -> HashMap<String, usize> {
+        entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    fn probs(entries: &[(&str, f64)]) -> HashMap<String, f64> {
+        entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_identical_distributions() {
+        // Same distribution as counts and as unnormalized probabilities
+        let p = counts(&[("00", 300), ("11", 100)]);
+        let q = probs(&[("00", 0.75), ("11", 0.25)]);
+
+        assert!((classical_fidelity(&p, &q) - 1.0).abs() < TOLERANCE);
+        assert!((hellinger_fidelity(&p, &q) - 1.0).abs() < TOLERANCE);
+        assert!(total_variation_distance(&p, &q).abs() < TOLERANCE);
+        assert!(kl_divergence(&p, &q).abs() < TOLERANCE);
+        assert!(kl_divergence(&q, &p).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_disjoint_distributions() {
+        let p = counts(&[("00", 10)]);
+        let q = counts(&[("11", 5), ("01", 5)]);
+
+        assert_eq!(classical_fidelity(&p, &q), 0.0);
+        assert_eq!(hellinger_fidelity(&p, &q), 0.0);
+        assert!((total_variation_distance(&p, &q) - 1.0).abs() < TOLERANCE);
+        assert_eq!(kl_divergence(&p, &q), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_partially_overlapping_distributions() {
+        // P = {00: 1/2, 01: 1/2}, Q = {00: 1/4, 10: 3/4}
+        let p = probs(&[("00", 0.5), ("01", 0.5)]);
+        let q = counts(&[("00", 1), ("10", 3)]);
+
+        let bc = (0.5_f64 * 0.25).sqrt();
+        assert!((classical_fidelity(&p, &q) - bc).abs() < TOLERANCE);
+        assert!((hellinger_fidelity(&p, &q) - bc * bc).abs() < TOLERANCE);
+        // ½(|½ - ¼| + |½ - 0| + |0 - ¾|) = 3/4
+        assert!((total_variation_distance(&p, &q) - 0.75).abs() < TOLERANCE);
+        assert_eq!(
+            total_variation_distance(&p, &q),
+            total_variation_distance(&q, &p)
+        );
+        // "01" is missing from Q, so D(P‖Q) diverges
+        assert_eq!(kl_divergence(&p, &q), f64::INFINITY);
+
+        // Q' = {00: ¼, 01: ¼, 10: ½} covers P's support: D(P‖Q') = ln 2
+        let q_full = probs(&[("00", 0.25), ("01", 0.25), ("10", 0.5)]);
+        assert!((kl_divergence(&p, &q_full) - 2.0_f64.ln()).abs() < TOLERANCE);
+    }
+}
@@ -103,4 +103,29 @@ mod tests {
         assert!((p111 - 0.5).abs() < 0.1);
         assert!((p000 + p111) > 0.9);
     }
+
+    #[test]
+    fn test_mps_simulator_long_range_gate_matches_state_vector() {
+        // Long-range gates in both directions: qubits 0, 1 and 3 end up set
+        let mut circuit = CircuitGenome::new(4);
+        circuit.add_gate(Gate::X(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 3)).unwrap();
+        circuit.add_gate(Gate::CNOT(3, 1)).unwrap();
+
+        let result = MpsSimulator::new(4).execute(&circuit, 100).unwrap();
+
+        let mut sv = crate::StateVectorSimulator::new(4);
+        sv.execute(&circuit).unwrap();
+        let expected = sv.measure(100).unwrap();
+
+        // The MPS prints qubit 0 leftmost, the state vector rightmost
+        assert_eq!(result.counts.get("1101"), Some(&100));
+        assert_eq!(expected.get("1011"), Some(&100));
+        let reversed: HashMap<String, usize> = result
+            .counts
+            .iter()
+            .map(|(bits, &n)| (bits.chars().rev().collect(), n))
+            .collect();
+        assert_eq!(reversed, expected);
+    }
 }
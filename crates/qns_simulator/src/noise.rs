@@ -360,6 +360,10 @@ impl KrausOperator {
     }
 }
 
+/// Largest qubit count accepted by the [`DepolarizingChannel`] fidelity
+/// conversions (`2^num_qubits` must fit in a `u64`).
+pub const MAX_FIDELITY_QUBITS: usize = 63;
+
 /// Depolarizing channel implementation.
 ///
 /// Applies X, Y, or Z with probability p/3 each.
@@ -375,7 +379,64 @@ impl DepolarizingChannel {
         }
     }
 
-    /// Samples which Pauli to apply (0=I, 1=X, 2=Y, 3=Z).
+    /// Depolarizing parameter `p` for a given average gate fidelity.
+    ///
+    /// Inverts `F = 1 - p(d-1)/d` with `d = 2^num_qubits`, where the channel is
+    /// `ρ → (1-p)ρ + p·I/d`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_qubits` is not in `1..=MAX_FIDELITY_QUBITS`.
+    pub fn depolarizing_parameter(fidelity: Fidelity, num_qubits: usize) -> f64 {
+        let d = Self::dimension(num_qubits);
+        ((1.0 - fidelity.value()) * d / (d - 1.0)).clamp(0.0, 1.0)
+    }
+
+    /// Creates a channel whose strength matches a calibrated gate fidelity.
+    ///
+    /// The depolarizing parameter `p` is converted to the probability that a
+    /// non-identity `num_qubits`-qubit Pauli acts, `p(d²-1)/d²`. [`sample`]
+    /// only draws single-qubit Paulis, so for `num_qubits > 1` the caller
+    /// decides how that error is spread over the qubits.
+    ///
+    /// [`sample`]: Self::sample
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_qubits` is not in `1..=MAX_FIDELITY_QUBITS`.
+    pub fn from_fidelity(fidelity: Fidelity, num_qubits: usize) -> Self {
+        let d = Self::dimension(num_qubits);
+        let p = Self::depolarizing_parameter(fidelity, num_qubits);
+        Self::new(p * (d * d - 1.0) / (d * d))
+    }
+
+    /// Average gate fidelity implied by this channel on `num_qubits` qubits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_qubits` is not in `1..=MAX_FIDELITY_QUBITS`.
+    pub fn average_gate_fidelity(&self, num_qubits: usize) -> Fidelity {
+        let d = Self::dimension(num_qubits);
+        let p = self.prob * d * d / (d * d - 1.0);
+        Fidelity::clamped(1.0 - p * (d - 1.0) / d)
+    }
+
+    /// Hilbert space dimension `2^num_qubits` for the fidelity conversions.
+    fn dimension(num_qubits: usize) -> f64 {
+        assert!(
+            (1..=MAX_FIDELITY_QUBITS).contains(&num_qubits),
+            "fidelity conversion needs 1..={} qubits, got {} \
+             (d = 1 has no error subspace)",
+            MAX_FIDELITY_QUBITS,
+            num_qubits
+        );
+        (1u64 << num_qubits) as f64
+    }
+
+    /// Samples a single-qubit Pauli (0=I, 1=X, 2=Y, 3=Z).
+    ///
+    /// Returns I with probability `1 - prob`, otherwise X, Y or Z uniformly,
+    /// so each Pauli is drawn with probability `prob/3`.
     pub fn sample<R: Rng>(&self, rng: &mut R) -> u8 {
         let r: f64 = rng.gen();
         if r < 1.0 - self.prob {
@@ -392,6 +453,24 @@ impl DepolarizingChannel {
         }
     }
 
+    /// Kraus operators `√(1-p)·I, √(p/3)·X, √(p/3)·Y, √(p/3)·Z` of the
+    /// channel that [`sample`](Self::sample) draws from.
+    pub fn kraus_operators(&self) -> [KrausOperator; 4] {
+        let pauli_prob = self.prob / 3.0;
+        [0u8, 1, 2, 3].map(|index| {
+            let prob = if index == 0 {
+                1.0 - self.prob
+            } else {
+                pauli_prob
+            };
+            let scale = C64::new(prob.sqrt(), 0.0);
+            KrausOperator {
+                matrix: Self::pauli_matrix(index).map(|row| row.map(|v| v * scale)),
+                prob,
+            }
+        })
+    }
+
     /// Returns the Pauli matrix for a given index.
     pub fn pauli_matrix(index: u8) -> &'static Matrix2x2 {
         match index {
@@ -493,6 +572,53 @@ mod tests {
         assert!(edge_model.is_valid());
     }
 
+    #[test]
+    fn test_depolarizing_from_fidelity() {
+        let fidelity = Fidelity::new(0.99);
+
+        // d = 2: p = (1 - F) * d / (d - 1) = 0.02
+        let p = DepolarizingChannel::depolarizing_parameter(fidelity, 1);
+        assert!((p - 0.02).abs() < 1e-12);
+
+        // Pauli error probability = p * 3/4
+        let channel = DepolarizingChannel::from_fidelity(fidelity, 1);
+        assert!((channel.prob - 0.015).abs() < 1e-12);
+
+        let back = channel.average_gate_fidelity(1);
+        assert!((back.value() - 0.99).abs() < 1e-12);
+
+        // Two-qubit round-trip
+        let channel = DepolarizingChannel::from_fidelity(Fidelity::new(0.95), 2);
+        assert!((channel.average_gate_fidelity(2).value() - 0.95).abs() < 1e-12);
+
+        // Largest supported width stays finite
+        let p = DepolarizingChannel::depolarizing_parameter(fidelity, MAX_FIDELITY_QUBITS);
+        assert!((p - 0.01).abs() < 1e-12);
+        let channel = DepolarizingChannel::from_fidelity(fidelity, MAX_FIDELITY_QUBITS);
+        assert!(channel
+            .average_gate_fidelity(MAX_FIDELITY_QUBITS)
+            .value()
+            .is_finite());
+    }
+
+    #[test]
+    #[should_panic(expected = "fidelity conversion needs")]
+    fn test_depolarizing_parameter_zero_qubits() {
+        DepolarizingChannel::depolarizing_parameter(Fidelity::new(0.99), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "fidelity conversion needs")]
+    fn test_from_fidelity_too_many_qubits() {
+        DepolarizingChannel::from_fidelity(Fidelity::new(0.99), MAX_FIDELITY_QUBITS + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "fidelity conversion needs")]
+    fn test_average_gate_fidelity_zero_qubits() {
+        DepolarizingChannel::new(0.1).average_gate_fidelity(0);
+    }
+
     #[test]
     fn test_depolarizing_channel() {
         let channel = DepolarizingChannel::new(0.5);
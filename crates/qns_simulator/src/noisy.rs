@@ -4,14 +4,43 @@
 
 use qns_core::physics::{Matrix2x2, C64, ZERO};
 use qns_core::prelude::*;
+use qns_noise::NoiseChannel;
 use rand::Rng;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::noise::{DepolarizingChannel, MeasurementError, NoiseModel};
-use crate::state_vector::StateVectorSimulator;
+use crate::state_vector::{mid_circuit_measurements, StateVectorSimulator};
 #[cfg(test)]
 use qns_core::physics::ONE;
 
+/// Gate class used to key per-gate noise channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GateKind {
+    /// Single-qubit gates
+    OneQubit,
+    /// Two-qubit gates, and Toffoli
+    TwoQubit,
+    /// Measurement and reset
+    Measure,
+}
+
+impl GateKind {
+    /// Classifies a gate.
+    pub fn of(gate: &Gate) -> Self {
+        if gate.is_non_unitary() {
+            Self::Measure
+        } else if gate.is_single_qubit() {
+            Self::OneQubit
+        } else {
+            Self::TwoQubit
+        }
+    }
+}
+
+/// Noise channel shared between clones of a simulator.
+type SharedChannel = Arc<dyn NoiseChannel + Send + Sync>;
+
 /// Quantum simulator with noise modeling.
 ///
 /// Wraps a StateVectorSimulator and applies noise after each gate.
@@ -23,6 +52,9 @@ use qns_core::physics::ONE;
 /// 2. **Gate errors**: Depolarizing channel after each gate
 /// 3. **Measurement errors**: Readout errors during measurement
 ///
+/// Channels set with [`with_gate_noise`](Self::with_gate_noise) replace the
+/// depolarizing gate error for their [`GateKind`].
+///
 /// # Example
 ///
 /// ```rust
@@ -54,6 +86,8 @@ pub struct NoisySimulator {
     gate_count: usize,
     /// Error events recorded
     error_count: usize,
+    /// Per-gate-kind channels replacing the model's gate error
+    gate_noise: HashMap<GateKind, SharedChannel>,
 }
 
 impl NoisySimulator {
@@ -65,9 +99,32 @@ impl NoisySimulator {
             elapsed_time_ns: 0.0,
             gate_count: 0,
             error_count: 0,
+            gate_noise: HashMap::new(),
         }
     }
 
+    /// Sets a noise channel per gate kind, e.g. a strong depolarizing channel
+    /// for two-qubit gates and a weaker one for single-qubit gates.
+    ///
+    /// After each gate of a listed kind, the channel's error distribution (see
+    /// [`NoiseChannel::apply`]) is sampled in place of the model's depolarizing
+    /// error; thermal relaxation and crosstalk still follow the model. When all
+    /// of a channel's error gates act on one qubit, they are placeholders on
+    /// qubit 0 and each of the gate's qubits draws independently; otherwise a
+    /// single draw maps error qubit `k` onto the gate's `k`-th qubit. `Rz` by
+    /// zero marks the no-error branch. A `Measure` channel acts just before
+    /// measurements and just after resets, which are otherwise ideal.
+    pub fn with_gate_noise(
+        mut self,
+        channels: HashMap<GateKind, Box<dyn NoiseChannel + Send + Sync>>,
+    ) -> Self {
+        self.gate_noise = channels
+            .into_iter()
+            .map(|(kind, channel)| (kind, Arc::from(channel)))
+            .collect();
+        self
+    }
+
     /// Creates a noisy simulator with ideal (no noise) model.
     pub fn ideal(num_qubits: usize) -> Self {
         Self::new(num_qubits, NoiseModel::ideal())
@@ -126,6 +183,12 @@ impl NoisySimulator {
         self.inner.amplitude(index)
     }
 
+    /// Returns the outcome of each collapsed measurement; see
+    /// [`StateVectorSimulator::classical_bits`].
+    pub fn classical_bits(&self) -> &[Option<u8>] {
+        self.inner.classical_bits()
+    }
+
     /// Applies a gate with noise.
     pub fn apply_gate(&mut self, gate: &Gate) -> Result<()> {
         // 1. Apply the ideal gate
@@ -143,14 +206,28 @@ impl NoisySimulator {
             | Gate::T(_)
             | Gate::Rx(_, _)
             | Gate::Ry(_, _)
-            | Gate::Rz(_, _) => (self.noise.single_gate_time_ns, self.noise.single_gate_error),
-            Gate::CNOT(q1, q2) | Gate::CZ(q1, q2) | Gate::SWAP(q1, q2) => {
+            | Gate::Rz(_, _)
+            | Gate::U(_, _, _, _) => (self.noise.single_gate_time_ns, self.noise.single_gate_error),
+            Gate::CNOT(q1, q2)
+            | Gate::CZ(q1, q2)
+            | Gate::CPhase(q1, q2, _)
+            | Gate::SWAP(q1, q2)
+            | Gate::FSim(q1, q2, _, _) => {
                 // Use edge-specific error rate if available
                 let edge_error = self.noise.get_edge_error(*q1, *q2);
                 (self.noise.two_gate_time_ns, edge_error)
             },
-            Gate::Measure(_) => {
-                return Ok(()); // Measurement handled separately
+            Gate::Toffoli(c1, c2, t) => {
+                // Charged as its two control-target interactions
+                let error = self.noise.get_edge_error(*c1, *t) + self.noise.get_edge_error(*c2, *t);
+                (2.0 * self.noise.two_gate_time_ns, error)
+            },
+            Gate::Measure(_) | Gate::Reset(_) => {
+                // Measurement and reset are ideal unless given a channel
+                if let Some(channel) = self.gate_noise.get(&GateKind::Measure).cloned() {
+                    self.apply_channel(channel.as_ref(), gate);
+                }
+                return Ok(());
             },
         };
 
@@ -159,8 +236,10 @@ impl NoisySimulator {
             self.apply_thermal_relaxation(gate.qubits(), gate_time);
         }
 
-        // 3. Apply gate error (depolarizing)
-        if self.noise.gate_errors && error_rate > 0.0 {
+        // 3. Apply gate error (per-kind channel, else depolarizing)
+        if let Some(channel) = self.gate_noise.get(&GateKind::of(gate)).cloned() {
+            self.apply_channel(channel.as_ref(), gate);
+        } else if self.noise.gate_errors && error_rate > 0.0 {
             self.apply_depolarizing_error(gate.qubits(), error_rate);
         }
 
@@ -270,6 +349,44 @@ impl NoisySimulator {
         }
     }
 
+    /// Samples `channel` after `gate` and applies the drawn error gates.
+    fn apply_channel(&mut self, channel: &(dyn NoiseChannel + Send + Sync), gate: &Gate) {
+        let outcomes = channel.apply(gate);
+        let total: f64 = outcomes.iter().map(|(p, _)| p.max(0.0)).sum();
+        if total <= 0.0 {
+            return;
+        }
+
+        let qubits = gate.qubits();
+        let draws: Vec<Vec<usize>> = if outcomes.iter().all(|(_, e)| e.qubits().len() == 1) {
+            qubits.iter().map(|&q| vec![q]).collect()
+        } else {
+            vec![qubits]
+        };
+
+        let mut rng = rand::thread_rng();
+        for targets in draws {
+            let mut r = rng.gen::<f64>() * total;
+            let Some((_, error)) = outcomes
+                .iter()
+                .find(|(p, _)| {
+                    r -= p.max(0.0);
+                    r < 0.0
+                })
+                .or(outcomes.last())
+            else {
+                continue;
+            };
+            let is_identity = matches!(error, Gate::Rz(_, theta) if *theta == 0.0);
+            if is_identity || error.qubits().iter().any(|&q| q >= targets.len()) {
+                continue;
+            }
+            if self.inner.apply_gate(&error.map_qubits(&targets)).is_ok() {
+                self.error_count += 1;
+            }
+        }
+    }
+
     /// Applies X error (bit flip) to a qubit.
     fn apply_x_error(&mut self, qubit: usize) {
         use qns_core::physics::PAULI_X;
@@ -350,6 +467,9 @@ impl NoisySimulator {
     }
 
     /// Executes a quantum circuit with noise.
+    ///
+    /// Mid-circuit measurements collapse and terminal ones are deferred to
+    /// [`measure`](Self::measure), as in [`StateVectorSimulator::execute`].
     pub fn execute(&mut self, circuit: &CircuitGenome) -> Result<()> {
         if circuit.num_qubits != self.num_qubits() {
             return Err(QnsError::DimensionMismatch(
@@ -358,8 +478,12 @@ impl NoisySimulator {
             ));
         }
 
-        for gate in &circuit.gates {
+        let collapse = mid_circuit_measurements(circuit, &[], &[]);
+        for (gate, collapse) in circuit.gates.iter().zip(collapse) {
             self.apply_gate(gate)?;
+            if let (Gate::Measure(q), true) = (gate, collapse) {
+                self.inner.measure_qubit(*q)?;
+            }
         }
 
         Ok(())
@@ -419,7 +543,8 @@ impl NoisySimulator {
             }
         }
 
-        probs.len() - 1
+        // Fallback for rounding shortfall: last outcome with nonzero probability
+        probs.iter().rposition(|&p| p > 0.0).unwrap_or(0)
     }
 
     /// Converts index to bitstring.
@@ -455,6 +580,7 @@ impl Clone for NoisySimulator {
             elapsed_time_ns: self.elapsed_time_ns,
             gate_count: self.gate_count,
             error_count: self.error_count,
+            gate_noise: self.gate_noise.clone(),
         }
     }
 }
@@ -522,6 +648,16 @@ mod tests {
         assert_eq!(sim.gate_count(), 0);
     }
 
+    #[test]
+    fn test_single_qubit_no_gates_measures_zero() {
+        let mut sim = NoisySimulator::ideal(1);
+        sim.run(&CircuitGenome::new(1)).unwrap();
+
+        let counts = sim.measure(100).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.get("0"), Some(&100));
+    }
+
     #[test]
     fn test_ideal_simulator() {
         let mut sim = NoisySimulator::ideal(2);
@@ -612,6 +748,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gate_noise_concentrated_on_cnots() {
+        use qns_noise::{Depolarizing, PhaseFlip};
+
+        let gates = [
+            Gate::H(0),
+            Gate::X(1),
+            Gate::Rx(1, 0.4),
+            Gate::CNOT(0, 1),
+            Gate::H(1),
+            Gate::Rx(1, -0.4),
+            Gate::CNOT(1, 0),
+            Gate::S(0),
+        ];
+        let channels = || {
+            let mut channels: HashMap<GateKind, Box<dyn NoiseChannel + Send + Sync>> =
+                HashMap::new();
+            channels.insert(GateKind::TwoQubit, Box::new(Depolarizing { p: 0.3 }));
+            channels.insert(GateKind::Measure, Box::new(PhaseFlip { p: 1.0 }));
+            channels
+        };
+
+        let trials = 1000;
+        let mut mean = vec![0.0; gates.len()];
+        for _ in 0..trials {
+            let mut ideal = StateVectorSimulator::new(2);
+            let mut noisy = NoisySimulator::new(2, NoiseModel::ideal()).with_gate_noise(channels());
+            let mut previous = 1.0;
+            for (step, gate) in gates.iter().enumerate() {
+                ideal.apply_gate(gate).unwrap();
+                noisy.apply_gate(gate).unwrap();
+                let fidelity = noisy.fidelity_with(&ideal).unwrap();
+                // Clean single-qubit gates act on both states alike
+                if !gate.is_two_qubit() {
+                    assert!((fidelity - previous).abs() < TOLERANCE, "step {}", step);
+                }
+                mean[step] += fidelity / trials as f64;
+                previous = fidelity;
+            }
+        }
+        // Two qubits each escape a 30% channel with probability 0.7²
+        assert!((mean[3] - 0.49).abs() < 0.1, "{:?}", mean);
+        assert!(mean[6] < mean[3] - 0.1, "{:?}", mean);
+
+        // The measure channel flips the phase of a measured |+>
+        let mut sim = NoisySimulator::new(1, NoiseModel::ideal()).with_gate_noise(channels());
+        sim.apply_gate(&Gate::H(0)).unwrap();
+        sim.apply_gate(&Gate::Measure(0)).unwrap();
+        assert!(sim.amplitude(1).re < 0.0);
+        assert_eq!(sim.error_count(), 1);
+        assert_eq!(sim.clone().error_count(), 1);
+    }
+
     #[test]
     fn test_gate_count_tracking() {
         let mut sim = NoisySimulator::ideal(2);
@@ -675,4 +864,32 @@ mod tests {
 
         assert!(sim.execute(&circuit).is_err());
     }
+
+    #[test]
+    fn test_execute_collapses_like_state_vector() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::Measure(0),
+                Gate::X(0),
+                Gate::H(1),
+                Gate::Measure(1),
+            ])
+            .unwrap();
+
+        let mut sim = NoisySimulator::ideal(2);
+        sim.execute(&circuit).unwrap();
+        let bit = sim.classical_bits()[0].expect("mid-circuit measure collapses") as usize;
+        assert_eq!(sim.classical_bits()[1], None);
+        // Qubit 0 holds the flipped outcome, qubit 1 stays in superposition
+        let p_q0 = |sim: &NoisySimulator, value: usize| -> f64 {
+            (0..4)
+                .filter(|i| i & 1 == value)
+                .map(|i| sim.probabilities()[i])
+                .sum()
+        };
+        assert!((p_q0(&sim, 1 - bit) - 1.0).abs() < TOLERANCE);
+        assert!((sim.probabilities()[1 - bit] - 0.5).abs() < TOLERANCE);
+    }
 }
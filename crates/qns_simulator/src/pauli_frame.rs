@@ -0,0 +1,341 @@
+//! Pauli-frame simulation of noisy Clifford circuits.
+//!
+//! A Clifford circuit with Pauli noise is simulated once without noise on a
+//! stabilizer tableau to obtain a reference measurement record. Each noisy
+//! shot then only tracks a Pauli frame — the X/Z error on every qubit — which
+//! Clifford gates map to another Pauli frame. A measured bit is the
+//! reference bit flipped by the frame's X component, so a shot costs
+//! `O(gates)` bit operations regardless of the state's entanglement.
+//!
+//! Frames start with a random Z on every qubit and receive a fresh random Z
+//! after each measurement. Z is invisible on `|0⟩` and on a just-measured
+//! qubit, but once propagated it randomizes outcomes that are random in the
+//! ideal circuit, so one reference record suffices for every shot.
+
+use std::collections::HashMap;
+
+use qns_core::prelude::*;
+use rand::Rng;
+
+use crate::noise::{DepolarizingChannel, MeasurementError, NoiseModel};
+use crate::tableau::{clifford_ops, CliffordOp, Tableau};
+
+const BACKEND: &str = "pauli-frame";
+
+/// One step of the compiled circuit.
+#[derive(Debug, Clone)]
+enum Step {
+    /// Clifford operation applied to the frame
+    Op(CliffordOp),
+    /// Depolarizing error on a qubit with the given probability
+    Depolarize(usize, f64),
+}
+
+/// Fast noisy sampler for Clifford circuits under Pauli noise.
+///
+/// Uses the Pauli part of a [`NoiseModel`], placed as in
+/// [`NoisySimulator`](crate::NoisySimulator): after every gate each of its
+/// qubits suffers an independent depolarizing error (the single-qubit rate,
+/// or the edge rate for two-qubit gates), and final readout flips bits with
+/// the readout error. Thermal relaxation and crosstalk are not Pauli
+/// channels and are ignored.
+///
+/// Gates must be Clifford: H, S, Paulis, CNOT, CZ, SWAP, and Rx/Ry/Rz/CPhase
+/// at multiples of π/2 (CPhase at 0 or π).
+///
+/// # Example
+///
+/// ```
+/// use qns_core::prelude::*;
+/// use qns_simulator::{NoiseModel, PauliFrameSimulator};
+///
+/// let mut ghz = CircuitGenome::new(3);
+/// ghz.add_gates([Gate::H(0), Gate::CNOT(0, 1), Gate::CNOT(1, 2)]).unwrap();
+///
+/// let counts = PauliFrameSimulator::new(3, NoiseModel::new())
+///     .sample(&ghz, 1000)
+///     .unwrap();
+/// assert_eq!(counts.values().sum::<usize>(), 1000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PauliFrameSimulator {
+    num_qubits: usize,
+    noise: NoiseModel,
+}
+
+impl PauliFrameSimulator {
+    /// Creates a sampler for `num_qubits`-qubit circuits.
+    pub fn new(num_qubits: usize, noise: NoiseModel) -> Self {
+        Self { num_qubits, noise }
+    }
+
+    /// Returns the number of qubits.
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Returns the noise model.
+    pub fn noise_model(&self) -> &NoiseModel {
+        &self.noise
+    }
+
+    /// Runs `circuit` for `shots` noisy shots and measures every qubit at
+    /// the end.
+    ///
+    /// Returns bitstring counts in the same format as
+    /// [`StateVectorSimulator::measure`](crate::StateVectorSimulator::measure)
+    /// (qubit 0 rightmost). `Measure` gates inside the circuit collapse the
+    /// qubit; the reported bit is its value at the end of the circuit.
+    ///
+    /// # Errors
+    /// [`QnsError::DimensionMismatch`] if the circuit width differs, and
+    /// [`QnsError::UnsupportedGate`] for non-Clifford gates.
+    pub fn sample(&self, circuit: &CircuitGenome, shots: usize) -> Result<HashMap<String, usize>> {
+        if circuit.num_qubits != self.num_qubits {
+            return Err(QnsError::DimensionMismatch(
+                self.num_qubits,
+                circuit.num_qubits,
+            ));
+        }
+
+        let steps = self.compile(circuit)?;
+        let mut rng = rand::thread_rng();
+        let reference = self.reference_record(&steps, &mut rng);
+        let readout = if self.noise.measurement_errors {
+            MeasurementError::symmetric(self.noise.readout_error)
+        } else {
+            MeasurementError::symmetric(0.0)
+        };
+
+        let n = self.num_qubits;
+        let mut results: HashMap<String, usize> = HashMap::new();
+        let mut frame_x = vec![false; n];
+        let mut frame_z = vec![false; n];
+        for _ in 0..shots {
+            frame_x.fill(false);
+            for z in frame_z.iter_mut() {
+                *z = rng.gen();
+            }
+            for step in &steps {
+                match *step {
+                    Step::Op(op) => propagate(op, &mut frame_x, &mut frame_z, &mut rng),
+                    Step::Depolarize(q, prob) => {
+                        let pauli = DepolarizingChannel::new(prob).sample(&mut rng);
+                        frame_x[q] ^= pauli == 1 || pauli == 2;
+                        frame_z[q] ^= pauli == 2 || pauli == 3;
+                    },
+                }
+            }
+
+            let bitstring: String = (0..n)
+                .rev()
+                .map(|q| {
+                    let bit = (reference[q] ^ frame_x[q]) as u8;
+                    if readout.apply(bit, &mut rng) == 1 {
+                        '1'
+                    } else {
+                        '0'
+                    }
+                })
+                .collect();
+            *results.entry(bitstring).or_insert(0) += 1;
+        }
+
+        Ok(results)
+    }
+
+    /// Lowers the circuit to Clifford operations interleaved with noise.
+    fn compile(&self, circuit: &CircuitGenome) -> Result<Vec<Step>> {
+        let mut steps = Vec::new();
+        for (index, gate) in circuit.gates.iter().enumerate() {
+            let ops = clifford_ops(gate, BACKEND)
+                .map_err(|e| QnsError::circuit_execution(index, gate, e))?;
+            steps.extend(ops.into_iter().map(Step::Op));
+
+            if gate.is_non_unitary() || !self.noise.gate_errors {
+                continue;
+            }
+            let rate = match gate.qubits()[..] {
+                [a, b] => self.noise.get_edge_error(a, b),
+                _ => self.noise.single_gate_error,
+            };
+            if rate > 0.0 {
+                steps.extend(gate.qubits().into_iter().map(|q| Step::Depolarize(q, rate)));
+            }
+        }
+        Ok(steps)
+    }
+
+    /// Noiseless final measurement record from one tableau run.
+    fn reference_record<R: Rng>(&self, steps: &[Step], rng: &mut R) -> Vec<bool> {
+        let mut tableau = Tableau::new(self.num_qubits);
+        for step in steps {
+            match *step {
+                Step::Op(CliffordOp::Measure(q)) => {
+                    tableau.measure(q, rng);
+                },
+                Step::Op(CliffordOp::Reset(q)) => tableau.reset(q, rng),
+                Step::Op(op) => tableau.apply(op),
+                Step::Depolarize(..) => {},
+            }
+        }
+        (0..self.num_qubits)
+            .map(|q| tableau.measure(q, rng).0)
+            .collect()
+    }
+}
+
+/// Conjugates the frame `(x, z)` by a Clifford operation; phases are dropped.
+fn propagate<R: Rng>(op: CliffordOp, x: &mut [bool], z: &mut [bool], rng: &mut R) {
+    match op {
+        CliffordOp::H(a) => std::mem::swap(&mut x[a], &mut z[a]),
+        CliffordOp::S(a) => z[a] ^= x[a],
+        CliffordOp::X(_) | CliffordOp::Y(_) | CliffordOp::Z(_) => {},
+        CliffordOp::CX(c, t) => {
+            x[t] ^= x[c];
+            z[c] ^= z[t];
+        },
+        CliffordOp::CZ(a, b) => {
+            z[a] ^= x[b];
+            z[b] ^= x[a];
+        },
+        CliffordOp::Swap(a, b) => {
+            x.swap(a, b);
+            z.swap(a, b);
+        },
+        // The collapsed qubit is a Z eigenstate; a random Z models the
+        // unknown post-measurement phase
+        CliffordOp::Measure(a) => z[a] ^= rng.gen::<bool>(),
+        // A reset qubit is |0⟩ in every shot, whatever error it carried
+        CliffordOp::Reset(a) => {
+            x[a] = false;
+            z[a] = rng.gen();
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::density_matrix::DensityMatrixSimulator;
+    use crate::noise::KrausOperator;
+    use qns_core::physics::{C64, PAULI_X, ZERO};
+
+    fn circuit_of(num_qubits: usize, gates: &[Gate]) -> CircuitGenome {
+        let mut circuit = CircuitGenome::new(num_qubits);
+        circuit.add_gates(gates.iter().cloned()).unwrap();
+        circuit
+    }
+
+    /// Exact output distribution from density-matrix evolution with the same
+    /// noise placement as the sampler.
+    fn density_matrix_reference(circuit: &CircuitGenome, noise: &NoiseModel) -> Vec<f64> {
+        let n = circuit.num_qubits;
+        let mut sim = DensityMatrixSimulator::new(n).with_noise(noise.clone());
+        sim.execute(circuit).unwrap();
+
+        let keep = C64::new((1.0 - noise.readout_error).sqrt(), 0.0);
+        let flip = C64::new(noise.readout_error.sqrt(), 0.0);
+        let readout = [
+            KrausOperator {
+                matrix: [[keep, ZERO], [ZERO, keep]],
+                prob: 1.0 - noise.readout_error,
+            },
+            KrausOperator {
+                matrix: PAULI_X.map(|row| row.map(|v| v * flip)),
+                prob: noise.readout_error,
+            },
+        ];
+        for q in 0..n {
+            sim.apply_kraus(q, &readout).unwrap();
+        }
+        sim.probabilities()
+    }
+
+    #[test]
+    fn test_noisy_distribution_matches_density_matrix() {
+        // GHZ on 0-1, plus a deterministic |1⟩ on qubit 2 built from H·Z·H
+        // and a swap, so both random and deterministic bits are exercised
+        let circuit = circuit_of(
+            3,
+            &[
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::S(1),
+                Gate::H(2),
+                Gate::Rz(2, std::f64::consts::PI),
+                Gate::H(2),
+                Gate::CZ(1, 2),
+                Gate::SWAP(0, 2),
+            ],
+        );
+        let mut noise = NoiseModel::ideal()
+            .with_gate_errors(0.05, 0.1)
+            .with_readout_error(0.03);
+        noise.gate_errors = true;
+        let expected = density_matrix_reference(&circuit, &noise);
+
+        let shots = 40_000;
+        let counts = PauliFrameSimulator::new(3, noise)
+            .sample(&circuit, shots)
+            .unwrap();
+        assert_eq!(counts.values().sum::<usize>(), shots);
+
+        for (index, want) in expected.iter().enumerate() {
+            let bits = format!("{:03b}", index);
+            let got = counts.get(&bits).copied().unwrap_or(0) as f64 / shots as f64;
+            assert!(
+                (got - want).abs() < 0.015,
+                "P({}) = {} but density matrix gives {}",
+                bits,
+                got,
+                want
+            );
+        }
+
+        // Marginal of each qubit being 1
+        for q in 0..3 {
+            let want: f64 = (0..8)
+                .filter(|i| i >> q & 1 == 1)
+                .map(|i| expected[i])
+                .sum();
+            let got = counts
+                .iter()
+                .filter(|(bits, _)| bits.as_bytes()[2 - q] == b'1')
+                .map(|(_, c)| *c as f64)
+                .sum::<f64>()
+                / shots as f64;
+            assert!(
+                (got - want).abs() < 0.015,
+                "qubit {}: {} vs {}",
+                q,
+                got,
+                want
+            );
+        }
+    }
+
+    #[test]
+    fn test_ideal_and_unsupported() {
+        // Without noise only the GHZ outcomes appear
+        let ghz = circuit_of(3, &[Gate::H(0), Gate::CNOT(0, 1), Gate::CNOT(1, 2)]);
+        let counts = PauliFrameSimulator::new(3, NoiseModel::ideal())
+            .sample(&ghz, 2000)
+            .unwrap();
+        assert!(
+            counts.keys().all(|k| k == "000" || k == "111"),
+            "{:?}",
+            counts
+        );
+        assert_eq!(counts.len(), 2);
+
+        let t = circuit_of(1, &[Gate::H(0), Gate::T(0)]);
+        let err = PauliFrameSimulator::new(1, NoiseModel::ideal())
+            .sample(&t, 1)
+            .unwrap_err();
+        assert!(err.to_string().contains("T(0)"), "{}", err);
+        assert!(PauliFrameSimulator::new(2, NoiseModel::ideal())
+            .sample(&ghz, 1)
+            .is_err());
+    }
+}
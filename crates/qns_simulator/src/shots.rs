@@ -0,0 +1,69 @@
+//! Shot-count planning for expectation value estimates.
+
+use crate::StateVectorSimulator;
+use qns_core::Result;
+
+/// Number of shots needed to reach `target_std_error` on an expectation value.
+///
+/// The standard error of a sample mean over `N` shots is `sqrt(Var / N)`, so
+/// `N = ceil(Var / target²)`. At least one shot is always returned; a
+/// non-positive target is unreachable and yields `usize::MAX`.
+pub fn shots_for_precision(variance_estimate: f64, target_std_error: f64) -> usize {
+    if target_std_error <= 0.0 {
+        return usize::MAX;
+    }
+    let shots = (variance_estimate.max(0.0) / (target_std_error * target_std_error)).ceil();
+    if shots >= usize::MAX as f64 {
+        usize::MAX
+    } else {
+        (shots as usize).max(1)
+    }
+}
+
+/// Single-shot variance of a Pauli observable with expectation `⟨P⟩`.
+///
+/// Pauli outcomes are ±1, so `Var = 1 - ⟨P⟩²`.
+pub fn pauli_variance(expectation: f64) -> f64 {
+    (1.0 - expectation * expectation).max(0.0)
+}
+
+/// Shots needed to estimate a Pauli string on `state` to `target_std_error`.
+///
+/// Uses the exact `expectation_pauli` of a preliminary simulation as the
+/// variance estimate.
+pub fn shots_for_pauli(
+    state: &StateVectorSimulator,
+    pauli: &str,
+    target_std_error: f64,
+) -> Result<usize> {
+    let expectation = state.expectation_pauli(pauli)?;
+    Ok(shots_for_precision(
+        pauli_variance(expectation),
+        target_std_error,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qns_core::Gate;
+
+    #[test]
+    fn test_shots_for_precision() {
+        assert_eq!(shots_for_precision(1.0, 0.01), 10_000);
+        assert_eq!(shots_for_precision(0.0, 0.01), 1);
+        assert_eq!(shots_for_precision(1.0, 0.0), usize::MAX);
+
+        // |0⟩ after a small Ry: ⟨Z⟩ ≈ 1, nearly deterministic
+        let mut nearly_deterministic = StateVectorSimulator::new(1);
+        nearly_deterministic.apply_gate(&Gate::Ry(0, 0.05)).unwrap();
+        // |+⟩: ⟨Z⟩ = 0, maximally uncertain
+        let mut uncertain = StateVectorSimulator::new(1);
+        uncertain.apply_gate(&Gate::H(0)).unwrap();
+
+        let few = shots_for_pauli(&nearly_deterministic, "Z", 0.01).unwrap();
+        let many = shots_for_pauli(&uncertain, "Z", 0.01).unwrap();
+        assert_eq!(many, 10_000);
+        assert!(few * 100 < many, "{} vs {}", few, many);
+    }
+}
@@ -0,0 +1,306 @@
+//! Stabilizer simulation of large Clifford circuits.
+//!
+//! The state is kept as an Aaronson-Gottesman tableau, so a gate costs
+//! `O(n)` and memory is `O(n²)` bits instead of `2^n` amplitudes. Hundreds of
+//! qubits are cheap as long as every gate is Clifford.
+
+use std::collections::HashMap;
+
+use qns_core::prelude::*;
+
+use crate::state_vector::mid_circuit_measurements;
+use crate::tableau::{clifford_ops, CliffordOp, Tableau};
+
+const BACKEND: &str = "stabilizer";
+
+/// Exact simulator for Clifford circuits.
+///
+/// Accepts H, S, Paulis, CNOT, CZ, SWAP, and Rx/Ry/Rz/CPhase at multiples of
+/// π/2 (CPhase at 0 or π); other gates fail with
+/// [`QnsError::UnsupportedGate`]. As in
+/// [`StateVectorSimulator`](crate::StateVectorSimulator), a `Measure` gate
+/// collapses its qubit when a later gate acts on it, while terminal
+/// measurements are left to [`measure`](Self::measure), which samples the
+/// final state. `Reset` collapses the qubit onto one branch.
+///
+/// # Example
+///
+/// ```
+/// use qns_core::prelude::*;
+/// use qns_simulator::StabilizerSimulator;
+///
+/// let mut ghz = CircuitGenome::new(300);
+/// ghz.add_gate(Gate::H(0)).unwrap();
+/// for q in 1..300 {
+///     ghz.add_gate(Gate::CNOT(q - 1, q)).unwrap();
+/// }
+///
+/// let mut sim = StabilizerSimulator::new(300);
+/// sim.execute(&ghz).unwrap();
+/// let counts = sim.measure(100).unwrap();
+/// assert!(counts.keys().all(|bits| bits == &"0".repeat(300) || bits == &"1".repeat(300)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StabilizerSimulator {
+    num_qubits: usize,
+    tableau: Tableau,
+}
+
+impl StabilizerSimulator {
+    /// Creates a simulator in `|0…0⟩`.
+    pub fn new(num_qubits: usize) -> Self {
+        Self {
+            num_qubits,
+            tableau: Tableau::new(num_qubits),
+        }
+    }
+
+    /// Returns the number of qubits.
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Resets to `|0…0⟩`.
+    pub fn reset(&mut self) {
+        self.tableau = Tableau::new(self.num_qubits);
+    }
+
+    /// Executes a circuit.
+    ///
+    /// Mid-circuit `Measure` gates collapse their qubit; terminal ones are
+    /// deferred to [`measure`](Self::measure).
+    ///
+    /// Gate failures are reported as `QnsError::CircuitExecution` with the
+    /// gate's position and display form.
+    pub fn execute(&mut self, circuit: &CircuitGenome) -> Result<()> {
+        if circuit.num_qubits != self.num_qubits {
+            return Err(QnsError::DimensionMismatch(
+                self.num_qubits,
+                circuit.num_qubits,
+            ));
+        }
+
+        let collapse = mid_circuit_measurements(circuit, &[], &[]);
+        for (index, gate) in circuit.gates.iter().enumerate() {
+            match gate {
+                Gate::Measure(q) if collapse[index] => self.measure_qubit(*q).map(|_| ()),
+                _ => self.apply_gate(gate),
+            }
+            .map_err(|e| QnsError::circuit_execution(index, gate, e))?;
+        }
+        Ok(())
+    }
+
+    /// Applies a Clifford gate.
+    ///
+    /// A `Measure` gate only validates its qubit, as for a terminal
+    /// measurement; use [`measure_qubit`](Self::measure_qubit) to collapse.
+    ///
+    /// # Errors
+    /// [`QnsError::InvalidQubit`] for out-of-range qubits and
+    /// [`QnsError::UnsupportedGate`] for non-Clifford gates; the state is
+    /// unchanged in both cases.
+    pub fn apply_gate(&mut self, gate: &Gate) -> Result<()> {
+        for q in gate.qubits() {
+            if q >= self.num_qubits {
+                return Err(QnsError::InvalidQubit(q, self.num_qubits));
+            }
+        }
+
+        let mut rng = rand::thread_rng();
+        for op in clifford_ops(gate, BACKEND)? {
+            match op {
+                CliffordOp::Measure(_) => {},
+                CliffordOp::Reset(q) => self.tableau.reset(q, &mut rng),
+                _ => self.tableau.apply(op),
+            }
+        }
+        Ok(())
+    }
+
+    /// Measures a single qubit and collapses the state.
+    ///
+    /// Returns the measurement result (0 or 1).
+    pub fn measure_qubit(&mut self, qubit: usize) -> Result<u8> {
+        if qubit >= self.num_qubits {
+            return Err(QnsError::InvalidQubit(qubit, self.num_qubits));
+        }
+        let (outcome, _) = self.tableau.measure(qubit, &mut rand::thread_rng());
+        Ok(outcome as u8)
+    }
+
+    /// Measures all qubits `shots` times without disturbing the state.
+    ///
+    /// Returns bitstring counts in the same format as
+    /// [`StateVectorSimulator::measure`](crate::StateVectorSimulator::measure)
+    /// (qubit 0 rightmost). The outcome distribution is computed once in
+    /// `O(n³)`; each shot then costs `O(n²)` at most.
+    pub fn measure(&self, shots: usize) -> Result<HashMap<String, usize>> {
+        let sampler = self.tableau.basis_sampler();
+        let mut rng = rand::thread_rng();
+        let mut results: HashMap<String, usize> = HashMap::new();
+        for _ in 0..shots {
+            let bits = sampler.sample(&mut rng);
+            let bitstring: String = bits
+                .iter()
+                .rev()
+                .map(|&bit| if bit { '1' } else { '0' })
+                .collect();
+            *results.entry(bitstring).or_insert(0) += 1;
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateVectorSimulator;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_ghz_256_qubits() {
+        let n = 256;
+        let mut ghz = CircuitGenome::new(n);
+        ghz.add_gate(Gate::H(0)).unwrap();
+        for q in 1..n {
+            ghz.add_gate(Gate::CNOT(q - 1, q)).unwrap();
+        }
+        ghz.add_gate(Gate::X(7)).unwrap();
+
+        let mut sim = StabilizerSimulator::new(n);
+        sim.execute(&ghz).unwrap();
+        let counts = sim.measure(400).unwrap();
+        assert_eq!(counts.values().sum::<usize>(), 400);
+        assert_eq!(counts.len(), 2, "{:?}", counts.keys().collect::<Vec<_>>());
+        for (bits, count) in &counts {
+            // Every qubit agrees except the flipped qubit 7
+            let flipped = bits.as_bytes()[n - 1 - 7];
+            assert!(bits
+                .bytes()
+                .enumerate()
+                .all(|(i, b)| (b == flipped) == (i == n - 1 - 7)));
+            assert!((150..=250).contains(count), "{}", count);
+        }
+
+        // Measuring does not collapse the stored state
+        assert_eq!(sim.measure(200).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_random_clifford_matches_state_vector() {
+        let n = 5;
+        let mut rng = StdRng::seed_from_u64(1760);
+        for _ in 0..10 {
+            let mut circuit = CircuitGenome::new(n);
+            for _ in 0..40 {
+                let a = rng.gen_range(0..n);
+                let b = (a + rng.gen_range(1..n)) % n;
+                let gate = match rng.gen_range(0..10) {
+                    0 => Gate::H(a),
+                    1 => Gate::S(a),
+                    2 => Gate::X(a),
+                    3 => Gate::Y(a),
+                    4 => Gate::Z(a),
+                    5 => Gate::Rx(a, FRAC_PI_2 * rng.gen_range(-3..4) as f64),
+                    6 => Gate::Ry(a, FRAC_PI_2 * rng.gen_range(-3..4) as f64),
+                    7 => Gate::CNOT(a, b),
+                    8 => Gate::CZ(a, b),
+                    _ => Gate::SWAP(a, b),
+                };
+                circuit.add_gate(gate).unwrap();
+            }
+
+            let mut exact = StateVectorSimulator::new(n);
+            exact.execute(&circuit).unwrap();
+            let support: Vec<usize> = exact
+                .probabilities()
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| **p > 1e-9)
+                .map(|(i, _)| i)
+                .collect();
+
+            let mut sim = StabilizerSimulator::new(n);
+            sim.execute(&circuit).unwrap();
+            let shots = 4000;
+            let counts = sim.measure(shots).unwrap();
+            let sampled: Vec<usize> = {
+                let mut v: Vec<usize> = counts
+                    .keys()
+                    .map(|bits| usize::from_str_radix(bits, 2).unwrap())
+                    .collect();
+                v.sort_unstable();
+                v
+            };
+            assert_eq!(sampled, support, "{:?}", circuit.gates);
+
+            // Stabilizer states are uniform on their support
+            let want = 1.0 / support.len() as f64;
+            for count in counts.values() {
+                let got = *count as f64 / shots as f64;
+                assert!((got - want).abs() < 0.03, "{} vs {}", got, want);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mid_circuit_measure_collapses() {
+        // Measuring the control first turns the Bell pair into |00⟩ or |11⟩
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([Gate::H(0), Gate::Measure(0), Gate::CNOT(0, 1)])
+            .unwrap();
+
+        let mut seen = HashSet::new();
+        for _ in 0..50 {
+            let mut sim = StabilizerSimulator::new(2);
+            sim.execute(&circuit).unwrap();
+            let counts = sim.measure(100).unwrap();
+            assert_eq!(counts.len(), 1, "{:?}", counts);
+            seen.extend(counts.into_keys());
+        }
+        let mut seen: Vec<String> = seen.into_iter().collect();
+        seen.sort_unstable();
+        assert_eq!(seen, ["00", "11"]);
+
+        // A terminal measurement leaves the distribution to `measure`
+        let mut terminal = CircuitGenome::new(2);
+        terminal
+            .add_gates([Gate::H(0), Gate::CNOT(0, 1), Gate::Measure(0)])
+            .unwrap();
+        let mut sim = StabilizerSimulator::new(2);
+        sim.execute(&terminal).unwrap();
+        assert_eq!(sim.measure(200).unwrap().len(), 2);
+
+        let mut sim = StabilizerSimulator::new(1);
+        sim.apply_gate(&Gate::X(0)).unwrap();
+        assert_eq!(sim.measure_qubit(0).unwrap(), 1);
+        assert!(matches!(
+            sim.measure_qubit(1),
+            Err(QnsError::InvalidQubit(1, 1))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_non_clifford() {
+        let mut sim = StabilizerSimulator::new(2);
+        sim.apply_gate(&Gate::H(0)).unwrap();
+        for gate in [Gate::T(0), Gate::Rz(1, 0.3), Gate::CPhase(0, 1, FRAC_PI_2)] {
+            let err = sim.apply_gate(&gate).unwrap_err();
+            assert!(
+                matches!(err, QnsError::UnsupportedGate { ref backend, .. } if backend == BACKEND),
+                "{}",
+                err
+            );
+        }
+        assert!(matches!(
+            sim.apply_gate(&Gate::CNOT(0, 2)),
+            Err(QnsError::InvalidQubit(2, 2))
+        ));
+        assert_eq!(sim.measure(100).unwrap().len(), 2);
+    }
+}
@@ -4,6 +4,7 @@
 //! - Maintains the complete quantum state as a complex amplitude vector
 //! - Applies single and two-qubit gates using efficient indexing
 //! - Performs measurements using Born rule sampling
+//! - Collapses mid-circuit measurements and feeds their outcomes forward
 //! - Calculates fidelity between states
 //!
 //! ## Physical Background
@@ -41,8 +42,8 @@
 
 use num_complex::Complex64;
 use qns_core::physics::{
-    rx, ry, rz, Matrix2x2, Matrix4x4, C64, CNOT, CZ, HADAMARD, ONE, PAULI_X, PAULI_Y, PAULI_Z,
-    SWAP, S_GATE, T_GATE, ZERO,
+    cphase, fsim, rx, ry, rz, u3, Matrix2x2, Matrix4x4, C64, CNOT, CZ, HADAMARD, ONE, PAULI_X,
+    PAULI_Y, PAULI_Z, SWAP, S_GATE, T_GATE, ZERO,
 };
 use qns_core::prelude::*;
 use rand::Rng;
@@ -66,12 +67,19 @@ pub struct StateVectorSimulator {
     state: Vec<C64>,
     /// Dimension (2^n)
     dimension: usize,
+    /// Renormalize after every this many gates during `execute`
+    auto_renormalize_every: Option<usize>,
+    /// Total norm drift removed by `renormalize` since the last reset
+    corrected_drift: f64,
+    /// Last measurement outcome of each qubit
+    classical_bits: Vec<Option<u8>>,
 }
 
 impl StateVectorSimulator {
     /// Creates a new simulator with the specified number of qubits.
     ///
-    /// Initializes the state to |0...0⟩.
+    /// Initializes the state to |0...0⟩. With zero qubits the state is the
+    /// scalar `[1]` and every shot measures the empty bitstring.
     ///
     /// # Panics
     ///
@@ -91,9 +99,29 @@ impl StateVectorSimulator {
             num_qubits,
             state,
             dimension,
+            auto_renormalize_every: None,
+            corrected_drift: 0.0,
+            classical_bits: vec![None; num_qubits],
         }
     }
 
+    /// Renormalizes the state after every `every` gates during
+    /// [`execute`](Self::execute) and
+    /// [`execute_with_faults`](Self::execute_with_faults).
+    ///
+    /// Rounding error makes `|ψ|²` drift away from 1 over thousands of gates;
+    /// periodic renormalization keeps long simulations accurate. `None` or
+    /// `Some(0)` disables it (the default).
+    pub fn with_auto_renormalize_every(mut self, every: Option<usize>) -> Self {
+        self.auto_renormalize_every = every.filter(|&n| n > 0);
+        self
+    }
+
+    /// Returns the automatic renormalization interval, if enabled.
+    pub fn auto_renormalize_every(&self) -> Option<usize> {
+        self.auto_renormalize_every
+    }
+
     /// Returns the number of qubits.
     pub fn num_qubits(&self) -> usize {
         self.num_qubits
@@ -108,6 +136,54 @@ impl StateVectorSimulator {
     pub fn reset(&mut self) {
         self.state.fill(ZERO);
         self.state[0] = ONE;
+        self.corrected_drift = 0.0;
+        self.classical_bits.fill(None);
+    }
+
+    /// Returns the classical register: the last outcome of
+    /// [`measure_qubit`](Self::measure_qubit) on each qubit, or `None` if the
+    /// qubit has not been measured since the last reset.
+    ///
+    /// Only collapsing measurements are recorded; see
+    /// [`execute`](Self::execute) for which `Measure` gates collapse.
+    pub fn classical_bits(&self) -> &[Option<u8>] {
+        &self.classical_bits
+    }
+
+    /// Returns the current norm drift `| |ψ|² - 1 |`.
+    pub fn norm_drift(&self) -> f64 {
+        let norm_sq: f64 = self.state.iter().map(|a| a.norm_sqr()).sum();
+        (norm_sq - 1.0).abs()
+    }
+
+    /// Returns the total norm drift removed by [`renormalize`](Self::renormalize)
+    /// since the last reset.
+    pub fn accumulated_norm_drift(&self) -> f64 {
+        self.corrected_drift
+    }
+
+    /// Rescales the state to unit norm.
+    ///
+    /// A zero state is left unchanged.
+    pub fn renormalize(&mut self) {
+        let norm_sq: f64 = self.state.iter().map(|a| a.norm_sqr()).sum();
+        if norm_sq <= 0.0 {
+            return;
+        }
+        self.corrected_drift += (norm_sq - 1.0).abs();
+        let scale = 1.0 / norm_sq.sqrt();
+        for amplitude in &mut self.state {
+            *amplitude *= scale;
+        }
+    }
+
+    /// Renormalizes if `gates_applied` completes an auto-renormalization interval.
+    fn auto_renormalize(&mut self, gates_applied: usize) {
+        if let Some(every) = self.auto_renormalize_every {
+            if gates_applied % every == 0 {
+                self.renormalize();
+            }
+        }
     }
 
     /// Returns a reference to the state vector.
@@ -144,6 +220,7 @@ impl StateVectorSimulator {
         }
 
         self.state = state;
+        self.classical_bits.fill(None);
         Ok(())
     }
 
@@ -214,6 +291,21 @@ impl StateVectorSimulator {
         }
     }
 
+    /// Applies a Toffoli gate: flips `target` where both controls are |1⟩.
+    ///
+    /// CCX is a basis-state permutation, so amplitudes are swapped in place
+    /// rather than multiplied by an 8x8 matrix.
+    fn apply_toffoli(&mut self, control1: usize, control2: usize, target: usize) {
+        let controls = (1 << control1) | (1 << control2);
+        let target_mask = 1 << target;
+
+        for base in 0..self.dimension {
+            if base & controls == controls && base & target_mask == 0 {
+                self.state.swap(base, base | target_mask);
+            }
+        }
+    }
+
     /// Applies a gate from the Gate enum.
     pub fn apply_gate(&mut self, gate: &Gate) -> Result<()> {
         match gate {
@@ -254,6 +346,10 @@ impl StateVectorSimulator {
                 self.validate_qubit(*q)?;
                 self.apply_single_qubit_gate(*q, &rz(*theta));
             },
+            Gate::U(q, theta, phi, lambda) => {
+                self.validate_qubit(*q)?;
+                self.apply_single_qubit_gate(*q, &u3(*theta, *phi, *lambda));
+            },
 
             // Two-qubit gates
             Gate::CNOT(ctrl, tgt) => {
@@ -266,16 +362,37 @@ impl StateVectorSimulator {
                 self.validate_qubit(*q2)?;
                 self.apply_two_qubit_gate(*q1, *q2, &CZ);
             },
+            Gate::CPhase(q1, q2, lambda) => {
+                self.validate_qubit(*q1)?;
+                self.validate_qubit(*q2)?;
+                self.apply_two_qubit_gate(*q1, *q2, &cphase(*lambda));
+            },
             Gate::SWAP(q1, q2) => {
                 self.validate_qubit(*q1)?;
                 self.validate_qubit(*q2)?;
                 self.apply_two_qubit_gate(*q1, *q2, &SWAP);
             },
+            Gate::FSim(q1, q2, theta, phi) => {
+                self.validate_qubit(*q1)?;
+                self.validate_qubit(*q2)?;
+                self.apply_two_qubit_gate(*q1, *q2, &fsim(*theta, *phi));
+            },
 
-            // Measurement is handled separately
-            Gate::Measure(_) => {
-                // Measurement collapses state - handled in measure()
-                // For now, we skip measurement gates during execution
+            // Three-qubit gates
+            Gate::Toffoli(c1, c2, t) => {
+                self.validate_qubit(*c1)?;
+                self.validate_qubit(*c2)?;
+                self.validate_qubit(*t)?;
+                self.apply_toffoli(*c1, *c2, *t);
+            },
+
+            // Terminal measurements are deferred to `measure()`; `execute`
+            // collapses the ones later gates depend on
+            Gate::Measure(q) => {
+                self.validate_qubit(*q)?;
+            },
+            Gate::Reset(q) => {
+                self.reset_qubit(*q)?;
             },
         }
 
@@ -291,8 +408,158 @@ impl StateVectorSimulator {
         }
     }
 
+    /// Applies `gate` only if qubit `bit` was last measured as `value`.
+    ///
+    /// Returns whether the gate was applied. This is classical feed-forward:
+    /// measure with [`measure_qubit`](Self::measure_qubit), then condition
+    /// corrections on the outcome.
+    ///
+    /// # Errors
+    /// [`QnsError::InvalidQubit`] if `bit` is out of range, and a simulator
+    /// error if it has not been measured since the last reset.
+    pub fn apply_conditioned(&mut self, gate: &Gate, bit: usize, value: u8) -> Result<bool> {
+        if !self.condition_holds(bit, value)? {
+            return Ok(false);
+        }
+        self.apply_gate(gate)?;
+        Ok(true)
+    }
+
+    /// Checks a feed-forward condition against the classical register.
+    fn condition_holds(&self, bit: usize, value: u8) -> Result<bool> {
+        self.validate_qubit(bit)?;
+        match self.classical_bits[bit] {
+            Some(measured) => Ok(measured == value),
+            None => Err(QnsError::simulator(format!(
+                "classical bit {} read before qubit {} was measured",
+                bit, bit
+            ))),
+        }
+    }
+
     /// Executes a quantum circuit.
+    ///
+    /// A `Measure` gate collapses its qubit and stores the outcome in
+    /// [`classical_bits`](Self::classical_bits) when a later gate acts on the
+    /// qubit. Terminal measurements are deferred so that
+    /// [`measure`](Self::measure) keeps sampling the full distribution.
+    ///
+    /// Gate failures are reported as `QnsError::CircuitExecution` with the
+    /// gate's position and display form.
     pub fn execute(&mut self, circuit: &CircuitGenome) -> Result<()> {
+        self.execute_with_feed_forward(circuit, &[])
+    }
+
+    /// Executes a circuit with classically controlled gates.
+    ///
+    /// Each `(index, bit, value)` makes the gate at `index` conditional on
+    /// qubit `bit` having been measured as `value` earlier in the circuit; a
+    /// gate with several conditions runs only if all of them hold. Measurements
+    /// read by a condition always collapse, as in [`execute`](Self::execute).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qns_core::prelude::*;
+    /// use qns_simulator::StateVectorSimulator;
+    ///
+    /// // Measure a |+⟩ qubit and flip it back to |0⟩ if the outcome was 1
+    /// let mut circuit = CircuitGenome::new(1);
+    /// circuit.add_gates([Gate::H(0), Gate::Measure(0), Gate::X(0)]).unwrap();
+    ///
+    /// let mut sim = StateVectorSimulator::new(1);
+    /// sim.execute_with_feed_forward(&circuit, &[(2, 0, 1)]).unwrap();
+    /// assert!(sim.classical_bits()[0].is_some());
+    /// assert!((sim.probability(0) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn execute_with_feed_forward(
+        &mut self,
+        circuit: &CircuitGenome,
+        conditions: &[(usize, usize, u8)],
+    ) -> Result<()> {
+        if let Some((index, _, _)) = conditions
+            .iter()
+            .find(|(i, _, _)| *i >= circuit.gates.len())
+        {
+            return Err(QnsError::simulator(format!(
+                "condition index {} out of range for circuit with {} gates",
+                index,
+                circuit.gates.len()
+            )));
+        }
+        self.execute_inner(circuit, &[], conditions)
+    }
+
+    /// Executes a circuit, injecting error gates at chosen positions.
+    ///
+    /// Each `(index, error)` applies `error` right after the gate at `index`;
+    /// several faults may share an index and are applied in the given order.
+    /// This builds deterministic fault scenarios such as "X error after gate 3".
+    /// Measurements collapse as in [`execute`](Self::execute), counting the
+    /// injected errors as later gates.
+    pub fn execute_with_faults(
+        &mut self,
+        circuit: &CircuitGenome,
+        faults: &[(usize, Gate)],
+    ) -> Result<()> {
+        if let Some((index, _)) = faults.iter().find(|(i, _)| *i >= circuit.gates.len()) {
+            return Err(QnsError::simulator(format!(
+                "fault index {} out of range for circuit with {} gates",
+                index,
+                circuit.gates.len()
+            )));
+        }
+        self.execute_inner(circuit, faults, &[])
+    }
+
+    /// Shared gate loop of the `execute*` methods.
+    fn execute_inner(
+        &mut self,
+        circuit: &CircuitGenome,
+        faults: &[(usize, Gate)],
+        conditions: &[(usize, usize, u8)],
+    ) -> Result<()> {
+        if circuit.num_qubits != self.num_qubits {
+            return Err(QnsError::DimensionMismatch(
+                self.num_qubits,
+                circuit.num_qubits,
+            ));
+        }
+
+        let collapse = mid_circuit_measurements(circuit, faults, conditions);
+        for (index, gate) in circuit.gates.iter().enumerate() {
+            let mut enabled = true;
+            for &(_, bit, value) in conditions.iter().filter(|(i, _, _)| *i == index) {
+                enabled &= self
+                    .condition_holds(bit, value)
+                    .map_err(|e| QnsError::circuit_execution(index, gate, e))?;
+            }
+            if enabled {
+                match gate {
+                    Gate::Measure(q) if collapse[index] => self.measure_qubit(*q).map(|_| ()),
+                    _ => self.apply_gate(gate),
+                }
+                .map_err(|e| QnsError::circuit_execution(index, gate, e))?;
+            }
+            for (_, error) in faults.iter().filter(|(i, _)| *i == index) {
+                self.apply_gate(error)
+                    .map_err(|e| QnsError::circuit_execution(index, error, e))?;
+            }
+            self.auto_renormalize(index + 1);
+        }
+
+        Ok(())
+    }
+
+    /// Applies the unitary part of a circuit, skipping measurements.
+    ///
+    /// Unlike [`execute`](Self::execute) nothing collapses, so the result is
+    /// deterministic; used to build unitaries and compare circuits.
+    ///
+    /// # Errors
+    /// `QnsError::CircuitExecution` wrapping [`QnsError::UnsupportedGate`] for
+    /// a `Reset`, which has no unitary.
+    pub(crate) fn execute_unitary(&mut self, circuit: &CircuitGenome) -> Result<()> {
         if circuit.num_qubits != self.num_qubits {
             return Err(QnsError::DimensionMismatch(
                 self.num_qubits,
@@ -300,8 +567,17 @@ impl StateVectorSimulator {
             ));
         }
 
-        for gate in &circuit.gates {
-            self.apply_gate(gate)?;
+        for (index, gate) in circuit.gates.iter().enumerate() {
+            match gate {
+                Gate::Measure(_) => Ok(()),
+                Gate::Reset(_) => Err(QnsError::unsupported_gate(
+                    gate,
+                    "unitary",
+                    "reset is not unitary",
+                )),
+                _ => self.apply_gate(gate),
+            }
+            .map_err(|e| QnsError::circuit_execution(index, gate, e))?;
         }
 
         Ok(())
@@ -350,6 +626,35 @@ impl StateVectorSimulator {
         Ok(results)
     }
 
+    /// Returns the `k` most likely measurement outcomes without sampling.
+    ///
+    /// Outcomes are computed exactly from the amplitudes and sorted by
+    /// descending probability (ties by basis index). Returns fewer than `k`
+    /// entries if the Hilbert space is smaller.
+    pub fn top_outcomes(&self, k: usize) -> Vec<(String, f64)> {
+        let mut ranked: Vec<(usize, f64)> = self.probabilities().into_iter().enumerate().collect();
+        let by_prob_desc = |a: &(usize, f64), b: &(usize, f64)| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        };
+
+        let k = k.min(ranked.len());
+        if k == 0 {
+            return Vec::new();
+        }
+        if k < ranked.len() {
+            ranked.select_nth_unstable_by(k - 1, by_prob_desc);
+            ranked.truncate(k);
+        }
+        ranked.sort_by(by_prob_desc);
+
+        ranked
+            .into_iter()
+            .map(|(i, p)| (self.index_to_bitstring(i), p))
+            .collect()
+    }
+
     /// Samples a single measurement outcome.
     fn sample_outcome(&mut self, probs: &[f64]) -> usize {
         let mut rng = rand::thread_rng();
@@ -363,8 +668,8 @@ impl StateVectorSimulator {
             }
         }
 
-        // Fallback (should not reach here for normalized states)
-        self.dimension - 1
+        // Fallback for rounding shortfall: last outcome with nonzero probability
+        probs.iter().rposition(|&p| p > 0.0).unwrap_or(0)
     }
 
     /// Converts a basis state index to a bit string.
@@ -411,8 +716,16 @@ impl StateVectorSimulator {
 
     /// Measures a single qubit and collapses the state.
     ///
-    /// Returns the measurement result (0 or 1).
+    /// Returns the measurement result (0 or 1), which is also stored in
+    /// [`classical_bits`](Self::classical_bits).
     pub fn measure_qubit(&mut self, qubit: usize) -> Result<u8> {
+        let result = self.collapse_qubit(qubit)?;
+        self.classical_bits[qubit] = Some(result);
+        Ok(result)
+    }
+
+    /// Projects a qubit onto a Born-sampled outcome without recording it.
+    fn collapse_qubit(&mut self, qubit: usize) -> Result<u8> {
         self.validate_qubit(qubit)?;
 
         let mask = 1 << qubit;
@@ -450,6 +763,22 @@ impl StateVectorSimulator {
         Ok(result)
     }
 
+    /// Resets a qubit to |0⟩ so it can be reused.
+    ///
+    /// The qubit is measured, collapsing the state, and flipped back to |0⟩
+    /// if the outcome was 1. A single run follows one measurement branch;
+    /// averaged over shots this is the reset channel, and any other qubit
+    /// entangled with the reset one is left in the matching branch.
+    ///
+    /// Returns the discarded measurement result.
+    pub fn reset_qubit(&mut self, qubit: usize) -> Result<u8> {
+        let result = self.collapse_qubit(qubit)?;
+        if result == 1 {
+            self.apply_single_qubit_gate(qubit, &PAULI_X);
+        }
+        Ok(result)
+    }
+
     /// Calculates the fidelity between the current state and a target state.
     ///
     /// Fidelity F = |⟨ψ|φ⟩|² where ψ is current state and φ is target.
@@ -528,6 +857,45 @@ impl StateVectorSimulator {
 
         Ok(exp_val)
     }
+
+    /// Returns the exact expectation value of a Pauli string.
+    ///
+    /// `pauli` has one character (`I`, `X`, `Y` or `Z`) per qubit, with qubit 0
+    /// as the rightmost character like measurement bit strings, e.g. `"ZI"`
+    /// measures Z on qubit 1.
+    pub fn expectation_pauli(&self, pauli: &str) -> Result<f64> {
+        if pauli.chars().count() != self.num_qubits {
+            return Err(QnsError::DimensionMismatch(
+                self.num_qubits,
+                pauli.chars().count(),
+            ));
+        }
+
+        let mut rotated = self.clone();
+        for (q, c) in pauli.chars().rev().enumerate() {
+            match c.to_ascii_uppercase() {
+                'I' => {},
+                'X' => rotated.apply_single_qubit_gate(q, &PAULI_X),
+                'Y' => rotated.apply_single_qubit_gate(q, &PAULI_Y),
+                'Z' => rotated.apply_single_qubit_gate(q, &PAULI_Z),
+                other => {
+                    return Err(QnsError::simulator(format!(
+                        "invalid Pauli operator '{}' in \"{}\"",
+                        other, pauli
+                    )))
+                },
+            }
+        }
+
+        // ⟨ψ|P|ψ⟩ is real for Hermitian P
+        let value: C64 = self
+            .state
+            .iter()
+            .zip(rotated.state.iter())
+            .map(|(a, b)| a.conj() * b)
+            .sum();
+        Ok(value.re)
+    }
 }
 
 impl Clone for StateVectorSimulator {
@@ -536,13 +904,53 @@ impl Clone for StateVectorSimulator {
             num_qubits: self.num_qubits,
             state: self.state.clone(),
             dimension: self.dimension,
+            auto_renormalize_every: self.auto_renormalize_every,
+            corrected_drift: self.corrected_drift,
+            classical_bits: self.classical_bits.clone(),
         }
     }
 }
 
+/// Flags the `Measure` gates whose qubit is touched again, by a later gate or
+/// an injected fault, or whose outcome a later condition reads; only these
+/// need to collapse during `execute`.
+pub(crate) fn mid_circuit_measurements(
+    circuit: &CircuitGenome,
+    faults: &[(usize, Gate)],
+    conditions: &[(usize, usize, u8)],
+) -> Vec<bool> {
+    let mut needed = vec![false; circuit.num_qubits];
+    let mut collapse = vec![false; circuit.gates.len()];
+    for (index, gate) in circuit.gates.iter().enumerate().rev() {
+        // Faults at `index` act after the gate itself
+        for (_, error) in faults.iter().filter(|(i, _)| *i == index) {
+            for q in error.qubits() {
+                if let Some(flag) = needed.get_mut(q) {
+                    *flag = true;
+                }
+            }
+        }
+        if let Gate::Measure(q) = gate {
+            collapse[index] = needed.get(*q).copied().unwrap_or(false);
+        }
+        for q in gate.qubits() {
+            if let Some(flag) = needed.get_mut(q) {
+                *flag = true;
+            }
+        }
+        for &(_, bit, _) in conditions.iter().filter(|(i, _, _)| *i == index) {
+            if let Some(flag) = needed.get_mut(bit) {
+                *flag = true;
+            }
+        }
+    }
+    collapse
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
     use std::f64::consts::PI;
 
     const TOLERANCE: f64 = 1e-10;
@@ -560,6 +968,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_single_qubit_no_gates_measures_zero() {
+        let mut sim = StateVectorSimulator::new(1);
+        sim.run(&CircuitGenome::new(1)).unwrap();
+
+        let counts = sim.measure(100).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.get("0"), Some(&100));
+    }
+
+    #[test]
+    fn test_zero_qubit_simulator() {
+        let mut sim = StateVectorSimulator::new(0);
+        assert_eq!(sim.statevector(), &[ONE]);
+
+        let counts = sim.measure(10).unwrap();
+        assert_eq!(counts.get(""), Some(&10));
+    }
+
     #[test]
     fn test_reset() {
         let mut sim = StateVectorSimulator::new(2);
@@ -627,6 +1054,35 @@ mod tests {
         assert!((sim.amplitude(3) - expected).norm() < TOLERANCE); // |11⟩
     }
 
+    #[test]
+    fn test_toffoli_flips_target_only_when_both_controls_set() {
+        // Controls on qubits 0 and 2, target on qubit 1
+        for input in 0..8usize {
+            let mut sim = StateVectorSimulator::new(3);
+            for q in 0..3 {
+                if input >> q & 1 == 1 {
+                    sim.apply_gate(&Gate::X(q)).unwrap();
+                }
+            }
+            sim.apply_gate(&Gate::Toffoli(0, 2, 1)).unwrap();
+
+            let expected = if input & 0b101 == 0b101 {
+                input ^ 0b010
+            } else {
+                input
+            };
+            assert!(
+                (sim.amplitude(expected) - ONE).norm() < TOLERANCE,
+                "input {:03b} should map to {:03b}",
+                input,
+                expected
+            );
+        }
+
+        let mut sim = StateVectorSimulator::new(3);
+        assert!(sim.apply_gate(&Gate::Toffoli(0, 1, 3)).is_err());
+    }
+
     #[test]
     fn test_bell_state() {
         let mut sim = StateVectorSimulator::new(2);
@@ -658,6 +1114,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_top_outcomes_ghz() {
+        let mut sim = StateVectorSimulator::new(4);
+        sim.prepare_ghz_state().unwrap();
+
+        let top = sim.top_outcomes(2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0, "0000");
+        assert_eq!(top[1].0, "1111");
+        for (_, p) in &top {
+            assert!((p - 0.5).abs() < TOLERANCE);
+        }
+
+        // Remaining outcomes have zero probability; k is capped at 2^n
+        let all = sim.top_outcomes(100);
+        assert_eq!(all.len(), 16);
+        assert!(all[2].1 < TOLERANCE);
+        assert!(sim.top_outcomes(0).is_empty());
+    }
+
     #[test]
     fn test_rotation_gates() {
         // Rx(π) = -iX (up to global phase, acts like X)
@@ -693,6 +1169,37 @@ mod tests {
         assert!((probs[3] - 0.5).abs() < TOLERANCE);
     }
 
+    #[test]
+    fn test_execute_reports_failing_gate() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        // Bypass add_gate validation to simulate a corrupted circuit
+        circuit.gates.push(Gate::CNOT(0, 5));
+
+        let mut sim = StateVectorSimulator::new(3);
+        let err = sim.execute(&circuit).unwrap_err();
+
+        match &err {
+            QnsError::CircuitExecution {
+                index,
+                gate,
+                source,
+            } => {
+                assert_eq!(*index, 2);
+                assert_eq!(gate, "CNOT(0, 5)");
+                assert!(matches!(**source, QnsError::InvalidQubit(5, 3)));
+            },
+            other => panic!("unexpected error: {other}"),
+        }
+        let msg = err.to_string();
+        assert!(
+            msg.contains("gate 2") && msg.contains("CNOT(0, 5)"),
+            "{}",
+            msg
+        );
+    }
+
     #[test]
     fn test_dimension_mismatch() {
         let mut sim = StateVectorSimulator::new(2);
@@ -727,6 +1234,31 @@ mod tests {
         assert!((fidelity - 1.0).abs() < TOLERANCE);
     }
 
+    #[test]
+    fn test_inverse_uncomputes() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::S(1),
+                Gate::T(2),
+                Gate::CNOT(0, 1),
+                Gate::Ry(2, 0.9),
+                Gate::U(1, 0.4, -1.3, 2.2),
+                Gate::Toffoli(0, 1, 2),
+                Gate::CPhase(2, 0, 0.6),
+            ])
+            .unwrap();
+
+        let mut sim = StateVectorSimulator::new(3);
+        let initial = sim.statevector().to_vec();
+        sim.execute(&circuit).unwrap();
+        assert!(sim.fidelity(&initial).unwrap() < 0.99);
+
+        sim.execute(&circuit.inverse().unwrap()).unwrap();
+        assert!((sim.fidelity(&initial).unwrap() - 1.0).abs() < TOLERANCE);
+    }
+
     #[test]
     fn test_fidelity_orthogonal() {
         let sim = StateVectorSimulator::new(1);
@@ -819,6 +1351,62 @@ mod tests {
         assert!((sim.amplitude(3) - expected).norm() < TOLERANCE);
     }
 
+    #[test]
+    fn test_expectation_pauli() {
+        let mut sim = StateVectorSimulator::new(2);
+        sim.prepare_bell_state().unwrap();
+        assert!((sim.expectation_pauli("ZZ").unwrap() - 1.0).abs() < TOLERANCE);
+        assert!((sim.expectation_pauli("XX").unwrap() - 1.0).abs() < TOLERANCE);
+        assert!((sim.expectation_pauli("YY").unwrap() + 1.0).abs() < TOLERANCE);
+        assert!(sim.expectation_pauli("IZ").unwrap().abs() < TOLERANCE);
+        assert!(sim.expectation_pauli("Z").is_err());
+        assert!(sim.expectation_pauli("ZQ").is_err());
+    }
+
+    #[test]
+    fn test_execute_with_faults() {
+        let mut bell = CircuitGenome::new(2);
+        bell.add_gate(Gate::H(0)).unwrap();
+        bell.add_gate(Gate::CNOT(0, 1)).unwrap();
+
+        // X on qubit 1 after the CNOT: |00⟩+|11⟩ → |10⟩+|01⟩
+        let mut sim = StateVectorSimulator::new(2);
+        sim.execute_with_faults(&bell, &[(1, Gate::X(1))]).unwrap();
+        let probs = sim.probabilities();
+        assert!(probs[0].abs() < TOLERANCE && probs[3].abs() < TOLERANCE);
+        assert!((probs[1] - 0.5).abs() < TOLERANCE && (probs[2] - 0.5).abs() < TOLERANCE);
+
+        // Injected before the CNOT, the error propagates differently: X(0) after H is absorbed
+        let mut sim = StateVectorSimulator::new(2);
+        sim.execute_with_faults(&bell, &[(0, Gate::X(0))]).unwrap();
+        assert!((sim.probability(0) - 0.5).abs() < TOLERANCE);
+
+        let mut sim = StateVectorSimulator::new(2);
+        assert!(sim.execute_with_faults(&bell, &[(2, Gate::X(0))]).is_err());
+    }
+
+    #[test]
+    fn test_fsim_gate() {
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+
+        // |01⟩ is split between |01⟩ and |10⟩ with a -i phase
+        let mut sim = StateVectorSimulator::new(2);
+        sim.apply_gate(&Gate::X(0)).unwrap();
+        sim.apply_gate(&Gate::FSim(0, 1, PI / 4.0, 0.0)).unwrap();
+        assert!((sim.amplitude(1) - Complex64::new(h, 0.0)).norm() < TOLERANCE);
+        assert!((sim.amplitude(2) - Complex64::new(0.0, -h)).norm() < TOLERANCE);
+
+        // Bell state: |00⟩ untouched, |11⟩ picks up e^{-iφ}
+        let mut sim = StateVectorSimulator::new(2);
+        sim.apply_gate(&Gate::H(0)).unwrap();
+        sim.apply_gate(&Gate::CNOT(0, 1)).unwrap();
+        sim.apply_gate(&Gate::FSim(0, 1, 0.4, PI / 2.0)).unwrap();
+        assert!((sim.amplitude(0) - Complex64::new(h, 0.0)).norm() < TOLERANCE);
+        assert!((sim.amplitude(3) - Complex64::new(0.0, -h)).norm() < TOLERANCE);
+        assert!(sim.amplitude(1).norm() < TOLERANCE);
+        assert!(sim.amplitude(2).norm() < TOLERANCE);
+    }
+
     #[test]
     fn test_normalization_preserved() {
         let mut sim = StateVectorSimulator::new(3);
@@ -834,6 +1422,273 @@ mod tests {
         assert!(sim.is_normalized());
     }
 
+    #[test]
+    fn test_auto_renormalize_long_circuit() {
+        let mut circuit = CircuitGenome::new(3);
+        for i in 0..10_000 {
+            let theta = 0.1 + 1e-3 * i as f64;
+            let gate = match i % 3 {
+                0 => Gate::Rx(i % 3, theta),
+                1 => Gate::Ry(i % 3, theta),
+                _ => Gate::Rz(i % 3, theta),
+            };
+            circuit.add_gate(gate).unwrap();
+            if i % 7 == 0 {
+                circuit.add_gate(Gate::CNOT(0, 2)).unwrap();
+            }
+        }
+
+        let mut sim = StateVectorSimulator::new(3).with_auto_renormalize_every(Some(100));
+        assert_eq!(sim.auto_renormalize_every(), Some(100));
+        // Start slightly off unit norm to stand in for accumulated drift
+        let scale = (1.0 + 5e-7_f64).sqrt();
+        sim.set_state(
+            (0..8)
+                .map(|i| Complex64::new(if i == 0 { scale } else { 0.0 }, 0.0))
+                .collect(),
+        )
+        .unwrap();
+        assert!(!sim.is_normalized());
+
+        sim.execute(&circuit).unwrap();
+        assert!(sim.norm_drift() < 1e-10, "drift {}", sim.norm_drift());
+        assert!(sim.is_normalized());
+        assert!(sim.accumulated_norm_drift() > 4e-7);
+
+        // Disabled by default; renormalize() can still be called by hand
+        let mut sim = StateVectorSimulator::new(1);
+        assert_eq!(sim.auto_renormalize_every(), None);
+        sim.set_state(vec![Complex64::new(scale, 0.0), ZERO])
+            .unwrap();
+        sim.renormalize();
+        assert!(sim.norm_drift() < 1e-15);
+    }
+
+    #[test]
+    fn test_repetition_code_syndrome_locates_error() {
+        type Builder = fn(&[Gate]) -> Result<CircuitGenome>;
+        type ErrorGate = fn(usize) -> Gate;
+        let codes: [(Builder, ErrorGate); 2] = [
+            (CircuitGenome::bit_flip_code_syndrome, Gate::X),
+            (CircuitGenome::phase_flip_code_syndrome, Gate::Z),
+        ];
+        for (build, error) in codes {
+            let mut syndromes = Vec::new();
+            for location in [None, Some(0), Some(1), Some(2)] {
+                let errors: Vec<Gate> = location.into_iter().map(error).collect();
+                // A non-trivial logical state, prepared on data qubit 0
+                let mut circuit = CircuitGenome::new(5);
+                circuit.add_gate(Gate::Ry(0, 1.1)).unwrap();
+                circuit.compose(&build(&errors).unwrap());
+
+                let mut sim = StateVectorSimulator::new(5);
+                sim.execute(&circuit).unwrap();
+                let counts = sim.measure(200).unwrap();
+                // Qubit 0 is the rightmost bit: ancillas 4 and 3 lead
+                let observed: HashSet<&str> = counts.keys().map(|bits| &bits[..2]).collect();
+                assert_eq!(observed.len(), 1, "syndrome must be deterministic");
+                syndromes.push(observed.into_iter().next().unwrap().to_string());
+            }
+            // (ancilla 4, ancilla 3): none, data 0, data 1, data 2
+            assert_eq!(syndromes, ["00", "01", "11", "10"]);
+        }
+    }
+
+    #[test]
+    fn test_reset_reuses_qubits() {
+        // Reset returns |1⟩ to |0⟩ and reports the discarded bit
+        let mut sim = StateVectorSimulator::new(1);
+        sim.apply_gate(&Gate::X(0)).unwrap();
+        assert_eq!(sim.reset_qubit(0).unwrap(), 1);
+        assert!((sim.probabilities()[0] - 1.0).abs() < 1e-12);
+
+        // Logical qubits 0..4 each talk only to their neighbours, so logical
+        // qubit k+2 can reuse the physical qubit of logical qubit k
+        let mut logical = CircuitGenome::new(4);
+        logical
+            .add_gates([
+                Gate::Ry(0, 0.7),
+                Gate::Ry(1, 1.1),
+                Gate::CNOT(0, 1),
+                Gate::Ry(2, 0.4),
+                Gate::CNOT(1, 2),
+                Gate::Ry(3, 0.9),
+                Gate::CNOT(2, 3),
+                Gate::Rx(2, 0.5),
+            ])
+            .unwrap();
+        let mut reused = CircuitGenome::new(2);
+        reused
+            .add_gates([
+                Gate::Ry(0, 0.7),
+                Gate::Ry(1, 1.1),
+                Gate::CNOT(0, 1),
+                Gate::Measure(0),
+                Gate::Reset(0),
+                Gate::Ry(0, 0.4),
+                Gate::CNOT(1, 0),
+                Gate::Measure(1),
+                Gate::Reset(1),
+                Gate::Ry(1, 0.9),
+                Gate::CNOT(0, 1),
+                Gate::Rx(0, 0.5),
+            ])
+            .unwrap();
+
+        let mut sim = StateVectorSimulator::new(4);
+        sim.execute(&logical).unwrap();
+        let expected = sim.probabilities();
+
+        // Mid-circuit measurements go to logical qubits 0 and 1, the final
+        // ones to 2 and 3
+        let shots = 20_000;
+        let mut counts = [0usize; 16];
+        let mut sim = StateVectorSimulator::new(2);
+        for _ in 0..shots {
+            sim.reset();
+            let mut measured = Vec::new();
+            for gate in &reused.gates {
+                match gate {
+                    Gate::Measure(q) => measured.push(sim.measure_qubit(*q).unwrap()),
+                    _ => sim.apply_gate(gate).unwrap(),
+                }
+            }
+            measured.push(sim.measure_qubit(0).unwrap());
+            measured.push(sim.measure_qubit(1).unwrap());
+            let index = measured
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (k, &bit)| acc | (bit as usize) << k);
+            counts[index] += 1;
+        }
+
+        let marginal = |probs: &dyn Fn(usize) -> f64, mask: usize, value: usize| -> f64 {
+            (0..16).filter(|i| i & mask == value).map(probs).sum()
+        };
+        let sampled = |i: usize| counts[i] as f64 / shots as f64;
+        let exact = |i: usize| expected[i];
+        for k in 0..4 {
+            let (got, want) = (
+                marginal(&sampled, 1 << k, 1 << k),
+                marginal(&exact, 1 << k, 1 << k),
+            );
+            assert!(
+                (got - want).abs() < 0.02,
+                "P(q{} = 1): {} vs {}",
+                k,
+                got,
+                want
+            );
+        }
+        // Joint distribution of the two qubits alive at the end
+        for value in 0..4 {
+            let (got, want) = (
+                marginal(&sampled, 0b1100, value << 2),
+                marginal(&exact, 0b1100, value << 2),
+            );
+            assert!(
+                (got - want).abs() < 0.02,
+                "P(q3q2 = {:02b}): {} vs {}",
+                value,
+                got,
+                want
+            );
+        }
+    }
+
+    #[test]
+    fn test_teleportation_with_feed_forward() {
+        let input = [Gate::Ry(0, 0.7), Gate::Rz(0, 0.3)];
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gates(input.clone()).unwrap();
+        circuit
+            .add_gates([
+                Gate::H(1),
+                Gate::CNOT(1, 2),
+                Gate::CNOT(0, 1),
+                Gate::H(0),
+                Gate::Measure(0),
+                Gate::Measure(1),
+                Gate::X(2),
+                Gate::Z(2),
+            ])
+            .unwrap();
+        let conditions = [(8, 1, 1), (9, 0, 1)];
+
+        let mut seen = HashSet::new();
+        let mut sim = StateVectorSimulator::new(3);
+        for _ in 0..64 {
+            sim.reset();
+            sim.execute_with_feed_forward(&circuit, &conditions)
+                .unwrap();
+            let (m0, m1) = match sim.classical_bits() {
+                [Some(m0), Some(m1), None] => (*m0, *m1),
+                bits => panic!("unexpected register {:?}", bits),
+            };
+            seen.insert((m0, m1));
+
+            // Qubits 0 and 1 sit in the measured basis state, qubit 2 holds the input
+            let mut expected = StateVectorSimulator::new(3);
+            for (q, bit) in [(0, m0), (1, m1)] {
+                if bit == 1 {
+                    expected.apply_gate(&Gate::X(q)).unwrap();
+                }
+            }
+            for gate in &input {
+                expected.apply_gate(&gate.map_qubits(&[2])).unwrap();
+            }
+            let fidelity = sim.fidelity_with(&expected).unwrap();
+            assert!(
+                (fidelity - 1.0).abs() < TOLERANCE,
+                "{} for {:?}",
+                fidelity,
+                (m0, m1)
+            );
+        }
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[test]
+    fn test_terminal_measure_is_deferred() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::Measure(0),
+                Gate::H(1),
+                Gate::Measure(1),
+                Gate::H(1),
+            ])
+            .unwrap();
+
+        let mut sim = StateVectorSimulator::new(2);
+        sim.execute(&circuit).unwrap();
+        assert_eq!(sim.classical_bits()[0], None);
+        assert!(sim.classical_bits()[1].is_some());
+        assert!((sim.probability(0) + sim.probability(1) - 0.5).abs() < TOLERANCE);
+
+        // Conditions may only read bits measured earlier
+        let err = sim
+            .execute_with_feed_forward(&CircuitGenome::new(2), &[(0, 0, 1)])
+            .unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{}", err);
+        sim.reset();
+        assert!(sim.apply_conditioned(&Gate::X(0), 0, 1).is_err());
+        assert_eq!(sim.classical_bits(), &[None, None]);
+
+        // An injected fault after a measurement makes it mid-circuit
+        let mut measured = CircuitGenome::new(2);
+        measured.add_gates([Gate::H(0), Gate::Measure(0)]).unwrap();
+        sim.execute_with_faults(&measured, &[(1, Gate::X(0))])
+            .unwrap();
+        let bit = sim.classical_bits()[0].expect("measurement collapsed") as usize;
+        assert!((sim.probability(1 - bit) - 1.0).abs() < TOLERANCE);
+
+        // Loading a state clears the register
+        sim.set_state(sim.statevector().to_vec()).unwrap();
+        assert_eq!(sim.classical_bits(), &[None, None]);
+    }
+
     #[test]
     fn test_clone() {
         let mut sim = StateVectorSimulator::new(2);
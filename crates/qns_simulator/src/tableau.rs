@@ -0,0 +1,314 @@
+//! Stabilizer tableau (Aaronson-Gottesman CHP) and Clifford gate lowering.
+//!
+//! Shared by the Clifford-only simulators. Gates are first lowered to
+//! [`CliffordOp`]s (H, S, CNOT, CZ, SWAP and Paulis); rotations are accepted
+//! when their angle is a multiple of π/2.
+
+use qns_core::prelude::*;
+use rand::Rng;
+
+/// Primitive Clifford operation, exact up to a global phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CliffordOp {
+    H(usize),
+    S(usize),
+    X(usize),
+    Y(usize),
+    Z(usize),
+    CX(usize, usize),
+    CZ(usize, usize),
+    Swap(usize, usize),
+    Measure(usize),
+    Reset(usize),
+}
+
+/// Number of quarter turns `k` with `angle ≈ k·π/2`, reduced mod 4.
+fn quarter_turns(angle: f64) -> Option<usize> {
+    let k = (angle / std::f64::consts::FRAC_PI_2).round();
+    if (angle - k * std::f64::consts::FRAC_PI_2).abs() < 1e-9 {
+        Some(k.rem_euclid(4.0) as usize)
+    } else {
+        None
+    }
+}
+
+/// Lowers `gate` to primitive Clifford operations.
+///
+/// Fails with [`QnsError::UnsupportedGate`] naming `backend` for gates that
+/// are not Clifford.
+pub(crate) fn clifford_ops(gate: &Gate, backend: &str) -> Result<Vec<CliffordOp>> {
+    use CliffordOp::*;
+
+    let unsupported = || QnsError::unsupported_gate(gate.to_string(), backend, "non-Clifford gate");
+    let s_power = |q: usize, k: usize| vec![S(q); k];
+
+    let ops = match *gate {
+        Gate::H(q) => vec![H(q)],
+        Gate::X(q) => vec![X(q)],
+        Gate::Y(q) => vec![Y(q)],
+        Gate::Z(q) => vec![Z(q)],
+        Gate::S(q) => vec![S(q)],
+        // Rz(θ) = S^k, Rx(θ) = H·S^k·H, Ry(θ) = S·Rx(θ)·S†
+        Gate::Rz(q, theta) => s_power(q, quarter_turns(theta).ok_or_else(unsupported)?),
+        Gate::Rx(q, theta) => {
+            let k = quarter_turns(theta).ok_or_else(unsupported)?;
+            [vec![H(q)], s_power(q, k), vec![H(q)]].concat()
+        },
+        Gate::Ry(q, theta) => {
+            let k = quarter_turns(theta).ok_or_else(unsupported)?;
+            [s_power(q, 3), vec![H(q)], s_power(q, k), vec![H(q), S(q)]].concat()
+        },
+        Gate::CNOT(c, t) => vec![CX(c, t)],
+        Gate::CZ(a, b) => vec![CZ(a, b)],
+        Gate::CPhase(a, b, lambda) => match quarter_turns(lambda) {
+            Some(0) => Vec::new(),
+            Some(2) => vec![CZ(a, b)],
+            _ => return Err(unsupported()),
+        },
+        Gate::SWAP(a, b) => vec![Swap(a, b)],
+        Gate::Measure(q) => vec![Measure(q)],
+        Gate::Reset(q) => vec![Reset(q)],
+        Gate::T(_) | Gate::U(..) | Gate::FSim(..) | Gate::Toffoli(..) => return Err(unsupported()),
+    };
+    Ok(ops)
+}
+
+/// Stabilizer tableau over `n` qubits.
+///
+/// Rows `0..n` are destabilizers, rows `n..2n` stabilizers and row `2n` is
+/// scratch space for deterministic measurements.
+#[derive(Debug, Clone)]
+pub(crate) struct Tableau {
+    n: usize,
+    x: Vec<Vec<bool>>,
+    z: Vec<Vec<bool>>,
+    r: Vec<bool>,
+}
+
+impl Tableau {
+    /// Tableau of `|0…0⟩`.
+    pub(crate) fn new(n: usize) -> Self {
+        let rows = 2 * n + 1;
+        let mut x = vec![vec![false; n]; rows];
+        let mut z = vec![vec![false; n]; rows];
+        for q in 0..n {
+            x[q][q] = true;
+            z[n + q][q] = true;
+        }
+        Self {
+            n,
+            x,
+            z,
+            r: vec![false; rows],
+        }
+    }
+
+    /// Applies a unitary operation; `Measure` and `Reset` are ignored.
+    pub(crate) fn apply(&mut self, op: CliffordOp) {
+        match op {
+            CliffordOp::H(a) => self.h(a),
+            CliffordOp::S(a) => self.s(a),
+            CliffordOp::X(a) => self.flip_signs(|_, z| z[a]),
+            CliffordOp::Z(a) => self.flip_signs(|x, _| x[a]),
+            CliffordOp::Y(a) => self.flip_signs(|x, z| x[a] != z[a]),
+            CliffordOp::CX(a, b) => self.cx(a, b),
+            CliffordOp::CZ(a, b) => {
+                self.h(b);
+                self.cx(a, b);
+                self.h(b);
+            },
+            CliffordOp::Swap(a, b) => {
+                self.cx(a, b);
+                self.cx(b, a);
+                self.cx(a, b);
+            },
+            CliffordOp::Measure(_) | CliffordOp::Reset(_) => {},
+        }
+    }
+
+    fn h(&mut self, a: usize) {
+        for i in 0..2 * self.n {
+            self.r[i] ^= self.x[i][a] && self.z[i][a];
+            let (x, z) = (self.x[i][a], self.z[i][a]);
+            self.x[i][a] = z;
+            self.z[i][a] = x;
+        }
+    }
+
+    fn s(&mut self, a: usize) {
+        for i in 0..2 * self.n {
+            self.r[i] ^= self.x[i][a] && self.z[i][a];
+            self.z[i][a] ^= self.x[i][a];
+        }
+    }
+
+    fn cx(&mut self, a: usize, b: usize) {
+        for i in 0..2 * self.n {
+            let (xa, za, xb, zb) = (self.x[i][a], self.z[i][a], self.x[i][b], self.z[i][b]);
+            self.r[i] ^= xa && zb && !(xb ^ za);
+            self.x[i][b] ^= xa;
+            self.z[i][a] ^= zb;
+        }
+    }
+
+    /// Negates every row whose Pauli anticommutes with the applied Pauli.
+    fn flip_signs(&mut self, anticommutes: impl Fn(&[bool], &[bool]) -> bool) {
+        for i in 0..2 * self.n {
+            if anticommutes(&self.x[i], &self.z[i]) {
+                self.r[i] = !self.r[i];
+            }
+        }
+    }
+
+    /// Left-multiplies row `h` by row `i`, tracking the sign.
+    fn rowsum(&mut self, h: usize, i: usize) {
+        // Exponent of i contributed by multiplying single-qubit Paulis
+        let g = |x1: bool, z1: bool, x2: bool, z2: bool| -> i32 {
+            match (x1, z1) {
+                (false, false) => 0,
+                (true, true) => z2 as i32 - x2 as i32,
+                (true, false) => z2 as i32 * (2 * x2 as i32 - 1),
+                (false, true) => x2 as i32 * (1 - 2 * z2 as i32),
+            }
+        };
+        let mut sum = 2 * self.r[h] as i32 + 2 * self.r[i] as i32;
+        for j in 0..self.n {
+            sum += g(self.x[i][j], self.z[i][j], self.x[h][j], self.z[h][j]);
+        }
+        self.r[h] = sum.rem_euclid(4) == 2;
+        for j in 0..self.n {
+            let (xi, zi) = (self.x[i][j], self.z[i][j]);
+            self.x[h][j] ^= xi;
+            self.z[h][j] ^= zi;
+        }
+    }
+
+    /// Sampler for measuring every qubit of the current state at once.
+    ///
+    /// The outcomes of a stabilizer state are uniform over the solutions of
+    /// `z·x = r` for its Z-type stabilizers `(-1)^r Z^z`. Gaussian
+    /// elimination on the X part of the stabilizers exposes those, and a
+    /// second elimination solves them for a set of free bits.
+    pub(crate) fn basis_sampler(&self) -> BasisSampler {
+        let n = self.n;
+        let mut t = self.clone();
+
+        // Pivot every X column into its own stabilizer row; the rows left
+        // over have no X part
+        let mut next = n;
+        for col in 0..n {
+            let Some(p) = (next..2 * n).find(|&p| t.x[p][col]) else {
+                continue;
+            };
+            t.x.swap(p, next);
+            t.z.swap(p, next);
+            t.r.swap(p, next);
+            for i in n..2 * n {
+                if i != next && t.x[i][col] {
+                    t.rowsum(i, next);
+                }
+            }
+            next += 1;
+        }
+
+        // Reduced row echelon form of the Z constraints over GF(2)
+        let mut rows: Vec<(Vec<bool>, bool)> =
+            (next..2 * n).map(|i| (t.z[i].clone(), t.r[i])).collect();
+        let mut constraints = Vec::new();
+        for col in 0..n {
+            let Some(p) = (constraints.len()..rows.len()).find(|&p| rows[p].0[col]) else {
+                continue;
+            };
+            rows.swap(p, constraints.len());
+            let pivot = rows[constraints.len()].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i != constraints.len() && row.0[col] {
+                    for (bit, &b) in row.0.iter_mut().zip(&pivot.0) {
+                        *bit ^= b;
+                    }
+                    row.1 ^= pivot.1;
+                }
+            }
+            constraints.push(col);
+        }
+
+        BasisSampler {
+            free: (0..n).filter(|q| !constraints.contains(q)).collect(),
+            constraints: constraints
+                .into_iter()
+                .zip(rows)
+                .map(|(pivot, (coefficients, rhs))| {
+                    let others = (0..n).filter(|&q| q != pivot && coefficients[q]).collect();
+                    (pivot, others, rhs)
+                })
+                .collect(),
+            n,
+        }
+    }
+
+    /// Measures qubit `a` and flips it back to |0⟩ if the outcome was 1.
+    pub(crate) fn reset<R: Rng>(&mut self, a: usize, rng: &mut R) {
+        if self.measure(a, rng).0 {
+            self.apply(CliffordOp::X(a));
+        }
+    }
+
+    /// Measures qubit `a` in the Z basis and collapses the state.
+    ///
+    /// Returns the outcome and whether it was random.
+    pub(crate) fn measure<R: Rng>(&mut self, a: usize, rng: &mut R) -> (bool, bool) {
+        let n = self.n;
+        if let Some(p) = (n..2 * n).find(|&p| self.x[p][a]) {
+            for i in 0..2 * n {
+                if i != p && self.x[i][a] {
+                    self.rowsum(i, p);
+                }
+            }
+            self.x[p - n] = self.x[p].clone();
+            self.z[p - n] = self.z[p].clone();
+            self.r[p - n] = self.r[p];
+            self.x[p].fill(false);
+            self.z[p].fill(false);
+            self.z[p][a] = true;
+            self.r[p] = rng.gen();
+            (self.r[p], true)
+        } else {
+            let scratch = 2 * n;
+            self.x[scratch].fill(false);
+            self.z[scratch].fill(false);
+            self.r[scratch] = false;
+            for i in 0..n {
+                if self.x[i][a] {
+                    self.rowsum(scratch, i + n);
+                }
+            }
+            (self.r[scratch], false)
+        }
+    }
+}
+
+/// Uniform sampler over the computational-basis outcomes of a stabilizer
+/// state; see [`Tableau::basis_sampler`].
+#[derive(Debug, Clone)]
+pub(crate) struct BasisSampler {
+    n: usize,
+    /// Bits chosen uniformly at random
+    free: Vec<usize>,
+    /// `(pivot, others, rhs)`: `x[pivot] = rhs ⊕ ⨁ x[others]`, with every
+    /// `others` bit free
+    constraints: Vec<(usize, Vec<usize>, bool)>,
+}
+
+impl BasisSampler {
+    /// Draws one outcome; entry `q` is the bit of qubit `q`.
+    pub(crate) fn sample<R: Rng>(&self, rng: &mut R) -> Vec<bool> {
+        let mut bits = vec![false; self.n];
+        for &q in &self.free {
+            bits[q] = rng.gen();
+        }
+        for (pivot, others, rhs) in &self.constraints {
+            bits[*pivot] = others.iter().fold(*rhs, |acc, &q| acc ^ bits[q]);
+        }
+        bits
+    }
+}
@@ -0,0 +1,108 @@
+//! Circuit unitaries for synthesis verification.
+
+use crate::StateVectorSimulator;
+use qns_core::physics::{C64, ONE, ZERO};
+use qns_core::{CircuitGenome, QnsError, Result};
+
+/// Largest circuit whose full unitary may be built (4^n complex entries).
+pub const MAX_UNITARY_QUBITS: usize = 10;
+
+/// Builds the full unitary of a circuit, `u[row][col]`.
+///
+/// Column `k` is the output state for basis input `|k⟩` (qubit 0 is the least
+/// significant bit). Measurements are ignored and a `Reset` is an error.
+pub fn circuit_unitary(circuit: &CircuitGenome) -> Result<Vec<Vec<C64>>> {
+    let n = circuit.num_qubits;
+    if n > MAX_UNITARY_QUBITS {
+        return Err(QnsError::simulator(format!(
+            "unitary of {} qubits exceeds the {}-qubit limit",
+            n, MAX_UNITARY_QUBITS
+        )));
+    }
+
+    let dim = 1usize << n;
+    let mut unitary = vec![vec![ZERO; dim]; dim];
+    let mut sim = StateVectorSimulator::new(n);
+    for col in 0..dim {
+        let mut basis = vec![ZERO; dim];
+        basis[col] = ONE;
+        sim.set_state(basis)?;
+        sim.execute_unitary(circuit)?;
+        for (row, amp) in sim.statevector().iter().enumerate() {
+            unitary[row][col] = *amp;
+        }
+    }
+    Ok(unitary)
+}
+
+/// Process infidelity `1 - |Tr(U_target† U_circuit) / d|²` of a circuit.
+///
+/// `target[row][col]` must be a `d × d` matrix with `d = 2^num_qubits`. The
+/// measure ignores global phase, so a circuit matching the target up to a
+/// phase has infidelity 0.
+pub fn unitary_infidelity(circuit: &CircuitGenome, target: &[Vec<C64>]) -> Result<f64> {
+    let unitary = circuit_unitary(circuit)?;
+    let dim = unitary.len();
+    if target.len() != dim {
+        return Err(QnsError::DimensionMismatch(dim, target.len()));
+    }
+    if let Some(row) = target.iter().find(|row| row.len() != dim) {
+        return Err(QnsError::DimensionMismatch(dim, row.len()));
+    }
+
+    let trace: C64 = target
+        .iter()
+        .zip(&unitary)
+        .flat_map(|(t_row, u_row)| t_row.iter().zip(u_row))
+        .map(|(t, u)| t.conj() * u)
+        .sum();
+
+    Ok((1.0 - (trace / dim as f64).norm_sqr()).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qns_core::Gate;
+
+    #[test]
+    fn test_unitary_infidelity() {
+        let mut bell = CircuitGenome::new(2);
+        bell.add_gate(Gate::H(0)).unwrap();
+        bell.add_gate(Gate::CNOT(0, 1)).unwrap();
+
+        // Exact synthesis of its own unitary
+        let target = circuit_unitary(&bell).unwrap();
+        assert!(unitary_infidelity(&bell, &target).unwrap() < 1e-12);
+
+        // Off by Rz(θ): |Tr|/d = cos(θ/2), infidelity = sin²(θ/2)
+        let theta: f64 = 0.2;
+        let mut off = bell.clone();
+        off.add_gate(Gate::Rz(1, theta)).unwrap();
+        let infidelity = unitary_infidelity(&off, &target).unwrap();
+        assert!((infidelity - (theta / 2.0).sin().powi(2)).abs() < 1e-12);
+
+        // Dimension and size validation
+        assert!(unitary_infidelity(&CircuitGenome::new(1), &target).is_err());
+        let big = CircuitGenome::new(MAX_UNITARY_QUBITS + 1);
+        assert!(circuit_unitary(&big).is_err());
+    }
+
+    #[test]
+    fn test_mid_circuit_measure_is_ignored() {
+        // H·M·H would collapse under `execute`; the unitary is exactly I
+        let mut circuit = CircuitGenome::new(1);
+        circuit
+            .add_gates([Gate::H(0), Gate::Measure(0), Gate::H(0)])
+            .unwrap();
+        for _ in 0..16 {
+            let u = circuit_unitary(&circuit).unwrap();
+            assert!((u[0][0] - ONE).norm() < 1e-12 && (u[1][1] - ONE).norm() < 1e-12);
+            assert!(u[0][1].norm() < 1e-12 && u[1][0].norm() < 1e-12);
+        }
+
+        circuit.add_gate(Gate::Reset(0)).unwrap();
+        let err = circuit_unitary(&circuit).unwrap_err();
+        assert!(err.to_string().contains("not unitary"), "{}", err);
+    }
+}
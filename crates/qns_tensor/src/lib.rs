@@ -1,3 +1,4 @@
+use nalgebra::DMatrix;
 use ndarray::{Array2, Array3};
 use num_complex::Complex64;
 use qns_core::types::Gate;
@@ -13,6 +14,9 @@ pub enum TensorError {
     ContractionError(String),
 }
 
+/// Backend name reported in `QnsError::UnsupportedGate`.
+const BACKEND_NAME: &str = "TensorNetwork";
+
 /// Matrix Product State (MPS) representation of a quantum state.
 pub struct TensorNetwork {
     num_qubits: usize,
@@ -21,12 +25,21 @@ pub struct TensorNetwork {
     nodes: Vec<Array3<Complex64>>,
     /// Maximum bond dimension (chi)
     max_bond_dim: usize,
+    /// Minimum bond dimension kept after each SVD
+    min_bond_dim: usize,
+    /// Singular values at or below this are discarded (down to `min_bond_dim`)
+    truncation_cutoff: f64,
     /// Noise model to apply
     noise_model: Option<Box<dyn NoiseChannel>>,
+    /// Bond entropy (bits) above which `apply_gate` fails instead of truncating
+    abort_on_entropy: Option<f64>,
 }
 
 impl TensorNetwork {
     /// Creates a new TensorNetwork in the |00...0> state.
+    ///
+    /// A 0-qubit network represents the scalar state and measures to the
+    /// empty bitstring.
     pub fn new(num_qubits: usize, max_bond_dim: usize) -> Self {
         let mut nodes = Vec::with_capacity(num_qubits);
 
@@ -44,10 +57,146 @@ impl TensorNetwork {
             num_qubits,
             nodes,
             max_bond_dim,
+            min_bond_dim: 1,
+            truncation_cutoff: 1e-10,
             noise_model: None,
+            abort_on_entropy: None,
         }
     }
 
+    /// Sets the minimum bond dimension kept after each SVD.
+    ///
+    /// Bonds start at 1 and grow only while singular values exceed the
+    /// truncation cutoff, capped at `max_bond_dim`; this sets the floor.
+    pub fn with_min_bond_dim(mut self, min_bond_dim: usize) -> Self {
+        self.min_bond_dim = min_bond_dim.max(1);
+        self
+    }
+
+    /// Sets the singular-value cutoff used when truncating bonds.
+    ///
+    /// Larger cutoffs keep bonds smaller at the cost of accuracy.
+    pub fn with_truncation_cutoff(mut self, cutoff: f64) -> Self {
+        self.truncation_cutoff = cutoff;
+        self
+    }
+
+    /// Fails two-qubit gates that push a bond's entanglement entropy above
+    /// `threshold` bits.
+    ///
+    /// A bond with entropy S needs a bond dimension of at least 2^S, so past a
+    /// tractable threshold the truncated MPS would silently lose accuracy.
+    /// With this set, `apply_gate` returns an error instead. The entropy is
+    /// taken from the singular values before truncation, on every bond the
+    /// gate touches (including the SWAP chains of long-range gates), so
+    /// thresholds above `log2(max_bond_dim)` still trip.
+    pub fn with_abort_on_entropy(mut self, threshold: f64) -> Self {
+        self.abort_on_entropy = Some(threshold);
+        self
+    }
+
+    /// Returns the von Neumann entanglement entropy (in bits) across `bond`,
+    /// the bond between qubits `bond` and `bond + 1`.
+    ///
+    /// Computed exactly from the Gram matrices of the left and right halves,
+    /// so the MPS need not be in canonical form.
+    ///
+    /// # Panics
+    /// Panics if `bond + 1 >= num_qubits`.
+    pub fn bond_entropy(&self, bond: usize) -> f64 {
+        assert!(
+            bond + 1 < self.num_qubits,
+            "bond {} out of range for {} qubits",
+            bond,
+            self.num_qubits
+        );
+        let left = self.left_environment(bond + 1);
+        let right = self.right_environment(bond + 1);
+        schmidt_entropy(left, &right)
+    }
+
+    /// Samples a Z-basis measurement of `qubit` and collapses the MPS onto
+    /// the observed outcome.
+    ///
+    /// The outcome is drawn from the qubit's exact marginal, the other
+    /// physical slice of its tensor is projected out, and the state is
+    /// renormalized, so later gates and measurements see the post-measurement
+    /// state. This enables mid-circuit measurement with classical feed-forward.
+    pub fn measure_qubit_collapse(&mut self, qubit: usize) -> Result<u8> {
+        if qubit >= self.num_qubits {
+            return Err(QnsError::InvalidQubit(qubit, self.num_qubits));
+        }
+
+        let left = self.left_environment(qubit);
+        let right = self.right_environment(qubit + 1);
+        let weights: Vec<f64> = (0..2)
+            .map(|p| {
+                let a = physical_slice(&self.nodes[qubit], p);
+                (a.adjoint() * &left * a * &right).trace().re.max(0.0)
+            })
+            .collect();
+        let total = weights[0] + weights[1];
+        if total <= 0.0 {
+            return Err(QnsError::simulator("cannot measure a zero-norm MPS"));
+        }
+
+        use rand::Rng;
+        let outcome = if rand::thread_rng().gen::<f64>() * total < weights[0] {
+            0
+        } else {
+            1
+        };
+        let scale = Complex64::new(1.0 / weights[outcome].sqrt(), 0.0);
+        let node = &mut self.nodes[qubit];
+        let (l, _, r) = node.dim();
+        for i in 0..l {
+            for j in 0..r {
+                node[[i, outcome, j]] *= scale;
+                node[[i, 1 - outcome, j]] = Complex64::new(0.0, 0.0);
+            }
+        }
+        Ok(outcome as u8)
+    }
+
+    /// Overlaps `<L_a|L_b>` of the states spanned by `nodes[..end]`, indexed
+    /// by the bond to their right.
+    fn left_environment(&self, end: usize) -> DMatrix<Complex64> {
+        let mut left = DMatrix::<Complex64>::identity(1, 1);
+        for node in &self.nodes[..end] {
+            left = (0..2)
+                .map(|p| {
+                    let a = physical_slice(node, p);
+                    a.adjoint() * &left * a
+                })
+                .fold(DMatrix::zeros(node.dim().2, node.dim().2), |acc, m| acc + m);
+        }
+        left
+    }
+
+    /// Transposed overlaps of the states spanned by `nodes[start..]`, indexed
+    /// by the bond to their left.
+    fn right_environment(&self, start: usize) -> DMatrix<Complex64> {
+        let mut right = DMatrix::<Complex64>::identity(1, 1);
+        for node in self.nodes[start..].iter().rev() {
+            right = (0..2)
+                .map(|p| {
+                    let a = physical_slice(node, p);
+                    &a * &right * a.adjoint()
+                })
+                .fold(DMatrix::zeros(node.dim().0, node.dim().0), |acc, m| acc + m);
+        }
+        right
+    }
+
+    /// Returns the bond dimension between each pair of neighboring qubits.
+    pub fn bond_dims(&self) -> Vec<usize> {
+        self.nodes
+            .iter()
+            .take(self.num_qubits.saturating_sub(1))
+            .map(|node| node.dim().2)
+            .collect()
+    }
+
     /// Sets the noise model for the simulation.
     pub fn with_noise(mut self, noise_model: Box<dyn NoiseChannel>) -> Self {
         self.noise_model = Some(noise_model);
@@ -60,7 +209,8 @@ impl TensorNetwork {
     }
 
     /// Applies a gate to the tensor network.
-    /// Currently only supports single qubit gates and nearest-neighbor two-qubit gates.
+    /// Supports single-qubit gates and two-qubit gates on any pair of qubits;
+    /// non-adjacent pairs are bridged with SWAP chains.
     pub fn apply_gate(&mut self, gate: &Gate) -> Result<()> {
         // 1. Apply the ideal gate
         self.apply_ideal_gate(gate)?;
@@ -90,6 +240,7 @@ impl TensorNetwork {
                 }
             }
         }
+
         Ok(())
     }
 
@@ -104,20 +255,18 @@ impl TensorNetwork {
             | Gate::T(q)
             | Gate::Rx(q, _)
             | Gate::Ry(q, _)
-            | Gate::Rz(q, _) => self.apply_single_qubit_gate(*q, gate),
-            Gate::CNOT(c, t) | Gate::CZ(c, t) | Gate::SWAP(c, t) => {
-                if (c.max(t) - c.min(t)) == 1 {
-                    self.apply_two_qubit_gate(*c, *t, gate)
-                } else {
-                    // For MVP, we require SWAP routing first.
-                    // Or we could implement SWAP chain here implicitly, but better to use Router.
-                    Err(QnsError::Unsupported("Non-nearest neighbor gates not supported in TensorNetwork yet. Use Router first.".to_string()))
-                }
-            },
-            _ => Err(QnsError::Unsupported(format!(
-                "Gate {} not supported",
-                gate
-            ))),
+            | Gate::Rz(q, _)
+            | Gate::U(q, _, _, _) => self.apply_single_qubit_gate(*q, gate),
+            Gate::CNOT(c, t)
+            | Gate::CZ(c, t)
+            | Gate::CPhase(c, t, _)
+            | Gate::SWAP(c, t)
+            | Gate::FSim(c, t, _, _) => self.apply_two_qubit_gate(*c, *t, gate),
+            _ => Err(QnsError::unsupported_gate(
+                gate,
+                BACKEND_NAME,
+                "gate has no MPS implementation",
+            )),
         }
     }
 
@@ -152,8 +301,36 @@ impl TensorNetwork {
         Ok(())
     }
 
+    /// Applies a two-qubit gate to any pair of sites.
+    ///
+    /// Non-adjacent pairs are handled with a chain of nearest-neighbour SWAPs
+    /// that moves `q1` next to `q2`, then the same chain in reverse. Every
+    /// intermediate SWAP is truncated like any other two-qubit gate.
     fn apply_two_qubit_gate(&mut self, q1: usize, q2: usize, gate: &Gate) -> Result<()> {
-        // Assume q1 and q2 are adjacent. q1 < q2 or q2 < q1.
+        if q1.abs_diff(q2) <= 1 {
+            return self.apply_adjacent_gate(q1, q2, gate);
+        }
+
+        // Sites q1 -> q1 ± 1 -> ... until q1's state sits next to q2
+        let chain: Vec<(usize, usize)> = if q1 < q2 {
+            (q1..q2 - 1).map(|s| (s, s + 1)).collect()
+        } else {
+            (q2 + 2..=q1).rev().map(|s| (s, s - 1)).collect()
+        };
+        let moved = chain.last().map_or(q1, |&(_, to)| to);
+
+        for &(a, b) in &chain {
+            self.apply_adjacent_gate(a, b, &Gate::SWAP(a, b))?;
+        }
+        self.apply_adjacent_gate(moved, q2, gate)?;
+        for &(a, b) in chain.iter().rev() {
+            self.apply_adjacent_gate(a, b, &Gate::SWAP(a, b))?;
+        }
+        Ok(())
+    }
+
+    /// Applies a gate to adjacent sites `q1`, `q2`, in the gate's qubit order.
+    fn apply_adjacent_gate(&mut self, q1: usize, q2: usize, gate: &Gate) -> Result<()> {
         let (left_q, right_q) = if q1 < q2 { (q1, q2) } else { (q2, q1) };
 
         let node_l = &self.nodes[left_q];
@@ -192,9 +369,19 @@ impl TensorNetwork {
         // Gate is 4x4 matrix acting on (p1, p2)
         // NewTheta[l, p1', p2', r] = sum_{p1,p2} U[p1'p2', p1p2] * Theta[l, p1, p2, r]
 
-        let gate_matrix = gate
+        let mut gate_matrix = gate
             .matrix_4x4()
             .ok_or_else(|| QnsError::Simulator("Failed to get 4x4 gate matrix".to_string()))?;
+        if q1 > q2 {
+            // The matrix's first qubit is the right site: swap the qubit order
+            let flip = |i: usize| ((i & 1) << 1) | (i >> 1);
+            let original = gate_matrix;
+            for (p_out, row) in gate_matrix.iter_mut().enumerate() {
+                for (p_in, entry) in row.iter_mut().enumerate() {
+                    *entry = original[flip(p_out)][flip(p_in)];
+                }
+            }
+        }
         let mut new_theta = Array2::<Complex64>::zeros((dl_l * 2 * 2, dr_r));
 
         for l_idx in 0..dl_l {
@@ -252,18 +439,47 @@ impl TensorNetwork {
         let s = svd.singular_values; // (min(rows, cols))
         let v_t = svd.v_t.unwrap(); // (min(rows, cols), cols)
 
-        // 4. Truncate
-        // Keep top chi singular values
-        let mut chi = self.max_bond_dim;
-        let available_chi = s.len();
-        if chi > available_chi {
-            chi = available_chi;
+        // Fail loudly once entanglement outgrows what the MPS can represent,
+        // judged before truncation hides it
+        if let Some(threshold) = self.abort_on_entropy {
+            let full = s.len();
+            let left = self.left_environment(left_q);
+            let right = self.right_environment(right_q + 1);
+            let gram_l = (0..2)
+                .map(|p| {
+                    let a = DMatrix::from_fn(dl_l, full, |l, k| u[(l * 2 + p, k)] * s[k]);
+                    a.adjoint() * &left * a
+                })
+                .fold(DMatrix::zeros(full, full), |acc, m| acc + m);
+            let gram_r = (0..2)
+                .map(|p| {
+                    let b = DMatrix::from_fn(full, dr_r, |k, r| v_t[(k, p * dr_r + r)]);
+                    &b * &right * b.adjoint()
+                })
+                .fold(DMatrix::zeros(full, full), |acc, m| acc + m);
+            let entropy = schmidt_entropy(gram_l, &gram_r);
+            if entropy > threshold {
+                return Err(QnsError::simulator(format!(
+                    "MPS bond {} reached entanglement entropy {:.3} bits after {} \
+                     (abort threshold {:.3}); an accurate result needs bond dimension >= {:.0}, \
+                     so use the state-vector or density-matrix simulator instead",
+                    left_q,
+                    entropy,
+                    gate,
+                    threshold,
+                    entropy.exp2().ceil()
+                )));
+            }
         }
 
-        // Filter small singular values? (Optional optimization)
-        while chi > 1 && s[chi - 1] < 1e-10 {
-            chi -= 1;
-        }
+        // 4. Truncate
+        // Keep singular values above the cutoff, within [min_bond_dim, max_bond_dim]
+        // (nalgebra returns them in descending order)
+        let available_chi = s.len();
+        let significant = s.iter().filter(|&&sv| sv > self.truncation_cutoff).count();
+        let upper = self.max_bond_dim.max(1).min(available_chi);
+        let lower = self.min_bond_dim.min(upper);
+        let chi = significant.clamp(lower, upper);
 
         // 5. Update Nodes
         // New Left Node: U_trunc * S_trunc (or sqrt(S)) -> Reshape to (dl_l, 2, chi)
@@ -308,47 +524,116 @@ impl TensorNetwork {
     }
 
     /// Measure all qubits in the computational basis.
-    /// Returns a map of bitstrings to counts.
-    /// Note: This is a simplified implementation that contracts the whole network to a state vector first.
-    /// For true MPS efficiency, we should sample without full contraction.
+    /// Returns a map of bitstrings to counts; see [`sample_mps`](Self::sample_mps).
     pub fn measure(&self, shots: usize) -> Result<std::collections::HashMap<String, usize>> {
-        // Warning: This full contraction is exponential in N.
-        // Only feasible for small N (e.g. < 20).
-        let state_vector = self.contract_to_state_vector()?;
+        self.sample_mps(shots)
+    }
+
+    /// Samples `shots` computational-basis measurements directly from the MPS.
+    ///
+    /// Right environments are built once by a right-to-left sweep; each shot
+    /// then samples the qubits left to right, drawing every qubit from its
+    /// distribution conditioned on the outcomes already drawn. A shot costs
+    /// `O(n·χ²)`, so this scales to large registers with small bonds where a
+    /// `2^n` state vector would not fit.
+    ///
+    /// Character `i` of each bitstring (from the left) is qubit `i`.
+    pub fn sample_mps(&self, shots: usize) -> Result<std::collections::HashMap<String, usize>> {
+        // rights[k] is the environment of nodes[k..] on site k's left bond
+        let mut rights = vec![DMatrix::<Complex64>::identity(1, 1); self.num_qubits + 1];
+        for k in (0..self.num_qubits).rev() {
+            rights[k] = (0..2)
+                .map(|p| {
+                    let a = physical_slice(&self.nodes[k], p);
+                    &a * &rights[k + 1] * a.adjoint()
+                })
+                .fold(
+                    DMatrix::zeros(self.nodes[k].dim().0, self.nodes[k].dim().0),
+                    |acc, m| acc + m,
+                );
+        }
+        let slices: Vec<[DMatrix<Complex64>; 2]> = self
+            .nodes
+            .iter()
+            .map(|node| [physical_slice(node, 0), physical_slice(node, 1)])
+            .collect();
 
         let mut counts = std::collections::HashMap::new();
         let mut rng = rand::thread_rng();
         use rand::Rng;
 
         for _ in 0..shots {
-            let r: f64 = rng.gen();
-            let mut cum_prob = 0.0;
-            let mut selected = 0;
-
-            for (i, amp) in state_vector.iter().enumerate() {
-                cum_prob += amp.norm_sqr();
-                if r < cum_prob {
-                    selected = i;
-                    break;
+            let mut bitstring = String::with_capacity(self.num_qubits);
+            // Row vector: the drawn prefix contracted into the left bond
+            let mut prefix = DMatrix::<Complex64>::identity(1, 1);
+            for (k, slice) in slices.iter().enumerate() {
+                let branches: Vec<DMatrix<Complex64>> = slice.iter().map(|a| &prefix * a).collect();
+                let weights: Vec<f64> = branches
+                    .iter()
+                    .map(|v| (v * &rights[k + 1] * v.adjoint())[(0, 0)].re.max(0.0))
+                    .collect();
+                let total = weights[0] + weights[1];
+                if total <= 0.0 {
+                    return Err(QnsError::simulator("cannot sample a zero-norm MPS"));
                 }
+                let outcome = usize::from(rng.gen::<f64>() * total >= weights[0]);
+                prefix = &branches[outcome] / Complex64::new(weights[outcome].sqrt(), 0.0);
+                bitstring.push(if outcome == 0 { '0' } else { '1' });
             }
-
-            let bitstring = format!("{:0width$b}", selected, width = self.num_qubits);
             *counts.entry(bitstring).or_insert(0) += 1;
         }
 
         Ok(counts)
     }
 
+    /// Exact probability of one computational-basis outcome.
+    ///
+    /// Contracts each node with the projector onto its bit, left to right, in
+    /// `O(n·χ²)` without touching the other `2^n - 1` amplitudes. Character `i`
+    /// of `bitstring` (from the left) is qubit `i`, as in
+    /// [`sample_mps`](Self::sample_mps). The result is `|⟨b|ψ⟩|²` for the stored
+    /// MPS; truncated weight is not renormalized.
+    ///
+    /// # Errors
+    /// `QnsError::DimensionMismatch` if the length differs from the number of
+    /// qubits, and a config error for characters other than `0` and `1`.
+    pub fn probability(&self, bitstring: &str) -> Result<f64> {
+        if bitstring.len() != self.num_qubits {
+            return Err(QnsError::DimensionMismatch(
+                self.num_qubits,
+                bitstring.len(),
+            ));
+        }
+
+        // Row vector: the projected prefix contracted into the left bond
+        let mut prefix = DMatrix::<Complex64>::identity(1, 1);
+        for (node, bit) in self.nodes.iter().zip(bitstring.chars()) {
+            let p = match bit {
+                '0' => 0,
+                '1' => 1,
+                other => {
+                    return Err(QnsError::config(format!(
+                        "invalid bit {:?} in bitstring {:?}",
+                        other, bitstring
+                    )))
+                },
+            };
+            prefix = &prefix * physical_slice(node, p);
+        }
+        Ok(prefix[(0, 0)].norm_sqr())
+    }
+
     /// Contract the MPS to a full state vector.
+    #[cfg(test)]
     fn contract_to_state_vector(&self) -> Result<Vec<Complex64>> {
         // Very naive contraction: contract left to right.
         // State starts as (1, 2, 1)
         // Contract with next (1, 2, 1) -> (1, 4, 1)
         // ...
 
+        // Zero qubits: the state is the scalar 1
         if self.nodes.is_empty() {
-            return Ok(vec![]);
+            return Ok(vec![Complex64::new(1.0, 0.0)]);
         }
 
         let current = self.nodes[0].clone();
@@ -403,6 +688,38 @@ impl TensorNetwork {
     }
 }
 
+/// Entanglement entropy (bits) across a bond, given the Gram matrices of the
+/// states on its left and right (as from `left_environment` and
+/// `right_environment`).
+fn schmidt_entropy(left: DMatrix<Complex64>, right: &DMatrix<Complex64>) -> f64 {
+    // Schmidt weights are the eigenvalues of sqrt(L)·R·sqrt(L)
+    let eigen = left.symmetric_eigen();
+    let sqrt_diag = DMatrix::from_diagonal(
+        &eigen
+            .eigenvalues
+            .map(|v| Complex64::new(v.max(0.0).sqrt(), 0.0)),
+    );
+    let sqrt_left = &eigen.eigenvectors * sqrt_diag * eigen.eigenvectors.adjoint();
+    let weights = (&sqrt_left * right * &sqrt_left).symmetric_eigenvalues();
+
+    let total: f64 = weights.iter().map(|w| w.max(0.0)).sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    weights
+        .iter()
+        .map(|w| w.max(0.0) / total)
+        .filter(|&p| p > 1e-14)
+        .map(|p| -p * p.log2())
+        .sum()
+}
+
+/// Matrix `A_p[l][r]` of an MPS node for physical index `p`.
+fn physical_slice(node: &Array3<Complex64>, p: usize) -> DMatrix<Complex64> {
+    let (l, _, r) = node.dim();
+    DMatrix::from_fn(l, r, |i, j| node[[i, p, j]])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,4 +765,300 @@ mod tests {
         assert!(sv[2].norm() < 1e-10);
         assert!((sv[3].norm() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-5);
     }
+
+    #[test]
+    fn test_adaptive_bond_dimension() {
+        // Control qubit stays |0>: no entanglement, bond stays at 1
+        let mut low = TensorNetwork::new(4, 8);
+        for gate in [Gate::H(1), Gate::CNOT(0, 1), Gate::H(3), Gate::CNOT(2, 3)] {
+            low.apply_gate(&gate).unwrap();
+        }
+        assert_eq!(low.bond_dims(), vec![1, 1, 1]);
+
+        // Layers of rotations and CZs entangle across the middle cut
+        let entangling = |tn: &mut TensorNetwork| {
+            for layer in 0..3 {
+                for q in 0..4 {
+                    tn.apply_gate(&Gate::Ry(q, 0.7 + 0.3 * (q + layer) as f64))
+                        .unwrap();
+                }
+                for q in [0, 2, 1] {
+                    tn.apply_gate(&Gate::CZ(q, q + 1)).unwrap();
+                }
+            }
+        };
+
+        let mut grown = TensorNetwork::new(4, 8);
+        entangling(&mut grown);
+        assert_eq!(grown.bond_dims()[1], 4);
+
+        // The cap bounds growth
+        let mut capped = TensorNetwork::new(4, 3);
+        entangling(&mut capped);
+        assert_eq!(capped.bond_dims()[1], 3);
+        assert_eq!(capped.nodes[1].dim().2, 3);
+
+        // The floor keeps bonds even when singular values vanish
+        let mut floored = TensorNetwork::new(2, 4).with_min_bond_dim(2);
+        floored.apply_gate(&Gate::CNOT(0, 1)).unwrap();
+        assert_eq!(floored.bond_dims(), vec![2]);
+    }
+
+    #[test]
+    fn test_long_range_gates_use_swap_chains() {
+        // CNOT(0, 3) with the control set flips the far target
+        let mut tn = TensorNetwork::new(4, 4);
+        tn.apply_gate(&Gate::X(0)).unwrap();
+        tn.apply_gate(&Gate::CNOT(0, 3)).unwrap();
+        let sv = tn.contract_to_state_vector().unwrap();
+        // Site 0 is the most significant bit of the contracted index
+        assert!((sv[0b1001].norm() - 1.0).abs() < 1e-10);
+        assert_eq!(tn.bond_dims(), vec![1, 1, 1]);
+
+        // Reversed orientation: control on the right, target on the left
+        let mut tn = TensorNetwork::new(4, 4);
+        tn.apply_gate(&Gate::X(3)).unwrap();
+        tn.apply_gate(&Gate::CNOT(3, 0)).unwrap();
+        tn.apply_gate(&Gate::CNOT(2, 1)).unwrap();
+        let sv = tn.contract_to_state_vector().unwrap();
+        assert!((sv[0b1001].norm() - 1.0).abs() < 1e-10);
+
+        // A long-range Bell pair leaves the qubits in between untouched
+        let mut tn = TensorNetwork::new(4, 4);
+        tn.apply_gate(&Gate::H(0)).unwrap();
+        tn.apply_gate(&Gate::CNOT(0, 3)).unwrap();
+        let sv = tn.contract_to_state_vector().unwrap();
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        assert!((sv[0b0000].norm() - h).abs() < 1e-10);
+        assert!((sv[0b1001].norm() - h).abs() < 1e-10);
+        assert!((tn.bond_entropy(1) - 1.0).abs() < 1e-9);
+
+        // Gates without an MPS implementation are still rejected
+        let err = tn.apply_gate(&Gate::Toffoli(0, 1, 2)).unwrap_err();
+        match err {
+            QnsError::UnsupportedGate { gate, backend, .. } => {
+                assert_eq!(gate, "Toffoli(0, 1, 2)");
+                assert_eq!(backend, "TensorNetwork");
+            },
+            other => panic!("Expected UnsupportedGate, got {other}"),
+        }
+    }
+
+    #[test]
+    fn test_single_qubit_no_gates_measures_zero() {
+        let tn = TensorNetwork::new(1, 2);
+        let counts = tn.measure(100).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.get("0"), Some(&100));
+    }
+
+    #[test]
+    fn test_zero_qubit_network() {
+        let tn = TensorNetwork::new(0, 2);
+        let sv = tn.contract_to_state_vector().unwrap();
+        assert_eq!(sv, vec![Complex64::new(1.0, 0.0)]);
+
+        let counts = tn.measure(10).unwrap();
+        assert_eq!(counts.get(""), Some(&10));
+    }
+
+    #[test]
+    fn test_sample_mps_matches_contraction() {
+        let mut tn = TensorNetwork::new(5, 4);
+        for q in 0..5 {
+            tn.apply_gate(&Gate::Ry(q, 0.4 + 0.3 * q as f64)).unwrap();
+        }
+        for q in 0..4 {
+            tn.apply_gate(&Gate::CNOT(q, q + 1)).unwrap();
+        }
+        tn.apply_gate(&Gate::Rx(2, 0.7)).unwrap();
+
+        let probs: Vec<f64> = tn
+            .contract_to_state_vector()
+            .unwrap()
+            .iter()
+            .map(|a| a.norm_sqr())
+            .collect();
+        let shots = 20_000;
+        let counts = tn.sample_mps(shots).unwrap();
+        assert_eq!(counts.values().sum::<usize>(), shots);
+        for (index, want) in probs.iter().enumerate() {
+            let bits = format!("{:05b}", index);
+            let got = counts.get(&bits).copied().unwrap_or(0) as f64 / shots as f64;
+            assert!(
+                (got - want).abs() < 0.015,
+                "P({}) = {} but contraction gives {}",
+                bits,
+                got,
+                want
+            );
+        }
+    }
+
+    #[test]
+    fn test_probability_bell_state() {
+        let mut tn = TensorNetwork::new(2, 4);
+        tn.apply_gate(&Gate::H(0)).unwrap();
+        tn.apply_gate(&Gate::CNOT(0, 1)).unwrap();
+
+        assert!((tn.probability("00").unwrap() - 0.5).abs() < 1e-10);
+        assert!((tn.probability("11").unwrap() - 0.5).abs() < 1e-10);
+        assert!(tn.probability("01").unwrap() < 1e-10);
+        assert!(tn.probability("10").unwrap() < 1e-10);
+
+        assert!(matches!(
+            tn.probability("000"),
+            Err(QnsError::DimensionMismatch(2, 3))
+        ));
+        assert!(tn.probability("0x").is_err());
+    }
+
+    #[test]
+    fn test_probability_matches_contraction() {
+        let mut tn = TensorNetwork::new(5, 4);
+        for q in 0..5 {
+            tn.apply_gate(&Gate::Ry(q, 0.4 + 0.3 * q as f64)).unwrap();
+        }
+        for q in 0..4 {
+            tn.apply_gate(&Gate::CNOT(q, q + 1)).unwrap();
+        }
+        tn.apply_gate(&Gate::Rx(2, 0.7)).unwrap();
+
+        let sv = tn.contract_to_state_vector().unwrap();
+        for (index, amplitude) in sv.iter().enumerate() {
+            let bits = format!("{:05b}", index);
+            let got = tn.probability(&bits).unwrap();
+            assert!((got - amplitude.norm_sqr()).abs() < 1e-12, "P({})", bits);
+        }
+    }
+
+    #[test]
+    fn test_sample_mps_large_product_state() {
+        // 2^25 amplitudes would not be contracted; bonds stay at 1
+        let n = 25;
+        let mut tn = TensorNetwork::new(n, 2);
+        for q in (0..n).step_by(2) {
+            tn.apply_gate(&Gate::X(q)).unwrap();
+        }
+        tn.apply_gate(&Gate::H(1)).unwrap();
+
+        let counts = tn.sample_mps(1000).unwrap();
+        assert_eq!(counts.values().sum::<usize>(), 1000);
+        assert_eq!(counts.len(), 2, "{:?}", counts);
+        for bits in counts.keys() {
+            for (q, bit) in bits.chars().enumerate() {
+                let want = if q == 1 {
+                    bit
+                } else if q % 2 == 0 {
+                    '1'
+                } else {
+                    '0'
+                };
+                assert_eq!(bit, want, "qubit {} in {}", q, bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_abort_on_entropy() {
+        let bell_pairs = || {
+            let mut tn = TensorNetwork::new(4, 16).with_abort_on_entropy(1.5);
+            for q in [0, 2] {
+                tn.apply_gate(&Gate::H(q)).unwrap();
+                tn.apply_gate(&Gate::CNOT(q, q + 1)).unwrap();
+            }
+            tn
+        };
+
+        // Neighbouring Bell pairs: one bit across bonds 0 and 2, none across 1
+        let pairs = bell_pairs();
+        assert!((pairs.bond_entropy(0) - 1.0).abs() < 1e-9);
+        assert!(pairs.bond_entropy(1).abs() < 1e-9);
+        assert!((pairs.bond_entropy(2) - 1.0).abs() < 1e-9);
+        assert_eq!(pairs.measure(20).unwrap().values().sum::<usize>(), 20);
+
+        // Interleaving the pairs puts two bits across the middle bond
+        let mut interleaved = bell_pairs();
+        let err = interleaved.apply_gate(&Gate::SWAP(1, 2)).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("bond 1"), "{}", message);
+        assert!(message.contains("state-vector"), "{}", message);
+
+        // Without a threshold the same circuit runs
+        let mut unchecked = TensorNetwork::new(4, 16);
+        for q in [0, 2] {
+            unchecked.apply_gate(&Gate::H(q)).unwrap();
+            unchecked.apply_gate(&Gate::CNOT(q, q + 1)).unwrap();
+        }
+        unchecked.apply_gate(&Gate::SWAP(1, 2)).unwrap();
+        assert!((unchecked.bond_entropy(1) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_abort_on_entropy_sees_truncated_bonds() {
+        // With chi = 2 a bond holds at most one bit, below the 1.5-bit
+        // threshold, so only the pre-truncation spectrum can trip it
+        let bell_pairs = || {
+            let mut tn = TensorNetwork::new(4, 2).with_abort_on_entropy(1.5);
+            for q in [0, 2] {
+                tn.apply_gate(&Gate::H(q)).unwrap();
+                tn.apply_gate(&Gate::CNOT(q, q + 1)).unwrap();
+            }
+            tn
+        };
+
+        let mut adjacent = bell_pairs();
+        let message = adjacent
+            .apply_gate(&Gate::SWAP(1, 2))
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("bond 1"), "{}", message);
+        assert!(message.contains("2.000 bits"), "{}", message);
+
+        // CZ(0, 3)'s SWAP chain splits both Bell pairs across bond 1
+        let mut long_range = bell_pairs();
+        let message = long_range
+            .apply_gate(&Gate::CZ(0, 3))
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("bond 1"), "{}", message);
+        assert!(message.contains("after SWAP(1, 2)"), "{}", message);
+
+        // The truncated state itself never exceeds log2(chi)
+        let mut truncated = TensorNetwork::new(4, 2);
+        for q in [0, 2] {
+            truncated.apply_gate(&Gate::H(q)).unwrap();
+            truncated.apply_gate(&Gate::CNOT(q, q + 1)).unwrap();
+        }
+        truncated.apply_gate(&Gate::SWAP(1, 2)).unwrap();
+        assert!(truncated.bond_entropy(1) <= 1.0 + 1e-9);
+    }
+
+    #[test]
+    fn test_measure_qubit_collapse() {
+        let mut seen = [0usize; 2];
+        for _ in 0..64 {
+            // Bell pair on qubits 0 and 1, with an idle qubit to the right
+            let mut tn = TensorNetwork::new(3, 4);
+            tn.apply_gate(&Gate::H(0)).unwrap();
+            tn.apply_gate(&Gate::CNOT(0, 1)).unwrap();
+
+            let first = tn.measure_qubit_collapse(1).unwrap();
+            assert!(tn.bond_entropy(0) < 1e-9);
+
+            // The partner is now deterministic and the state stays normalized
+            let sv = tn.contract_to_state_vector().unwrap();
+            let norm: f64 = sv.iter().map(|a| a.norm_sqr()).sum();
+            assert!((norm - 1.0).abs() < 1e-9);
+            for _ in 0..3 {
+                assert_eq!(tn.measure_qubit_collapse(0).unwrap(), first);
+            }
+            assert_eq!(tn.measure_qubit_collapse(2).unwrap(), 0);
+            seen[first as usize] += 1;
+        }
+        assert!(seen[0] > 0 && seen[1] > 0, "{:?}", seen);
+
+        let mut tn = TensorNetwork::new(2, 4);
+        assert!(tn.measure_qubit_collapse(2).is_err());
+    }
 }
@@ -24,6 +24,31 @@ pub enum FoldingType {
     Global,
 }
 
+/// 적응형 스케일 선택 설정
+///
+/// 스케일 1.0에서 시작해 외삽 추정 오차가 `tolerance` 이하가 되거나
+/// `max_scale`에 도달할 때까지 스케일 팩터를 하나씩 추가
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveScaling {
+    /// 허용 추정 오차 (외삽값의 표준오차)
+    pub tolerance: f64,
+    /// 스케일 팩터 증가폭 (LocalFolder는 홀수 스케일만 구현하므로 기본 2.0)
+    pub scale_step: f64,
+    /// 최대 스케일 팩터
+    pub max_scale: f64,
+}
+
+impl AdaptiveScaling {
+    /// 허용 오차와 최대 스케일로 생성 (증가폭 2.0)
+    pub fn new(tolerance: f64, max_scale: f64) -> Self {
+        Self {
+            tolerance,
+            scale_step: 2.0,
+            max_scale,
+        }
+    }
+}
+
 /// ZNE 실행 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZneConfig {
@@ -39,6 +64,10 @@ pub struct ZneConfig {
 
     /// 측정 샷 수
     pub shots: usize,
+
+    /// 적응형 스케일 선택 (설정 시 `scale_factors` 대신 사용)
+    #[serde(default)]
+    pub adaptive: Option<AdaptiveScaling>,
 }
 
 impl Default for ZneConfig {
@@ -48,6 +77,7 @@ impl Default for ZneConfig {
             scale_factors: vec![1.0, 2.0, 3.0],
             folding_type: FoldingType::Local,
             shots: 1024,
+            adaptive: None,
         }
     }
 }
@@ -84,6 +114,12 @@ impl ZneConfig {
         self.shots = shots;
         self
     }
+
+    /// 적응형 스케일 선택 설정
+    pub fn with_adaptive(mut self, adaptive: AdaptiveScaling) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
 }
 
 #[cfg(test)]
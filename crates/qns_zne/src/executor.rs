@@ -2,12 +2,13 @@
 
 use qns_core::prelude::*;
 
-use crate::config::{ExtrapolationMethod, ZneConfig};
+use crate::config::{AdaptiveScaling, ExtrapolationMethod, FoldingType, ZneConfig};
 use crate::error::{ZneError, ZneResult};
 use crate::extrapolator::{
-    ExponentialExtrapolator, Extrapolator, LinearExtrapolator, RichardsonExtrapolator,
+    linear_intercept_std_error, ExponentialExtrapolator, Extrapolator, LinearExtrapolator,
+    RichardsonExtrapolator,
 };
-use crate::folding::{CircuitFolder, LocalFolder};
+use crate::folding::{CircuitFolder, GlobalFolder, LocalFolder};
 
 /// 회로 실행기 트레이트
 ///
@@ -41,8 +42,12 @@ impl CircuitExecutor for FidelityEstimator {
 
         for gate in &circuit.gates {
             let error = match gate {
-                Gate::CNOT(_, _) | Gate::CZ(_, _) | Gate::SWAP(_, _) => self.base_error_2q,
-                Gate::Measure(_) => 0.0,
+                Gate::CNOT(_, _)
+                | Gate::CZ(_, _)
+                | Gate::CPhase(_, _, _)
+                | Gate::SWAP(_, _)
+                | Gate::FSim(_, _, _, _) => self.base_error_2q,
+                Gate::Measure(_) | Gate::Reset(_) => 0.0,
                 _ => self.base_error_1q,
             };
             fidelity *= 1.0 - error;
@@ -66,11 +71,17 @@ pub struct ZneExecutor<E: CircuitExecutor> {
 
 impl<E: CircuitExecutor> ZneExecutor<E> {
     /// 새 ZNE 실행기 생성
+    ///
+    /// 폴더는 `config.folding_type`에 따라 선택
     pub fn new(config: ZneConfig, executor: E) -> Self {
+        let folder: Box<dyn CircuitFolder + Send + Sync> = match config.folding_type {
+            FoldingType::Local => Box::new(LocalFolder::default()),
+            FoldingType::Global => Box::new(GlobalFolder::new()),
+        };
         Self {
             config,
             executor,
-            folder: Box::new(LocalFolder::default()),
+            folder,
         }
     }
 
@@ -81,7 +92,13 @@ impl<E: CircuitExecutor> ZneExecutor<E> {
     }
 
     /// ZNE 실행 - 제로-노이즈 기댓값 추정
+    ///
+    /// `config.adaptive`가 설정되면 고정 스케일 목록 대신 적응형 선택 사용
     pub fn execute(&self, circuit: &CircuitGenome) -> ZneResult<ZneExecutionResult> {
+        if let Some(adaptive) = self.config.adaptive {
+            return self.execute_adaptive(circuit, adaptive);
+        }
+
         // 1. 스케일 팩터 검증
         if self.config.scale_factors.len() < 2 {
             return Err(ZneError::InsufficientScaleFactors {
@@ -92,26 +109,72 @@ impl<E: CircuitExecutor> ZneExecutor<E> {
 
         // 2. 각 스케일 팩터에 대해 회로 폴딩 및 실행
         let mut data_points: Vec<(f64, f64)> = Vec::new();
-        let mut scaled_circuits: Vec<(f64, CircuitGenome)> = Vec::new();
-
         for &scale in &self.config.scale_factors {
-            // 회로 폴딩
-            let folded = self.folder.fold(circuit, scale)?;
+            data_points.push(self.measure(circuit, scale)?);
+        }
 
-            // 실행
-            let expectation = self.executor.execute(&folded, self.config.shots)?;
+        // 3. 외삽
+        self.finish(data_points)
+    }
 
-            data_points.push((scale, expectation));
-            scaled_circuits.push((scale, folded));
+    /// 적응형 ZNE 실행
+    ///
+    /// 스케일 1.0에서 시작해 `scale_step`씩 스케일을 추가하며, 3개 이상의
+    /// 포인트에서 외삽 표준오차가 `tolerance` 이하가 되면 조기 종료.
+    /// 다음 스케일이 `max_scale`을 넘으면 그때까지의 포인트로 외삽
+    fn execute_adaptive(
+        &self,
+        circuit: &CircuitGenome,
+        adaptive: AdaptiveScaling,
+    ) -> ZneResult<ZneExecutionResult> {
+        if adaptive.scale_step <= 0.0 || 1.0 + adaptive.scale_step > adaptive.max_scale {
+            return Err(ZneError::InsufficientScaleFactors {
+                required: 2,
+                provided: 1,
+            });
         }
 
-        // 3. 외삽
+        let mut data_points = vec![self.measure(circuit, 1.0)?];
+        let mut scale = 1.0;
+        loop {
+            scale += adaptive.scale_step;
+            if scale > adaptive.max_scale + 1e-9 {
+                break;
+            }
+            data_points.push(self.measure(circuit, scale)?);
+
+            if data_points.len() >= 3
+                && linear_intercept_std_error(&data_points)? <= adaptive.tolerance
+            {
+                break;
+            }
+        }
+
+        self.finish(data_points)
+    }
+
+    /// 회로를 폴딩하여 주어진 스케일에서 기댓값 측정
+    ///
+    /// (폴더가 보고한 유효 스케일, 기댓값) 반환
+    fn measure(&self, circuit: &CircuitGenome, scale: f64) -> ZneResult<(f64, f64)> {
+        let folded = self.folder.fold(circuit, scale)?;
+        let effective = self.folder.effective_scale(circuit, &folded, scale);
+        Ok((
+            effective,
+            self.executor.execute(&folded, self.config.shots)?,
+        ))
+    }
+
+    /// 측정 데이터를 외삽하여 결과 생성
+    fn finish(&self, data_points: Vec<(f64, f64)>) -> ZneResult<ZneExecutionResult> {
         let zero_noise_value = self.extrapolate(&data_points)?;
+        let estimated_error = linear_intercept_std_error(&data_points).ok();
 
         Ok(ZneExecutionResult {
             zero_noise_value,
             data_points,
             method: self.config.method,
+            estimated_error,
         })
     }
 
@@ -130,10 +193,14 @@ impl<E: CircuitExecutor> ZneExecutor<E> {
 pub struct ZneExecutionResult {
     /// 제로-노이즈 외삽 기댓값
     pub zero_noise_value: f64,
-    /// 각 스케일 팩터에서의 측정값
+    /// 각 스케일 팩터에서의 (유효 스케일, 측정값)
+    ///
+    /// 유효 스케일은 폴더의 [`CircuitFolder::effective_scale`] 값
     pub data_points: Vec<(f64, f64)>,
     /// 사용된 외삽 방법
     pub method: ExtrapolationMethod,
+    /// 외삽값의 추정 표준오차 (선형 피팅 기준, 포인트 3개 이상일 때)
+    pub estimated_error: Option<f64>,
 }
 
 impl ZneExecutionResult {
@@ -152,6 +219,51 @@ impl ZneExecutionResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zne_executor_global_reports_realized_scale() {
+        let config = ZneConfig {
+            folding_type: FoldingType::Global,
+            ..ZneConfig::default()
+        };
+        assert_eq!(config.scale_factors, vec![1.0, 2.0, 3.0]);
+        let zne = ZneExecutor::new(config, FidelityEstimator::default());
+
+        let mut circuit = CircuitGenome::new(2);
+        circuit
+            .add_gates([Gate::H(0), Gate::CNOT(0, 1), Gate::Measure(0)])
+            .unwrap();
+        let result = zne.execute(&circuit).unwrap();
+
+        // 2.0은 2k+1 = 3배로 실행되므로 x = 3으로 기록
+        let xs: Vec<f64> = result.data_points.iter().map(|(x, _)| *x).collect();
+        assert_eq!(xs, vec![1.0, 3.0, 3.0]);
+        let (_, y1) = result.data_points[0];
+        let (_, y3) = result.data_points[1];
+        assert_eq!(y3, result.data_points[2].1);
+        let expected = y1 - (y3 - y1) / 2.0;
+        assert!(
+            (result.zero_noise_value - expected).abs() < 1e-12,
+            "{} vs {}",
+            result.zero_noise_value,
+            expected
+        );
+
+        // 중복 스케일로는 Richardson 보간 불가
+        let richardson = ZneExecutor::new(
+            ZneConfig {
+                method: ExtrapolationMethod::Richardson,
+                folding_type: FoldingType::Global,
+                scale_factors: vec![1.0, 2.0, 3.0],
+                ..ZneConfig::default()
+            },
+            FidelityEstimator::default(),
+        );
+        assert!(matches!(
+            richardson.execute(&circuit),
+            Err(ZneError::ExtrapolationFailed(_))
+        ));
+    }
+
     #[test]
     fn test_zne_executor_linear() {
         let config = ZneConfig::linear();
@@ -170,16 +282,104 @@ mod tests {
         assert!(result.data_points.len() >= 2);
     }
 
+    #[test]
+    fn test_zne_executor_richardson_preset() {
+        let config = ZneConfig::richardson();
+        let scales = config.scale_factors.clone();
+        let zne = ZneExecutor::new(config, FidelityEstimator::default());
+
+        // CNOT 4개: 1.5, 2.0, 2.5배가 정확히 6, 8, 10개로 폴딩됨
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        for _ in 0..4 {
+            circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        }
+        let result = zne.execute(&circuit).unwrap();
+
+        // 기본 LocalFolder는 요청한 스케일을 그대로 x축으로 사용
+        let xs: Vec<f64> = result.data_points.iter().map(|(x, _)| *x).collect();
+        assert_eq!(xs, scales);
+        assert_eq!(result.method, ExtrapolationMethod::Richardson);
+
+        // 노이즈 없는 값: H의 1-qubit 에러만 남음
+        let ideal = 1.0 - FidelityEstimator::default().base_error_1q;
+        assert!(
+            (result.zero_noise_value - ideal).abs() < 1e-4,
+            "{} vs {}",
+            result.zero_noise_value,
+            ideal
+        );
+    }
+
     #[test]
     fn test_improvement_calculation() {
         let result = ZneExecutionResult {
             zero_noise_value: 0.95,
             data_points: vec![(1.0, 0.90), (2.0, 0.85), (3.0, 0.80)],
             method: ExtrapolationMethod::Linear,
+            estimated_error: None,
         };
 
         let improvement = result.improvement();
         // (0.95 - 0.90) / 0.90 * 100 ≈ 5.56%
         assert!(improvement > 5.0 && improvement < 6.0);
     }
+
+    /// 폴딩 배율을 게이트 수로 추정하는 합성 실행기
+    ///
+    /// E(λ) = 0.9 - 0.05λ에 홀수 스케일마다 부호가 바뀌는 ±`jitter` 추가
+    struct SyntheticExecutor {
+        base_gates: usize,
+        jitter: f64,
+    }
+
+    impl CircuitExecutor for SyntheticExecutor {
+        fn execute(&self, circuit: &CircuitGenome, _shots: usize) -> ZneResult<f64> {
+            let scale = circuit.gates.len() as f64 / self.base_gates as f64;
+            let fold = ((scale - 1.0) / 2.0).round() as i32;
+            Ok(0.9 - 0.05 * scale + self.jitter * f64::from((-1i32).pow(fold as u32)))
+        }
+    }
+
+    #[test]
+    fn test_adaptive_scaling_stops_when_precise() {
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        circuit.add_gate(Gate::CZ(0, 1)).unwrap();
+        let config = ZneConfig::linear().with_adaptive(AdaptiveScaling::new(1e-3, 11.0));
+
+        // 정확한 직선: 최소 3개 포인트에서 종료
+        let precise = ZneExecutor::new(
+            config.clone(),
+            SyntheticExecutor {
+                base_gates: 2,
+                jitter: 0.0,
+            },
+        );
+        let result = precise.execute(&circuit).unwrap();
+        let scales: Vec<f64> = result.data_points.iter().map(|(s, _)| *s).collect();
+        assert_eq!(scales, vec![1.0, 3.0, 5.0]);
+        assert!((result.zero_noise_value - 0.9).abs() < 1e-9);
+        assert!(result.estimated_error.unwrap() < 1e-9);
+
+        // 노이즈가 큰 데이터: 최대 스케일까지 포인트 추가
+        let noisy = ZneExecutor::new(
+            config,
+            SyntheticExecutor {
+                base_gates: 2,
+                jitter: 0.02,
+            },
+        );
+        let result = noisy.execute(&circuit).unwrap();
+        let scales: Vec<f64> = result.data_points.iter().map(|(s, _)| *s).collect();
+        assert_eq!(scales, vec![1.0, 3.0, 5.0, 7.0, 9.0, 11.0]);
+        assert!(result.estimated_error.unwrap() > 1e-3);
+
+        // 두 번째 스케일조차 상한을 넘으면 에러
+        let capped = ZneExecutor::new(
+            ZneConfig::linear().with_adaptive(AdaptiveScaling::new(1e-3, 2.0)),
+            FidelityEstimator::default(),
+        );
+        assert!(capped.execute(&circuit).is_err());
+    }
 }
@@ -54,6 +54,39 @@ impl Extrapolator for LinearExtrapolator {
     }
 }
 
+/// 선형 피팅 절편 E(0)의 표준오차
+///
+/// 잔차 분산 s² = RSS / (n - 2)로부터 SE(a) = sqrt(s² Σx² / (nΣx² - (Σx)²)).
+/// 자유도 확보를 위해 3개 이상의 데이터 포인트 필요
+pub fn linear_intercept_std_error(data: &[(f64, f64)]) -> ZneResult<f64> {
+    if data.len() < 3 {
+        return Err(ZneError::InsufficientScaleFactors {
+            required: 3,
+            provided: data.len(),
+        });
+    }
+
+    let n = data.len() as f64;
+    let sum_x: f64 = data.iter().map(|(x, _)| x).sum();
+    let sum_y: f64 = data.iter().map(|(_, y)| y).sum();
+    let sum_xy: f64 = data.iter().map(|(x, y)| x * y).sum();
+    let sum_x2: f64 = data.iter().map(|(x, _)| x * x).sum();
+
+    let denom = n * sum_x2 - sum_x * sum_x;
+    if denom.abs() < 1e-15 {
+        return Err(ZneError::ExtrapolationFailed(
+            "Degenerate data points".to_string(),
+        ));
+    }
+
+    let b = (n * sum_xy - sum_x * sum_y) / denom;
+    let a = (sum_y - b * sum_x) / n;
+    let rss: f64 = data.iter().map(|(x, y)| (y - a - b * x).powi(2)).sum();
+    let variance = rss / (n - 2.0);
+
+    Ok((variance * sum_x2 / denom).sqrt())
+}
+
 /// Richardson 외삽
 ///
 /// 다항식 피팅: E(λ) = a₀ + a₁λ + a₂λ² + ...
@@ -103,6 +136,12 @@ impl Extrapolator for RichardsonExtrapolator {
             let (x1, y1) = data[0];
             let (x2, y2) = data[1];
             let (x3, y3) = data[2];
+            if (x1 - x2).abs() < 1e-12 || (x1 - x3).abs() < 1e-12 || (x2 - x3).abs() < 1e-12 {
+                return Err(ZneError::ExtrapolationFailed(format!(
+                    "Duplicate scale factors in {:?}",
+                    [x1, x2, x3]
+                )));
+            }
 
             // Lagrange 보간
             // L₀(0) = (0-x₂)(0-x₃) / (x₁-x₂)(x₁-x₃)
@@ -122,6 +161,96 @@ impl Extrapolator for RichardsonExtrapolator {
     }
 }
 
+/// 다항식 최소제곱 외삽
+///
+/// E(λ) = a₀ + a₁λ + … + a_dλ^d 를 최소제곱으로 피팅하고 E(0) = a₀ 반환.
+/// 차수 d는 고정이며 d+1개 이상의 데이터 포인트 필요.
+/// 데이터가 d+1개보다 많으면 노이즈를 평균화하는 회귀가 됨
+pub struct PolynomialExtrapolator {
+    order: usize,
+}
+
+impl PolynomialExtrapolator {
+    /// 차수 `order` 다항식 외삽기 생성
+    pub fn new(order: usize) -> Self {
+        Self { order }
+    }
+
+    /// 다항식 차수
+    pub fn order(&self) -> usize {
+        self.order
+    }
+}
+
+impl Extrapolator for PolynomialExtrapolator {
+    fn extrapolate(&self, data: &[(f64, f64)]) -> ZneResult<f64> {
+        let cols = self.order + 1;
+        if data.len() < cols {
+            return Err(ZneError::InsufficientScaleFactors {
+                required: cols,
+                provided: data.len(),
+            });
+        }
+
+        // 조건수 개선을 위해 λ를 최대 |λ|로 정규화 (절편은 불변)
+        let scale = data.iter().map(|(x, _)| x.abs()).fold(0.0, f64::max);
+        if scale < 1e-15 {
+            return Err(ZneError::ExtrapolationFailed(
+                "Degenerate data points".to_string(),
+            ));
+        }
+
+        // Vandermonde 행렬 [1, t, t², …] 와 우변 y
+        let mut a: Vec<Vec<f64>> = data
+            .iter()
+            .map(|(x, _)| {
+                let t = x / scale;
+                std::iter::successors(Some(1.0), |p| Some(p * t))
+                    .take(cols)
+                    .collect()
+            })
+            .collect();
+        let mut b: Vec<f64> = data.iter().map(|(_, y)| *y).collect();
+
+        // Householder QR: A = QR, 최소제곱해는 R c = Qᵀ y
+        for k in 0..cols {
+            let norm = a[k..].iter().map(|row| row[k] * row[k]).sum::<f64>().sqrt();
+            if norm < 1e-12 {
+                return Err(ZneError::ExtrapolationFailed(format!(
+                    "Degenerate data points for order {} fit",
+                    self.order
+                )));
+            }
+            let alpha = if a[k][k] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = a[k..].iter().map(|row| row[k]).collect();
+            v[0] -= alpha;
+            let v_norm2: f64 = v.iter().map(|x| x * x).sum();
+
+            for j in k..cols {
+                let dot: f64 = v.iter().zip(&a[k..]).map(|(vi, row)| vi * row[j]).sum();
+                let f = 2.0 * dot / v_norm2;
+                for (vi, row) in v.iter().zip(&mut a[k..]) {
+                    row[j] -= f * vi;
+                }
+            }
+            let dot: f64 = v.iter().zip(&b[k..]).map(|(vi, bi)| vi * bi).sum();
+            let f = 2.0 * dot / v_norm2;
+            for (vi, bi) in v.iter().zip(&mut b[k..]) {
+                *bi -= f * vi;
+            }
+        }
+
+        // 후진 대입
+        let mut coeffs = vec![0.0; cols];
+        for k in (0..cols).rev() {
+            let tail: f64 = (k + 1..cols).map(|j| a[k][j] * coeffs[j]).sum();
+            coeffs[k] = (b[k] - tail) / a[k][k];
+        }
+
+        Ok(coeffs[0])
+    }
+}
+
 /// 지수 외삽 (간단한 구현)
 ///
 /// E(λ) = a * exp(-b*λ) + c 모델
@@ -204,6 +333,72 @@ mod tests {
         assert!((e0 - 1.0).abs() < 0.1);
     }
 
+    #[test]
+    fn test_linear_intercept_std_error() {
+        // 정확한 직선: 오차 0
+        let exact = vec![(1.0, 0.9), (3.0, 0.7), (5.0, 0.5)];
+        assert!(linear_intercept_std_error(&exact).unwrap() < 1e-12);
+
+        // 잔차 ±0.01: RSS = 0.0006, s² = 0.0006, Σx² = 35, denom = 24
+        let noisy = vec![(1.0, 0.91), (3.0, 0.68), (5.0, 0.51)];
+        let expected = (0.0006_f64 * 35.0 / 24.0).sqrt();
+        assert!((linear_intercept_std_error(&noisy).unwrap() - expected).abs() < 1e-9);
+
+        assert!(linear_intercept_std_error(&exact[..2]).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_fits_quadratic_exactly() {
+        // y = 0.95 - 0.12λ + 0.015λ² (5개 스케일)
+        let f = |x: f64| 0.95 - 0.12 * x + 0.015 * x * x;
+        let data: Vec<(f64, f64)> = [1.0, 1.5, 2.0, 2.5, 3.0]
+            .iter()
+            .map(|&x| (x, f(x)))
+            .collect();
+
+        let e0 = PolynomialExtrapolator::new(2).extrapolate(&data).unwrap();
+        assert!((e0 - 0.95).abs() < 1e-10, "{}", e0);
+
+        // 3차 피팅도 2차 데이터를 정확히 재현
+        let e0 = PolynomialExtrapolator::new(3).extrapolate(&data).unwrap();
+        assert!((e0 - 0.95).abs() < 1e-9, "{}", e0);
+    }
+
+    #[test]
+    fn test_polynomial_order_one_matches_linear() {
+        let data = vec![
+            (1.0, 0.91),
+            (2.0, 0.79),
+            (3.0, 0.72),
+            (4.0, 0.58),
+            (5.0, 0.51),
+        ];
+        let poly = PolynomialExtrapolator::new(1).extrapolate(&data).unwrap();
+        let linear = LinearExtrapolator.extrapolate(&data).unwrap();
+        assert!((poly - linear).abs() < 1e-12, "{} vs {}", poly, linear);
+    }
+
+    #[test]
+    fn test_polynomial_underdetermined() {
+        let data = vec![(1.0, 0.9), (2.0, 0.8), (3.0, 0.7)];
+        let err = PolynomialExtrapolator::new(3)
+            .extrapolate(&data)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ZneError::InsufficientScaleFactors {
+                required: 4,
+                provided: 3
+            }
+        ));
+
+        // 중복 스케일로는 2차 피팅 불가
+        let repeated = vec![(1.0, 0.9), (1.0, 0.91), (2.0, 0.8)];
+        assert!(PolynomialExtrapolator::new(2)
+            .extrapolate(&repeated)
+            .is_err());
+    }
+
     #[test]
     fn test_insufficient_data() {
         let extrap = LinearExtrapolator;
@@ -1,6 +1,7 @@
 //! 게이트 폴딩 (노이즈 증폭) 구현
 //!
 //! Local Folding: G → G · G† · G
+//! Global Folding: C → C · (C† · C)^k
 //! 이를 통해 게이트 에러를 인위적으로 증폭시킴
 
 use qns_core::prelude::*;
@@ -11,6 +12,94 @@ use crate::error::{ZneError, ZneResult};
 pub trait CircuitFolder {
     /// 회로의 노이즈를 scale_factor 배로 증폭
     fn fold(&self, circuit: &CircuitGenome, scale_factor: f64) -> ZneResult<CircuitGenome>;
+
+    /// 폴딩된 회로의 실제 노이즈 배율 (외삽 x축에 사용)
+    ///
+    /// 기본 구현은 요청한 `scale_factor`를 그대로 반환
+    fn effective_scale(
+        &self,
+        _original: &CircuitGenome,
+        _folded: &CircuitGenome,
+        scale_factor: f64,
+    ) -> f64 {
+        scale_factor
+    }
+}
+
+/// 유효 스케일 가중용 게이트 실행 시간 (ns)
+///
+/// 기본값은 `qns_core::physics::gate_times`; 스코어링 설정과 맞추려면
+/// `ScoreConfig`의 같은 이름 필드를 복사
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateDurations {
+    /// 1-qubit 게이트 시간
+    pub gate_time_1q: f64,
+    /// 2-qubit 게이트 시간
+    pub gate_time_2q: f64,
+    /// 측정/리셋 시간
+    pub measure_time: f64,
+}
+
+impl Default for GateDurations {
+    fn default() -> Self {
+        Self {
+            gate_time_1q: gate_times::SINGLE_QUBIT,
+            gate_time_2q: gate_times::TWO_QUBIT,
+            measure_time: gate_times::MEASUREMENT,
+        }
+    }
+}
+
+/// 유효 스케일 팩터 계산 시 게이트별 가중치
+///
+/// 1-qubit 게이트(짧고 에러 낮음)와 2-qubit 게이트(길고 에러 높음)는
+/// 같은 횟수로 폴딩해도 노이즈 증폭량이 다르므로, 가중치 합의 비율
+/// Σw(폴딩 회로) / Σw(원본 회로)를 실제 노이즈 배율로 사용
+#[derive(Debug, Clone, Default)]
+pub enum ScaleWeighting {
+    /// 가중치 없음: 요청한 scale_factor를 그대로 보고
+    #[default]
+    GateCount,
+    /// 게이트 실행 시간 가중
+    Duration(GateDurations),
+    /// 게이트 에러율 가중 (`NoiseVector`의 1q/2q/readout 에러율)
+    ErrorRate(NoiseVector),
+}
+
+impl ScaleWeighting {
+    /// 게이트 하나의 가중치 (`GateCount`는 1.0)
+    fn gate_weight(&self, gate: &Gate) -> f64 {
+        match self {
+            ScaleWeighting::GateCount => 1.0,
+            ScaleWeighting::Duration(config) => {
+                if gate.is_non_unitary() {
+                    config.measure_time
+                } else if gate.is_two_qubit() {
+                    config.gate_time_2q
+                } else if gate.is_three_qubit() {
+                    2.0 * config.gate_time_2q
+                } else {
+                    config.gate_time_1q
+                }
+            },
+            ScaleWeighting::ErrorRate(noise) => {
+                if gate.is_non_unitary() {
+                    noise.readout_error
+                } else if gate.is_two_qubit() {
+                    noise.gate_error_2q
+                } else if gate.is_three_qubit() {
+                    2.0 * noise.gate_error_2q
+                } else {
+                    noise.gate_error_1q
+                }
+            },
+        }
+    }
+
+    /// 회로 전체 가중치 합
+    fn circuit_weight(&self, circuit: &CircuitGenome) -> f64 {
+        circuit.gates.iter().map(|g| self.gate_weight(g)).sum()
+    }
 }
 
 /// Local Folding 구현
@@ -19,14 +108,26 @@ pub trait CircuitFolder {
 /// scale_factor = 1 → 원본
 /// scale_factor = 3 → 모든 게이트 1회 폴딩 (G → G·G†·G)
 /// scale_factor = 5 → 모든 게이트 2회 폴딩 (G → G·G†·G·G†·G)
+///
+/// 홀수가 아닌 배율은 앞쪽 게이트부터 한 번씩 더 폴딩하여, 폴딩 대상
+/// 게이트 수가 scale_factor 배에 가장 가깝도록 맞춤
+/// (예: CNOT 4개에 1.5 → 첫 CNOT만 1회 폴딩하여 6개)
 pub struct LocalFolder {
     /// 2-qubit 게이트만 폴딩할지 여부
     pub fold_only_2q: bool,
+    /// 라우팅이 삽입한 게이트(`metadata.routing_gates`)를 폴딩에서 제외할지 여부
+    pub skip_routing_gates: bool,
+    /// 유효 스케일 팩터 가중 방식
+    pub weighting: ScaleWeighting,
 }
 
 impl Default for LocalFolder {
     fn default() -> Self {
-        Self { fold_only_2q: true }
+        Self {
+            fold_only_2q: true,
+            skip_routing_gates: false,
+            weighting: ScaleWeighting::GateCount,
+        }
     }
 }
 
@@ -42,6 +143,25 @@ impl LocalFolder {
         self
     }
 
+    /// 라우팅 삽입 게이트 제외 설정
+    ///
+    /// 라우팅 SWAP은 알고리즘 게이트가 아니므로, 제외하면
+    /// 알고리즘 게이트의 노이즈만 증폭됨
+    pub fn with_skip_routing_gates(mut self, skip: bool) -> Self {
+        self.skip_routing_gates = skip;
+        self
+    }
+
+    /// 유효 스케일 가중 방식 설정
+    ///
+    /// `GateCount`(기본값)면 [`CircuitFolder::effective_scale`]이 요청한
+    /// scale_factor를, 그 외 방식이면 게이트 시간/에러율로 가중한 실제 노이즈
+    /// 배율을 반환
+    pub fn with_weighting(mut self, weighting: ScaleWeighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
     /// 게이트의 adjoint (†) 반환
     fn adjoint(&self, gate: &Gate) -> Gate {
         match gate {
@@ -53,6 +173,7 @@ impl LocalFolder {
             Gate::CNOT(c, t) => Gate::CNOT(*c, *t),
             Gate::CZ(c, t) => Gate::CZ(*c, *t),
             Gate::SWAP(a, b) => Gate::SWAP(*a, *b),
+            Gate::Toffoli(c1, c2, t) => Gate::Toffoli(*c1, *c2, *t),
 
             // S† 및 T† (역방향 회전)
             Gate::S(q) => Gate::Rz(*q, -std::f64::consts::FRAC_PI_2),
@@ -62,26 +183,31 @@ impl LocalFolder {
             Gate::Rx(q, theta) => Gate::Rx(*q, -theta),
             Gate::Ry(q, theta) => Gate::Ry(*q, -theta),
             Gate::Rz(q, theta) => Gate::Rz(*q, -theta),
+            Gate::U(q, theta, phi, lambda) => Gate::U(*q, -theta, -lambda, -phi),
+            Gate::CPhase(c, t, lambda) => Gate::CPhase(*c, *t, -lambda),
+            Gate::FSim(a, b, theta, phi) => Gate::FSim(*a, *b, -theta, -phi),
 
-            // Measure: 폴딩 불가
+            // Measure/Reset: 폴딩 불가
             Gate::Measure(q) => Gate::Measure(*q),
+            Gate::Reset(q) => Gate::Reset(*q),
         }
     }
 
     /// 게이트가 2-qubit인지 확인
     fn is_2q_gate(&self, gate: &Gate) -> bool {
-        matches!(gate, Gate::CNOT(_, _) | Gate::CZ(_, _) | Gate::SWAP(_, _))
+        gate.is_two_qubit()
+    }
+
+    /// 폴딩 대상 게이트인지 확인
+    ///
+    /// Measure/Reset은 폴딩하지 않으며, `fold_only_2q`면 1-qubit 게이트도 제외
+    fn is_foldable(&self, gate: &Gate) -> bool {
+        !gate.is_non_unitary() && (!self.fold_only_2q || self.is_2q_gate(gate))
     }
 
     /// 단일 게이트 폴딩 (num_folds 회)
     fn fold_gate(&self, gate: &Gate, num_folds: usize) -> Vec<Gate> {
-        if matches!(gate, Gate::Measure(_)) {
-            // Measure는 폴딩하지 않음
-            return vec![gate.clone()];
-        }
-
-        if self.fold_only_2q && !self.is_2q_gate(gate) {
-            // 1-qubit 게이트는 폴딩하지 않음 (옵션)
+        if !self.is_foldable(gate) {
             return vec![gate.clone()];
         }
 
@@ -108,26 +234,142 @@ impl CircuitFolder for LocalFolder {
             return Ok(circuit.clone());
         }
 
-        // 폴딩 횟수 계산: scale_factor = 1 + 2*num_folds
-        // num_folds = (scale_factor - 1) / 2
-        let num_folds = ((scale_factor - 1.0) / 2.0).round() as usize;
+        // 대상 게이트 n개에 총 round((scale_factor - 1) / 2 · n)회 폴딩을
+        // 앞쪽 게이트부터 고르게 배분 (scale_factor = 1 + 2·폴딩 수 / n)
+        let targets: Vec<bool> = circuit
+            .gates
+            .iter()
+            .enumerate()
+            .map(|(i, gate)| {
+                self.is_foldable(gate) && !(self.skip_routing_gates && circuit.is_routing_gate(i))
+            })
+            .collect();
+        let num_targets = targets.iter().filter(|&&t| t).count().max(1);
+        let total_folds = ((scale_factor - 1.0) / 2.0 * num_targets as f64).round() as usize;
+        let (base_folds, extra_folds) = (total_folds / num_targets, total_folds % num_targets);
 
         let mut folded = CircuitGenome::new(circuit.num_qubits);
+        let mut target_index = 0;
+
+        for (i, gate) in circuit.gates.iter().enumerate() {
+            let mut num_folds = 0;
+            if targets[i] {
+                num_folds = base_folds + usize::from(target_index < extra_folds);
+                target_index += 1;
+            }
 
-        for gate in &circuit.gates {
-            let expanded_gates = self.fold_gate(gate, num_folds);
-            for g in expanded_gates {
-                folded.add_gate(g)?;
+            if !circuit.is_routing_gate(i) {
+                folded.add_gates(self.fold_gate(gate, num_folds))?;
+            } else if self.skip_routing_gates {
+                folded.add_routing_gate(gate.clone())?;
+            } else {
+                // 폴딩된 사본도 라우팅 게이트로 유지
+                for g in self.fold_gate(gate, num_folds) {
+                    folded.add_routing_gate(g)?;
+                }
             }
         }
 
         Ok(folded)
     }
+
+    /// `GateCount`는 요청한 scale_factor, 그 외는 가중치 합의 비율
+    fn effective_scale(
+        &self,
+        original: &CircuitGenome,
+        folded: &CircuitGenome,
+        scale_factor: f64,
+    ) -> f64 {
+        if matches!(self.weighting, ScaleWeighting::GateCount) {
+            return scale_factor;
+        }
+        let base = self.weighting.circuit_weight(original);
+        if base <= 0.0 {
+            return scale_factor;
+        }
+        self.weighting.circuit_weight(folded) / base
+    }
+}
+
+/// Global Folding 구현
+///
+/// 회로 전체를 C → C·(C†·C)^k 형태로 확장하여 노이즈 증폭
+/// scale_factor = 2k+1 (홀수 배율로 반올림, 유효 스케일로도 2k+1을 보고)
+/// 측정은 폴딩할 수 없으므로 원래 순서대로 회로 끝으로 이동
+/// (중간 측정 이후의 게이트가 있으면 측정 시점이 바뀜)
+pub struct GlobalFolder;
+
+impl Default for GlobalFolder {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl GlobalFolder {
+    /// 새 GlobalFolder 생성
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 요청 배율에 대한 (C†·C) 반복 횟수 k
+    fn num_folds(scale_factor: f64) -> usize {
+        ((scale_factor - 1.0) / 2.0).round() as usize
+    }
+}
+
+impl CircuitFolder for GlobalFolder {
+    fn fold(&self, circuit: &CircuitGenome, scale_factor: f64) -> ZneResult<CircuitGenome> {
+        if scale_factor < 1.0 {
+            return Err(ZneError::InvalidScaleFactor(scale_factor));
+        }
+
+        if (scale_factor - 1.0).abs() < 1e-9 {
+            return Ok(circuit.clone());
+        }
+
+        let num_folds = Self::num_folds(scale_factor);
+
+        // 측정을 분리한 유니터리 부분 (라우팅 태그 유지)
+        let mut body = CircuitGenome::new(circuit.num_qubits);
+        let mut measurements = Vec::new();
+        for (i, gate) in circuit.gates.iter().enumerate() {
+            if matches!(gate, Gate::Measure(_)) {
+                measurements.push(gate.clone());
+            } else if circuit.is_routing_gate(i) {
+                body.add_routing_gate(gate.clone())?;
+            } else {
+                body.add_gate(gate.clone())?;
+            }
+        }
+        let inverse = body
+            .inverse()
+            .map_err(|e| ZneError::FoldingError(e.to_string()))?;
+
+        let mut folded = body.clone();
+        for _ in 0..num_folds {
+            folded.compose(&inverse);
+            folded.compose(&body);
+        }
+        folded.add_gates(measurements)?;
+
+        Ok(folded)
+    }
+
+    /// 실제로 적용된 홀수 배율 2k+1 (예: 2.0 요청은 3배로 실행됨)
+    fn effective_scale(
+        &self,
+        _original: &CircuitGenome,
+        _folded: &CircuitGenome,
+        scale_factor: f64,
+    ) -> f64 {
+        (2 * Self::num_folds(scale_factor) + 1) as f64
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qns_simulator::StateVectorSimulator;
 
     #[test]
     fn test_fold_scale_1() {
@@ -153,6 +395,162 @@ mod tests {
         assert_eq!(folded.gates.len(), 1 + 3);
     }
 
+    #[test]
+    fn test_fold_partial_scale() {
+        let folder = LocalFolder::new();
+        let mut circuit = CircuitGenome::new(2);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        for _ in 0..4 {
+            circuit.add_gate(Gate::CNOT(0, 1)).unwrap();
+        }
+
+        // CNOT 수가 요청 배율을 따름: 4 × {1.5, 2, 2.5, 3}
+        for (scale, cnots) in [(1.5, 6), (2.0, 8), (2.5, 10), (3.0, 12)] {
+            let folded = folder.fold(&circuit, scale).unwrap();
+            assert_eq!(folded.gates.len(), 1 + cnots, "scale {}", scale);
+        }
+
+        // 추가 폴딩은 앞쪽 게이트부터
+        let folded = folder.fold(&circuit, 1.5).unwrap();
+        assert_eq!(
+            folded.gates[..4],
+            [
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(0, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_global_fold() {
+        let mut circuit = CircuitGenome::new(3);
+        circuit
+            .add_gates([
+                Gate::H(0),
+                Gate::T(0),
+                Gate::CNOT(0, 1),
+                Gate::Measure(1),
+                Gate::Ry(2, 0.7),
+                Gate::CZ(1, 2),
+                Gate::Measure(0),
+            ])
+            .unwrap();
+        let folder = GlobalFolder::new();
+
+        let same = folder.fold(&circuit, 1.0).unwrap();
+        assert_eq!(same.gates, circuit.gates);
+
+        let folded = folder.fold(&circuit, 3.0).unwrap();
+        // 유니터리 5개 × 3 + 측정 2개 (끝으로 이동)
+        assert_eq!(folded.gates.len(), 5 * 3 + 2);
+        assert_eq!(folded.gates[15..], [Gate::Measure(1), Gate::Measure(0)]);
+        assert!(folded.gates[..15].iter().all(|g| !g.is_non_unitary()));
+
+        // C·C†·C = C: 이상적 상태벡터 불변 (중간 측정은 붕괴하므로 유니터리 부분만 비교)
+        let unitary = |gates: &[Gate]| {
+            let mut c = CircuitGenome::new(3);
+            c.add_gates(gates.iter().filter(|g| !g.is_non_unitary()).cloned())
+                .unwrap();
+            c
+        };
+        let mut original = StateVectorSimulator::new(3);
+        original.execute(&unitary(&circuit.gates)).unwrap();
+        let mut amplified = StateVectorSimulator::new(3);
+        amplified.execute(&unitary(&folded.gates)).unwrap();
+        let fidelity = original.fidelity_with(&amplified).unwrap();
+        assert!((fidelity - 1.0).abs() < 1e-10, "{}", fidelity);
+
+        assert_eq!(folder.fold(&circuit, 5.0).unwrap().gates.len(), 5 * 5 + 2);
+        assert!(folder.fold(&circuit, 0.5).is_err());
+
+        // Reset은 역연산이 없음
+        circuit.add_gate(Gate::Reset(2)).unwrap();
+        assert!(matches!(
+            folder.fold(&circuit, 3.0),
+            Err(ZneError::FoldingError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fold_skips_routing_gates() {
+        // 라우팅된 회로: SWAP(0,1)은 라우팅 삽입, CNOT은 알고리즘 게이트
+        let mut circuit = CircuitGenome::new(3);
+        circuit.add_gate(Gate::H(0)).unwrap();
+        circuit.add_routing_gate(Gate::SWAP(0, 1)).unwrap();
+        circuit.add_gate(Gate::CNOT(1, 2)).unwrap();
+
+        let count_swaps = |c: &CircuitGenome| {
+            c.gates
+                .iter()
+                .filter(|g| matches!(g, Gate::SWAP(_, _)))
+                .count()
+        };
+
+        let folded = LocalFolder::new()
+            .with_skip_routing_gates(true)
+            .fold(&circuit, 3.0)
+            .unwrap();
+        assert_eq!(count_swaps(&folded), 1);
+        assert_eq!(folded.gates.len(), 1 + 1 + 3);
+        assert_eq!(folded.metadata.routing_gates, vec![1]);
+
+        // 기본 설정은 SWAP도 폴딩
+        let folded = LocalFolder::new().fold(&circuit, 3.0).unwrap();
+        assert_eq!(count_swaps(&folded), 3);
+    }
+
+    #[test]
+    fn test_weighted_effective_scale() {
+        // 2-qubit 게이트 위주 회로 vs 1-qubit 게이트 위주 회로
+        let mut cnot_heavy = CircuitGenome::new(2);
+        cnot_heavy
+            .add_gates([
+                Gate::H(0),
+                Gate::CNOT(0, 1),
+                Gate::CNOT(1, 0),
+                Gate::CNOT(0, 1),
+            ])
+            .unwrap();
+        let mut h_heavy = CircuitGenome::new(2);
+        h_heavy.add_gates([Gate::H(0), Gate::H(1)]).unwrap();
+        h_heavy.add_gates([Gate::X(0), Gate::X(1)]).unwrap();
+        h_heavy.add_gates([Gate::H(0), Gate::H(1)]).unwrap();
+        h_heavy.add_gate(Gate::CNOT(0, 1)).unwrap();
+
+        let effective = |folder: &LocalFolder, circuit: &CircuitGenome| {
+            let folded = folder.fold(circuit, 3.0).unwrap();
+            folder.effective_scale(circuit, &folded, 3.0)
+        };
+
+        // 기본값: 요청한 스케일 그대로
+        let plain = LocalFolder::new();
+        assert_eq!(effective(&plain, &cnot_heavy), 3.0);
+        assert_eq!(effective(&plain, &h_heavy), 3.0);
+        let folded = plain.fold(&cnot_heavy, 2.0).unwrap();
+        assert_eq!(plain.effective_scale(&cnot_heavy, &folded, 2.0), 2.0);
+
+        // 시간 가중: (35 + 3·900) / (35 + 900), (210 + 900) / (210 + 300)
+        let timed =
+            LocalFolder::new().with_weighting(ScaleWeighting::Duration(GateDurations::default()));
+        let heavy_2q = effective(&timed, &cnot_heavy);
+        let heavy_1q = effective(&timed, &h_heavy);
+        assert!((heavy_2q - 2735.0 / 935.0).abs() < 1e-9, "{}", heavy_2q);
+        assert!((heavy_1q - 1110.0 / 510.0).abs() < 1e-9, "{}", heavy_1q);
+        assert!(heavy_2q > heavy_1q);
+
+        // 에러율 가중도 같은 순서
+        let noisy = LocalFolder::new().with_weighting(ScaleWeighting::ErrorRate(
+            NoiseVector::comprehensive(0, 100.0, 80.0, 0.001, 0.01, 0.02),
+        ));
+        assert!(effective(&noisy, &cnot_heavy) > effective(&noisy, &h_heavy));
+
+        // 스케일 1은 가중 방식과 무관하게 1
+        let folded = timed.fold(&h_heavy, 1.0).unwrap();
+        assert_eq!(timed.effective_scale(&h_heavy, &folded, 1.0), 1.0);
+    }
+
     #[test]
     fn test_adjoint_hermitian() {
         let folder = LocalFolder::new();
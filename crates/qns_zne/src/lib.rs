@@ -5,7 +5,8 @@
 //! ## 주요 기능
 //!
 //! - **LocalFolding**: 게이트 수준 노이즈 증폭 (CNOT → CNOT-CNOT†-CNOT)
-//! - **Extrapolation**: Linear, Richardson, Exponential 외삽 알고리즘
+//! - **GlobalFolding**: 회로 수준 노이즈 증폭 (C → C-C†-C)
+//! - **Extrapolation**: Linear, Richardson, Polynomial, Exponential 외삽 알고리즘
 //! - **ZneExecutor**: 전체 ZNE 파이프라인 실행
 //!
 //! ## 사용 예시
@@ -29,8 +30,11 @@ mod config;
 mod error;
 mod executor;
 
-pub use config::{ExtrapolationMethod, FoldingType, ZneConfig};
+pub use config::{AdaptiveScaling, ExtrapolationMethod, FoldingType, ZneConfig};
 pub use error::{ZneError, ZneResult};
 pub use executor::{CircuitExecutor, FidelityEstimator, ZneExecutionResult, ZneExecutor};
-pub use extrapolator::{Extrapolator, LinearExtrapolator, RichardsonExtrapolator};
-pub use folding::{CircuitFolder, LocalFolder};
+pub use extrapolator::{
+    linear_intercept_std_error, Extrapolator, LinearExtrapolator, PolynomialExtrapolator,
+    RichardsonExtrapolator,
+};
+pub use folding::{CircuitFolder, GateDurations, GlobalFolder, LocalFolder, ScaleWeighting};